@@ -13,7 +13,7 @@
  *   sys thaw --mint <MINT_KEY> --account <TOKEN_ACCOUNT>
  *   sys blacklist add --mint <MINT_KEY> --address <WALLET>
  *   sys blacklist remove --mint <MINT_KEY> --address <WALLET>
- *   sys seize --mint <MINT_KEY> --from <TOKEN_ACCOUNT> --amount 100000
+ *   sys seize --mint <MINT_KEY> --from <TOKEN_ACCOUNT> --amount 100000 --reason "Court order"
  *   sys info --mint <MINT_KEY>
  *   sys role update --mint <MINT_KEY> --role mint_authority --to <NEW_KEY>
  */
@@ -108,8 +108,10 @@ program
     .description("Freeze a token account")
     .requiredOption("--mint <mint>", "Mint address")
     .requiredOption("--account <account>", "Token account to freeze")
+    .option("--reason <reason>", "Why the account is frozen (kept on-chain)", "")
     .action(async (opts) => {
         console.log(`\n❄️  Freezing account ${opts.account}...`);
+        if (opts.reason) console.log(`  Reason: ${opts.reason}`);
         console.log(`  ✅ Account frozen!\n`);
     });
 
@@ -159,10 +161,12 @@ program
     .requiredOption("--mint <mint>", "Mint address")
     .requiredOption("--from <from>", "Token account to seize from")
     .requiredOption("--amount <amount>", "Amount to seize")
-    .option("--to <to>", "Treasury account (defaults to authority)")
+    .option("--to <to>", "Destination account, e.g. treasury or escrow (defaults to authority)")
+    .requiredOption("--reason <reason>", "Legal basis recorded with the seizure")
     .action(async (opts) => {
         console.log(`\n⚖️  Seizing ${opts.amount} tokens from ${opts.from}...`);
-        console.log(`  ✅ Tokens seized to treasury!\n`);
+        console.log(`  Reason: ${opts.reason}`);
+        console.log(`  ✅ Tokens seized!\n`);
     });
 
 // ── Info ──────────────────────────────────────────────
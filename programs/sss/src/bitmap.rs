@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::state::BlacklistBitmap;
+
+/// Domain separator so bitmap indices never collide with other hashes of an address
+const BITMAP_PREFIX: &[u8] = b"sss-blacklist-bitmap";
+
+/// Bit addressed by `owner`: the first four bytes of `sha256(prefix || owner)`,
+/// reduced to the bitmap width. With `BITS` = 8192 (a 13-bit index) and `n`
+/// flagged owners, an unlisted owner is falsely blocked with probability ~n/8192.
+pub fn bit_index(owner: &Pubkey) -> usize {
+    let hash = hashv(&[BITMAP_PREFIX, owner.as_ref()]).to_bytes();
+    u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]]) as usize % BlacklistBitmap::BITS
+}
+
+/// Returns true if `owner`'s bit is set in the mint's bitmap.
+/// Reads the raw account so the hook never copies the full bit array.
+pub fn is_flagged(info: &AccountInfo, mint: &Pubkey, owner: &Pubkey) -> Result<bool> {
+    if info.data_is_empty() || info.owner != &crate::ID {
+        return Ok(false);
+    }
+    let data = info.try_borrow_data()?;
+    if data.len() < BlacklistBitmap::SPACE
+        || data[..8] != BlacklistBitmap::DISCRIMINATOR
+        || data[8..40] != mint.to_bytes()
+    {
+        return Ok(false);
+    }
+
+    let index = bit_index(owner);
+    Ok(data[BlacklistBitmap::BITS_OFFSET + index / 8] & (1 << (index % 8)) != 0)
+}
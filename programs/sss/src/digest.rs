@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+/// Domain separator so a digest can never be confused with other hashes of addresses
+const DIGEST_PREFIX: &[u8] = b"sss-blacklist-digest";
+
+/// Start of the blacklist digest chain: `d_0 = keccak256(prefix)`.
+///
+/// The digest of a blacklist set is `d_n`, where `d_i = keccak256(d_{i-1} || a_i)` over
+/// the addresses `a_1 < ... < a_n` in ascending order. Folding in a fixed order makes the
+/// digest a function of the set alone, and lets it be built up page by page.
+pub fn digest_seed() -> [u8; 32] {
+    hashv(&[DIGEST_PREFIX]).to_bytes()
+}
+
+/// Fold the next address (in ascending order) into the running digest
+pub fn fold_address(digest: &[u8; 32], address: &Pubkey) -> [u8; 32] {
+    hashv(&[digest, address.as_ref()]).to_bytes()
+}
@@ -8,6 +8,12 @@ pub enum SSSError {
     #[msg("Symbol too long (max 10 characters)")]
     SymbolTooLong,
 
+    #[msg("URI too long (max 200 characters)")]
+    UriTooLong,
+
+    #[msg("Invalid decimals (max 9, and must match the mint)")]
+    InvalidDecimals,
+
     #[msg("Unauthorized: caller lacks the required role")]
     Unauthorized,
 
@@ -46,4 +52,250 @@ pub enum SSSError {
 
     #[msg("Invalid role for this operation")]
     InvalidRole,
+
+    #[msg("Stablecoin is paused")]
+    ProgramPaused,
+
+    #[msg("No pending authority transfer to accept")]
+    NoPendingAuthority,
+
+    #[msg("Blacklist and allowlist modes are mutually exclusive")]
+    ListModeConflict,
+
+    #[msg("Allowlist mode not enabled for this stablecoin")]
+    AllowlistModeRequired,
+
+    #[msg("Address is already allowlisted")]
+    AlreadyAllowlisted,
+
+    #[msg("Address is not allowlisted")]
+    NotAllowlisted,
+
+    #[msg("Batch too large (max 15 entries)")]
+    BatchTooLarge,
+
+    #[msg("Batch amounts do not match the number of token accounts")]
+    BatchLengthMismatch,
+
+    #[msg("Token account does not belong to this mint")]
+    MintMismatch,
+
+    #[msg("Stablecoin is already SSS-2")]
+    AlreadySSS2,
+
+    #[msg("Mint is missing a required Token-2022 extension")]
+    MissingExtension,
+
+    #[msg("A required optional account was not provided")]
+    MissingAccount,
+
+    #[msg("Note too long (max 128 characters)")]
+    NoteTooLong,
+
+    #[msg("Expiry must be in the future")]
+    InvalidExpiry,
+
+    #[msg("Role has been permanently renounced")]
+    RoleRenounced,
+
+    #[msg("Transfer amount exceeds the per-transfer limit")]
+    TransferLimitExceeded,
+
+    #[msg("Mint would exceed the daily mint limit")]
+    DailyMintLimitExceeded,
+
+    #[msg("Target account owner is blacklisted (pass force to override)")]
+    TargetBlacklisted,
+
+    #[msg("Memo too long (max 128 characters)")]
+    MemoTooLong,
+
+    #[msg("Account does not match the expected PDA")]
+    InvalidPda,
+
+    #[msg("Role change timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("No role change is pending")]
+    NoPendingRoleChange,
+
+    #[msg("Delay must not be negative")]
+    InvalidDelay,
+
+    #[msg("Reason too long (max 128 characters)")]
+    ReasonTooLong,
+
+    #[msg("Timestamp is in the future or too far in the past")]
+    InvalidTimestamp,
+
+    #[msg("Invalid destination account")]
+    InvalidDestination,
+
+    #[msg("Mint authority rotation cooldown is still active")]
+    RotationCooldownActive,
+
+    #[msg("Fee basis points exceed 10000")]
+    InvalidFeeBasisPoints,
+
+    #[msg("Reference too long (max 32 bytes)")]
+    ReferenceTooLong,
+
+    #[msg("Redemption has already been fulfilled")]
+    RedemptionAlreadyFulfilled,
+
+    #[msg("Source and destination accounts are the same")]
+    SelfTransfer,
+
+    #[msg("Account has been active within the dormancy threshold")]
+    AccountNotDormant,
+
+    #[msg("Compliance officer list is full (max 10)")]
+    OfficerListFull,
+
+    #[msg("Address is already a compliance officer")]
+    OfficerAlreadyListed,
+
+    #[msg("Address is not a compliance officer")]
+    OfficerNotListed,
+
+    #[msg("Permanent delegate and transfer hook must be enabled together")]
+    InconsistentPreset,
+
+    #[msg("Stablecoin state is already at the current version")]
+    AlreadyMigrated,
+
+    #[msg("Interest rate out of range (max ±5000 basis points)")]
+    InvalidInterestRate,
+
+    #[msg("Exempt address list is full (max 8)")]
+    ExemptListFull,
+
+    #[msg("Address is already exempt")]
+    AlreadyExempt,
+
+    #[msg("Address is not exempt")]
+    NotExempt,
+
+    #[msg("Transfer amount is below the minimum transfer amount")]
+    BelowMinimumTransfer,
+
+    #[msg("Oracle price deviates from the peg by more than the allowed threshold")]
+    PegDeviationExceeded,
+
+    #[msg("Oracle account does not match the configured feed or is not a valid price")]
+    InvalidOracle,
+
+    #[msg("Peg deviation threshold out of range (max 10000 basis points)")]
+    InvalidPegDeviation,
+
+    #[msg("Net supply must be zero to release the symbol")]
+    SupplyOutstanding,
+
+    #[msg("Mint allowance exhausted")]
+    AllowanceExhausted,
+
+    #[msg("Transfer cooldown has not elapsed for the source account")]
+    TransferCooldownActive,
+
+    #[msg("Seized tokens must go to a token account owned by the seizure treasury")]
+    InvalidTreasury,
+
+    #[msg("Clawback window has expired (or clawbacks are disabled)")]
+    ClawbackWindowExpired,
+
+    #[msg("Amount exceeds the single-operation cap")]
+    AmountTooLarge,
+
+    #[msg("Sender's KYC tier is too low for this transfer amount")]
+    KycTierInsufficient,
+
+    #[msg("KYC tier or tier thresholds out of range")]
+    InvalidKycTier,
+
+    #[msg("Fee collector does not match the configured mint fee collector")]
+    InvalidFeeCollector,
+
+    #[msg("Symbol is already registered to another stablecoin")]
+    SymbolTaken,
+
+    #[msg("Source and destination must be different accounts")]
+    DuplicateAccount,
+
+    #[msg("Newly minted tokens are still locked in this account")]
+    TokensLocked,
+
+    #[msg("Blacklist has reached its maximum number of entries")]
+    BlacklistFull,
+
+    #[msg("Mirrored transfer fee does not match the mint's transfer-fee extension")]
+    TransferFeeMismatch,
+
+    #[msg("Name must be printable ASCII and symbol uppercase alphanumeric, both non-empty")]
+    InvalidMetadataFormat,
+
+    #[msg("Seizure would exceed the daily seize limit")]
+    DailySeizeLimitExceeded,
+
+    #[msg("Seize cooldown has not elapsed since the last seizure")]
+    SeizeCooldownActive,
+
+    #[msg("Token is non-transferable: peer-to-peer transfers are disabled")]
+    NonTransferable,
+
+    #[msg("Multisig threshold must be between 1 and the number of distinct signers")]
+    InvalidMultisigConfig,
+
+    #[msg("Action does not have enough approvals to execute")]
+    InsufficientApprovals,
+
+    #[msg("Signer has already approved this action")]
+    AlreadyApproved,
+
+    #[msg("Action has already been executed")]
+    ActionAlreadyExecuted,
+
+    #[msg("Account is already under an active compliance hold")]
+    HoldAlreadyActive,
+
+    #[msg("Account has no active compliance hold")]
+    NoActiveHold,
+
+    #[msg("Configuration is permanently locked")]
+    ConfigLocked,
+
+    #[msg("Seizures require a second compliance officer; use propose_seizure")]
+    DualSeizeRequired,
+
+    #[msg("The approving officer must differ from the proposer")]
+    SameOfficerApproval,
+
+    #[msg("Reissue amount exceeds the original seizure")]
+    ReissueExceedsSeizure,
+
+    #[msg("Cannot thaw an account whose owner is blacklisted")]
+    CannotThawBlacklisted,
+
+    #[msg("Transfer would leave a balance below the account minimum")]
+    WouldLeaveDust,
+
+    #[msg("State account data does not match its stored layout version")]
+    InvalidStateLayout,
+
+    #[msg("Blacklist entries must be passed in strictly increasing address order")]
+    BlacklistNotSorted,
+
+    #[msg("Active blacklist entries passed do not match active_blacklist_count")]
+    IncompleteBlacklist,
+
+    #[msg("Pre-versioning state must be upgraded with migrate_state")]
+    LegacyStateLayout,
+
+    #[msg("The transfer would not be blocked by the transfer hook")]
+    TransferNotBlocked,
+
+    #[msg("The Token-2022 freeze authority must be delegated to the stablecoin PDA")]
+    FreezeAuthorityNotDelegated,
+
+    #[msg("No blacklist digest pass is in progress; start one with begin_blacklist_digest")]
+    NoDigestInProgress,
 }
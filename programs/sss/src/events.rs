@@ -1,21 +1,111 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{RoundingPolicy, DustPolicy};
+
 #[event]
 pub struct StablecoinInitialized {
     pub mint: Pubkey,
     pub authority: Pubkey,
     pub name: String,
     pub symbol: String,
+    pub uri: String,
     pub decimals: u8,
     pub preset: String,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct PresetUpgraded {
+    pub mint: Pubkey,
+    pub old_preset: String,
+    pub new_preset: String,
+    pub compliance_officer: Pubkey,
+    pub upgraded_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StateMigrated {
+    pub mint: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub migrated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StateReallocated {
+    pub mint: Pubkey,
+    pub old_size: u32,
+    pub new_size: u32,
+    pub reallocated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub mint: Pubkey,
+    pub old_symbol: String,
+    pub new_symbol: String,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SymbolReleased {
+    pub mint: Pubkey,
+    pub symbol: String,
+    pub released_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TokensMinted {
     pub mint: Pubkey,
     pub to: Pubkey,
     pub amount: u64,
+    pub ui_amount: String,
+    pub minted_by: Pubkey,
+    pub total_minted: u64,
+    pub memo: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintFeeCollected {
+    pub mint: Pubkey,
+    pub to: Pubkey,
+    pub collector: Pubkey,
+    pub gross_amount: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintAllowanceGranted {
+    pub mint: Pubkey,
+    pub delegate: Pubkey,
+    pub amount: u64,
+    pub granted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintClawedBack {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub minted_at: i64,
+    pub clawed_back_by: Pubkey,
+    pub total_burned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchMintCompleted {
+    pub mint: Pubkey,
+    pub recipients: u8,
+    pub total_amount: u64,
     pub minted_by: Pubkey,
     pub total_minted: u64,
     pub timestamp: i64,
@@ -26,8 +116,61 @@ pub struct TokensBurned {
     pub mint: Pubkey,
     pub from: Pubkey,
     pub amount: u64,
+    pub ui_amount: String,
     pub burned_by: Pubkey,
     pub total_burned: u64,
+    pub memo: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SupplyReconciled {
+    pub mint: Pubkey,
+    pub mint_supply: u64,
+    pub tracked_supply: u64,
+    pub drift: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionRequested {
+    pub mint: Pubkey,
+    pub request: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub reference: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionFulfilled {
+    pub mint: Pubkey,
+    pub request: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub fulfilled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReservesAttested {
+    pub mint: Pubkey,
+    pub period: u64,
+    pub reserves: u64,
+    pub liabilities: u64,
+    pub fully_backed: bool,
+    pub attested_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SupplySnapshotTaken {
+    pub mint: Pubkey,
+    pub epoch: u64,
+    pub total_minted: u64,
+    pub total_burned: u64,
+    pub net_supply: u64,
+    pub taken_by: Pubkey,
     pub timestamp: i64,
 }
 
@@ -37,6 +180,217 @@ pub struct TokensTransferred {
     pub from: Pubkey,
     pub to: Pubkey,
     pub amount: u64,
+    pub ui_amount: String,
+    pub fee: u64,
+    pub cumulative_volume: u128,
+    pub memo: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchTransferCompleted {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub recipients: u8,
+    pub total_amount: u64,
+    pub transferred_by: Pubkey,
+    pub cumulative_volume: u128,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InterestRateUpdated {
+    pub mint: Pubkey,
+    pub old_rate_bps: i16,
+    pub new_rate_bps: i16,
+    pub effective_at: i64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleUpdated {
+    pub mint: Pubkey,
+    pub oracle: Option<Pubkey>,
+    pub max_peg_deviation_bps: u16,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintFeeUpdated {
+    pub mint: Pubkey,
+    pub old_basis_points: u16,
+    pub new_basis_points: u16,
+    pub old_collector: Pubkey,
+    pub new_collector: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeRoundingUpdated {
+    pub mint: Pubkey,
+    pub old_policy: RoundingPolicy,
+    pub new_policy: RoundingPolicy,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinAccountBalanceUpdated {
+    pub mint: Pubkey,
+    pub old_minimum: Option<u64>,
+    pub new_minimum: Option<u64>,
+    pub old_policy: DustPolicy,
+    pub new_policy: DustPolicy,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferFeeUpdated {
+    pub mint: Pubkey,
+    pub old_basis_points: u16,
+    pub new_basis_points: u16,
+    pub old_max_fee: u64,
+    pub new_max_fee: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinTransferUpdated {
+    pub mint: Pubkey,
+    pub old_minimum: Option<u64>,
+    pub new_minimum: Option<u64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxSingleOpAmountUpdated {
+    pub mint: Pubkey,
+    pub old_max: Option<u64>,
+    pub new_max: Option<u64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KycThresholdsUpdated {
+    pub mint: Pubkey,
+    pub old_thresholds: [u64; 3],
+    pub new_thresholds: [u64; 3],
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClawbackWindowUpdated {
+    pub mint: Pubkey,
+    pub old_window: Option<i64>,
+    pub new_window: Option<i64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferCooldownUpdated {
+    pub mint: Pubkey,
+    pub old_cooldown: Option<i64>,
+    pub new_cooldown: Option<i64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LimitExemptionSet {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub exempt: bool,
+    pub set_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeAuthorityUpdated {
+    pub mint: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub collector: Pubkey,
+    pub amount: u64,
+    pub withdrawn_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxBlacklistEntriesUpdated {
+    pub mint: Pubkey,
+    pub old_max: Option<u64>,
+    pub new_max: Option<u64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintLockPeriodUpdated {
+    pub mint: Pubkey,
+    pub old_period: Option<i64>,
+    pub new_period: Option<i64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferLimitUpdated {
+    pub mint: Pubkey,
+    pub old_limit: Option<u64>,
+    pub new_limit: Option<u64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DailyMintLimitUpdated {
+    pub mint: Pubkey,
+    pub old_limit: Option<u64>,
+    pub new_limit: Option<u64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DailySeizeLimitUpdated {
+    pub mint: Pubkey,
+    pub old_limit: Option<u64>,
+    pub new_limit: Option<u64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizeCooldownUpdated {
+    pub mint: Pubkey,
+    pub old_cooldown: Option<i64>,
+    pub new_cooldown: Option<i64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferBatchSizeUpdated {
+    pub mint: Pubkey,
+    pub old_size: u8,
+    pub new_size: u8,
+    pub updated_by: Pubkey,
     pub timestamp: i64,
 }
 
@@ -45,6 +399,35 @@ pub struct AccountFrozenEvent {
     pub mint: Pubkey,
     pub account: Pubkey,
     pub frozen_by: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchFreezeCompleted {
+    pub mint: Pubkey,
+    pub frozen: u8,
+    pub skipped: u8,
+    pub frozen_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchThawCompleted {
+    pub mint: Pubkey,
+    pub thawed: u8,
+    pub skipped: u8,
+    pub thawed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMarkedDormant {
+    pub mint: Pubkey,
+    pub account: Pubkey,
+    pub last_transfer_at: i64,
+    pub inactive_for: i64,
+    pub marked_by: Pubkey,
     pub timestamp: i64,
 }
 
@@ -56,11 +439,57 @@ pub struct AccountThawedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct AccountApproved {
+    pub mint: Pubkey,
+    pub account: Pubkey,
+    pub approved_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AddressBlacklisted {
     pub mint: Pubkey,
     pub address: Pubkey,
     pub added_by: Pubkey,
+    pub reason_code: u16,
+    pub note: String,
+    pub expires_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchBlacklistCompleted {
+    pub mint: Pubkey,
+    pub added: u8,
+    pub skipped: u8,
+    pub added_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchBlacklistCleared {
+    pub mint: Pubkey,
+    pub cleared: u8,
+    pub skipped: u8,
+    pub cleared_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BlacklistCountChanged {
+    pub mint: Pubkey,
+    pub active_blacklist_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BlacklistDigestUpdated {
+    pub mint: Pubkey,
+    pub digest: [u8; 32],
+    pub entry_count: u32,
+    pub expired_count: u32,
+    pub updated_by: Pubkey,
     pub timestamp: i64,
 }
 
@@ -72,15 +501,139 @@ pub struct AddressUnblacklisted {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct BitmapBlacklistUpdated {
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    pub bit_index: u32,
+    pub flagged: bool,
+    pub flagged_bits: u32,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AddressAllowlisted {
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    pub added_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AddressUnallowlisted {
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KycTierSet {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub old_tier: u8,
+    pub new_tier: u8,
+    pub set_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllowlistRootUpdated {
+    pub mint: Pubkey,
+    pub old_root: [u8; 32],
+    pub new_root: [u8; 32],
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizureTreasuryUpdated {
+    pub mint: Pubkey,
+    pub old_treasury: Option<Pubkey>,
+    pub new_treasury: Option<Pubkey>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TokensSeized {
     pub mint: Pubkey,
     pub from: Pubkey,
+    pub to: Pubkey,
     pub amount: u64,
+    pub cumulative_seized: u64,
     pub seized_by: Pubkey,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ForcedTransfer {
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub officer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizedTokensBurned {
+    pub mint: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub total_seized: u64,
+    pub total_burned: u64,
+    pub burned_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizedTokensReissued {
+    pub mint: Pubkey,
+    pub seizure: Pubkey,
+    pub record: Pubkey,
+    pub treasury: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub total_seized: u64,
+    pub reissued_by: Pubkey,
+    pub co_signer: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizureRecorded {
+    pub mint: Pubkey,
+    pub record: Pubkey,
+    pub target: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub officer: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizureProposed {
+    pub mint: Pubkey,
+    pub pending: Pubkey,
+    pub target: Pubkey,
+    pub amount: u64,
+    pub seize_all: bool,
+    pub proposed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizureApproved {
+    pub mint: Pubkey,
+    pub pending: Pubkey,
+    pub proposed_by: Pubkey,
+    pub approved_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct RoleUpdated {
     pub mint: Pubkey,
@@ -91,6 +644,51 @@ pub struct RoleUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct TokenAuthoritySynced {
+    pub mint: Pubkey,
+    pub authority_type: String,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub synced_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleChangeProposed {
+    pub mint: Pubkey,
+    pub role: String,
+    pub new_authority: Pubkey,
+    pub proposed_by: Pubkey,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRenouncedEvent {
+    pub mint: Pubkey,
+    pub role: String,
+    pub old_authority: Pubkey,
+    pub renounced_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub mint: Pubkey,
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub mint: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TransferHookExecuted {
     pub mint: Pubkey,
@@ -98,5 +696,98 @@ pub struct TransferHookExecuted {
     pub destination: Pubkey,
     pub amount: u64,
     pub allowed: bool,
+    pub blocked_party: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExemptAddressUpdated {
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    pub exempt: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Paused {
+    pub mint: Pubkey,
+    pub paused_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Unpaused {
+    pub mint: Pubkey,
+    pub unpaused_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub mint: Pubkey,
+    pub flag: String,
+    pub old_value: bool,
+    pub new_value: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ComplianceOfficerAdded {
+    pub mint: Pubkey,
+    pub officer: Pubkey,
+    pub added_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ComplianceOfficerRemoved {
+    pub mint: Pubkey,
+    pub officer: Pubkey,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigActionProposed {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub proposed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigActionApproved {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub approved_by: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigActionExecuted {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HoldPlaced {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub reason: String,
+    pub expires_at: Option<i64>,
+    pub placed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HoldReleased {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub released_by: Pubkey,
     pub timestamp: i64,
 }
@@ -0,0 +1,34 @@
+use crate::state::RoundingPolicy;
+
+/// Basis points in one whole
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Render a raw token amount as a decimal string, e.g. `1_500_000` at 6 decimals
+/// is `"1.5"`. Trailing fractional zeros are trimmed to match Token-2022's
+/// `ui_amount_string`; a string avoids the precision loss of an `f64`.
+pub fn format_ui_amount(amount: u64, decimals: u8) -> String {
+    let digits = amount.to_string();
+    if decimals == 0 {
+        return digits;
+    }
+
+    let decimals = decimals as usize;
+    let padded = format!("{:0>width$}", digits, width = decimals + 1);
+    let (whole, fraction) = padded.split_at(padded.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+/// Divide a basis-point product (`amount * bps`) by 10_000, rounding per `policy`.
+/// E.g. 2_500_250 (2.5% of 10_001) is 250 rounded down or to nearest, 251 rounded up.
+pub fn apply_rounding(value: u128, policy: RoundingPolicy) -> u128 {
+    match policy {
+        RoundingPolicy::RoundDown => value / BPS_DENOMINATOR,
+        RoundingPolicy::RoundUp => value.div_ceil(BPS_DENOMINATOR),
+        RoundingPolicy::Nearest => (value + BPS_DENOMINATOR / 2) / BPS_DENOMINATOR,
+    }
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::state::{StablecoinState, AccountActivity, ComplianceOfficers};
+use crate::errors::SSSError;
+use crate::events::AccountMarkedDormant;
+
+/// Start tracking transfer activity for a token account.
+/// Anyone may pay for the activity PDA; `transfer` updates it when passed in.
+pub fn track_activity_handler(
+    ctx: Context<TrackActivity>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let activity = &mut ctx.accounts.activity;
+    activity.stablecoin = ctx.accounts.stablecoin_state.mint;
+    activity.token_account = ctx.accounts.token_account.key();
+    activity.last_transfer_at = clock.unix_timestamp;
+    activity.last_amount = 0;
+    activity.dormant = false;
+    activity.last_outbound_at = 0;
+    activity.bump = ctx.bumps.activity;
+
+    msg!("SSS: Tracking activity for {}", activity.token_account);
+    Ok(())
+}
+
+/// Flag a tracked account as dormant once it has been inactive for `threshold` seconds.
+/// Any later transfer through `transfer` clears the flag.
+pub fn mark_dormant_handler(
+    ctx: Context<MarkDormant>,
+    threshold: i64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
+    require!(threshold >= 0, SSSError::InvalidDelay);
+
+    let clock = Clock::get()?;
+    let activity = &mut ctx.accounts.activity;
+    let inactive_for = clock.unix_timestamp.saturating_sub(activity.last_transfer_at);
+    require!(inactive_for >= threshold, SSSError::AccountNotDormant);
+
+    activity.dormant = true;
+
+    emit!(AccountMarkedDormant {
+        mint: state.mint,
+        account: activity.token_account,
+        last_transfer_at: activity.last_transfer_at,
+        inactive_for,
+        marked_by: ctx.accounts.compliance_officer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Marked {} dormant ({}s inactive)", activity.token_account, inactive_for);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TrackActivity<'info> {
+    /// Payer for the activity PDA
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Token account to track
+    #[account(token::mint = mint)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Activity PDA for the token account
+    #[account(
+        init,
+        payer = payer,
+        space = AccountActivity::SPACE,
+        seeds = [b"activity", mint.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub activity: Account<'info, AccountActivity>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MarkDormant<'info> {
+    /// Compliance officer
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Activity PDA to flag
+    #[account(
+        mut,
+        seeds = [b"activity", mint.key().as_ref(), activity.token_account.as_ref()],
+        bump = activity.bump,
+    )]
+    pub activity: Account<'info, AccountActivity>,
+}
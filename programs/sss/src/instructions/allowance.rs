@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, MintTo as SplMintTo, mint_to};
+
+use crate::state::{StablecoinState, MintAllowance};
+use crate::errors::SSSError;
+use crate::format::format_ui_amount;
+use crate::events::{MintAllowanceGranted, TokensMinted};
+use crate::instructions::hook::is_blacklisted;
+use crate::oracle::check_peg;
+
+/// Grant (or reset) a delegate's minting budget. Only callable by the mint authority.
+/// Re-granting overwrites the remaining budget; a zero amount revokes it.
+pub fn grant_mint_allowance_handler(
+    ctx: Context<GrantMintAllowance>,
+    delegate: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.mint_authority.key() == state.mint_authority,
+        SSSError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    let allowance = &mut ctx.accounts.mint_allowance;
+    allowance.stablecoin = state.mint;
+    allowance.delegate = delegate;
+    allowance.remaining = amount;
+    allowance.granted_by = ctx.accounts.mint_authority.key();
+    allowance.granted_at = clock.unix_timestamp;
+    allowance.bump = ctx.bumps.mint_allowance;
+
+    emit!(MintAllowanceGranted {
+        mint: state.mint,
+        delegate,
+        amount,
+        granted_by: ctx.accounts.mint_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Granted {} mint allowance to {}", amount, delegate);
+    Ok(())
+}
+
+/// Mint against a delegate's allowance.
+/// The stablecoin PDA signs the mint, so the Token-2022 mint authority must be
+/// set to the `[b"stablecoin", mint]` PDA for allowances to be usable.
+pub fn mint_with_allowance_handler(
+    ctx: Context<MintWithAllowance>,
+    amount: u64,
+) -> Result<()> {
+    require!(!ctx.accounts.stablecoin_state.paused, SSSError::ProgramPaused);
+    require!(amount > 0, SSSError::InvalidAmount);
+
+    let state = &ctx.accounts.stablecoin_state;
+    require!(
+        amount <= ctx.accounts.mint_allowance.remaining,
+        SSSError::AllowanceExhausted
+    );
+
+    // SSS-2: never mint into a blacklisted wallet
+    if state.is_sss2 {
+        let recipient_blacklist = ctx
+            .accounts
+            .recipient_blacklist
+            .as_ref()
+            .ok_or(SSSError::MissingAccount)?;
+        require!(
+            !is_blacklisted(
+                recipient_blacklist,
+                &state.mint,
+                &ctx.accounts.token_account.owner,
+                Clock::get()?.unix_timestamp,
+            )?,
+            SSSError::TransferBlocked
+        );
+    }
+
+    check_peg(state, ctx.accounts.oracle.as_ref())?;
+
+    // Mint via Token-2022, signing as the stablecoin PDA
+    let mint_key = ctx.accounts.mint.key();
+    let bump = state.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"stablecoin", mint_key.as_ref(), &[bump]]];
+
+    let cpi_accounts = SplMintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.token_account.to_account_info(),
+        authority: ctx.accounts.stablecoin_state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    mint_to(cpi_ctx, amount)?;
+
+    // Update audit state
+    let now = Clock::get()?.unix_timestamp;
+    let allowance = &mut ctx.accounts.mint_allowance;
+    allowance.remaining -= amount;
+    let remaining = allowance.remaining;
+
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.record_daily_mint(amount, now)?;
+    state.total_minted = state.total_minted.checked_add(amount).ok_or(SSSError::Overflow)?;
+    state.mint_op_count = state.mint_op_count.saturating_add(1);
+    state.updated_at = now;
+
+    if state.emit_events {
+        emit!(TokensMinted {
+            mint: state.mint,
+            to: ctx.accounts.token_account.key(),
+            amount,
+            ui_amount: format_ui_amount(amount, state.decimals),
+            minted_by: ctx.accounts.delegate.key(),
+            total_minted: state.total_minted,
+            memo: String::new(),
+            timestamp: now,
+        });
+    }
+
+    msg!("SSS: Delegate minted {} tokens ({} allowance left)", amount, remaining);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct GrantMintAllowance<'info> {
+    /// Mint authority (must match stablecoin_state.mint_authority; pays for the allowance)
+    #[account(mut)]
+    pub mint_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Mint allowance PDA (created or overwritten)
+    #[account(
+        init_if_needed,
+        payer = mint_authority,
+        space = MintAllowance::SPACE,
+        seeds = [b"allowance", mint.key().as_ref(), delegate.as_ref()],
+        bump,
+    )]
+    pub mint_allowance: Account<'info, MintAllowance>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintWithAllowance<'info> {
+    /// Delegate holding the allowance
+    pub delegate: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Destination token account
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Stablecoin state PDA (Token-2022 mint authority)
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Mint allowance PDA for the delegate
+    #[account(
+        mut,
+        seeds = [b"allowance", mint.key().as_ref(), delegate.key().as_ref()],
+        bump = mint_allowance.bump,
+    )]
+    pub mint_allowance: Account<'info, MintAllowance>,
+
+    /// Blacklist entry PDA for the destination owner (required for SSS-2; may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), token_account.owner.as_ref()],
+        bump,
+    )]
+    pub recipient_blacklist: Option<AccountInfo<'info>>,
+
+    /// Price account (required once `stablecoin_state.oracle` is set)
+    /// CHECK: Key matched against `stablecoin_state.oracle` and layout parsed in the handler
+    pub oracle: Option<AccountInfo<'info>>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+}
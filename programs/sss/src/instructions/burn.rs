@@ -1,17 +1,35 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, Burn as SplBurn, burn};
 
-use crate::state::StablecoinState;
+use crate::state::{StablecoinState, ComplianceOfficers};
 use crate::errors::SSSError;
 use crate::events::TokensBurned;
+use crate::instructions::hook::is_blacklisted;
+use crate::validation::validate_amount;
+use crate::format::format_ui_amount;
 
 /// Burn tokens from a specified token account.
 /// Only callable by the token owner or an authorized authority.
 pub fn handler(
     ctx: Context<Burn>,
     amount: u64,
+    memo: Option<String>,
 ) -> Result<()> {
-    require!(amount > 0, SSSError::InvalidAmount);
+    require!(!ctx.accounts.stablecoin_state.paused, SSSError::ProgramPaused);
+    validate_amount(amount, ctx.accounts.stablecoin_state.max_single_op_amount)?;
+    let memo = StablecoinState::validate_memo(memo)?;
+
+    // Never pair this stablecoin's state with another mint's token account
+    require!(
+        ctx.accounts.token_account.mint == ctx.accounts.stablecoin_state.mint,
+        SSSError::MintMismatch
+    );
+
+    // Fail with a clear error rather than the token program's generic one
+    require!(
+        amount <= ctx.accounts.token_account.amount,
+        SSSError::InsufficientBalance
+    );
 
     // Perform the burn via Token-2022
     let cpi_accounts = SplBurn {
@@ -28,18 +46,102 @@ pub fn handler(
     // Update audit state
     let state = &mut ctx.accounts.stablecoin_state;
     state.total_burned = state.total_burned.checked_add(amount).ok_or(SSSError::Overflow)?;
+    state.burn_op_count = state.burn_op_count.saturating_add(1);
+    state.updated_at = Clock::get()?.unix_timestamp;
+
+    if state.emit_events {
+        emit!(TokensBurned {
+            mint: state.mint,
+            from: ctx.accounts.token_account.key(),
+            amount,
+            ui_amount: format_ui_amount(amount, state.decimals),
+            burned_by: ctx.accounts.owner.key(),
+            total_burned: state.total_burned,
+            memo,
+            timestamp: state.updated_at,
+        });
+    }
+
+    msg!("SSS: Burned {} tokens from {}", amount, ctx.accounts.token_account.key());
+
+    Ok(())
+}
+
+/// Burn tokens from any token account via the permanent delegate (SSS-2 only).
+/// Callable by the compliance officer or mint authority, e.g. after an off-chain
+/// redemption. Blacklisted holders are refused unless `force` is set.
+pub fn burn_from_handler(
+    ctx: Context<BurnFrom>,
+    amount: u64,
+    force: bool,
+) -> Result<()> {
+    require!(!ctx.accounts.stablecoin_state.paused, SSSError::ProgramPaused);
+    require!(amount > 0, SSSError::InvalidAmount);
+
+    let state = &ctx.accounts.stablecoin_state;
+    let caller = ctx.accounts.caller.key();
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(state.permanent_delegate_enabled, SSSError::PermanentDelegateNotEnabled);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&caller)
+            || caller == state.mint_authority,
+        SSSError::Unauthorized
+    );
+
+    if !force {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !is_blacklisted(
+                &ctx.accounts.blacklist_entry,
+                &state.mint,
+                &ctx.accounts.token_account.owner,
+                now,
+            )?,
+            SSSError::TargetBlacklisted
+        );
+    }
+
+    require!(
+        amount <= ctx.accounts.token_account.amount,
+        SSSError::InsufficientBalance
+    );
+
+    // Burn via Token-2022, signing as the permanent delegate
+    let mint_key = ctx.accounts.mint.key();
+    let bump = state.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"stablecoin", mint_key.as_ref(), &[bump]]];
+
+    let cpi_accounts = SplBurn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.token_account.to_account_info(),
+        authority: ctx.accounts.stablecoin_state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    burn(cpi_ctx, amount)?;
+
+    // Update audit state
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.total_burned = state.total_burned.checked_add(amount).ok_or(SSSError::Overflow)?;
+    state.burn_op_count = state.burn_op_count.saturating_add(1);
     state.updated_at = Clock::get()?.unix_timestamp;
 
     emit!(TokensBurned {
         mint: state.mint,
         from: ctx.accounts.token_account.key(),
         amount,
-        burned_by: ctx.accounts.owner.key(),
+        ui_amount: format_ui_amount(amount, state.decimals),
+        burned_by: state.key(),
         total_burned: state.total_burned,
+        memo: String::new(),
         timestamp: state.updated_at,
     });
 
-    msg!("SSS: Burned {} tokens from {}", amount, ctx.accounts.token_account.key());
+    msg!("SSS: {} burned {} tokens from {} via permanent delegate", caller, amount, ctx.accounts.token_account.key());
 
     Ok(())
 }
@@ -54,10 +156,9 @@ pub struct Burn<'info> {
     #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
-    /// Source token account to burn from
+    /// Source token account to burn from (mint checked against the state in the handler)
     #[account(
         mut,
-        token::mint = mint,
         token::authority = owner,
         token::token_program = token_program,
     )]
@@ -74,3 +175,48 @@ pub struct Burn<'info> {
     /// Token-2022 program
     pub token_program: Interface<'info, TokenInterface>,
 }
+
+#[derive(Accounts)]
+pub struct BurnFrom<'info> {
+    /// Compliance officer or mint authority
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Token account to burn from (any holder)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Stablecoin state PDA (permanent delegate)
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Blacklist entry PDA for the token account owner (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), token_account.owner.as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: AccountInfo<'info>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{StablecoinState, ChangeLog, ChangeLogEntry, AdminAction};
+use crate::errors::SSSError;
+
+/// Create the change log ring buffer (authority only).
+/// From then on pauses, role changes and config changes must pass the log and
+/// append to it, so the last `ChangeLog::CAPACITY` admin actions stay queryable.
+pub fn init_changelog_handler(
+    ctx: Context<InitChangeLog>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    let changelog = &mut ctx.accounts.changelog;
+    changelog.stablecoin = state.mint;
+    changelog.next_index = 0;
+    changelog.len = 0;
+    changelog.bump = ctx.bumps.changelog;
+
+    state.changelog_enabled = true;
+    state.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("SSS: Change log created for {}", state.symbol);
+    Ok(())
+}
+
+/// Return the change log entries, oldest first, as instruction return data.
+/// Read-only; intended to be called through a simulated transaction.
+pub fn get_changelog_handler(
+    ctx: Context<GetChangeLog>,
+) -> Result<Vec<ChangeLogEntry>> {
+    Ok(ctx.accounts.changelog.ordered())
+}
+
+/// Append an admin action to the change log. Once `init_changelog` has run the
+/// log must be passed, so actions cannot skip it.
+pub(crate) fn record_admin_action(
+    changelog: Option<&mut Account<ChangeLog>>,
+    changelog_enabled: bool,
+    action: AdminAction,
+    actor: Pubkey,
+    timestamp: i64,
+) -> Result<()> {
+    match changelog {
+        Some(changelog) => changelog.record(action, actor, timestamp),
+        None => require!(!changelog_enabled, SSSError::MissingAccount),
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitChangeLog<'info> {
+    /// Stablecoin authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Change log PDA
+    #[account(
+        init,
+        payer = authority,
+        space = ChangeLog::SPACE,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump,
+    )]
+    pub changelog: Account<'info, ChangeLog>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetChangeLog<'info> {
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Change log PDA
+    #[account(
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Account<'info, ChangeLog>,
+}
@@ -1,59 +1,263 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, Burn as SplBurn, transfer_checked, burn};
 
-use crate::state::{StablecoinState, BlacklistEntry};
+use crate::state::{StablecoinState, BlacklistEntry, BlacklistBitmap, AllowlistEntry, KycRecord, SeizureRecord, PendingSeizure, ReissueRecord, ComplianceOfficers, ComplianceHold};
 use crate::errors::SSSError;
-use crate::events::{AddressBlacklisted, AddressUnblacklisted, TokensSeized};
+use crate::events::{AddressBlacklisted, AddressUnblacklisted, BitmapBlacklistUpdated, BatchBlacklistCompleted, BatchBlacklistCleared, BlacklistCountChanged, BlacklistDigestUpdated, AccountFrozenEvent, AccountThawedEvent, AddressAllowlisted, AddressUnallowlisted, AllowlistRootUpdated, KycTierSet, TokensSeized, SeizureRecorded, SeizureProposed, SeizureApproved, SeizedTokensBurned, SeizedTokensReissued, SeizureTreasuryUpdated, ForcedTransfer, HoldPlaced, HoldReleased};
+use crate::validation::validate_amount;
+use crate::bitmap::bit_index;
+use crate::digest::{digest_seed, fold_address};
+use crate::instructions::freeze::{freeze_held_by_pda, set_account_frozen};
+use crate::instructions::hook::is_blacklisted;
+use crate::pda::create_pda_account;
 
 /// Add an address to the blacklist (SSS-2 only).
 /// Creates a PDA that the transfer hook checks before every transfer.
+/// With `freeze_on_blacklist` set, also freezes the address's token account,
+/// which must then be passed along with the freeze authority.
 pub fn blacklist_add_handler(
     ctx: Context<BlacklistAdd>,
     address: Pubkey,
+    reason_code: u16,
+    note: String,
+    expires_at: Option<i64>,
 ) -> Result<()> {
-    let state = &ctx.accounts.stablecoin_state;
+    // Signs the freeze below when the PDA holds the freeze authority
+    let stablecoin_info = ctx.accounts.stablecoin_state.to_account_info();
+    let state = &mut ctx.accounts.stablecoin_state;
 
     // Must be SSS-2
     require!(state.is_sss2, SSSError::SSS2Required);
 
-    // Must be compliance officer
+    // Must be the blacklist authority
     require!(
-        ctx.accounts.compliance_officer.key() == state.compliance_officer,
+        ctx.accounts.blacklist_authority.key() == state.blacklist_authority,
         SSSError::Unauthorized
     );
 
-    let entry = &mut ctx.accounts.blacklist_entry;
-    require!(!entry.is_active, SSSError::AlreadyBlacklisted);
+    require!(note.len() <= BlacklistEntry::MAX_NOTE_LEN, SSSError::NoteTooLong);
 
     let clock = Clock::get()?;
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > clock.unix_timestamp, SSSError::InvalidExpiry);
+    }
+
+    // An expired entry can be re-used for a fresh blacklisting
+    let entry = &mut ctx.accounts.blacklist_entry;
+    require!(!entry.is_effective(clock.unix_timestamp), SSSError::AlreadyBlacklisted);
+
+    // An expired entry is still active, so it is already counted
+    let was_active = entry.is_active;
+    if !was_active {
+        state.check_blacklist_capacity(1)?;
+    }
+
     entry.stablecoin = state.mint;
     entry.blacklisted_address = address;
-    entry.added_by = ctx.accounts.compliance_officer.key();
+    entry.added_by = ctx.accounts.blacklist_authority.key();
     entry.added_at = clock.unix_timestamp;
     entry.is_active = true;
     entry.bump = ctx.bumps.blacklist_entry;
+    entry.reason_code = reason_code;
+    entry.note = note.clone();
+    entry.expires_at = expires_at;
+    state.abandon_blacklist_digest();
 
     emit!(AddressBlacklisted {
         mint: state.mint,
         address,
-        added_by: ctx.accounts.compliance_officer.key(),
+        added_by: ctx.accounts.blacklist_authority.key(),
+        reason_code,
+        note: note.clone(),
+        expires_at,
         timestamp: clock.unix_timestamp,
     });
 
+    if state.freeze_on_blacklist {
+        let (Some(token_account), Some(freeze_authority), Some(token_program)) = (
+            &ctx.accounts.token_account,
+            &ctx.accounts.freeze_authority,
+            &ctx.accounts.token_program,
+        ) else {
+            return Err(SSSError::MissingAccount.into());
+        };
+
+        require!(
+            freeze_authority.key() == state.freeze_authority,
+            SSSError::Unauthorized
+        );
+        require!(token_account.mint == state.mint, SSSError::MintMismatch);
+        require!(token_account.owner == address, SSSError::Unauthorized);
+
+        if !token_account.is_frozen() {
+            set_account_frozen(
+                true,
+                token_program.to_account_info(),
+                &ctx.accounts.mint,
+                token_account.to_account_info(),
+                stablecoin_info,
+                state.bump,
+                freeze_authority.to_account_info(),
+            )?;
+
+            state.freeze_op_count = state.freeze_op_count.saturating_add(1);
+
+            emit!(AccountFrozenEvent {
+                mint: state.mint,
+                account: token_account.key(),
+                frozen_by: freeze_authority.key(),
+                reason: note,
+                timestamp: clock.unix_timestamp,
+            });
+
+            msg!("SSS: Froze account {}", token_account.key());
+        }
+    }
+
+    if !was_active {
+        state.active_blacklist_count = state.active_blacklist_count.saturating_add(1);
+        emit!(BlacklistCountChanged {
+            mint: state.mint,
+            active_blacklist_count: state.active_blacklist_count,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     msg!("SSS: Blacklisted address {}", address);
     Ok(())
 }
 
+/// Blacklist several addresses in one call (SSS-2 only).
+/// `remaining_accounts[i]` must be the blacklist entry PDA for `addresses[i]`, which
+/// expires at `expires_at[i]` (`None` for never); missing PDAs are created, and
+/// already-effective entries are skipped.
+pub fn blacklist_add_batch_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BlacklistAddBatch<'info>>,
+    addresses: Vec<Pubkey>,
+    reason_code: u16,
+    note: String,
+    expires_at: Vec<Option<i64>>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(
+        ctx.accounts.blacklist_authority.key() == state.blacklist_authority,
+        SSSError::Unauthorized
+    );
+    require!(addresses.len() <= StablecoinState::MAX_BATCH_SIZE, SSSError::BatchTooLarge);
+    require!(
+        addresses.len() == ctx.remaining_accounts.len() && addresses.len() == expires_at.len(),
+        SSSError::BatchLengthMismatch
+    );
+    require!(note.len() <= BlacklistEntry::MAX_NOTE_LEN, SSSError::NoteTooLong);
+
+    let clock = Clock::get()?;
+    for expiry in expires_at.iter().flatten() {
+        require!(*expiry > clock.unix_timestamp, SSSError::InvalidExpiry);
+    }
+    let mint_key = ctx.accounts.mint.key();
+    let added_by = ctx.accounts.blacklist_authority.key();
+    let stablecoin = state.mint;
+    let mut added: u8 = 0;
+    let mut skipped: u8 = 0;
+    let mut newly_active: u64 = 0;
+
+    for ((address, entry_info), expires_at) in addresses
+        .iter()
+        .zip(ctx.remaining_accounts.iter())
+        .zip(expires_at.into_iter())
+    {
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"blacklist", mint_key.as_ref(), address.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(entry_info.key(), expected, SSSError::InvalidPda);
+
+        if entry_info.data_is_empty() {
+            let signer_seeds: &[&[&[u8]]] = &[&[b"blacklist", mint_key.as_ref(), address.as_ref(), &[bump]]];
+            create_pda_account(
+                &ctx.accounts.blacklist_authority.to_account_info(),
+                entry_info,
+                &ctx.accounts.system_program.to_account_info(),
+                BlacklistEntry::SPACE,
+                signer_seeds,
+            )?;
+        }
+
+        // Freshly created accounts are zeroed, so skip the discriminator check
+        let mut entry = Account::<BlacklistEntry>::try_from_unchecked(entry_info)?;
+        if entry.is_effective(clock.unix_timestamp) {
+            skipped += 1;
+            continue;
+        }
+        if !entry.is_active {
+            newly_active += 1;
+        }
+
+        entry.stablecoin = stablecoin;
+        entry.blacklisted_address = *address;
+        entry.added_by = added_by;
+        entry.added_at = clock.unix_timestamp;
+        entry.is_active = true;
+        entry.bump = bump;
+        entry.reason_code = reason_code;
+        entry.note = note.clone();
+        entry.expires_at = expires_at;
+        entry.exit(ctx.program_id)?;
+        added += 1;
+
+        emit!(AddressBlacklisted {
+            mint: stablecoin,
+            address: *address,
+            added_by,
+            reason_code,
+            note: note.clone(),
+            expires_at,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(BatchBlacklistCompleted {
+        mint: stablecoin,
+        added,
+        skipped,
+        added_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    let state = &mut ctx.accounts.stablecoin_state;
+    if added > 0 {
+        state.abandon_blacklist_digest();
+    }
+    if newly_active > 0 {
+        state.check_blacklist_capacity(newly_active)?;
+        state.active_blacklist_count = state.active_blacklist_count.saturating_add(newly_active);
+        emit!(BlacklistCountChanged {
+            mint: stablecoin,
+            active_blacklist_count: state.active_blacklist_count,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("SSS: Batch blacklisted {} addresses ({} already listed)", added, skipped);
+    Ok(())
+}
+
 /// Remove an address from the blacklist (SSS-2 only).
+/// With `thaw_on_remove`, also thaws the address's token account if it is frozen.
 pub fn blacklist_remove_handler(
     ctx: Context<BlacklistRemove>,
     address: Pubkey,
+    thaw_on_remove: bool,
 ) -> Result<()> {
-    let state = &ctx.accounts.stablecoin_state;
+    // Signs the thaw below when the PDA holds the freeze authority
+    let stablecoin_info = ctx.accounts.stablecoin_state.to_account_info();
+    let state = &mut ctx.accounts.stablecoin_state;
 
     require!(state.is_sss2, SSSError::SSS2Required);
     require!(
-        ctx.accounts.compliance_officer.key() == state.compliance_officer,
+        ctx.accounts.blacklist_authority.key() == state.blacklist_authority,
         SSSError::Unauthorized
     );
 
@@ -61,154 +265,1860 @@ pub fn blacklist_remove_handler(
     require!(entry.is_active, SSSError::NotBlacklisted);
 
     entry.is_active = false;
+    state.active_blacklist_count = state.active_blacklist_count.saturating_sub(1);
+    state.abandon_blacklist_digest();
 
     let clock = Clock::get()?;
     emit!(AddressUnblacklisted {
         mint: state.mint,
         address,
-        removed_by: ctx.accounts.compliance_officer.key(),
+        removed_by: ctx.accounts.blacklist_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(BlacklistCountChanged {
+        mint: state.mint,
+        active_blacklist_count: state.active_blacklist_count,
         timestamp: clock.unix_timestamp,
     });
 
+    if thaw_on_remove {
+        let (Some(token_account), Some(freeze_authority), Some(token_program)) = (
+            &ctx.accounts.token_account,
+            &ctx.accounts.freeze_authority,
+            &ctx.accounts.token_program,
+        ) else {
+            return Err(SSSError::MissingAccount.into());
+        };
+
+        require!(
+            freeze_authority.key() == state.freeze_authority,
+            SSSError::Unauthorized
+        );
+        require!(token_account.mint == state.mint, SSSError::MintMismatch);
+        require!(token_account.owner == address, SSSError::Unauthorized);
+
+        if token_account.is_frozen() {
+            set_account_frozen(
+                false,
+                token_program.to_account_info(),
+                &ctx.accounts.mint,
+                token_account.to_account_info(),
+                stablecoin_info,
+                state.bump,
+                freeze_authority.to_account_info(),
+            )?;
+
+            emit!(AccountThawedEvent {
+                mint: state.mint,
+                account: token_account.key(),
+                thawed_by: freeze_authority.key(),
+                timestamp: clock.unix_timestamp,
+            });
+
+            msg!("SSS: Thawed account {}", token_account.key());
+        }
+    }
+
     msg!("SSS: Removed {} from blacklist", address);
     Ok(())
 }
 
-/// Seize tokens from a blacklisted account using the permanent delegate.
-/// This is an SSS-2 compliance feature for regulatory requirements.
-pub fn seize_tokens_handler(
-    ctx: Context<SeizeTokens>,
-    amount: u64,
+/// Deactivate a batch of blacklist entries at once (SSS-2, authority only).
+/// Incident response for a bad blacklist push; entries already inactive are skipped.
+/// Each account must be the canonical entry PDA for this mint and its address.
+pub fn clear_all_blacklist_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClearAllBlacklist<'info>>,
 ) -> Result<()> {
     let state = &ctx.accounts.stablecoin_state;
 
     require!(state.is_sss2, SSSError::SSS2Required);
-    require!(state.permanent_delegate_enabled, SSSError::PermanentDelegateNotEnabled);
     require!(
-        ctx.accounts.compliance_officer.key() == state.compliance_officer,
+        ctx.accounts.authority.key() == state.authority,
         SSSError::Unauthorized
     );
-    require!(amount > 0, SSSError::InvalidAmount);
+    require!(!ctx.remaining_accounts.is_empty(), SSSError::InvalidAmount);
+    require!(
+        ctx.remaining_accounts.len() <= StablecoinState::MAX_BATCH_SIZE,
+        SSSError::BatchTooLarge
+    );
 
-    // Verify the target is blacklisted
-    let blacklist_entry = &ctx.accounts.blacklist_entry;
-    require!(blacklist_entry.is_active, SSSError::SeizeNotBlacklisted);
+    let clock = Clock::get()?;
+    let authority = ctx.accounts.authority.key();
+    let stablecoin = state.mint;
+    let mut cleared: u8 = 0;
+    let mut skipped: u8 = 0;
+
+    for entry_info in ctx.remaining_accounts.iter() {
+        let mut entry = Account::<BlacklistEntry>::try_from(entry_info)?;
+        require!(entry.stablecoin == stablecoin, SSSError::MintMismatch);
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"blacklist", stablecoin.as_ref(), entry.blacklisted_address.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(entry_info.key(), expected, SSSError::InvalidPda);
+
+        if !entry.is_active {
+            skipped += 1;
+            continue;
+        }
+
+        entry.is_active = false;
+        entry.exit(ctx.program_id)?;
+        cleared += 1;
+
+        emit!(AddressUnblacklisted {
+            mint: stablecoin,
+            address: entry.blacklisted_address,
+            removed_by: authority,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(BatchBlacklistCleared {
+        mint: stablecoin,
+        cleared,
+        skipped,
+        cleared_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if cleared > 0 {
+        let state = &mut ctx.accounts.stablecoin_state;
+        state.active_blacklist_count = state.active_blacklist_count.saturating_sub(cleared as u64);
+        state.abandon_blacklist_digest();
+        state.updated_at = clock.unix_timestamp;
+        emit!(BlacklistCountChanged {
+            mint: stablecoin,
+            active_blacklist_count: state.active_blacklist_count,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("SSS: Cleared {} blacklist entries ({} already inactive)", cleared, skipped);
+    Ok(())
+}
+
+/// Start a paged blacklist digest pass (SSS-2 only), discarding any pass in progress.
+/// Entries are then folded in with `compute_blacklist_digest`. Expiry is judged at this
+/// instant for the whole pass, and any blacklist change before the pass completes
+/// abandons it. With no active entries, the digest of the empty set is stored at once.
+pub fn begin_blacklist_digest_handler(ctx: Context<ComputeBlacklistDigest>) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(
+        ctx.accounts.blacklist_authority.key() == state.blacklist_authority,
+        SSSError::Unauthorized
+    );
 
-    // Transfer tokens from the blacklisted account to the treasury
-    // using the permanent delegate authority
-    // Note: In production, this would use Token-2022's permanent delegate CPI
-    // For now, we track the seizure in the audit log
-    
     let clock = Clock::get()?;
-    emit!(TokensSeized {
+    state.digest_snapshot_at = clock.unix_timestamp;
+    state.digest_accumulator = digest_seed();
+    state.digest_cursor = None;
+    state.digest_active_seen = 0;
+    state.digest_expired_seen = 0;
+
+    msg!("SSS: Began blacklist digest over {} active entries", state.active_blacklist_count);
+    if state.active_blacklist_count == 0 {
+        finish_blacklist_digest(state, ctx.accounts.blacklist_authority.key(), clock.unix_timestamp);
+    }
+    Ok(())
+}
+
+/// Fold a page of `BlacklistEntry` accounts from `remaining_accounts` into the pass
+/// started by `begin_blacklist_digest` (SSS-2 only). Entries must continue in strictly
+/// increasing address order across pages, and inactive ones are skipped. An active entry
+/// that had expired when the pass began is not blacklisted for the transfer hook, so it
+/// is left out of the digest; it still counts toward `active_blacklist_count`, and the
+/// pass completes (replacing `blacklist_digest`) once every active entry has been visited.
+pub fn compute_blacklist_digest_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ComputeBlacklistDigest<'info>>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(
+        ctx.accounts.blacklist_authority.key() == state.blacklist_authority,
+        SSSError::Unauthorized
+    );
+    require!(state.digest_snapshot_at != 0, SSSError::NoDigestInProgress);
+    require!(!ctx.remaining_accounts.is_empty(), SSSError::InvalidAmount);
+
+    for entry_info in ctx.remaining_accounts.iter() {
+        let entry = Account::<BlacklistEntry>::try_from(entry_info)?;
+        require!(entry.stablecoin == state.mint, SSSError::MintMismatch);
+        // Strict ordering rules out passing the same entry twice, in this page or an earlier one
+        require!(
+            state.digest_cursor.map_or(true, |cursor| cursor < entry.blacklisted_address),
+            SSSError::BlacklistNotSorted
+        );
+        state.digest_cursor = Some(entry.blacklisted_address);
+        if !entry.is_active {
+            continue;
+        }
+
+        state.digest_active_seen = state.digest_active_seen.saturating_add(1);
+        if entry.is_effective(state.digest_snapshot_at) {
+            state.digest_accumulator = fold_address(&state.digest_accumulator, &entry.blacklisted_address);
+        } else {
+            state.digest_expired_seen = state.digest_expired_seen.saturating_add(1);
+        }
+    }
+
+    msg!(
+        "SSS: Blacklist digest visited {} of {} active entries",
+        state.digest_active_seen,
+        state.active_blacklist_count
+    );
+    if state.digest_active_seen == state.active_blacklist_count {
+        let clock = Clock::get()?;
+        finish_blacklist_digest(state, ctx.accounts.blacklist_authority.key(), clock.unix_timestamp);
+    }
+    Ok(())
+}
+
+/// Store the completed pass as `blacklist_digest` and close it
+fn finish_blacklist_digest(state: &mut StablecoinState, updated_by: Pubkey, now: i64) {
+    let entry_count = state.digest_active_seen.saturating_sub(state.digest_expired_seen);
+    state.blacklist_digest = state.digest_accumulator;
+    state.digest_updated_at = now;
+    state.updated_at = now;
+    state.abandon_blacklist_digest();
+
+    emit!(BlacklistDigestUpdated {
         mint: state.mint,
-        from: ctx.accounts.target_account.key(),
-        amount,
-        seized_by: ctx.accounts.compliance_officer.key(),
+        digest: state.blacklist_digest,
+        entry_count: entry_count as u32,
+        expired_count: state.digest_expired_seen as u32,
+        updated_by,
+        timestamp: now,
+    });
+
+    msg!("SSS: Blacklist digest computed over {} entries", entry_count);
+}
+
+/// Create the blacklist bitmap for a mint (SSS-2 only).
+/// The bitmap is consulted by the transfer hook once `bitmap_blacklist` is enabled.
+pub fn init_blacklist_bitmap_handler(ctx: Context<InitBlacklistBitmap>) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(
+        ctx.accounts.blacklist_authority.key() == state.blacklist_authority,
+        SSSError::Unauthorized
+    );
+
+    let bitmap = &mut ctx.accounts.blacklist_bitmap;
+    bitmap.stablecoin = state.mint;
+    bitmap.bump = ctx.bumps.blacklist_bitmap;
+    bitmap.flagged_bits = 0;
+
+    msg!("SSS: Initialized blacklist bitmap for {}", state.mint);
+    Ok(())
+}
+
+/// Set `address`'s bit in the blacklist bitmap (SSS-2 only).
+/// Idempotent: a bit already set (possibly by a colliding address) stays set.
+pub fn bitmap_blacklist_add_handler(
+    ctx: Context<UpdateBlacklistBitmap>,
+    address: Pubkey,
+) -> Result<()> {
+    update_bitmap(ctx, address, true)
+}
+
+/// Clear `address`'s bit in the blacklist bitmap (SSS-2 only).
+/// Clearing also unblocks any other address that hashes to the same bit.
+pub fn bitmap_blacklist_remove_handler(
+    ctx: Context<UpdateBlacklistBitmap>,
+    address: Pubkey,
+) -> Result<()> {
+    update_bitmap(ctx, address, false)
+}
+
+fn update_bitmap(ctx: Context<UpdateBlacklistBitmap>, address: Pubkey, flagged: bool) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(
+        ctx.accounts.blacklist_authority.key() == state.blacklist_authority,
+        SSSError::Unauthorized
+    );
+
+    let index = bit_index(&address);
+    let bitmap = &mut ctx.accounts.blacklist_bitmap;
+    if !flagged {
+        require!(bitmap.is_set(index), SSSError::NotBlacklisted);
+    }
+    bitmap.update(index, flagged);
+
+    let clock = Clock::get()?;
+    emit!(BitmapBlacklistUpdated {
+        mint: state.mint,
+        address,
+        bit_index: index as u32,
+        flagged,
+        flagged_bits: bitmap.flagged_bits,
+        updated_by: ctx.accounts.blacklist_authority.key(),
         timestamp: clock.unix_timestamp,
     });
 
-    msg!("SSS: Seized {} tokens from blacklisted account {}", amount, ctx.accounts.target_account.key());
+    msg!("SSS: Set bitmap bit {} for {} to {}", index, address, flagged);
     Ok(())
 }
 
-#[derive(Accounts)]
-#[instruction(address: Pubkey)]
-pub struct BlacklistAdd<'info> {
-    /// Compliance officer
-    #[account(mut)]
-    pub compliance_officer: Signer<'info>,
+/// Add an address to the allowlist (allowlist mode only).
+/// Creates a PDA that the transfer hook requires for both parties.
+pub fn allowlist_add_handler(
+    ctx: Context<AllowlistAdd>,
+    address: Pubkey,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
 
-    /// The mint
-    pub mint: InterfaceAccount<'info, Mint>,
+    require!(state.allowlist_mode, SSSError::AllowlistModeRequired);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
 
-    /// Stablecoin state PDA
-    #[account(
-        seeds = [b"stablecoin", mint.key().as_ref()],
-        bump = stablecoin_state.bump,
-    )]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    let entry = &mut ctx.accounts.allowlist_entry;
+    require!(!entry.is_active, SSSError::AlreadyAllowlisted);
 
-    /// Blacklist entry PDA (created or updated)
-    #[account(
-        init_if_needed,
-        payer = compliance_officer,
-        space = BlacklistEntry::SPACE,
-        seeds = [b"blacklist", mint.key().as_ref(), address.as_ref()],
-        bump,
-    )]
-    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    let clock = Clock::get()?;
+    entry.stablecoin = state.mint;
+    entry.allowlisted_address = address;
+    entry.added_by = ctx.accounts.compliance_officer.key();
+    entry.added_at = clock.unix_timestamp;
+    entry.is_active = true;
+    entry.bump = ctx.bumps.allowlist_entry;
 
-    /// System program
-    pub system_program: Program<'info, System>,
+    emit!(AddressAllowlisted {
+        mint: state.mint,
+        address,
+        added_by: ctx.accounts.compliance_officer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Allowlisted address {}", address);
+    Ok(())
 }
 
-#[derive(Accounts)]
-#[instruction(address: Pubkey)]
-pub struct BlacklistRemove<'info> {
-    /// Compliance officer
-    #[account(mut)]
-    pub compliance_officer: Signer<'info>,
+/// Remove an address from the allowlist (allowlist mode only).
+pub fn allowlist_remove_handler(
+    ctx: Context<AllowlistRemove>,
+    address: Pubkey,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
 
-    /// The mint
-    pub mint: InterfaceAccount<'info, Mint>,
+    require!(state.allowlist_mode, SSSError::AllowlistModeRequired);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
 
-    /// Stablecoin state PDA
-    #[account(
-        seeds = [b"stablecoin", mint.key().as_ref()],
-        bump = stablecoin_state.bump,
-    )]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    let entry = &mut ctx.accounts.allowlist_entry;
+    require!(entry.is_active, SSSError::NotAllowlisted);
 
-    /// Blacklist entry PDA to deactivate
-    #[account(
-        mut,
-        seeds = [b"blacklist", mint.key().as_ref(), address.as_ref()],
-        bump = blacklist_entry.bump,
-    )]
-    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    entry.is_active = false;
+
+    let clock = Clock::get()?;
+    emit!(AddressUnallowlisted {
+        mint: state.mint,
+        address,
+        removed_by: ctx.accounts.compliance_officer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Removed {} from allowlist", address);
+    Ok(())
 }
 
-#[derive(Accounts)]
-pub struct SeizeTokens<'info> {
-    /// Compliance officer
-    #[account(mut)]
-    pub compliance_officer: Signer<'info>,
+/// Set the merkle root of allowlisted owners (allowlist mode only).
+/// With a root set, `transfer` accepts a merkle proof for the destination owner
+/// instead of a per-address allowlist PDA. An all-zero root disables the check.
+pub fn set_allowlist_root_handler(
+    ctx: Context<SetAllowlistRoot>,
+    root: [u8; 32],
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
 
-    /// The mint
-    #[account(mut)]
-    pub mint: InterfaceAccount<'info, Mint>,
+    require!(state.allowlist_mode, SSSError::AllowlistModeRequired);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
 
-    /// Stablecoin state PDA
-    #[account(
-        seeds = [b"stablecoin", mint.key().as_ref()],
-        bump = stablecoin_state.bump,
-    )]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    let clock = Clock::get()?;
+    let old_root = state.allowlist_root;
+    state.allowlist_root = root;
+    state.updated_at = clock.unix_timestamp;
 
-    /// Blacklist entry for the target account owner
-    #[account(
-        seeds = [b"blacklist", mint.key().as_ref(), target_account.owner.as_ref()],
-        bump = blacklist_entry.bump,
-    )]
-    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    emit!(AllowlistRootUpdated {
+        mint: state.mint,
+        old_root,
+        new_root: root,
+        updated_by: ctx.accounts.compliance_officer.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
-    /// Target token account to seize from
-    #[account(
-        mut,
-        token::mint = mint,
-        token::token_program = token_program,
-    )]
-    pub target_account: InterfaceAccount<'info, TokenAccount>,
+    msg!("SSS: Updated allowlist root");
+    Ok(())
+}
 
-    /// Treasury token account to receive seized tokens
-    #[account(
-        mut,
-        token::mint = mint,
-        token::token_program = token_program,
-    )]
-    pub treasury_account: InterfaceAccount<'info, TokenAccount>,
+/// Record the verified KYC tier of an owner.
+/// `transfer` requires a sender tier of at least `i + 1` once the amount exceeds
+/// `kyc_tier_thresholds[i]`; tier 0 withdraws any verification.
+pub fn kyc_set_tier_handler(
+    ctx: Context<KycSetTier>,
+    owner: Pubkey,
+    tier: u8,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
 
-    /// Token-2022 program
-    pub token_program: Interface<'info, TokenInterface>,
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
+    require!(tier <= StablecoinState::MAX_KYC_TIER, SSSError::InvalidKycTier);
+
+    let clock = Clock::get()?;
+    let record = &mut ctx.accounts.kyc_record;
+    let old_tier = record.tier;
+    record.stablecoin = state.mint;
+    record.owner = owner;
+    record.tier = tier;
+    record.set_by = ctx.accounts.compliance_officer.key();
+    record.updated_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.kyc_record;
+
+    emit!(KycTierSet {
+        mint: state.mint,
+        owner,
+        old_tier,
+        new_tier: tier,
+        set_by: ctx.accounts.compliance_officer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Set KYC tier of {} from {} to {}", owner, old_tier, tier);
+    Ok(())
+}
+
+/// Place a compliance hold on an owner pending review (SSS-2 only).
+/// The transfer hook blocks transfers to or from a held owner until the hold is
+/// released or `expires_at` passes, without creating a formal blacklist entry.
+pub fn place_hold_handler(
+    ctx: Context<PlaceHold>,
+    owner: Pubkey,
+    reason: String,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
+    require!(reason.len() <= ComplianceHold::MAX_REASON_LEN, SSSError::ReasonTooLong);
+
+    let clock = Clock::get()?;
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > clock.unix_timestamp, SSSError::InvalidExpiry);
+    }
+
+    // A released or expired hold can be re-used for a fresh one
+    let hold = &mut ctx.accounts.compliance_hold;
+    require!(!hold.is_effective(clock.unix_timestamp), SSSError::HoldAlreadyActive);
+
+    hold.stablecoin = state.mint;
+    hold.owner = owner;
+    hold.placed_by = ctx.accounts.compliance_officer.key();
+    hold.placed_at = clock.unix_timestamp;
+    hold.expires_at = expires_at;
+    hold.reason = reason.clone();
+    hold.is_active = true;
+    hold.bump = ctx.bumps.compliance_hold;
+
+    emit!(HoldPlaced {
+        mint: state.mint,
+        owner,
+        reason,
+        expires_at,
+        placed_by: ctx.accounts.compliance_officer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Placed compliance hold on {}", owner);
+    Ok(())
+}
+
+/// Release a compliance hold before it expires (SSS-2 only).
+pub fn release_hold_handler(
+    ctx: Context<ReleaseHold>,
+    owner: Pubkey,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    let hold = &mut ctx.accounts.compliance_hold;
+    require!(hold.is_effective(clock.unix_timestamp), SSSError::NoActiveHold);
+
+    hold.is_active = false;
+
+    emit!(HoldReleased {
+        mint: state.mint,
+        owner,
+        released_by: ctx.accounts.compliance_officer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Released compliance hold on {}", owner);
+    Ok(())
+}
+
+/// Seize tokens from a blacklisted account using the permanent delegate.
+/// This is an SSS-2 compliance feature for regulatory requirements.
+///
+/// The stablecoin state PDA is the mint's permanent delegate and signs the
+/// transfer. With `seize_all`, the full balance is seized and `amount` is ignored.
+/// Tokens go to a token account owned by the seizure treasury (the authority
+/// unless `set_seizure_treasury` designated e.g. a court escrow), and a
+/// `SeizureRecord` keyed by `seized_at` keeps the reason for audit.
+/// Any transfer-hook extra accounts are forwarded from `remaining_accounts`.
+/// Refused while `require_dual_seize` is set; use `propose_seizure` instead.
+pub fn seize_tokens_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SeizeTokens<'info>>,
+    amount: u64,
+    seize_all: bool,
+    reason: String,
+    seized_at: i64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(state.permanent_delegate_enabled, SSSError::PermanentDelegateNotEnabled);
+    require!(!state.require_dual_seize, SSSError::DualSeizeRequired);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
+
+    require!(reason.len() <= SeizureRecord::MAX_REASON_LEN, SSSError::ReasonTooLong);
+    require!(
+        ctx.accounts.destination_account.key() != ctx.accounts.target_account.key(),
+        SSSError::DuplicateAccount
+    );
+    require!(
+        ctx.accounts.destination_account.owner == state.seizure_treasury_owner(),
+        SSSError::InvalidTreasury
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        seized_at <= clock.unix_timestamp
+            && clock.unix_timestamp - seized_at <= SeizureRecord::MAX_TIMESTAMP_LAG,
+        SSSError::InvalidTimestamp
+    );
+
+    // Verify the target is blacklisted (and the entry has not expired)
+    let blacklist_entry = &ctx.accounts.blacklist_entry;
+    require!(
+        blacklist_entry.is_effective(clock.unix_timestamp),
+        SSSError::SeizeNotBlacklisted
+    );
+
+    let balance = ctx.accounts.target_account.amount;
+    let seized = if seize_all {
+        balance
+    } else {
+        require!(amount <= balance, SSSError::InsufficientBalance);
+        amount
+    };
+    validate_amount(seized, state.max_single_op_amount)?;
+
+    let record = &mut ctx.accounts.seizure_record;
+    record.stablecoin = ctx.accounts.stablecoin_state.mint;
+    record.target = ctx.accounts.target_account.key();
+    record.destination = ctx.accounts.destination_account.key();
+    record.amount = seized;
+    record.officer = ctx.accounts.compliance_officer.key();
+    record.reason = reason;
+    record.seized_at = seized_at;
+    record.bump = ctx.bumps.seizure_record;
+
+    execute_seizure(
+        &mut ctx.accounts.stablecoin_state,
+        &ctx.accounts.mint,
+        &ctx.accounts.target_account,
+        &ctx.accounts.destination_account,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+        &ctx.accounts.seizure_record,
+    )
+}
+
+/// First step of a dual-control seizure (SSS-2 only): a compliance officer
+/// records the seizure in a `PendingSeizure` PDA. Nothing moves until a
+/// different officer calls `approve_seizure`.
+pub fn propose_seizure_handler(
+    ctx: Context<ProposeSeizure>,
+    amount: u64,
+    seize_all: bool,
+    reason: String,
+    seized_at: i64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(state.permanent_delegate_enabled, SSSError::PermanentDelegateNotEnabled);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
+    require!(reason.len() <= SeizureRecord::MAX_REASON_LEN, SSSError::ReasonTooLong);
+    if !seize_all {
+        require!(amount > 0, SSSError::InvalidAmount);
+    }
+    require!(
+        ctx.accounts.destination_account.key() != ctx.accounts.target_account.key(),
+        SSSError::DuplicateAccount
+    );
+    require!(
+        ctx.accounts.destination_account.owner == state.seizure_treasury_owner(),
+        SSSError::InvalidTreasury
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        seized_at <= clock.unix_timestamp
+            && clock.unix_timestamp - seized_at <= SeizureRecord::MAX_TIMESTAMP_LAG,
+        SSSError::InvalidTimestamp
+    );
+    require!(
+        ctx.accounts.blacklist_entry.is_effective(clock.unix_timestamp),
+        SSSError::SeizeNotBlacklisted
+    );
+
+    let pending = &mut ctx.accounts.pending_seizure;
+    pending.stablecoin = state.mint;
+    pending.target = ctx.accounts.target_account.key();
+    pending.destination = ctx.accounts.destination_account.key();
+    pending.amount = amount;
+    pending.seize_all = seize_all;
+    pending.reason = reason;
+    pending.seized_at = seized_at;
+    pending.proposed_by = ctx.accounts.compliance_officer.key();
+    pending.bump = ctx.bumps.pending_seizure;
+
+    emit!(SeizureProposed {
+        mint: state.mint,
+        pending: pending.key(),
+        target: pending.target,
+        amount,
+        seize_all,
+        proposed_by: pending.proposed_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Seizure proposed against {}", pending.target);
+    Ok(())
+}
+
+/// Second step of a dual-control seizure: a different compliance officer
+/// approves the `PendingSeizure`, which executes it and closes the proposal.
+/// The target must still be blacklisted and the daily seize limits apply now.
+pub fn approve_seizure_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ApproveSeizure<'info>>,
+    seized_at: i64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+    let pending = &ctx.accounts.pending_seizure;
+    let approver = ctx.accounts.compliance_officer.key();
+
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&approver),
+        SSSError::Unauthorized
+    );
+    require!(approver != pending.proposed_by, SSSError::SameOfficerApproval);
+    require!(
+        ctx.accounts.destination_account.owner == state.seizure_treasury_owner(),
+        SSSError::InvalidTreasury
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        ctx.accounts.blacklist_entry.is_effective(clock.unix_timestamp),
+        SSSError::SeizeNotBlacklisted
+    );
+
+    let balance = ctx.accounts.target_account.amount;
+    let seized = if pending.seize_all {
+        balance
+    } else {
+        require!(pending.amount <= balance, SSSError::InsufficientBalance);
+        pending.amount
+    };
+    validate_amount(seized, state.max_single_op_amount)?;
+
+    let proposed_by = pending.proposed_by;
+    let record = &mut ctx.accounts.seizure_record;
+    record.stablecoin = pending.stablecoin;
+    record.target = pending.target;
+    record.destination = pending.destination;
+    record.amount = seized;
+    record.officer = approver;
+    record.reason = pending.reason.clone();
+    record.seized_at = seized_at;
+    record.bump = ctx.bumps.seizure_record;
+
+    execute_seizure(
+        &mut ctx.accounts.stablecoin_state,
+        &ctx.accounts.mint,
+        &ctx.accounts.target_account,
+        &ctx.accounts.destination_account,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+        &ctx.accounts.seizure_record,
+    )?;
+
+    emit!(SeizureApproved {
+        mint: ctx.accounts.stablecoin_state.mint,
+        pending: ctx.accounts.pending_seizure.key(),
+        proposed_by,
+        approved_by: approver,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Move `record.amount` from a blacklisted account to the seizure destination,
+/// signing as the permanent delegate, and update the seize counters.
+/// A target frozen on blacklisting is thawed for the move and frozen again,
+/// which needs the freeze authority delegated to the stablecoin PDA.
+/// Shared by `seize_tokens` and `approve_seizure`; the caller fills in `record`.
+fn execute_seizure<'info>(
+    state: &mut Account<'info, StablecoinState>,
+    mint: &InterfaceAccount<'info, Mint>,
+    target_account: &InterfaceAccount<'info, TokenAccount>,
+    destination_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    remaining_accounts: &[AccountInfo<'info>],
+    record: &Account<'info, SeizureRecord>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let seized = record.amount;
+
+    // Move tokens to the destination, signing as the permanent delegate
+    let mint_key = mint.key();
+    let bump = state.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"stablecoin", mint_key.as_ref(), &[bump]]];
+
+    // Thawing is refused while the owner is blacklisted, so the seizure itself
+    // lifts the freeze for the duration of the move
+    let was_frozen = target_account.is_frozen();
+    if was_frozen {
+        require!(
+            freeze_held_by_pda(mint, &state.key()),
+            SSSError::FreezeAuthorityNotDelegated
+        );
+        set_account_frozen(
+            false,
+            token_program.to_account_info(),
+            mint,
+            target_account.to_account_info(),
+            state.to_account_info(),
+            bump,
+            state.to_account_info(),
+        )?;
+    }
+
+    let cpi_accounts = TransferChecked {
+        from: target_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: destination_account.to_account_info(),
+        authority: state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    )
+    .with_remaining_accounts(remaining_accounts.to_vec());
+    transfer_checked(cpi_ctx, seized, mint.decimals)?;
+
+    if was_frozen {
+        set_account_frozen(
+            true,
+            token_program.to_account_info(),
+            mint,
+            target_account.to_account_info(),
+            state.to_account_info(),
+            bump,
+            state.to_account_info(),
+        )?;
+    }
+
+    state.record_daily_seize(seized, clock.unix_timestamp)?;
+    let cumulative_seized = state.record_seizure(seized)?;
+    state.seize_op_count = state.seize_op_count.saturating_add(1);
+    state.updated_at = clock.unix_timestamp;
+
+    emit!(TokensSeized {
+        mint: state.mint,
+        from: record.target,
+        to: record.destination,
+        amount: seized,
+        cumulative_seized,
+        seized_by: record.officer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(SeizureRecorded {
+        mint: state.mint,
+        record: record.key(),
+        target: record.target,
+        destination: record.destination,
+        amount: seized,
+        officer: record.officer,
+        reason: record.reason.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Seized {} tokens from blacklisted account {}", seized, record.target);
+    Ok(())
+}
+
+/// Move tokens between two arbitrary accounts under legal order (SSS-2 only).
+/// Signs as the permanent delegate, so neither party's blacklist status is checked.
+pub fn force_transfer_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ForceTransfer<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(state.permanent_delegate_enabled, SSSError::PermanentDelegateNotEnabled);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
+    require!(amount > 0, SSSError::InvalidAmount);
+    require!(amount <= ctx.accounts.source.amount, SSSError::InsufficientBalance);
+    require!(
+        ctx.accounts.destination.key() != ctx.accounts.source.key(),
+        SSSError::DuplicateAccount
+    );
+
+    // Move tokens, signing as the permanent delegate
+    let mint_key = ctx.accounts.mint.key();
+    let bump = state.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"stablecoin", mint_key.as_ref(), &[bump]]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.source.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
+        authority: ctx.accounts.stablecoin_state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ForcedTransfer {
+        mint: state.mint,
+        source: ctx.accounts.source.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        officer: ctx.accounts.compliance_officer.key(),
+        timestamp: state.updated_at,
+    });
+
+    msg!(
+        "SSS: Forced transfer of {} tokens from {} to {}",
+        amount,
+        ctx.accounts.source.key(),
+        ctx.accounts.destination.key()
+    );
+    Ok(())
+}
+
+/// Burn previously seized tokens held in a treasury account (SSS-2 only).
+/// The account must belong to the seizure treasury owner. Signs as the
+/// permanent delegate and moves `amount` from `total_seized` into `total_burned`.
+pub fn burn_seized_handler(
+    ctx: Context<BurnSeized>,
+    amount: u64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(state.permanent_delegate_enabled, SSSError::PermanentDelegateNotEnabled);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
+    require!(amount > 0, SSSError::InvalidAmount);
+    require!(amount <= ctx.accounts.treasury.amount, SSSError::InsufficientBalance);
+
+    // Burn via Token-2022, signing as the permanent delegate
+    let mint_key = ctx.accounts.mint.key();
+    let bump = state.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"stablecoin", mint_key.as_ref(), &[bump]]];
+
+    let cpi_accounts = SplBurn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.treasury.to_account_info(),
+        authority: ctx.accounts.stablecoin_state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    burn(cpi_ctx, amount)?;
+
+    // Update audit state
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.record_seized_burn(amount)?;
+    state.burn_op_count = state.burn_op_count.saturating_add(1);
+    state.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(SeizedTokensBurned {
+        mint: state.mint,
+        treasury: ctx.accounts.treasury.key(),
+        amount,
+        total_seized: state.total_seized,
+        total_burned: state.total_burned,
+        burned_by: ctx.accounts.compliance_officer.key(),
+        timestamp: state.updated_at,
+    });
+
+    msg!("SSS: Burned {} seized tokens from treasury {}", amount, ctx.accounts.treasury.key());
+    Ok(())
+}
+
+/// Return seized tokens from the treasury to a rightful owner (SSS-2, authority only).
+/// Moves up to the original seizure's amount with `transfer_checked`, signing as
+/// the permanent delegate, and records a `ReissueRecord` keyed by the seizure so
+/// each seizure is reissued at most once. With `require_dual_seize` set, a
+/// compliance officer other than the authority must co-sign.
+/// Any transfer-hook extra accounts are forwarded from `remaining_accounts`.
+pub fn reissue_seized_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReissueSeized<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+    let authority = ctx.accounts.authority.key();
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(state.permanent_delegate_enabled, SSSError::PermanentDelegateNotEnabled);
+    require!(authority == state.authority, SSSError::Unauthorized);
+
+    let co_signer = ctx.accounts.co_signer.as_ref().map(|co_signer| co_signer.key());
+    if state.require_dual_seize {
+        let co_signer = co_signer.ok_or(SSSError::MissingAccount)?;
+        require!(
+            ctx.accounts.compliance_officers.is_compliance_officer(&co_signer),
+            SSSError::Unauthorized
+        );
+        require!(co_signer != authority, SSSError::SameOfficerApproval);
+    }
+
+    require!(amount > 0, SSSError::InvalidAmount);
+    require!(
+        amount <= ctx.accounts.seizure_record.amount,
+        SSSError::ReissueExceedsSeizure
+    );
+    require!(amount <= ctx.accounts.treasury.amount, SSSError::InsufficientBalance);
+    require!(
+        ctx.accounts.treasury.owner == state.seizure_treasury_owner(),
+        SSSError::InvalidTreasury
+    );
+    require!(
+        ctx.accounts.recipient_account.key() != ctx.accounts.treasury.key(),
+        SSSError::DuplicateAccount
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        !is_blacklisted(
+            &ctx.accounts.recipient_blacklist,
+            &state.mint,
+            &ctx.accounts.recipient_account.owner,
+            clock.unix_timestamp,
+        )?,
+        SSSError::TransferBlocked
+    );
+
+    // Move tokens out of the treasury, signing as the permanent delegate
+    let mint_key = ctx.accounts.mint.key();
+    let bump = state.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"stablecoin", mint_key.as_ref(), &[bump]]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.treasury.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_account.to_account_info(),
+        authority: ctx.accounts.stablecoin_state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.record_seized_reissue(amount)?;
+    state.updated_at = clock.unix_timestamp;
+
+    let record = &mut ctx.accounts.reissue_record;
+    record.stablecoin = state.mint;
+    record.seizure = ctx.accounts.seizure_record.key();
+    record.treasury = ctx.accounts.treasury.key();
+    record.recipient = ctx.accounts.recipient_account.key();
+    record.amount = amount;
+    record.reissued_by = authority;
+    record.co_signer = co_signer;
+    record.reissued_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.reissue_record;
+
+    emit!(SeizedTokensReissued {
+        mint: state.mint,
+        seizure: record.seizure,
+        record: record.key(),
+        treasury: record.treasury,
+        recipient: record.recipient,
+        amount,
+        total_seized: state.total_seized,
+        reissued_by: authority,
+        co_signer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Reissued {} seized tokens to {}", amount, record.recipient);
+    Ok(())
+}
+
+/// Designate the owner whose token accounts receive seized tokens (SSS-2, authority only).
+/// `None` falls back to the stablecoin authority.
+pub fn set_seizure_treasury_handler(
+    ctx: Context<SetSeizureTreasury>,
+    treasury: Option<Pubkey>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+
+    let clock = Clock::get()?;
+    let old_treasury = state.seizure_treasury;
+    state.seizure_treasury = treasury;
+    state.updated_at = clock.unix_timestamp;
+
+    emit!(SeizureTreasuryUpdated {
+        mint: state.mint,
+        old_treasury,
+        new_treasury: treasury,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Seizure treasury set to {:?}", treasury);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct BlacklistAdd<'info> {
+    /// Blacklist authority (must match stablecoin_state.blacklist_authority)
+    #[account(mut)]
+    pub blacklist_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Blacklist entry PDA (created or updated)
+    #[account(
+        init_if_needed,
+        payer = blacklist_authority,
+        space = BlacklistEntry::SPACE,
+        seeds = [b"blacklist", mint.key().as_ref(), address.as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    /// Token account to freeze (only with `freeze_on_blacklist`)
+    #[account(mut)]
+    pub token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Freeze authority (only with `freeze_on_blacklist`)
+    pub freeze_authority: Option<Signer<'info>>,
+
+    /// Token-2022 program (only with `freeze_on_blacklist`)
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BlacklistAddBatch<'info> {
+    /// Blacklist authority (pays for new entries)
+    #[account(mut)]
+    pub blacklist_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: writable blacklist entry PDAs, one per address
+}
+
+#[derive(Accounts)]
+pub struct ClearAllBlacklist<'info> {
+    /// Stablecoin authority
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+    // remaining_accounts: writable blacklist entry PDAs to deactivate
+}
+
+#[derive(Accounts)]
+pub struct ComputeBlacklistDigest<'info> {
+    /// Blacklist authority (must match stablecoin_state.blacklist_authority)
+    pub blacklist_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+    // remaining_accounts (compute_blacklist_digest): the next page of blacklist entry PDAs
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct BlacklistRemove<'info> {
+    /// Blacklist authority (must match stablecoin_state.blacklist_authority)
+    #[account(mut)]
+    pub blacklist_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Blacklist entry PDA to deactivate
+    #[account(
+        mut,
+        seeds = [b"blacklist", mint.key().as_ref(), address.as_ref()],
+        bump = blacklist_entry.bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    /// Token account to thaw (only with `thaw_on_remove`)
+    #[account(mut)]
+    pub token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Freeze authority (only with `thaw_on_remove`)
+    pub freeze_authority: Option<Signer<'info>>,
+
+    /// Token-2022 program (only with `thaw_on_remove`)
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct AllowlistAdd<'info> {
+    /// Compliance officer
+    #[account(mut)]
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Allowlist entry PDA (created or updated)
+    #[account(
+        init_if_needed,
+        payer = compliance_officer,
+        space = AllowlistEntry::SPACE,
+        seeds = [b"allowlist", mint.key().as_ref(), address.as_ref()],
+        bump,
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct AllowlistRemove<'info> {
+    /// Compliance officer
+    #[account(mut)]
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Allowlist entry PDA to deactivate
+    #[account(
+        mut,
+        seeds = [b"allowlist", mint.key().as_ref(), address.as_ref()],
+        bump = allowlist_entry.bump,
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+}
+
+#[derive(Accounts)]
+pub struct SetAllowlistRoot<'info> {
+    /// Compliance officer
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct KycSetTier<'info> {
+    /// Compliance officer (pays for a new record)
+    #[account(mut)]
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// KYC record PDA for the owner (created or updated)
+    #[account(
+        init_if_needed,
+        payer = compliance_officer,
+        space = KycRecord::SPACE,
+        seeds = [b"kyc", mint.key().as_ref(), owner.as_ref()],
+        bump,
+    )]
+    pub kyc_record: Account<'info, KycRecord>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct PlaceHold<'info> {
+    /// Compliance officer (pays for a new hold)
+    #[account(mut)]
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Compliance hold PDA for the owner (created, or re-used once released or expired)
+    #[account(
+        init_if_needed,
+        payer = compliance_officer,
+        space = ComplianceHold::SPACE,
+        seeds = [b"hold", mint.key().as_ref(), owner.as_ref()],
+        bump,
+    )]
+    pub compliance_hold: Account<'info, ComplianceHold>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct ReleaseHold<'info> {
+    /// Compliance officer
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Compliance hold PDA for the owner
+    #[account(
+        mut,
+        seeds = [b"hold", mint.key().as_ref(), owner.as_ref()],
+        bump = compliance_hold.bump,
+    )]
+    pub compliance_hold: Account<'info, ComplianceHold>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, seize_all: bool, reason: String, seized_at: i64)]
+pub struct SeizeTokens<'info> {
+    /// Compliance officer
+    #[account(mut)]
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Blacklist entry for the target account owner
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), target_account.owner.as_ref()],
+        bump = blacklist_entry.bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    /// Target token account to seize from
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub target_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account to receive seized tokens (must be owned by the seizure treasury)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Seizure record PDA for this action
+    #[account(
+        init,
+        payer = compliance_officer,
+        space = SeizureRecord::SPACE,
+        seeds = [b"seizure", mint.key().as_ref(), target_account.key().as_ref(), &seized_at.to_le_bytes()],
+        bump,
+    )]
+    pub seizure_record: Account<'info, SeizureRecord>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, seize_all: bool, reason: String, seized_at: i64)]
+pub struct ProposeSeizure<'info> {
+    /// Proposing compliance officer
+    #[account(mut)]
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Blacklist entry for the target account owner
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), target_account.owner.as_ref()],
+        bump = blacklist_entry.bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    /// Target token account to seize from
+    #[account(
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub target_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account to receive seized tokens (must be owned by the seizure treasury)
+    #[account(
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Pending seizure PDA awaiting a second officer
+    #[account(
+        init,
+        payer = compliance_officer,
+        space = PendingSeizure::SPACE,
+        seeds = [b"pending_seizure", mint.key().as_ref(), target_account.key().as_ref(), &seized_at.to_le_bytes()],
+        bump,
+    )]
+    pub pending_seizure: Account<'info, PendingSeizure>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(seized_at: i64)]
+pub struct ApproveSeizure<'info> {
+    /// Approving compliance officer (must differ from the proposer)
+    #[account(mut)]
+    pub compliance_officer: Signer<'info>,
+
+    /// Proposing officer (receives the pending seizure rent)
+    /// CHECK: Address matched against `pending_seizure.proposed_by`
+    #[account(mut, address = pending_seizure.proposed_by)]
+    pub proposer: AccountInfo<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Blacklist entry for the target account owner
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), target_account.owner.as_ref()],
+        bump = blacklist_entry.bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    /// Target token account named in the proposal
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub target_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Destination token account named in the proposal
+    #[account(
+        mut,
+        address = pending_seizure.destination,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Pending seizure PDA, closed once executed
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"pending_seizure", mint.key().as_ref(), target_account.key().as_ref(), &seized_at.to_le_bytes()],
+        bump = pending_seizure.bump,
+    )]
+    pub pending_seizure: Account<'info, PendingSeizure>,
+
+    /// Seizure record PDA for this action
+    #[account(
+        init,
+        payer = compliance_officer,
+        space = SeizureRecord::SPACE,
+        seeds = [b"seizure", mint.key().as_ref(), target_account.key().as_ref(), &seized_at.to_le_bytes()],
+        bump,
+    )]
+    pub seizure_record: Account<'info, SeizureRecord>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ForceTransfer<'info> {
+    /// Compliance officer
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA (permanent delegate)
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Token account to move tokens from (any holder)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub source: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account to move tokens to (any holder)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct BurnSeized<'info> {
+    /// Compliance officer
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA (permanent delegate)
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Treasury token account holding seized tokens (must be owned by the seizure treasury)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = treasury.owner == stablecoin_state.seizure_treasury_owner() @ SSSError::InvalidTreasury,
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ReissueSeized<'info> {
+    /// Stablecoin authority (pays for the reissue record)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Compliance officer co-signing (required while `require_dual_seize` is set)
+    pub co_signer: Option<Signer<'info>>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA (permanent delegate)
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Seizure being reversed
+    #[account(
+        constraint = seizure_record.stablecoin == mint.key() @ SSSError::MintMismatch,
+    )]
+    pub seizure_record: Account<'info, SeizureRecord>,
+
+    /// Treasury token account holding the seized tokens
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account of the rightful owner
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub recipient_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Blacklist entry PDA for the recipient owner (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), recipient_account.owner.as_ref()],
+        bump,
+    )]
+    pub recipient_blacklist: AccountInfo<'info>,
+
+    /// Reissue record PDA, one per seizure
+    #[account(
+        init,
+        payer = authority,
+        space = ReissueRecord::SPACE,
+        seeds = [b"reissue", mint.key().as_ref(), seizure_record.key().as_ref()],
+        bump,
+    )]
+    pub reissue_record: Account<'info, ReissueRecord>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetSeizureTreasury<'info> {
+    /// Stablecoin authority
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+}
+
+#[derive(Accounts)]
+pub struct InitBlacklistBitmap<'info> {
+    /// Blacklist authority (pays for the bitmap)
+    #[account(mut)]
+    pub blacklist_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Blacklist bitmap PDA
+    #[account(
+        init,
+        payer = blacklist_authority,
+        space = BlacklistBitmap::SPACE,
+        seeds = [b"blacklist-bitmap", mint.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_bitmap: Box<Account<'info, BlacklistBitmap>>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateBlacklistBitmap<'info> {
+    /// Blacklist authority
+    pub blacklist_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Blacklist bitmap PDA
+    #[account(
+        mut,
+        seeds = [b"blacklist-bitmap", mint.key().as_ref()],
+        bump = blacklist_bitmap.bump,
+    )]
+    pub blacklist_bitmap: Box<Account<'info, BlacklistBitmap>>,
 }
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use spl_token_2022::extension::transfer_fee::instruction::{
+    harvest_withheld_tokens_to_mint, withdraw_withheld_tokens_from_mint,
+};
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::Mint as MintState;
+
+use crate::state::StablecoinState;
+use crate::errors::SSSError;
+use crate::events::FeesWithdrawn;
+
+/// Move transfer fees withheld in the token accounts in `remaining_accounts` onto the mint.
+/// Only callable by the fee authority; up to `MAX_BATCH_SIZE` accounts per call.
+pub fn harvest_withheld_fees_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, HarvestWithheldFees<'info>>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.fee_authority.key() == state.fee_authority,
+        SSSError::Unauthorized
+    );
+    require!(!ctx.remaining_accounts.is_empty(), SSSError::InvalidAmount);
+    require!(ctx.remaining_accounts.len() <= StablecoinState::MAX_BATCH_SIZE, SSSError::BatchTooLarge);
+
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let withheld_before = mint_withheld_amount(&mint_info)?;
+
+    let sources: Vec<&Pubkey> = ctx.remaining_accounts.iter().map(|account| account.key).collect();
+    let ix = harvest_withheld_tokens_to_mint(
+        &ctx.accounts.token_program.key(),
+        &ctx.accounts.mint.key(),
+        &sources,
+    )?;
+    let mut account_infos = vec![mint_info.clone()];
+    account_infos.extend(ctx.remaining_accounts.iter().cloned());
+    invoke(&ix, &account_infos)?;
+
+    let harvested = mint_withheld_amount(&mint_info)?.saturating_sub(withheld_before);
+
+    msg!(
+        "SSS: Harvested {} withheld tokens from {} accounts",
+        harvested,
+        ctx.remaining_accounts.len()
+    );
+    Ok(())
+}
+
+/// Withdraw all fees withheld on the mint to `fee_collector` (fee authority only).
+/// The mint's withdraw-withheld authority must be the stablecoin state PDA.
+pub fn withdraw_withheld_fees_handler(
+    ctx: Context<WithdrawWithheldFees>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.fee_authority.key() == state.fee_authority,
+        SSSError::Unauthorized
+    );
+
+    let amount = mint_withheld_amount(&ctx.accounts.mint.to_account_info())?;
+    require!(amount > 0, SSSError::InvalidAmount);
+
+    // Withdraw, signing as the withdraw-withheld authority
+    let mint_key = ctx.accounts.mint.key();
+    let bump = state.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"stablecoin", mint_key.as_ref(), &[bump]]];
+
+    let ix = withdraw_withheld_tokens_from_mint(
+        &ctx.accounts.token_program.key(),
+        &mint_key,
+        &ctx.accounts.fee_collector.key(),
+        &state.key(),
+        &[],
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.fee_collector.to_account_info(),
+            ctx.accounts.stablecoin_state.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(FeesWithdrawn {
+        mint: state.mint,
+        collector: ctx.accounts.fee_collector.key(),
+        amount,
+        withdrawn_by: ctx.accounts.fee_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Withdrew {} withheld tokens to {}", amount, ctx.accounts.fee_collector.key());
+    Ok(())
+}
+
+/// Fees currently withheld on the mint by its transfer-fee extension.
+fn mint_withheld_amount(mint: &AccountInfo) -> Result<u64> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let fee_config = mint_state
+        .get_extension::<TransferFeeConfig>()
+        .map_err(|_| SSSError::MissingExtension)?;
+    Ok(u64::from(fee_config.withheld_amount))
+}
+
+#[derive(Accounts)]
+pub struct HarvestWithheldFees<'info> {
+    /// Fee authority (must match stablecoin_state.fee_authority)
+    pub fee_authority: Signer<'info>,
+
+    /// The mint (receives the harvested fees)
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+    // remaining_accounts: writable token accounts to harvest from
+}
+
+#[derive(Accounts)]
+pub struct WithdrawWithheldFees<'info> {
+    /// Fee authority (must match stablecoin_state.fee_authority)
+    pub fee_authority: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA (the mint's withdraw-withheld authority)
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Token account receiving the withdrawn fees
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub fee_collector: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+}
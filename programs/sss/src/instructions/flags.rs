@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{StablecoinState, FeatureFlag, ChangeLog, AdminAction};
+use crate::errors::SSSError;
+use crate::events::ConfigUpdated;
+use crate::instructions::changelog::record_admin_action;
+
+/// Set a stablecoin-level feature flag.
+/// Every change goes through here so each toggle leaves a `ConfigUpdated` audit record.
+pub fn set_feature_flag_handler(
+    ctx: Context<SetFeatureFlag>,
+    flag: FeatureFlag,
+    value: bool,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+
+    let old_value = match flag {
+        FeatureFlag::Paused => state.paused,
+        FeatureFlag::AllowlistMode => {
+            if value {
+                require!(!state.is_sss2, SSSError::ListModeConflict);
+                require!(state.transfer_hook_enabled, SSSError::TransferHookNotEnabled);
+            }
+            state.allowlist_mode
+        }
+        FeatureFlag::DefaultAccountFrozen => {
+            require!(state.is_sss2, SSSError::SSS2Required);
+            state.default_account_frozen
+        }
+        FeatureFlag::FreezeOnBlacklist => {
+            require!(state.is_sss2, SSSError::SSS2Required);
+            state.freeze_on_blacklist
+        }
+        FeatureFlag::BitmapBlacklist => {
+            require!(state.is_sss2, SSSError::SSS2Required);
+            state.bitmap_blacklist
+        }
+        FeatureFlag::DualSeize => {
+            require!(state.is_sss2, SSSError::SSS2Required);
+            state.require_dual_seize
+        }
+    };
+
+    match flag {
+        FeatureFlag::Paused => state.paused = value,
+        FeatureFlag::AllowlistMode => state.allowlist_mode = value,
+        FeatureFlag::DefaultAccountFrozen => state.default_account_frozen = value,
+        FeatureFlag::FreezeOnBlacklist => state.freeze_on_blacklist = value,
+        FeatureFlag::BitmapBlacklist => state.bitmap_blacklist = value,
+        FeatureFlag::DualSeize => state.require_dual_seize = value,
+    }
+
+    let clock = Clock::get()?;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::FeatureFlagChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(ConfigUpdated {
+        mint: state.mint,
+        flag: flag.name().to_string(),
+        old_value,
+        new_value: value,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Set {} from {} to {}", flag.name(), old_value, value);
+    Ok(())
+}
+
+/// Turn mint, burn and transfer event emission on or off to save compute on
+/// high-throughput deployments. Compliance events are never suppressed, and the
+/// change itself is always recorded as a `ConfigUpdated` event.
+pub fn set_event_emission_handler(
+    ctx: Context<SetFeatureFlag>,
+    enabled: bool,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+
+    let old_value = state.emit_events;
+    state.emit_events = enabled;
+
+    let clock = Clock::get()?;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::FeatureFlagChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(ConfigUpdated {
+        mint: state.mint,
+        flag: "emit_events".to_string(),
+        old_value,
+        new_value: enabled,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Set emit_events from {} to {}", old_value, enabled);
+    Ok(())
+}
+
+/// Permanently lock the configuration (authority only, irreversible).
+/// Metadata, feature flags, events, limit setters (including the fee authority
+/// and limit exemptions), the seizure treasury and the SSS-2 upgrade are refused
+/// afterwards. Role changes, pausing and compliance actions stay available; the
+/// latter are the list and enforcement actions of the compliance roles: blacklist
+/// and allowlist entries, the allowlist root, KYC tiers, holds, freezes and seizures.
+pub fn lock_config_handler(ctx: Context<SetFeatureFlag>) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+
+    state.config_locked = true;
+
+    let clock = Clock::get()?;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::FeatureFlagChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(ConfigUpdated {
+        mint: state.mint,
+        flag: "config_locked".to_string(),
+        old_value: false,
+        new_value: true,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Configuration locked");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeatureFlag<'info> {
+    /// Stablecoin authority (owner)
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
@@ -1,13 +1,61 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, FreezeAccount as SplFreeze, ThawAccount as SplThaw, freeze_account, thaw_account};
 
-use crate::state::StablecoinState;
+use crate::state::{StablecoinState, FrozenAccountRecord, ThawApproval, ComplianceOfficers};
 use crate::errors::SSSError;
-use crate::events::{AccountFrozenEvent, AccountThawedEvent};
+use crate::events::{AccountFrozenEvent, AccountThawedEvent, AccountApproved, BatchFreezeCompleted, BatchThawCompleted};
+use crate::instructions::hook::is_blacklisted;
+
+/// Freeze or thaw `account` through the Token-2022 freeze authority.
+/// Once `delegate_freeze_authority` has handed that authority to the stablecoin
+/// PDA, the PDA signs and the caller's role check is all that gates the call;
+/// otherwise `signer` must be the on-chain freeze authority itself.
+pub(crate) fn set_account_frozen<'info>(
+    frozen: bool,
+    token_program: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    account: AccountInfo<'info>,
+    stablecoin: AccountInfo<'info>,
+    bump: u8,
+    signer: AccountInfo<'info>,
+) -> Result<()> {
+    let mint_key = mint.key();
+    let bump = [bump];
+    let pda_seeds: &[&[&[u8]]] = &[&[b"stablecoin", mint_key.as_ref(), &bump]];
+    let (authority, signer_seeds) = if freeze_held_by_pda(mint, stablecoin.key) {
+        (stablecoin, pda_seeds)
+    } else {
+        (signer, &[][..])
+    };
+
+    if frozen {
+        let cpi_accounts = SplFreeze {
+            account,
+            mint: mint.to_account_info(),
+            authority,
+        };
+        freeze_account(CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds))
+    } else {
+        let cpi_accounts = SplThaw {
+            account,
+            mint: mint.to_account_info(),
+            authority,
+        };
+        thaw_account(CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds))
+    }
+}
+
+/// Whether the mint's Token-2022 freeze authority is the stablecoin PDA.
+pub(crate) fn freeze_held_by_pda(mint: &InterfaceAccount<Mint>, stablecoin: &Pubkey) -> bool {
+    mint.freeze_authority == COption::Some(*stablecoin)
+}
 
 /// Freeze a token account — prevents all transfers in/out.
+/// The reason is kept in a `FrozenAccountRecord` PDA for audits.
 pub fn freeze_handler(
     ctx: Context<FreezeAccount>,
+    reason: String,
 ) -> Result<()> {
     let state = &ctx.accounts.stablecoin_state;
 
@@ -16,23 +64,37 @@ pub fn freeze_handler(
         ctx.accounts.freeze_authority.key() == state.freeze_authority,
         SSSError::Unauthorized
     );
+    require!(reason.len() <= FrozenAccountRecord::MAX_REASON_LEN, SSSError::ReasonTooLong);
 
-    let cpi_accounts = SplFreeze {
-        account: ctx.accounts.token_account.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        authority: ctx.accounts.freeze_authority.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new(
+    set_account_frozen(
+        true,
         ctx.accounts.token_program.to_account_info(),
-        cpi_accounts,
-    );
-    freeze_account(cpi_ctx)?;
+        &ctx.accounts.mint,
+        ctx.accounts.token_account.to_account_info(),
+        ctx.accounts.stablecoin_state.to_account_info(),
+        state.bump,
+        ctx.accounts.freeze_authority.to_account_info(),
+    )?;
 
     let clock = Clock::get()?;
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.freeze_op_count = state.freeze_op_count.saturating_add(1);
+
+    // A re-freeze after a thaw overwrites the previous record
+    let record = &mut ctx.accounts.frozen_record;
+    record.stablecoin = state.mint;
+    record.token_account = ctx.accounts.token_account.key();
+    record.frozen_by = ctx.accounts.freeze_authority.key();
+    record.frozen_at = clock.unix_timestamp;
+    record.reason = reason.clone();
+    record.thawed_at = None;
+    record.bump = ctx.bumps.frozen_record;
+
     emit!(AccountFrozenEvent {
         mint: state.mint,
         account: ctx.accounts.token_account.key(),
         frozen_by: ctx.accounts.freeze_authority.key(),
+        reason,
         timestamp: clock.unix_timestamp,
     });
 
@@ -40,6 +102,127 @@ pub fn freeze_handler(
     Ok(())
 }
 
+/// Freeze a token account on behalf of the compliance officer (SSS-2 only).
+/// Lets an investigation freeze a suspect account without the freeze authority.
+/// The officer holds no Token-2022 authority, so the freeze authority must first
+/// be delegated to the stablecoin PDA, which then signs the freeze.
+pub fn freeze_by_compliance_handler(
+    ctx: Context<FreezeByCompliance>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&ctx.accounts.compliance_officer.key()),
+        SSSError::Unauthorized
+    );
+
+    require!(
+        freeze_held_by_pda(&ctx.accounts.mint, &state.key()),
+        SSSError::FreezeAuthorityNotDelegated
+    );
+
+    set_account_frozen(
+        true,
+        ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint,
+        ctx.accounts.token_account.to_account_info(),
+        ctx.accounts.stablecoin_state.to_account_info(),
+        state.bump,
+        ctx.accounts.compliance_officer.to_account_info(),
+    )?;
+
+    let clock = Clock::get()?;
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.freeze_op_count = state.freeze_op_count.saturating_add(1);
+
+    emit!(AccountFrozenEvent {
+        mint: state.mint,
+        account: ctx.accounts.token_account.key(),
+        frozen_by: ctx.accounts.compliance_officer.key(),
+        reason: String::new(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Compliance froze account {}", ctx.accounts.token_account.key());
+    Ok(())
+}
+
+/// Register a freshly created token account with the stablecoin.
+/// When `default_account_frozen` is set, an empty, unfrozen account is frozen
+/// so it must be explicitly thawed before it can receive transfers.
+pub fn register_account_handler(
+    ctx: Context<RegisterAccount>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.freeze_authority.key() == state.freeze_authority,
+        SSSError::Unauthorized
+    );
+
+    let token_account = &ctx.accounts.token_account;
+    if !state.default_account_frozen || token_account.is_frozen() || token_account.amount > 0 {
+        msg!("SSS: Registered account {} (left as is)", token_account.key());
+        return Ok(());
+    }
+
+    set_account_frozen(
+        true,
+        ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint,
+        token_account.to_account_info(),
+        state.to_account_info(),
+        state.bump,
+        ctx.accounts.freeze_authority.to_account_info(),
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(AccountFrozenEvent {
+        mint: state.mint,
+        account: token_account.key(),
+        frozen_by: ctx.accounts.freeze_authority.key(),
+        reason: String::new(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Registered and froze account {}", token_account.key());
+    Ok(())
+}
+
+/// Approve a token account to receive transfers (SSS-2 only).
+/// While `default_account_frozen` is set, the transfer hook refuses any
+/// destination without a `ThawApproval`.
+pub fn approve_account_handler(
+    ctx: Context<ApproveAccount>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(state.is_sss2, SSSError::SSS2Required);
+    require!(
+        ctx.accounts.freeze_authority.key() == state.freeze_authority,
+        SSSError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    let approval = &mut ctx.accounts.thaw_approval;
+    approval.stablecoin = state.mint;
+    approval.token_account = ctx.accounts.token_account.key();
+    approval.approved_by = ctx.accounts.freeze_authority.key();
+    approval.approved_at = clock.unix_timestamp;
+    approval.bump = ctx.bumps.thaw_approval;
+
+    emit!(AccountApproved {
+        mint: state.mint,
+        account: approval.token_account,
+        approved_by: approval.approved_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Approved account {}", approval.token_account);
+    Ok(())
+}
+
 /// Thaw (unfreeze) a token account — re-enables transfers.
 pub fn thaw_handler(
     ctx: Context<ThawAccount>,
@@ -51,18 +234,41 @@ pub fn thaw_handler(
         SSSError::Unauthorized
     );
 
-    let cpi_accounts = SplThaw {
-        account: ctx.accounts.token_account.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        authority: ctx.accounts.freeze_authority.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new(
+    let clock = Clock::get()?;
+
+    // SSS-2: a blacklisted owner must be removed from the blacklist before
+    // their account can be thawed
+    if state.is_sss2 {
+        let owner_blacklist = ctx
+            .accounts
+            .owner_blacklist
+            .as_ref()
+            .ok_or(SSSError::MissingAccount)?;
+        require!(
+            !is_blacklisted(
+                owner_blacklist,
+                &state.mint,
+                &ctx.accounts.token_account.owner,
+                clock.unix_timestamp,
+            )?,
+            SSSError::CannotThawBlacklisted
+        );
+    }
+
+    set_account_frozen(
+        false,
         ctx.accounts.token_program.to_account_info(),
-        cpi_accounts,
-    );
-    thaw_account(cpi_ctx)?;
+        &ctx.accounts.mint,
+        ctx.accounts.token_account.to_account_info(),
+        state.to_account_info(),
+        state.bump,
+        ctx.accounts.freeze_authority.to_account_info(),
+    )?;
+
+    if let Some(record) = ctx.accounts.frozen_record.as_mut() {
+        record.thawed_at = Some(clock.unix_timestamp);
+    }
 
-    let clock = Clock::get()?;
     emit!(AccountThawedEvent {
         mint: state.mint,
         account: ctx.accounts.token_account.key(),
@@ -74,6 +280,152 @@ pub fn thaw_handler(
     Ok(())
 }
 
+/// Freeze several token accounts (passed as remaining accounts) in one instruction.
+/// Accounts that are already frozen are skipped. No `FrozenAccountRecord`s are
+/// written; the reason is carried in each `AccountFrozenEvent`.
+pub fn freeze_batch_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FreezeBatch<'info>>,
+    reason: String,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.freeze_authority.key() == state.freeze_authority,
+        SSSError::Unauthorized
+    );
+    require!(!ctx.remaining_accounts.is_empty(), SSSError::InvalidAmount);
+    require!(ctx.remaining_accounts.len() <= StablecoinState::MAX_BATCH_SIZE, SSSError::BatchTooLarge);
+    require!(reason.len() <= FrozenAccountRecord::MAX_REASON_LEN, SSSError::ReasonTooLong);
+
+    let clock = Clock::get()?;
+    let mut frozen: u8 = 0;
+    let mut skipped: u8 = 0;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        let token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+        require!(
+            token_account.mint == ctx.accounts.mint.key(),
+            SSSError::MintMismatch
+        );
+        if token_account.is_frozen() {
+            skipped += 1;
+            continue;
+        }
+
+        set_account_frozen(
+            true,
+            ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint,
+            account_info.clone(),
+            state.to_account_info(),
+            state.bump,
+            ctx.accounts.freeze_authority.to_account_info(),
+        )?;
+        frozen += 1;
+
+        emit!(AccountFrozenEvent {
+            mint: state.mint,
+            account: account_info.key(),
+            frozen_by: ctx.accounts.freeze_authority.key(),
+            reason: reason.clone(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.freeze_op_count = state.freeze_op_count.saturating_add(frozen as u64);
+
+    emit!(BatchFreezeCompleted {
+        mint: state.mint,
+        frozen,
+        skipped,
+        frozen_by: ctx.accounts.freeze_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Batch froze {} accounts ({} already frozen)", frozen, skipped);
+    Ok(())
+}
+
+/// Thaw several token accounts (passed as remaining accounts) in one instruction.
+/// Accounts that are not frozen are skipped. On SSS-2 each token account is
+/// followed by its owner's blacklist entry PDA, and a blacklisted owner fails
+/// the whole batch just as it would fail `thaw_account`.
+pub fn thaw_batch_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FreezeBatch<'info>>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.freeze_authority.key() == state.freeze_authority,
+        SSSError::Unauthorized
+    );
+    let stride = if state.is_sss2 { 2 } else { 1 };
+    require!(!ctx.remaining_accounts.is_empty(), SSSError::InvalidAmount);
+    require!(ctx.remaining_accounts.len() % stride == 0, SSSError::MissingAccount);
+    require!(ctx.remaining_accounts.len() / stride <= StablecoinState::MAX_BATCH_SIZE, SSSError::BatchTooLarge);
+
+    let clock = Clock::get()?;
+    let mint_key = ctx.accounts.mint.key();
+    let mut thawed: u8 = 0;
+    let mut skipped: u8 = 0;
+
+    for accounts in ctx.remaining_accounts.chunks(stride) {
+        let account_info = &accounts[0];
+        let token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+        require!(
+            token_account.mint == mint_key,
+            SSSError::MintMismatch
+        );
+        if !token_account.is_frozen() {
+            skipped += 1;
+            continue;
+        }
+
+        if state.is_sss2 {
+            let blacklist_info = &accounts[1];
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"blacklist", mint_key.as_ref(), token_account.owner.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(blacklist_info.key(), expected, SSSError::InvalidPda);
+            require!(
+                !is_blacklisted(blacklist_info, &state.mint, &token_account.owner, clock.unix_timestamp)?,
+                SSSError::CannotThawBlacklisted
+            );
+        }
+
+        set_account_frozen(
+            false,
+            ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint,
+            account_info.clone(),
+            state.to_account_info(),
+            state.bump,
+            ctx.accounts.freeze_authority.to_account_info(),
+        )?;
+        thawed += 1;
+
+        emit!(AccountThawedEvent {
+            mint: state.mint,
+            account: account_info.key(),
+            thawed_by: ctx.accounts.freeze_authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(BatchThawCompleted {
+        mint: state.mint,
+        thawed,
+        skipped,
+        thawed_by: ctx.accounts.freeze_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Batch thawed {} accounts ({} not frozen)", thawed, skipped);
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct FreezeAccount<'info> {
     /// Freeze authority
@@ -91,6 +443,84 @@ pub struct FreezeAccount<'info> {
     )]
     pub token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Frozen account record PDA (created or overwritten)
+    #[account(
+        init_if_needed,
+        payer = freeze_authority,
+        space = FrozenAccountRecord::SPACE,
+        seeds = [b"frozen", mint.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub frozen_record: Account<'info, FrozenAccountRecord>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeByCompliance<'info> {
+    /// Compliance officer
+    #[account(mut)]
+    pub compliance_officer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Token account to freeze
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterAccount<'info> {
+    /// Freeze authority
+    #[account(mut)]
+    pub freeze_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Newly created token account to register
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
     /// Stablecoin state PDA
     #[account(
         seeds = [b"stablecoin", mint.key().as_ref()],
@@ -102,6 +532,46 @@ pub struct FreezeAccount<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
+#[derive(Accounts)]
+pub struct ApproveAccount<'info> {
+    /// Freeze authority (pays for the approval)
+    #[account(mut)]
+    pub freeze_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Token account to approve
+    #[account(
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Thaw approval PDA for the token account
+    #[account(
+        init,
+        payer = freeze_authority,
+        space = ThawApproval::SPACE,
+        seeds = [b"approved", mint.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub thaw_approval: Account<'info, ThawApproval>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct ThawAccount<'info> {
     /// Freeze authority
@@ -126,6 +596,44 @@ pub struct ThawAccount<'info> {
     )]
     pub stablecoin_state: Account<'info, StablecoinState>,
 
+    /// Frozen account record PDA (absent for accounts frozen without one)
+    #[account(
+        mut,
+        seeds = [b"frozen", mint.key().as_ref(), token_account.key().as_ref()],
+        bump = frozen_record.bump,
+    )]
+    pub frozen_record: Option<Account<'info, FrozenAccountRecord>>,
+
+    /// Blacklist entry PDA for the token account owner (required for SSS-2; may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), token_account.owner.as_ref()],
+        bump,
+    )]
+    pub owner_blacklist: Option<AccountInfo<'info>>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeBatch<'info> {
+    /// Freeze authority
+    pub freeze_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
     /// Token-2022 program
     pub token_program: Interface<'info, TokenInterface>,
+    // remaining_accounts: writable token accounts to freeze or thaw; for an
+    // SSS-2 thaw each is followed by the blacklist entry PDA of its owner
 }
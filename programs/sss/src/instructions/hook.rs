@@ -1,11 +1,197 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+use std::collections::BTreeSet;
 
-use crate::state::{StablecoinState, BlacklistEntry};
+use crate::state::{StablecoinState, BlacklistEntry, AllowlistEntry, ThawApproval, ComplianceHold, MintLock, ComplianceOfficers};
 use crate::errors::SSSError;
-use crate::events::TransferHookExecuted;
+use crate::events::{TransferHookExecuted, ExemptAddressUpdated};
+use crate::bitmap::is_flagged;
+
+// Account indices in the transfer-hook-interface Execute instruction
+const SOURCE_INDEX: u8 = 0;
+const MINT_INDEX: u8 = 1;
+const DESTINATION_INDEX: u8 = 2;
+
+// Number of entries returned by `extra_account_metas`
+const EXTRA_ACCOUNT_META_COUNT: usize = 10;
+
+// Leading extra accounts named in `TransferHook`; the rest arrive as remaining accounts
+const NAMED_EXTRA_ACCOUNT_COUNT: usize = 5;
+
+// Remaining-account indices of the source and destination compliance holds
+const SOURCE_HOLD_INDEX: usize = 2;
+const DESTINATION_HOLD_INDEX: usize = 3;
+
+// `TransferHookExecuted::blocked_party` bits (0 = none, 3 = both)
+pub const BLOCKED_NONE: u8 = 0;
+pub const BLOCKED_SOURCE: u8 = 1;
+pub const BLOCKED_DESTINATION: u8 = 2;
+
+// Offset and length of the owner field in a token account
+const TOKEN_ACCOUNT_OWNER_OFFSET: u8 = 32;
+const PUBKEY_LEN: u8 = 32;
+
+/// Extra accounts Token-2022 resolves for every Execute, in `TransferHook` order:
+/// stablecoin state, sender/recipient blacklist entries, source/destination allowlist entries,
+/// then the blacklist bitmap, the destination's thaw approval, the source/destination
+/// compliance holds and the source's mint lock (passed through as remaining accounts 0 to 4).
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    let owner_of = |account_index: u8| Seed::AccountData {
+        account_index,
+        data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+        length: PUBKEY_LEN,
+    };
+    let list_entry = |prefix: &[u8], account_index: u8| {
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: prefix.to_vec() },
+                Seed::AccountKey { index: MINT_INDEX },
+                owner_of(account_index),
+            ],
+            false,
+            false,
+        )
+    };
+
+    Ok(vec![
+        // stablecoin_state
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: b"stablecoin".to_vec() },
+                Seed::AccountKey { index: MINT_INDEX },
+            ],
+            false,
+            false,
+        )?,
+        // sender_blacklist / recipient_blacklist
+        list_entry(b"blacklist", SOURCE_INDEX)?,
+        list_entry(b"blacklist", DESTINATION_INDEX)?,
+        // source_allowlist / destination_allowlist
+        list_entry(b"allowlist", SOURCE_INDEX)?,
+        list_entry(b"allowlist", DESTINATION_INDEX)?,
+        // blacklist_bitmap
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: b"blacklist-bitmap".to_vec() },
+                Seed::AccountKey { index: MINT_INDEX },
+            ],
+            false,
+            false,
+        )?,
+        // destination_approval
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: b"approved".to_vec() },
+                Seed::AccountKey { index: MINT_INDEX },
+                Seed::AccountKey { index: DESTINATION_INDEX },
+            ],
+            false,
+            false,
+        )?,
+        // source_hold / destination_hold
+        list_entry(b"hold", SOURCE_INDEX)?,
+        list_entry(b"hold", DESTINATION_INDEX)?,
+        // source_lock
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: b"mint_lock".to_vec() },
+                Seed::AccountKey { index: MINT_INDEX },
+                Seed::AccountKey { index: SOURCE_INDEX },
+            ],
+            false,
+            false,
+        )?,
+    ])
+}
+
+/// Account size for the ExtraAccountMetaList PDA.
+pub fn extra_account_meta_list_size() -> usize {
+    // size_of only fails on overflow, which a fixed count cannot hit
+    ExtraAccountMetaList::size_of(EXTRA_ACCOUNT_META_COUNT).unwrap_or_default()
+}
+
+/// Create the `[b"extra-account-metas", mint]` PDA that tells Token-2022 which
+/// extra accounts to pass to the transfer hook.
+pub fn initialize_extra_account_meta_list_handler(
+    ctx: Context<InitializeExtraAccountMetaList>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    require!(state.transfer_hook_enabled, SSSError::TransferHookNotEnabled);
+
+    let metas = extra_account_metas()?;
+    let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+
+    msg!("SSS: Initialized transfer hook extra account metas for {}", state.mint);
+    Ok(())
+}
+
+/// Rewrite an existing ExtraAccountMetaList with the current `extra_account_metas`.
+/// Lists created before the approval, hold or mint lock entries existed resolve
+/// fewer accounts, and the hook refuses transfers that need the missing ones;
+/// this grows the PDA (topping up rent from the authority) and brings it current.
+pub fn update_extra_account_meta_list_handler(
+    ctx: Context<UpdateExtraAccountMetaList>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    require!(state.transfer_hook_enabled, SSSError::TransferHookNotEnabled);
+
+    let info = ctx.accounts.extra_account_meta_list.to_account_info();
+    let size = extra_account_meta_list_size();
+    let shortfall = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(info.lamports());
+    if shortfall > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: info.clone(),
+            },
+        );
+        transfer(cpi_ctx, shortfall)?;
+    }
+    if info.data_len() < size {
+        info.realloc(size, false)?;
+    }
+
+    let metas = extra_account_metas()?;
+    let mut data = info.try_borrow_mut_data()?;
+    ExtraAccountMetaList::update::<ExecuteInstruction>(&mut data, &metas)?;
+
+    msg!("SSS: Updated transfer hook extra account metas for {}", state.mint);
+    Ok(())
+}
 
 /// Transfer hook handler — executed automatically by Token-2022 before every transfer.
 /// For SSS-2 tokens, this checks that neither the sender nor recipient is blacklisted.
+/// With `bitmap_blacklist` set, the check reads the blacklist bitmap (the first
+/// remaining account) instead of the per-address blacklist entries. With
+/// `default_account_frozen` set, the destination also needs a `ThawApproval`
+/// (the second remaining account, required then). An owner under an effective `ComplianceHold`
+/// (the third and fourth remaining accounts, required whenever the meta list
+/// resolves them) is blocked like a blacklisted one.
+/// With `non_transferable` set, only permanent-delegate movements pass. With a mint
+/// lock period set, a source still under its `MintLock` (the fifth remaining
+/// account, required then) fails with `TokensLocked`.
+/// The transfer limits, cooldown, KYC tiers and minimum balance are advisory:
+/// only the `transfer` / `transfer_batch` wrappers enforce them, so a direct
+/// Token-2022 transfer skips them.
+/// A blocked transfer fails the whole transaction, so nothing the hook writes would
+/// survive; `report_blocked_transfer` records it on-chain instead.
 pub fn transfer_hook_handler(
     ctx: Context<TransferHook>,
     amount: u64,
@@ -19,70 +205,458 @@ pub fn transfer_hook_handler(
 
     let clock = Clock::get()?;
 
-    // Check sender blacklist
-    if let Some(sender_bl) = &ctx.accounts.sender_blacklist {
-        if sender_bl.is_active {
-            emit!(TransferHookExecuted {
-                mint: state.mint,
-                source: ctx.accounts.source.key(),
-                destination: ctx.accounts.destination.key(),
-                amount,
-                allowed: false,
-                timestamp: clock.unix_timestamp,
-            });
-            return Err(SSSError::TransferBlocked.into());
-        }
+    // Permanent-delegate movements (seizure, forced transfer) are compliance actions and skip list checks
+    if ctx.accounts.owner.key() == state.key() {
+        emit!(TransferHookExecuted {
+            mint: state.mint,
+            source: ctx.accounts.source.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            allowed: true,
+            blocked_party: BLOCKED_NONE,
+            timestamp: clock.unix_timestamp,
+        });
+        return Ok(());
     }
 
-    // Check recipient blacklist
-    if let Some(recipient_bl) = &ctx.accounts.recipient_blacklist {
-        if recipient_bl.is_active {
-            emit!(TransferHookExecuted {
-                mint: state.mint,
-                source: ctx.accounts.source.key(),
-                destination: ctx.accounts.destination.key(),
-                amount,
-                allowed: false,
-                timestamp: clock.unix_timestamp,
-            });
-            return Err(SSSError::TransferBlocked.into());
+    // Soulbound tokens only move via the permanent delegate
+    require!(!state.non_transferable, SSSError::NonTransferable);
+
+    // Freshly minted tokens stay put until the lock expires; a meta list that
+    // predates the lock must be brought current before locked mints can move
+    if state.mint_lock_period.is_some() {
+        let source_lock = ctx.remaining_accounts.get(4).ok_or(SSSError::MissingAccount)?;
+        if let Some(lock) = load_entry::<MintLock>(source_lock)? {
+            require!(clock.unix_timestamp >= lock.unlock_at, SSSError::TokensLocked);
         }
     }
 
+    let source_owner = token_account_owner(&ctx.accounts.source)?;
+    let destination_owner = token_account_owner(&ctx.accounts.destination)?;
+
+    // Holds can be placed at any time, so once the meta list resolves the hold
+    // PDAs a missing one cannot be read as "not held"
+    let listed = listed_remaining_accounts(&ctx.accounts.extra_account_meta_list)?;
+    let source_hold = listed_account(ctx.remaining_accounts, listed, SOURCE_HOLD_INDEX)?;
+    let destination_hold = listed_account(ctx.remaining_accounts, listed, DESTINATION_HOLD_INDEX)?;
+
+    let blocked_party = blocked_party(
+        state,
+        &ctx.accounts.destination.key(),
+        &source_owner,
+        &destination_owner,
+        &PartyAccounts {
+            sender_blacklist: &ctx.accounts.sender_blacklist,
+            recipient_blacklist: &ctx.accounts.recipient_blacklist,
+            source_allowlist: &ctx.accounts.source_allowlist,
+            destination_allowlist: &ctx.accounts.destination_allowlist,
+            bitmap: ctx.remaining_accounts.first(),
+            destination_approval: ctx.remaining_accounts.get(1),
+            source_hold,
+            destination_hold,
+        },
+        clock.unix_timestamp,
+    )?;
+    let allowed = blocked_party == BLOCKED_NONE;
+
     emit!(TransferHookExecuted {
         mint: state.mint,
         source: ctx.accounts.source.key(),
         destination: ctx.accounts.destination.key(),
         amount,
-        allowed: true,
+        allowed,
+        blocked_party,
         timestamp: clock.unix_timestamp,
     });
 
+    require!(allowed, SSSError::TransferBlocked);
+
     msg!("SSS: Transfer hook passed — {} tokens allowed", amount);
     Ok(())
 }
 
+/// Record a transfer the hook rejects (compliance officer or blacklist authority).
+/// A rejected transfer rolls back everything the hook did, so monitoring replays
+/// the attempt here: the parties are re-checked against the same lists and, if
+/// still blocked, `blocked_transfer_count` is bumped and
+/// `TransferHookExecuted { allowed: false }` is emitted. Fails with
+/// `TransferNotBlocked` if the transfer would now pass.
+pub fn report_blocked_transfer_handler(
+    ctx: Context<ReportBlockedTransfer>,
+    amount: u64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+    let reporter = ctx.accounts.reporter.key();
+
+    require!(
+        ctx.accounts.compliance_officers.is_compliance_officer(&reporter)
+            || reporter == state.blacklist_authority,
+        SSSError::Unauthorized
+    );
+    require!(state.transfer_hook_enabled, SSSError::TransferHookNotEnabled);
+
+    let clock = Clock::get()?;
+    let blocked_party = blocked_party(
+        state,
+        &ctx.accounts.destination.key(),
+        &ctx.accounts.source.owner,
+        &ctx.accounts.destination.owner,
+        &PartyAccounts {
+            sender_blacklist: &ctx.accounts.sender_blacklist,
+            recipient_blacklist: &ctx.accounts.recipient_blacklist,
+            source_allowlist: &ctx.accounts.source_allowlist,
+            destination_allowlist: &ctx.accounts.destination_allowlist,
+            bitmap: Some(&ctx.accounts.blacklist_bitmap),
+            destination_approval: Some(&ctx.accounts.destination_approval),
+            source_hold: Some(&ctx.accounts.source_hold),
+            destination_hold: Some(&ctx.accounts.destination_hold),
+        },
+        clock.unix_timestamp,
+    )?;
+    require!(blocked_party != BLOCKED_NONE, SSSError::TransferNotBlocked);
+
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.blocked_transfer_count = state.blocked_transfer_count.saturating_add(1);
+
+    emit!(TransferHookExecuted {
+        mint: state.mint,
+        source: ctx.accounts.source.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        allowed: false,
+        blocked_party,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Reported blocked transfer ({} total)", state.blocked_transfer_count);
+    Ok(())
+}
+
+/// List accounts `blocked_party` reads. The hook gets them from Token-2022's meta
+/// resolution (the optional ones are absent for older meta lists, which
+/// `update_extra_account_meta_list` brings current);
+/// `report_blocked_transfer` passes them all, seed-checked.
+pub(crate) struct PartyAccounts<'a, 'info> {
+    pub sender_blacklist: &'a AccountInfo<'info>,
+    pub recipient_blacklist: &'a AccountInfo<'info>,
+    pub source_allowlist: &'a AccountInfo<'info>,
+    pub destination_allowlist: &'a AccountInfo<'info>,
+    pub bitmap: Option<&'a AccountInfo<'info>>,
+    pub destination_approval: Option<&'a AccountInfo<'info>>,
+    pub source_hold: Option<&'a AccountInfo<'info>>,
+    pub destination_hold: Option<&'a AccountInfo<'info>>,
+}
+
+/// Which parties of a transfer the lists block, as `BLOCKED_*` bits.
+/// An exempt owner (treasury, mint authority) is never blocked itself, but the
+/// other party is still checked. A party is blocked if it has an effective
+/// blacklist entry or compliance hold or, in allowlist mode, is not approved; the
+/// destination also needs a `ThawApproval` under `default_account_frozen`.
+/// Both parties are checked so events name each one.
+pub(crate) fn blocked_party(
+    state: &StablecoinState,
+    destination: &Pubkey,
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+    accounts: &PartyAccounts,
+    now: i64,
+) -> Result<u8> {
+    let (source_listed, destination_listed) = if state.bitmap_blacklist {
+        let bitmap = accounts.bitmap.ok_or(SSSError::MissingAccount)?;
+        (
+            is_flagged(bitmap, &state.mint, source_owner)?,
+            is_flagged(bitmap, &state.mint, destination_owner)?,
+        )
+    } else {
+        (
+            is_blacklisted(accounts.sender_blacklist, &state.mint, source_owner, now)?,
+            is_blacklisted(accounts.recipient_blacklist, &state.mint, destination_owner, now)?,
+        )
+    };
+    let source_held = match accounts.source_hold {
+        Some(hold) => is_held(hold, &state.mint, source_owner, now)?,
+        None => false,
+    };
+    let destination_held = match accounts.destination_hold {
+        Some(hold) => is_held(hold, &state.mint, destination_owner, now)?,
+        None => false,
+    };
+    let source_blocked = !state.is_exempt(source_owner)
+        && (source_listed
+            || source_held
+            || (state.allowlist_mode && !is_allowlisted(accounts.source_allowlist, &state.mint, source_owner)?));
+    let destination_unapproved = if state.default_account_frozen {
+        let approval = accounts.destination_approval.ok_or(SSSError::MissingAccount)?;
+        !is_approved(approval, &state.mint, destination)?
+    } else {
+        false
+    };
+    let destination_blocked = !state.is_exempt(destination_owner)
+        && (destination_listed
+            || destination_held
+            || destination_unapproved
+            || (state.allowlist_mode && !is_allowlisted(accounts.destination_allowlist, &state.mint, destination_owner)?));
+
+    let mut blocked_party = BLOCKED_NONE;
+    if source_blocked {
+        blocked_party |= BLOCKED_SOURCE;
+    }
+    if destination_blocked {
+        blocked_party |= BLOCKED_DESTINATION;
+    }
+    Ok(blocked_party)
+}
+
+/// Exempt an owner from transfer hook list checks (authority only).
+/// The exempt owner is never blocked, but its counterparty is still checked.
+pub fn add_exempt_address_handler(
+    ctx: Context<UpdateExemptAddresses>,
+    address: Pubkey,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    require!(!state.is_exempt(&address), SSSError::AlreadyExempt);
+    require!(
+        state.exempt_addresses.len() < StablecoinState::MAX_EXEMPT_ADDRESSES,
+        SSSError::ExemptListFull
+    );
+
+    let clock = Clock::get()?;
+    state.exempt_addresses.push(address);
+    state.updated_at = clock.unix_timestamp;
+
+    emit!(ExemptAddressUpdated {
+        mint: state.mint,
+        address,
+        exempt: true,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: {} exempt from transfer hook checks", address);
+    Ok(())
+}
+
+/// Remove an owner's transfer hook exemption (authority only).
+pub fn remove_exempt_address_handler(
+    ctx: Context<UpdateExemptAddresses>,
+    address: Pubkey,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    let index = state
+        .exempt_addresses
+        .iter()
+        .position(|key| key == &address)
+        .ok_or(SSSError::NotExempt)?;
+
+    let clock = Clock::get()?;
+    state.exempt_addresses.remove(index);
+    state.updated_at = clock.unix_timestamp;
+
+    emit!(ExemptAddressUpdated {
+        mint: state.mint,
+        address,
+        exempt: false,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: {} no longer exempt from transfer hook checks", address);
+    Ok(())
+}
+
+/// Number of remaining accounts the mint's ExtraAccountMetaList resolves for the
+/// hook, derived from the size of the list PDA.
+fn listed_remaining_accounts(meta_list: &AccountInfo) -> Result<usize> {
+    let header = ExtraAccountMetaList::size_of(0)?;
+    let metas = meta_list.data_len().saturating_sub(header) / std::mem::size_of::<ExtraAccountMeta>();
+    Ok(metas.saturating_sub(NAMED_EXTRA_ACCOUNT_COUNT))
+}
+
+/// The remaining account at `index`: required when the meta list resolves it,
+/// `None` for an older list that predates it.
+fn listed_account<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    listed: usize,
+    index: usize,
+) -> Result<Option<&'a AccountInfo<'info>>> {
+    if index >= listed {
+        return Ok(None);
+    }
+    let account = remaining_accounts.get(index).ok_or(SSSError::MissingAccount)?;
+    Ok(Some(account))
+}
+
+/// Read the owner out of a raw Token-2022 token account.
+fn token_account_owner(account: &AccountInfo) -> Result<Pubkey> {
+    let data = account.try_borrow_data()?;
+    let token_account = TokenAccount::try_deserialize(&mut &data[..])?;
+    Ok(token_account.owner)
+}
+
+/// Deserialize a list-entry PDA. The PDA is resolved for every transfer but only
+/// exists once an address has been listed, so an empty account means "not listed".
+pub(crate) fn load_entry<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.data_is_empty() || info.owner != &crate::ID {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+/// Returns true if the entry exists, is effective, and matches the mint and owner.
+pub(crate) fn is_blacklisted(info: &AccountInfo, mint: &Pubkey, owner: &Pubkey, now: i64) -> Result<bool> {
+    Ok(match load_entry::<BlacklistEntry>(info)? {
+        Some(entry) => {
+            entry.is_effective(now)
+                && entry.stablecoin == *mint
+                && entry.blacklisted_address == *owner
+        }
+        None => false,
+    })
+}
+
+/// Returns true if the entry exists, is active, and matches the mint and owner.
+fn is_allowlisted(info: &AccountInfo, mint: &Pubkey, owner: &Pubkey) -> Result<bool> {
+    Ok(match load_entry::<AllowlistEntry>(info)? {
+        Some(entry) => {
+            entry.is_active
+                && entry.stablecoin == *mint
+                && entry.allowlisted_address == *owner
+        }
+        None => false,
+    })
+}
+
+/// Returns true if the hold exists, is effective, and matches the mint and owner.
+fn is_held(info: &AccountInfo, mint: &Pubkey, owner: &Pubkey, now: i64) -> Result<bool> {
+    Ok(match load_entry::<ComplianceHold>(info)? {
+        Some(hold) => hold.is_effective(now) && hold.stablecoin == *mint && hold.owner == *owner,
+        None => false,
+    })
+}
+
+/// Returns true if the approval exists and matches the mint and token account.
+fn is_approved(info: &AccountInfo, mint: &Pubkey, token_account: &Pubkey) -> Result<bool> {
+    Ok(match load_entry::<ThawApproval>(info)? {
+        Some(approval) => approval.stablecoin == *mint && approval.token_account == *token_account,
+        None => false,
+    })
+}
+
 /// Fallback handler for the transfer hook interface.
-/// Routes SPI transfer-hook-interface instructions to our handler.
+/// Token-2022 invokes the hook with the interface's Execute discriminator rather
+/// than Anchor's, so we unpack it here and dispatch to `transfer_hook_handler`.
 pub fn fallback_handler<'info>(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &'info [AccountInfo<'info>],
     data: &[u8],
 ) -> Result<()> {
-    // Check if this is the Execute instruction from the transfer hook interface
-    let instruction_discriminator = &data[..8];
-    
-    // SPL Transfer Hook Interface "Execute" instruction discriminator
-    let execute_discriminator: [u8; 8] = spl_transfer_hook_interface::instruction::ExecuteInstruction::SPL_DISCRIMINATOR_SLICE
-        .try_into()
-        .unwrap_or([0u8; 8]);
-
-    if instruction_discriminator == execute_discriminator {
-        msg!("SSS: Transfer hook execute called via fallback");
-        // In a full implementation, deserialize accounts and call transfer_hook_handler
+    let instruction = TransferHookInstruction::unpack(data)?;
+
+    match instruction {
+        TransferHookInstruction::Execute { amount } => {
+            let mut bumps = TransferHookBumps::default();
+            let mut remaining_accounts: &'info [AccountInfo<'info>] = accounts;
+            let mut hook_accounts = TransferHook::try_accounts(
+                program_id,
+                &mut remaining_accounts,
+                data,
+                &mut bumps,
+                &mut BTreeSet::new(),
+            )?;
+            let ctx = Context::new(program_id, &mut hook_accounts, remaining_accounts, bumps);
+            transfer_hook_handler(ctx, amount)
+        }
+        _ => Err(ProgramError::InvalidInstructionData.into()),
     }
+}
 
-    Ok(())
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    /// Stablecoin authority (pays for the PDA)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// ExtraAccountMetaList PDA read by Token-2022
+    /// CHECK: Initialized in the handler via spl-tlv-account-resolution
+    #[account(
+        init,
+        payer = authority,
+        space = extra_account_meta_list_size(),
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetaList<'info> {
+    /// Stablecoin authority (pays for any extra rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Existing ExtraAccountMetaList PDA
+    /// CHECK: Address verified by seeds and owner; rewritten via spl-tlv-account-resolution
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateExemptAddresses<'info> {
+    /// Stablecoin authority
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
 }
 
 #[derive(Accounts)]
@@ -103,6 +677,14 @@ pub struct TransferHook<'info> {
     /// CHECK: Validated by Token-2022 program
     pub owner: AccountInfo<'info>,
 
+    /// ExtraAccountMetaList PDA
+    /// CHECK: Address verified by seeds; contents read by Token-2022
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
     /// Extra account: stablecoin state
     #[account(
         seeds = [b"stablecoin", mint.key().as_ref()],
@@ -110,9 +692,91 @@ pub struct TransferHook<'info> {
     )]
     pub stablecoin_state: Account<'info, StablecoinState>,
 
-    /// Extra account: sender blacklist entry (optional — may not exist)
-    pub sender_blacklist: Option<Account<'info, BlacklistEntry>>,
+    /// Extra account: sender blacklist entry PDA (may not exist)
+    /// CHECK: Loaded and matched against the source owner in the handler
+    pub sender_blacklist: AccountInfo<'info>,
+
+    /// Extra account: recipient blacklist entry PDA (may not exist)
+    /// CHECK: Loaded and matched against the destination owner in the handler
+    pub recipient_blacklist: AccountInfo<'info>,
+
+    /// Extra account: source owner allowlist entry PDA (may not exist)
+    /// CHECK: Loaded and matched against the source owner in the handler
+    pub source_allowlist: AccountInfo<'info>,
+
+    /// Extra account: destination owner allowlist entry PDA (may not exist)
+    /// CHECK: Loaded and matched against the destination owner in the handler
+    pub destination_allowlist: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReportBlockedTransfer<'info> {
+    /// Compliance officer or blacklist authority
+    pub reporter: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Source token account of the rejected transfer
+    #[account(token::mint = mint)]
+    pub source: InterfaceAccount<'info, TokenAccount>,
+
+    /// Destination token account of the rejected transfer
+    #[account(token::mint = mint)]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// Blacklist entry PDA for the source owner (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(seeds = [b"blacklist", mint.key().as_ref(), source.owner.as_ref()], bump)]
+    pub sender_blacklist: AccountInfo<'info>,
+
+    /// Blacklist entry PDA for the destination owner (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(seeds = [b"blacklist", mint.key().as_ref(), destination.owner.as_ref()], bump)]
+    pub recipient_blacklist: AccountInfo<'info>,
+
+    /// Allowlist entry PDA for the source owner (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(seeds = [b"allowlist", mint.key().as_ref(), source.owner.as_ref()], bump)]
+    pub source_allowlist: AccountInfo<'info>,
+
+    /// Allowlist entry PDA for the destination owner (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(seeds = [b"allowlist", mint.key().as_ref(), destination.owner.as_ref()], bump)]
+    pub destination_allowlist: AccountInfo<'info>,
+
+    /// Blacklist bitmap PDA (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(seeds = [b"blacklist-bitmap", mint.key().as_ref()], bump)]
+    pub blacklist_bitmap: AccountInfo<'info>,
+
+    /// Thaw approval PDA for the destination token account (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(seeds = [b"approved", mint.key().as_ref(), destination.key().as_ref()], bump)]
+    pub destination_approval: AccountInfo<'info>,
+
+    /// Compliance hold PDA for the source owner (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(seeds = [b"hold", mint.key().as_ref(), source.owner.as_ref()], bump)]
+    pub source_hold: AccountInfo<'info>,
 
-    /// Extra account: recipient blacklist entry (optional — may not exist)
-    pub recipient_blacklist: Option<Account<'info, BlacklistEntry>>,
+    /// Compliance hold PDA for the destination owner (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(seeds = [b"hold", mint.key().as_ref(), destination.owner.as_ref()], bump)]
+    pub destination_hold: AccountInfo<'info>,
 }
@@ -2,9 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_2022;
 use anchor_spl::token_interface::{Mint, TokenInterface};
 
-use crate::state::{StablecoinConfig, StablecoinState};
+use crate::state::{StablecoinConfig, StablecoinState, SymbolRegistry, RoundingPolicy, DustPolicy};
 use crate::errors::SSSError;
 use crate::events::StablecoinInitialized;
+use crate::validation::{validate_name, validate_symbol};
 
 /// Initialize a new stablecoin with either SSS-1 or SSS-2 preset.
 /// 
@@ -13,32 +14,185 @@ use crate::events::StablecoinInitialized;
 pub fn handler(
     ctx: Context<Initialize>,
     config: StablecoinConfig,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    initialize_state(
+        &mut ctx.accounts.stablecoin_state,
+        &mut ctx.accounts.symbol_registry,
+        &ctx.accounts.mint,
+        authority,
+        config,
+        ctx.bumps.stablecoin_state,
+        ctx.bumps.symbol_registry,
+    )
+}
+
+/// Initialize a stablecoin unless its state already exists.
+///
+/// Lets deployment scripts re-run safely: an existing state (recognized by its
+/// discriminator, checked when the account loads, and a non-zero `created_at`)
+/// is left untouched, so a second call cannot change the authority or config.
+pub fn initialize_if_needed_handler(
+    ctx: Context<InitializeIfNeeded>,
+    config: StablecoinConfig,
+) -> Result<()> {
+    if ctx.accounts.stablecoin_state.created_at != 0 {
+        msg!("SSS: {} already initialized, leaving state as is", ctx.accounts.stablecoin_state.symbol);
+        return Ok(());
+    }
+
+    // A fresh state must also claim a fresh symbol
+    require!(ctx.accounts.symbol_registry.registered_at == 0, SSSError::SymbolTaken);
+
+    let authority = ctx.accounts.authority.key();
+    initialize_state(
+        &mut ctx.accounts.stablecoin_state,
+        &mut ctx.accounts.symbol_registry,
+        &ctx.accounts.mint,
+        authority,
+        config,
+        ctx.bumps.stablecoin_state,
+        ctx.bumps.symbol_registry,
+    )
+}
+
+/// Validate `config` and populate a newly created state and symbol registry.
+fn initialize_state(
+    state: &mut StablecoinState,
+    registry: &mut SymbolRegistry,
+    mint: &InterfaceAccount<Mint>,
+    authority: Pubkey,
+    config: StablecoinConfig,
+    state_bump: u8,
+    registry_bump: u8,
 ) -> Result<()> {
     // Validate config
-    require!(config.name.len() <= StablecoinState::MAX_NAME_LEN, SSSError::NameTooLong);
-    require!(config.symbol.len() <= StablecoinState::MAX_SYMBOL_LEN, SSSError::SymbolTooLong);
+    validate_name(&config.name)?;
+    validate_symbol(&config.symbol)?;
+    require!(config.uri.len() <= StablecoinState::MAX_URI_LEN, SSSError::UriTooLong);
+    require!(config.decimals <= StablecoinState::MAX_DECIMALS, SSSError::InvalidDecimals);
+    require!(config.decimals == mint.decimals, SSSError::InvalidDecimals);
+    if let Some(limit) = config.max_transfer_amount {
+        require!(limit > 0, SSSError::InvalidAmount);
+    }
+    require!(
+        config.transfer_fee_basis_points <= StablecoinState::MAX_FEE_BASIS_POINTS,
+        SSSError::InvalidFeeBasisPoints
+    );
+    require!(config.role_change_delay >= 0, SSSError::InvalidDelay);
+    require!(config.mint_authority_rotation_cooldown >= 0, SSSError::InvalidDelay);
+    // Permanent delegate and transfer hook come as a pair; allowlist mode is the
+    // one hook-only preset, since the hook is what enforces the list
+    require!(
+        config.enable_permanent_delegate == config.enable_transfer_hook || config.allowlist_mode,
+        SSSError::InconsistentPreset
+    );
+    if config.allowlist_mode {
+        require!(!config.is_sss2(), SSSError::ListModeConflict);
+        require!(config.enable_transfer_hook, SSSError::TransferHookNotEnabled);
+    }
+
+    if config.freeze_on_blacklist {
+        require!(config.is_sss2(), SSSError::SSS2Required);
+    }
+
+    let compliance_officer = config.compliance_officer.unwrap_or(authority);
+    if compliance_officer != authority {
+        require!(config.is_sss2(), SSSError::SSS2Required);
+    }
 
     let clock = Clock::get()?;
-    let state = &mut ctx.accounts.stablecoin_state;
 
     // Store stablecoin state
-    state.mint = ctx.accounts.mint.key();
-    state.authority = ctx.accounts.authority.key();
-    state.mint_authority = ctx.accounts.authority.key();
-    state.freeze_authority = ctx.accounts.authority.key();
-    state.compliance_officer = ctx.accounts.authority.key();
+    state.version = StablecoinState::CURRENT_VERSION;
+    state.mint = mint.key();
+    state.authority = authority;
+    state.pending_authority = None;
+    state.mint_authority = config.mint_authority.unwrap_or(authority);
+    state.freeze_authority = config.freeze_authority.unwrap_or(authority);
+    state.compliance_officer = compliance_officer;
+    state.pauser = authority;
+    state.renounced_roles = 0;
+    state.role_change_delay = config.role_change_delay;
+    state.mint_authority_rotation_cooldown = config.mint_authority_rotation_cooldown;
+    state.last_mint_authority_change = clock.unix_timestamp;
     state.is_sss2 = config.is_sss2();
     state.permanent_delegate_enabled = config.enable_permanent_delegate;
     state.transfer_hook_enabled = config.enable_transfer_hook;
     state.default_account_frozen = config.default_account_frozen;
+    state.paused = false;
+    state.allowlist_mode = config.allowlist_mode;
+    state.allowlist_root = [0u8; 32];
+    state.max_transfer_amount = config.max_transfer_amount;
+    state.transfer_fee_basis_points = config.transfer_fee_basis_points;
+    state.max_transfer_fee = config.max_transfer_fee;
+    state.daily_mint_limit = None;
+    state.minted_today = 0;
+    state.mint_window_start = clock.unix_timestamp;
     state.total_minted = 0;
     state.total_burned = 0;
+    state.total_seized = 0;
+    state.supply_drift = 0;
+    state.active_blacklist_count = 0;
     state.created_at = clock.unix_timestamp;
     state.updated_at = clock.unix_timestamp;
-    state.bump = ctx.bumps.stablecoin_state;
+    state.bump = state_bump;
     state.name = config.name.clone();
     state.symbol = config.symbol.clone();
+    state.uri = config.uri.clone();
     state.decimals = config.decimals;
+    state.last_migrated_at = 0;
+    state.interest_rate_bps = 0;
+    state.exempt_addresses = Vec::new();
+    state.total_transfer_volume = 0;
+    state.min_transfer_amount = None;
+    state.oracle = None;
+    state.max_peg_deviation_bps = 0;
+    state.mint_op_count = 0;
+    state.burn_op_count = 0;
+    state.freeze_op_count = 0;
+    state.seize_op_count = 0;
+    state.transfer_cooldown = None;
+    state.blacklist_authority = compliance_officer;
+    state.seizure_treasury = None;
+    state.clawback_window = None;
+    state.max_single_op_amount = None;
+    state.freeze_on_blacklist = config.freeze_on_blacklist;
+    state.bitmap_blacklist = false;
+    state.emit_events = true;
+    state.kyc_tier_thresholds = [0; StablecoinState::MAX_KYC_TIER as usize];
+    state.mint_fee_bps = 0;
+    state.mint_fee_collector = Pubkey::default();
+    state.mint_lock_period = None;
+    state.max_blacklist_entries = None;
+    state.fee_authority = state.authority;
+    state.changelog_enabled = false;
+    state.daily_seize_limit = None;
+    state.seized_today = 0;
+    state.seize_window_start = clock.unix_timestamp;
+    state.seize_cooldown = None;
+    state.last_seize_at = 0;
+    state.non_transferable = config.non_transferable;
+    state.max_transfer_batch_size = 0;
+    state.config_locked = false;
+    state.require_dual_seize = false;
+    state.blacklist_digest = [0u8; 32];
+    state.digest_updated_at = 0;
+    state.fee_rounding = RoundingPolicy::RoundDown;
+    state.blocked_transfer_count = 0;
+    state.min_account_balance = None;
+    state.dust_policy = DustPolicy::Reject;
+    state.digest_snapshot_at = 0;
+    state.digest_accumulator = [0u8; 32];
+    state.digest_cursor = None;
+    state.digest_active_seen = 0;
+    state.digest_expired_seen = 0;
+
+    // Claim the symbol; the caller already rejected a registry owned by another mint
+    registry.symbol = config.symbol.clone();
+    registry.mint = mint.key();
+    registry.registered_at = clock.unix_timestamp;
+    registry.bump = registry_bump;
 
     // Emit initialization event for audit trail
     emit!(StablecoinInitialized {
@@ -46,6 +200,7 @@ pub fn handler(
         authority: state.authority,
         name: config.name,
         symbol: config.symbol,
+        uri: config.uri,
         decimals: config.decimals,
         preset: state.preset_label(),
         timestamp: clock.unix_timestamp,
@@ -91,6 +246,61 @@ pub struct Initialize<'info> {
     )]
     pub stablecoin_state: Account<'info, StablecoinState>,
 
+    /// Symbol registry PDA; fails to initialize if the symbol is already taken
+    #[account(
+        init,
+        payer = authority,
+        space = SymbolRegistry::SPACE,
+        seeds = [b"symbol", config.symbol.as_bytes()],
+        bump,
+    )]
+    pub symbol_registry: Account<'info, SymbolRegistry>,
+
+    /// Token-2022 program (required for extensions)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(config: StablecoinConfig)]
+pub struct InitializeIfNeeded<'info> {
+    /// The authority (deployer) who owns this stablecoin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint account for the new stablecoin token
+    /// Created via Token-2022 with appropriate extensions
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// PDA storing the stablecoin's configuration and state (left as is if it exists)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = StablecoinState::SPACE,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Symbol registry PDA; must be fresh whenever the state is
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SymbolRegistry::SPACE,
+        seeds = [b"symbol", config.symbol.as_bytes()],
+        bump,
+    )]
+    pub symbol_registry: Account<'info, SymbolRegistry>,
+
     /// Token-2022 program (required for extensions)
     pub token_program: Interface<'info, TokenInterface>,
 
@@ -0,0 +1,899 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{StablecoinState, LimitExemption, ChangeLog, AdminAction, RoundingPolicy, DustPolicy};
+use crate::errors::SSSError;
+use crate::events::{TransferLimitUpdated, MinTransferUpdated, DailyMintLimitUpdated, TransferFeeUpdated, InterestRateUpdated, OracleUpdated, TransferCooldownUpdated, ClawbackWindowUpdated, MaxSingleOpAmountUpdated, KycThresholdsUpdated, MintFeeUpdated, FeeRoundingUpdated, MinAccountBalanceUpdated, MintLockPeriodUpdated, MaxBlacklistEntriesUpdated, FeeAuthorityUpdated, LimitExemptionSet, DailySeizeLimitUpdated, SeizeCooldownUpdated, TransferBatchSizeUpdated};
+use crate::instructions::changelog::record_admin_action;
+
+/// Set (or clear with `None`) the per-transfer maximum amount.
+/// Enforced by the `transfer` wrappers only; the transfer hook does not check it.
+pub fn set_transfer_limit_handler(
+    ctx: Context<SetLimit>,
+    max_transfer_amount: Option<u64>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    if let Some(limit) = max_transfer_amount {
+        require!(limit > 0, SSSError::InvalidAmount);
+        if let Some(minimum) = state.min_transfer_amount {
+            require!(limit >= minimum, SSSError::InvalidAmount);
+        }
+    }
+
+    let clock = Clock::get()?;
+    let old_limit = state.max_transfer_amount;
+    state.max_transfer_amount = max_transfer_amount;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(TransferLimitUpdated {
+        mint: state.mint,
+        old_limit,
+        new_limit: max_transfer_amount,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Transfer limit set to {:?}", max_transfer_amount);
+    Ok(())
+}
+
+/// Set (or clear with `None`) the per-transfer minimum amount.
+/// Enforced by the `transfer` wrappers only; the transfer hook does not check it.
+pub fn set_min_transfer_handler(
+    ctx: Context<SetLimit>,
+    min_transfer_amount: Option<u64>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    if let Some(minimum) = min_transfer_amount {
+        require!(minimum > 0, SSSError::InvalidAmount);
+        if let Some(limit) = state.max_transfer_amount {
+            require!(minimum <= limit, SSSError::InvalidAmount);
+        }
+    }
+
+    let clock = Clock::get()?;
+    let old_minimum = state.min_transfer_amount;
+    state.min_transfer_amount = min_transfer_amount;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(MinTransferUpdated {
+        mint: state.mint,
+        old_minimum,
+        new_minimum: min_transfer_amount,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Minimum transfer set to {:?}", min_transfer_amount);
+    Ok(())
+}
+
+/// Set (or clear with `None`) the cap on a single mint, burn, transfer or seizure.
+pub fn set_max_single_op_amount_handler(
+    ctx: Context<SetLimit>,
+    max_single_op_amount: Option<u64>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    if let Some(max) = max_single_op_amount {
+        require!(max > 0, SSSError::InvalidAmount);
+    }
+
+    let clock = Clock::get()?;
+    let old_max = state.max_single_op_amount;
+    state.max_single_op_amount = max_single_op_amount;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(MaxSingleOpAmountUpdated {
+        mint: state.mint,
+        old_max,
+        new_max: max_single_op_amount,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Single-operation cap set to {:?}", max_single_op_amount);
+    Ok(())
+}
+
+/// Set the transfer amounts above which each KYC tier is required.
+/// `thresholds[i]` gates tier `i + 1`; zero leaves a tier unused, and the
+/// non-zero entries must increase with the tier. Enforced by the `transfer`
+/// wrappers only; the transfer hook does not check tiers.
+pub fn set_kyc_thresholds_handler(
+    ctx: Context<SetLimit>,
+    thresholds: [u64; 3],
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    let mut previous = 0u64;
+    for threshold in thresholds.iter().filter(|threshold| **threshold > 0) {
+        require!(*threshold > previous, SSSError::InvalidKycTier);
+        previous = *threshold;
+    }
+
+    let clock = Clock::get()?;
+    let old_thresholds = state.kyc_tier_thresholds;
+    state.kyc_tier_thresholds = thresholds;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(KycThresholdsUpdated {
+        mint: state.mint,
+        old_thresholds,
+        new_thresholds: thresholds,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: KYC tier thresholds set to {:?}", thresholds);
+    Ok(())
+}
+
+/// Set (or clear with `None`) the rolling 24h mint limit.
+pub fn set_daily_mint_limit_handler(
+    ctx: Context<SetLimit>,
+    daily_mint_limit: Option<u64>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    if let Some(limit) = daily_mint_limit {
+        require!(limit > 0, SSSError::InvalidAmount);
+    }
+
+    let clock = Clock::get()?;
+    let old_limit = state.daily_mint_limit;
+    state.daily_mint_limit = daily_mint_limit;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(DailyMintLimitUpdated {
+        mint: state.mint,
+        old_limit,
+        new_limit: daily_mint_limit,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Daily mint limit set to {:?}", daily_mint_limit);
+    Ok(())
+}
+
+/// Set (or clear with `None`) the rolling 24h seize limit.
+pub fn set_daily_seize_limit_handler(
+    ctx: Context<SetLimit>,
+    daily_seize_limit: Option<u64>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    if let Some(limit) = daily_seize_limit {
+        require!(limit > 0, SSSError::InvalidAmount);
+    }
+
+    let clock = Clock::get()?;
+    let old_limit = state.daily_seize_limit;
+    state.daily_seize_limit = daily_seize_limit;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(DailySeizeLimitUpdated {
+        mint: state.mint,
+        old_limit,
+        new_limit: daily_seize_limit,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Daily seize limit set to {:?}", daily_seize_limit);
+    Ok(())
+}
+
+/// Set (or clear with `None`) the minimum interval between two seizures.
+pub fn set_seize_cooldown_handler(
+    ctx: Context<SetLimit>,
+    seize_cooldown: Option<i64>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    if let Some(cooldown) = seize_cooldown {
+        require!(cooldown >= 0, SSSError::InvalidDelay);
+    }
+
+    let clock = Clock::get()?;
+    let old_cooldown = state.seize_cooldown;
+    state.seize_cooldown = seize_cooldown;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(SeizeCooldownUpdated {
+        mint: state.mint,
+        old_cooldown,
+        new_cooldown: seize_cooldown,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Seize cooldown set to {:?}", seize_cooldown);
+    Ok(())
+}
+
+/// Set the recipient cap for `transfer_batch` (0 restores `MAX_BATCH_SIZE`).
+pub fn set_transfer_batch_size_handler(
+    ctx: Context<SetLimit>,
+    max_recipients: u8,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    require!(
+        max_recipients as usize <= StablecoinState::MAX_BATCH_SIZE,
+        SSSError::BatchTooLarge
+    );
+
+    let clock = Clock::get()?;
+    let old_size = state.max_transfer_batch_size;
+    state.max_transfer_batch_size = max_recipients;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(TransferBatchSizeUpdated {
+        mint: state.mint,
+        old_size,
+        new_size: max_recipients,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Transfer batch size set to {}", state.transfer_batch_limit());
+    Ok(())
+}
+
+/// Update the mirrored transfer-fee settings.
+/// Only the program-side mirror changes; the Token-2022 extension is not updated here.
+pub fn set_transfer_fee_handler(
+    ctx: Context<SetLimit>,
+    basis_points: u16,
+    max_fee: u64,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    require!(
+        basis_points <= StablecoinState::MAX_FEE_BASIS_POINTS,
+        SSSError::InvalidFeeBasisPoints
+    );
+
+    let clock = Clock::get()?;
+    let old_basis_points = state.transfer_fee_basis_points;
+    let old_max_fee = state.max_transfer_fee;
+    state.transfer_fee_basis_points = basis_points;
+    state.max_transfer_fee = max_fee;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(TransferFeeUpdated {
+        mint: state.mint,
+        old_basis_points,
+        new_basis_points: basis_points,
+        old_max_fee,
+        new_max_fee: max_fee,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Transfer fee set to {} bps (max {})", basis_points, max_fee);
+    Ok(())
+}
+
+/// Set the issuance fee taken from each `mint_to` / `mint_to_new` and the token
+/// account it is minted to. Zero basis points disables the fee.
+pub fn set_mint_fee_handler(
+    ctx: Context<SetLimit>,
+    basis_points: u16,
+    collector: Pubkey,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    require!(
+        basis_points <= StablecoinState::MAX_FEE_BASIS_POINTS,
+        SSSError::InvalidFeeBasisPoints
+    );
+    if basis_points > 0 {
+        require!(collector != Pubkey::default(), SSSError::InvalidFeeCollector);
+    }
+
+    let clock = Clock::get()?;
+    let old_basis_points = state.mint_fee_bps;
+    let old_collector = state.mint_fee_collector;
+    state.mint_fee_bps = basis_points;
+    state.mint_fee_collector = collector;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(MintFeeUpdated {
+        mint: state.mint,
+        old_basis_points,
+        new_basis_points: basis_points,
+        old_collector,
+        new_collector: collector,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Mint fee set to {} bps (collector {})", basis_points, collector);
+    Ok(())
+}
+
+/// Choose how the issuance fee is rounded. The transfer fee always rounds up to
+/// match the Token-2022 extension.
+pub fn set_fee_rounding_handler(
+    ctx: Context<SetLimit>,
+    policy: RoundingPolicy,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+
+    let clock = Clock::get()?;
+    let old_policy = state.fee_rounding;
+    state.fee_rounding = policy;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(FeeRoundingUpdated {
+        mint: state.mint,
+        old_policy,
+        new_policy: policy,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Fee rounding set to {:?}", policy);
+    Ok(())
+}
+
+/// Set (or clear with `None`) the smallest non-zero balance a transfer may leave
+/// in the source, and whether such a transfer is refused or sweeps the remainder.
+/// Enforced by the `transfer` wrappers only; the transfer hook does not check it.
+pub fn set_min_account_balance_handler(
+    ctx: Context<SetLimit>,
+    min_account_balance: Option<u64>,
+    policy: DustPolicy,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    if let Some(minimum) = min_account_balance {
+        require!(minimum > 0, SSSError::InvalidAmount);
+    }
+
+    let clock = Clock::get()?;
+    let old_minimum = state.min_account_balance;
+    let old_policy = state.dust_policy;
+    state.min_account_balance = min_account_balance;
+    state.dust_policy = policy;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(MinAccountBalanceUpdated {
+        mint: state.mint,
+        old_minimum,
+        new_minimum: min_account_balance,
+        old_policy,
+        new_policy: policy,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Minimum account balance set to {:?} ({:?})", min_account_balance, policy);
+    Ok(())
+}
+
+/// Update the mirrored interest rate, for indexers that don't parse mint extensions.
+/// The rate takes effect immediately; negative values model a decaying balance.
+pub fn set_interest_rate_handler(
+    ctx: Context<SetLimit>,
+    rate_bps: i16,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    require!(
+        (-StablecoinState::MAX_INTEREST_RATE_BPS..=StablecoinState::MAX_INTEREST_RATE_BPS).contains(&rate_bps),
+        SSSError::InvalidInterestRate
+    );
+
+    let clock = Clock::get()?;
+    let old_rate_bps = state.interest_rate_bps;
+    state.interest_rate_bps = rate_bps;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(InterestRateUpdated {
+        mint: state.mint,
+        old_rate_bps,
+        new_rate_bps: rate_bps,
+        effective_at: clock.unix_timestamp,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Interest rate set to {} bps", rate_bps);
+    Ok(())
+}
+
+/// Set (or clear with `None`) the price oracle checked before minting,
+/// together with the allowed deviation from the peg.
+pub fn set_oracle_handler(
+    ctx: Context<SetLimit>,
+    oracle: Option<Pubkey>,
+    max_peg_deviation_bps: u16,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    require!(
+        max_peg_deviation_bps <= StablecoinState::MAX_PEG_DEVIATION_BPS,
+        SSSError::InvalidPegDeviation
+    );
+
+    let clock = Clock::get()?;
+    state.oracle = oracle;
+    state.max_peg_deviation_bps = max_peg_deviation_bps;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(OracleUpdated {
+        mint: state.mint,
+        oracle,
+        max_peg_deviation_bps,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Oracle set to {:?} (max deviation {} bps)", oracle, max_peg_deviation_bps);
+    Ok(())
+}
+
+/// Set (or clear with `None`) the minimum interval between transfers out of one account.
+/// Enforced by the `transfer` wrappers only; the transfer hook does not check it.
+pub fn set_transfer_cooldown_handler(
+    ctx: Context<SetLimit>,
+    transfer_cooldown: Option<i64>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    if let Some(cooldown) = transfer_cooldown {
+        require!(cooldown >= 0, SSSError::InvalidDelay);
+    }
+
+    let clock = Clock::get()?;
+    let old_cooldown = state.transfer_cooldown;
+    state.transfer_cooldown = transfer_cooldown;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(TransferCooldownUpdated {
+        mint: state.mint,
+        old_cooldown,
+        new_cooldown: transfer_cooldown,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Transfer cooldown set to {:?}", transfer_cooldown);
+    Ok(())
+}
+
+/// Set (or clear with `None`) how long after a `mint_to` it can still be clawed back.
+pub fn set_clawback_window_handler(
+    ctx: Context<SetLimit>,
+    clawback_window: Option<i64>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    if let Some(window) = clawback_window {
+        require!(window >= 0, SSSError::InvalidDelay);
+    }
+
+    let clock = Clock::get()?;
+    let old_window = state.clawback_window;
+    state.clawback_window = clawback_window;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(ClawbackWindowUpdated {
+        mint: state.mint,
+        old_window,
+        new_window: clawback_window,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Clawback window set to {:?}", clawback_window);
+    Ok(())
+}
+
+/// Set (or clear with `None`) how long tokens from `mint_to` / `mint_to_new` stay locked
+/// in the destination. Batch mints do not create locks.
+pub fn set_mint_lock_period_handler(
+    ctx: Context<SetLimit>,
+    mint_lock_period: Option<i64>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    if let Some(period) = mint_lock_period {
+        require!(period >= 0, SSSError::InvalidDelay);
+    }
+
+    let clock = Clock::get()?;
+    let old_period = state.mint_lock_period;
+    state.mint_lock_period = mint_lock_period;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(MintLockPeriodUpdated {
+        mint: state.mint,
+        old_period,
+        new_period: mint_lock_period,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Mint lock period set to {:?}", mint_lock_period);
+    Ok(())
+}
+
+/// Set (or clear with `None`) the cap on active blacklist entries. Lowering it below the
+/// current count only blocks new additions; existing entries stay in force.
+pub fn set_max_blacklist_entries_handler(
+    ctx: Context<SetLimit>,
+    max_blacklist_entries: Option<u64>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+
+    let clock = Clock::get()?;
+    let old_max = state.max_blacklist_entries;
+    state.max_blacklist_entries = max_blacklist_entries;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(MaxBlacklistEntriesUpdated {
+        mint: state.mint,
+        old_max,
+        new_max: max_blacklist_entries,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Max blacklist entries set to {:?}", max_blacklist_entries);
+    Ok(())
+}
+
+/// Designate who may harvest and withdraw withheld transfer fees.
+pub fn set_fee_authority_handler(
+    ctx: Context<SetLimit>,
+    fee_authority: Pubkey,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+
+    let clock = Clock::get()?;
+    let old_authority = state.fee_authority;
+    state.fee_authority = fee_authority;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::LimitChanged,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(FeeAuthorityUpdated {
+        mint: state.mint,
+        old_authority,
+        new_authority: fee_authority,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Fee authority set to {}", fee_authority);
+    Ok(())
+}
+
+/// Exempt `owner` from `max_transfer_amount`, `min_transfer_amount` and the transfer
+/// cooldown when sending (or lift the exemption). KYC, lock and list checks still apply.
+pub fn set_limit_exempt_handler(
+    ctx: Context<SetLimitExempt>,
+    owner: Pubkey,
+    exempt: bool,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+
+    let clock = Clock::get()?;
+    let exemption = &mut ctx.accounts.limit_exemption;
+    exemption.stablecoin = state.mint;
+    exemption.owner = owner;
+    exemption.exempt = exempt;
+    exemption.set_by = ctx.accounts.authority.key();
+    exemption.updated_at = clock.unix_timestamp;
+    exemption.bump = ctx.bumps.limit_exemption;
+
+    emit!(LimitExemptionSet {
+        mint: state.mint,
+        owner,
+        exempt,
+        set_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Set limit exemption of {} to {}", owner, exempt);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLimit<'info> {
+    /// Stablecoin authority (owner)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct SetLimitExempt<'info> {
+    /// Stablecoin authority (pays for a new exemption)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Limit exemption PDA for the owner (created or updated)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = LimitExemption::SPACE,
+        seeds = [b"limit_exempt", mint.key().as_ref(), owner.as_ref()],
+        bump,
+    )]
+    pub limit_exemption: Account<'info, LimitExemption>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{StablecoinState, SymbolRegistry};
+use crate::errors::SSSError;
+use crate::validation::{validate_name, validate_symbol};
+use crate::events::{MetadataUpdated, SymbolReleased};
+
+/// Update the stablecoin's name, symbol, and/or URI.
+/// Only provided fields change; `None` leaves the stored value intact.
+pub fn update_metadata_handler(
+    ctx: Context<UpdateMetadata>,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+
+    // Validate with the same limits as initialize
+    if let Some(name) = &name {
+        validate_name(name)?;
+    }
+    if let Some(symbol) = &symbol {
+        validate_symbol(symbol)?;
+    }
+    if let Some(uri) = &uri {
+        require!(uri.len() <= StablecoinState::MAX_URI_LEN, SSSError::UriTooLong);
+    }
+
+    let old_symbol = state.symbol.clone();
+
+    if let Some(name) = name {
+        state.name = name;
+    }
+    if let Some(symbol) = symbol {
+        state.symbol = symbol;
+    }
+    if let Some(uri) = uri {
+        state.uri = uri;
+    }
+
+    let clock = Clock::get()?;
+    state.updated_at = clock.unix_timestamp;
+
+    emit!(MetadataUpdated {
+        mint: state.mint,
+        old_symbol,
+        new_symbol: state.symbol.clone(),
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Updated metadata for {}", state.symbol);
+    Ok(())
+}
+
+/// Release the stablecoin's claim on a symbol so another mint can register it.
+/// Part of winding a coin down, so it requires the net supply to be zero.
+/// Takes the registered symbol, which may differ from `state.symbol` after a metadata update.
+pub fn release_symbol_handler(
+    ctx: Context<ReleaseSymbol>,
+    symbol: String,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    require!(state.net_supply() == 0, SSSError::SupplyOutstanding);
+
+    let clock = Clock::get()?;
+    emit!(SymbolReleased {
+        mint: state.mint,
+        symbol: symbol.clone(),
+        released_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Released symbol {}", symbol);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    /// Stablecoin authority (owner)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+}
+
+#[derive(Accounts)]
+#[instruction(symbol: String)]
+pub struct ReleaseSymbol<'info> {
+    /// Stablecoin authority (receives the registry rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Symbol registry PDA (closed)
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"symbol", symbol.as_bytes()],
+        bump = symbol_registry.bump,
+        constraint = symbol_registry.mint == mint.key() @ SSSError::MintMismatch,
+    )]
+    pub symbol_registry: Account<'info, SymbolRegistry>,
+}
@@ -1,17 +1,30 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, MintTo as SplMintTo, mint_to};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, MintTo as SplMintTo, Burn as SplBurn, mint_to, burn};
 
-use crate::state::StablecoinState;
+use crate::state::{StablecoinState, ClawbackRecord, MintLock, MintStats};
 use crate::errors::SSSError;
-use crate::events::TokensMinted;
+use crate::events::{TokensMinted, MintFeeCollected, BatchMintCompleted, MintClawedBack};
+use crate::instructions::hook::is_blacklisted;
+use crate::oracle::check_peg;
+use crate::pda::create_pda_account;
+use crate::validation::validate_amount;
+use crate::format::format_ui_amount;
 
 /// Mint new tokens to a specified token account.
-/// Only callable by the designated mint authority.
+/// Only callable by the designated mint authority. With a mint fee set, `fee_collector`
+/// receives the fee and the destination the remainder. While a clawback window is set, the
+/// net mint is recorded in the destination's `ClawbackRecord` so it can be reversed, and
+/// with a mint lock period set the destination's `MintLock` is pushed out accordingly.
+/// Passing `mint_stats` also adds the mint to the destination's cumulative issuance.
 pub fn handler(
     ctx: Context<MintTo>,
     amount: u64,
+    memo: Option<String>,
 ) -> Result<()> {
-    require!(amount > 0, SSSError::InvalidAmount);
+    require!(!ctx.accounts.stablecoin_state.paused, SSSError::ProgramPaused);
+    validate_amount(amount, ctx.accounts.stablecoin_state.max_single_op_amount)?;
+    let memo = StablecoinState::validate_memo(memo)?;
 
     let state = &ctx.accounts.stablecoin_state;
     
@@ -21,33 +34,485 @@ pub fn handler(
         SSSError::Unauthorized
     );
 
+    // Refuse to add supply while the coin trades off its peg
+    check_peg(state, ctx.accounts.oracle.as_ref())?;
+
+    let cpi = MintCpi {
+        mint: ctx.accounts.mint.to_account_info(),
+        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        fee_collector: ctx.accounts.fee_collector.as_ref(),
+    };
+    let recipient = Recipient {
+        token_account: ctx.accounts.token_account.to_account_info(),
+        owner: ctx.accounts.token_account.owner,
+        blacklist: ctx.accounts.recipient_blacklist.as_ref(),
+        clawback_record: ctx.accounts.clawback_record.as_deref_mut().zip(ctx.bumps.clawback_record),
+        mint_lock: ctx.accounts.mint_lock.as_deref_mut().zip(ctx.bumps.mint_lock),
+        mint_stats: ctx.accounts.mint_stats.as_deref_mut().zip(ctx.bumps.mint_stats),
+    };
+    let now = Clock::get()?.unix_timestamp;
+    mint_to_recipient(&mut ctx.accounts.stablecoin_state, &cpi, recipient, amount, memo, now)?;
+
+    msg!("SSS: Minted {} tokens to {}", amount, ctx.accounts.token_account.key());
+
+    Ok(())
+}
+
+/// Mint new tokens to `owner`'s associated token account, creating the ATA
+/// first if it does not exist yet. Same checks, fee, clawback tracking, mint lock
+/// and optional `mint_stats` as `mint_to`.
+pub fn mint_to_new_handler(
+    ctx: Context<MintToNew>,
+    amount: u64,
+    memo: Option<String>,
+) -> Result<()> {
+    require!(!ctx.accounts.stablecoin_state.paused, SSSError::ProgramPaused);
+    validate_amount(amount, ctx.accounts.stablecoin_state.max_single_op_amount)?;
+    let memo = StablecoinState::validate_memo(memo)?;
+
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.mint_authority.key() == state.mint_authority,
+        SSSError::Unauthorized
+    );
+
+    check_peg(state, ctx.accounts.oracle.as_ref())?;
+
+    let cpi = MintCpi {
+        mint: ctx.accounts.mint.to_account_info(),
+        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        fee_collector: ctx.accounts.fee_collector.as_deref(),
+    };
+    let recipient = Recipient {
+        token_account: ctx.accounts.token_account.to_account_info(),
+        owner: ctx.accounts.owner.key(),
+        blacklist: ctx.accounts.recipient_blacklist.as_ref(),
+        clawback_record: ctx.accounts.clawback_record.as_deref_mut().zip(ctx.bumps.clawback_record),
+        mint_lock: ctx
+            .accounts
+            .mint_lock
+            .as_mut()
+            .map(|lock| &mut ***lock)
+            .zip(ctx.bumps.mint_lock),
+        mint_stats: ctx
+            .accounts
+            .mint_stats
+            .as_mut()
+            .map(|stats| &mut ***stats)
+            .zip(ctx.bumps.mint_stats),
+    };
+    let now = Clock::get()?.unix_timestamp;
+    mint_to_recipient(&mut ctx.accounts.stablecoin_state, &cpi, recipient, amount, memo, now)?;
+
+    msg!(
+        "SSS: Minted {} tokens to {} (ATA of {})",
+        amount,
+        ctx.accounts.token_account.key(),
+        ctx.accounts.owner.key()
+    );
+
+    Ok(())
+}
+
+/// Accounts every mint CPI needs, shared by all recipients of an instruction
+struct MintCpi<'a, 'info> {
+    mint: AccountInfo<'info>,
+    mint_authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    fee_collector: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// One mint destination and its per-recipient PDAs, each paired with its bump
+struct Recipient<'a, 'info> {
+    token_account: AccountInfo<'info>,
+    owner: Pubkey,
+    blacklist: Option<&'a AccountInfo<'info>>,
+    clawback_record: Option<(&'a mut ClawbackRecord, u8)>,
+    mint_lock: Option<(&'a mut MintLock, u8)>,
+    mint_stats: Option<(&'a mut MintStats, u8)>,
+}
+
+/// Mint `amount` to one recipient once the caller is authorised and the peg checked.
+/// Shared by `mint_to`, `mint_to_new` and `mint_to_batch` so every path applies the
+/// SSS-2 blacklist, the issuance fee, the daily limit and supply counters, and keeps
+/// the recipient's clawback record, mint lock and stats current. Returns the net amount.
+fn mint_to_recipient(
+    state: &mut StablecoinState,
+    cpi: &MintCpi,
+    recipient: Recipient,
+    amount: u64,
+    memo: String,
+    now: i64,
+) -> Result<u64> {
+    // SSS-2: never mint into a blacklisted wallet
+    if state.is_sss2 {
+        let blacklist = recipient.blacklist.ok_or(SSSError::MissingAccount)?;
+        require!(
+            !is_blacklisted(blacklist, &state.mint, &recipient.owner, now)?,
+            SSSError::TransferBlocked
+        );
+    }
+
+    // Split off the issuance fee; the destination receives the rest
+    let fee = state.mint_fee(amount);
+    let net_amount = amount - fee;
+    collect_mint_fee(
+        state,
+        cpi.fee_collector,
+        cpi.mint.clone(),
+        cpi.mint_authority.clone(),
+        cpi.token_program.clone(),
+        fee,
+    )?;
+
     // Perform the mint via Token-2022
     let cpi_accounts = SplMintTo {
+        mint: cpi.mint.clone(),
+        to: recipient.token_account.clone(),
+        authority: cpi.mint_authority.clone(),
+    };
+    mint_to(CpiContext::new(cpi.token_program.clone(), cpi_accounts), net_amount)?;
+
+    // Update audit state
+    state.record_daily_mint(amount, now)?;
+    state.total_minted = state.total_minted.checked_add(amount).ok_or(SSSError::Overflow)?;
+    state.mint_op_count = state.mint_op_count.saturating_add(1);
+    state.updated_at = now;
+
+    let destination = recipient.token_account.key();
+
+    // Only needed while a clawback window is configured
+    match recipient.clawback_record {
+        Some((record, bump)) => {
+            record.stablecoin = state.mint;
+            record.destination = destination;
+            record.amount = net_amount;
+            record.minted_at = now;
+            record.bump = bump;
+        }
+        None => require!(state.clawback_window.is_none(), SSSError::MissingAccount),
+    }
+
+    // Only needed while a mint lock period is configured
+    match recipient.mint_lock {
+        Some((lock, bump)) => {
+            lock.stablecoin = state.mint;
+            lock.token_account = destination;
+            if let Some(period) = state.mint_lock_period {
+                lock.extend(now, period);
+            }
+            lock.bump = bump;
+        }
+        None => require!(state.mint_lock_period.is_none(), SSSError::MissingAccount),
+    }
+
+    if let Some((stats, bump)) = recipient.mint_stats {
+        stats.stablecoin = state.mint;
+        stats.token_account = destination;
+        stats.record(net_amount)?;
+        stats.bump = bump;
+    }
+
+    if state.emit_events {
+        if fee > 0 {
+            emit!(MintFeeCollected {
+                mint: state.mint,
+                to: destination,
+                collector: state.mint_fee_collector,
+                gross_amount: amount,
+                fee,
+                timestamp: now,
+            });
+        }
+        emit!(TokensMinted {
+            mint: state.mint,
+            to: destination,
+            amount: net_amount,
+            ui_amount: format_ui_amount(net_amount, state.decimals),
+            minted_by: cpi.mint_authority.key(),
+            total_minted: state.total_minted,
+            memo,
+            timestamp: now,
+        });
+    }
+
+    Ok(net_amount)
+}
+
+/// Mint the issuance fee to the configured collector (a no-op for a zero fee).
+fn collect_mint_fee<'info>(
+    state: &StablecoinState,
+    fee_collector: Option<&InterfaceAccount<'info, TokenAccount>>,
+    mint: AccountInfo<'info>,
+    mint_authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    fee: u64,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+
+    let fee_collector = fee_collector.ok_or(SSSError::MissingAccount)?;
+    require!(
+        fee_collector.key() == state.mint_fee_collector,
+        SSSError::InvalidFeeCollector
+    );
+
+    let cpi_accounts = SplMintTo {
+        mint,
+        to: fee_collector.to_account_info(),
+        authority: mint_authority,
+    };
+    mint_to(CpiContext::new(token_program, cpi_accounts), fee)
+}
+
+/// Load a recipient PDA passed to `mint_to_batch`, creating it first when missing.
+/// The address must match `seeds`; returns the account and its bump.
+fn load_or_create<'info, T>(
+    info: &'info AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<(Account<'info, T>, u8)>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    let (expected, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(info.key(), expected, SSSError::InvalidPda);
+
+    if info.owner == &crate::ID {
+        return Ok((Account::try_from(info)?, bump));
+    }
+
+    let bump_seed = [bump];
+    let mut signer_seeds = seeds.to_vec();
+    signer_seeds.push(&bump_seed);
+    create_pda_account(payer, info, system_program, space, &[&signer_seeds[..]])?;
+
+    // Freshly created accounts are zeroed, so skip the discriminator check
+    Ok((Account::try_from_unchecked(info)?, bump))
+}
+
+/// Mint tokens to multiple token accounts in one instruction, applying the same
+/// per-recipient rules as `mint_to`. `amounts[i]` is minted to the i-th group of
+/// remaining accounts: the token account, then its owner's blacklist entry (SSS-2),
+/// clawback record (while `clawback_window` is set), mint lock (while
+/// `mint_lock_period` is set) and, with `track_stats`, its mint stats. Missing
+/// PDAs are created, paid for by the mint authority.
+pub fn batch_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MintToBatch<'info>>,
+    amounts: Vec<u64>,
+    track_stats: bool,
+) -> Result<()> {
+    require!(!ctx.accounts.stablecoin_state.paused, SSSError::ProgramPaused);
+    require!(!amounts.is_empty(), SSSError::InvalidAmount);
+    require!(amounts.len() <= StablecoinState::MAX_BATCH_SIZE, SSSError::BatchTooLarge);
+
+    let state = &ctx.accounts.stablecoin_state;
+    let stride = 1
+        + state.is_sss2 as usize
+        + state.clawback_window.is_some() as usize
+        + state.mint_lock_period.is_some() as usize
+        + track_stats as usize;
+    require!(
+        amounts.len() * stride == ctx.remaining_accounts.len(),
+        SSSError::BatchLengthMismatch
+    );
+
+    // Verify caller is the mint authority
+    require!(
+        ctx.accounts.mint_authority.key() == state.mint_authority,
+        SSSError::Unauthorized
+    );
+
+    check_peg(state, ctx.accounts.oracle.as_ref())?;
+
+    // Check the running total up front so an overflow aborts before any CPI
+    let batch_total = amounts
+        .iter()
+        .try_fold(0u64, |acc, amount| acc.checked_add(*amount))
+        .ok_or(SSSError::Overflow)?;
+    state.total_minted.checked_add(batch_total).ok_or(SSSError::Overflow)?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let mint_key = ctx.accounts.mint.key();
+    let payer = ctx.accounts.mint_authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let cpi = MintCpi {
         mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.token_account.to_account_info(),
-        authority: ctx.accounts.mint_authority.to_account_info(),
+        mint_authority: payer.clone(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        fee_collector: ctx.accounts.fee_collector.as_ref(),
     };
-    let cpi_ctx = CpiContext::new(
+
+    for (group, amount) in ctx.remaining_accounts.chunks(stride).zip(amounts.iter()) {
+        validate_amount(*amount, ctx.accounts.stablecoin_state.max_single_op_amount)?;
+
+        let mut accounts = group.iter();
+        let account_info = accounts.next().ok_or(SSSError::MissingAccount)?;
+        let token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+        require!(token_account.mint == mint_key, SSSError::MintMismatch);
+        let destination = account_info.key();
+
+        let state = &ctx.accounts.stablecoin_state;
+        let blacklist = if state.is_sss2 {
+            let info = accounts.next().ok_or(SSSError::MissingAccount)?;
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"blacklist", mint_key.as_ref(), token_account.owner.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(info.key(), expected, SSSError::InvalidPda);
+            Some(info)
+        } else {
+            None
+        };
+        let mut clawback_record = if state.clawback_window.is_some() {
+            Some(load_or_create::<ClawbackRecord>(
+                accounts.next().ok_or(SSSError::MissingAccount)?,
+                &payer,
+                &system_program,
+                ClawbackRecord::SPACE,
+                &[b"clawback", mint_key.as_ref(), destination.as_ref()],
+            )?)
+        } else {
+            None
+        };
+        let mut mint_lock = if state.mint_lock_period.is_some() {
+            Some(load_or_create::<MintLock>(
+                accounts.next().ok_or(SSSError::MissingAccount)?,
+                &payer,
+                &system_program,
+                MintLock::SPACE,
+                &[b"mint_lock", mint_key.as_ref(), destination.as_ref()],
+            )?)
+        } else {
+            None
+        };
+        let mut mint_stats = if track_stats {
+            Some(load_or_create::<MintStats>(
+                accounts.next().ok_or(SSSError::MissingAccount)?,
+                &payer,
+                &system_program,
+                MintStats::SPACE,
+                &[b"mintstats", mint_key.as_ref(), destination.as_ref()],
+            )?)
+        } else {
+            None
+        };
+
+        let recipient = Recipient {
+            token_account: account_info.clone(),
+            owner: token_account.owner,
+            blacklist,
+            clawback_record: clawback_record.as_mut().map(|(record, bump)| (&mut **record, *bump)),
+            mint_lock: mint_lock.as_mut().map(|(lock, bump)| (&mut **lock, *bump)),
+            mint_stats: mint_stats.as_mut().map(|(stats, bump)| (&mut **stats, *bump)),
+        };
+        mint_to_recipient(
+            &mut ctx.accounts.stablecoin_state,
+            &cpi,
+            recipient,
+            *amount,
+            String::new(),
+            timestamp,
+        )?;
+
+        if let Some((record, _)) = clawback_record {
+            record.exit(ctx.program_id)?;
+        }
+        if let Some((lock, _)) = mint_lock {
+            lock.exit(ctx.program_id)?;
+        }
+        if let Some((stats, _)) = mint_stats {
+            stats.exit(ctx.program_id)?;
+        }
+    }
+
+    let state = &ctx.accounts.stablecoin_state;
+    if state.emit_events {
+        emit!(BatchMintCompleted {
+            mint: state.mint,
+            recipients: amounts.len() as u8,
+            total_amount: batch_total,
+            minted_by: ctx.accounts.mint_authority.key(),
+            total_minted: state.total_minted,
+            timestamp,
+        });
+    }
+
+    msg!("SSS: Batch minted {} tokens to {} accounts", batch_total, amounts.len());
+
+    Ok(())
+}
+
+/// Reverse the latest `mint_to` into a token account by burning it back via the
+/// permanent delegate. Only callable by the mint authority, within the
+/// stablecoin's `clawback_window`, and while the balance still covers the mint.
+pub fn clawback_handler(ctx: Context<ClawbackMint>) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.mint_authority.key() == state.mint_authority,
+        SSSError::Unauthorized
+    );
+    require!(state.permanent_delegate_enabled, SSSError::PermanentDelegateNotEnabled);
+
+    let record = &ctx.accounts.clawback_record;
+    let amount = record.amount;
+    require!(amount > 0, SSSError::InvalidAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    let window = state.clawback_window.ok_or(SSSError::ClawbackWindowExpired)?;
+    require!(
+        now.saturating_sub(record.minted_at) <= window,
+        SSSError::ClawbackWindowExpired
+    );
+    require!(
+        ctx.accounts.token_account.amount >= amount,
+        SSSError::InsufficientBalance
+    );
+
+    // Burn via Token-2022, signing as the permanent delegate
+    let mint_key = ctx.accounts.mint.key();
+    let bump = state.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"stablecoin", mint_key.as_ref(), &[bump]]];
+
+    let cpi_accounts = SplBurn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.token_account.to_account_info(),
+        authority: ctx.accounts.stablecoin_state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
         cpi_accounts,
+        signer_seeds,
     );
-    mint_to(cpi_ctx, amount)?;
+    burn(cpi_ctx, amount)?;
 
     // Update audit state
+    let minted_at = ctx.accounts.clawback_record.minted_at;
+    ctx.accounts.clawback_record.amount = 0;
+
     let state = &mut ctx.accounts.stablecoin_state;
-    state.total_minted = state.total_minted.checked_add(amount).ok_or(SSSError::Overflow)?;
-    state.updated_at = Clock::get()?.unix_timestamp;
+    state.total_burned = state.total_burned.checked_add(amount).ok_or(SSSError::Overflow)?;
+    state.burn_op_count = state.burn_op_count.saturating_add(1);
+    state.updated_at = now;
 
-    emit!(TokensMinted {
+    emit!(MintClawedBack {
         mint: state.mint,
-        to: ctx.accounts.token_account.key(),
+        destination: ctx.accounts.token_account.key(),
         amount,
-        minted_by: ctx.accounts.mint_authority.key(),
-        total_minted: state.total_minted,
-        timestamp: state.updated_at,
+        minted_at,
+        clawed_back_by: ctx.accounts.mint_authority.key(),
+        total_burned: state.total_burned,
+        timestamp: now,
     });
 
-    msg!("SSS: Minted {} tokens to {}", amount, ctx.accounts.token_account.key());
+    msg!("SSS: Clawed back {} tokens from {}", amount, ctx.accounts.token_account.key());
 
     Ok(())
 }
@@ -78,6 +543,231 @@ pub struct MintTo<'info> {
     )]
     pub stablecoin_state: Account<'info, StablecoinState>,
 
+    /// Blacklist entry PDA for the destination owner (required for SSS-2; may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), token_account.owner.as_ref()],
+        bump,
+    )]
+    pub recipient_blacklist: Option<AccountInfo<'info>>,
+
+    /// Price account (required once `stablecoin_state.oracle` is set)
+    /// CHECK: Key matched against `stablecoin_state.oracle` and layout parsed in the handler
+    pub oracle: Option<AccountInfo<'info>>,
+
+    /// Clawback record PDA for the destination (required while `clawback_window` is set;
+    /// created or overwritten)
+    #[account(
+        init_if_needed,
+        payer = mint_authority,
+        space = ClawbackRecord::SPACE,
+        seeds = [b"clawback", mint.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub clawback_record: Option<Account<'info, ClawbackRecord>>,
+
+    /// Mint lock PDA for the destination (required while `mint_lock_period` is set;
+    /// created or extended)
+    #[account(
+        init_if_needed,
+        payer = mint_authority,
+        space = MintLock::SPACE,
+        seeds = [b"mint_lock", mint.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub mint_lock: Option<Account<'info, MintLock>>,
+
+    /// Mint stats PDA for the destination (optional; created on first use)
+    #[account(
+        init_if_needed,
+        payer = mint_authority,
+        space = MintStats::SPACE,
+        seeds = [b"mintstats", mint.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub mint_stats: Option<Account<'info, MintStats>>,
+
+    /// Token account receiving the issuance fee (required while `mint_fee_bps` > 0)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub fee_collector: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintToNew<'info> {
+    /// Mint authority (must match stablecoin_state.mint_authority); pays for the ATA
+    #[account(mut)]
+    pub mint_authority: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Wallet that will own the destination ATA
+    /// CHECK: Any address may receive tokens; only used to derive the ATA and blacklist PDA
+    pub owner: UncheckedAccount<'info>,
+
+    /// Destination associated token account (created if missing)
+    #[account(
+        init_if_needed,
+        payer = mint_authority,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Blacklist entry PDA for the owner (required for SSS-2; may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub recipient_blacklist: Option<AccountInfo<'info>>,
+
+    /// Price account (required once `stablecoin_state.oracle` is set)
+    /// CHECK: Key matched against `stablecoin_state.oracle` and layout parsed in the handler
+    pub oracle: Option<AccountInfo<'info>>,
+
+    /// Clawback record PDA for the destination (required while `clawback_window` is set;
+    /// created or overwritten)
+    #[account(
+        init_if_needed,
+        payer = mint_authority,
+        space = ClawbackRecord::SPACE,
+        seeds = [b"clawback", mint.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub clawback_record: Option<Account<'info, ClawbackRecord>>,
+
+    /// Mint lock PDA for the destination (required while `mint_lock_period` is set;
+    /// created or extended)
+    #[account(
+        init_if_needed,
+        payer = mint_authority,
+        space = MintLock::SPACE,
+        seeds = [b"mint_lock", mint.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub mint_lock: Option<Box<Account<'info, MintLock>>>,
+
+    /// Mint stats PDA for the destination (optional; created on first use)
+    #[account(
+        init_if_needed,
+        payer = mint_authority,
+        space = MintStats::SPACE,
+        seeds = [b"mintstats", mint.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub mint_stats: Option<Box<Account<'info, MintStats>>>,
+
+    /// Token account receiving the issuance fee (required while `mint_fee_bps` > 0)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub fee_collector: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Associated Token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintToBatch<'info> {
+    /// Mint authority (must match stablecoin_state.mint_authority)
+    #[account(mut)]
+    pub mint_authority: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Price account (required once `stablecoin_state.oracle` is set)
+    /// CHECK: Key matched against `stablecoin_state.oracle` and layout parsed in the handler
+    pub oracle: Option<AccountInfo<'info>>,
+
+    /// Token account receiving the issuance fee (required while `mint_fee_bps` > 0)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub fee_collector: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program (creates missing per-recipient PDAs)
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: per amount, the destination token account followed by its
+    // blacklist entry, clawback record, mint lock and mint stats as enabled (see batch_handler)
+}
+
+#[derive(Accounts)]
+pub struct ClawbackMint<'info> {
+    /// Mint authority (must match stablecoin_state.mint_authority)
+    pub mint_authority: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Token account that received the mint
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Stablecoin state PDA (permanent delegate)
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Clawback record PDA for the token account
+    #[account(
+        mut,
+        seeds = [b"clawback", mint.key().as_ref(), token_account.key().as_ref()],
+        bump = clawback_record.bump,
+    )]
+    pub clawback_record: Account<'info, ClawbackRecord>,
+
     /// Token-2022 program
     pub token_program: Interface<'info, TokenInterface>,
 }
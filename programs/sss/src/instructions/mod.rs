@@ -1,19 +1,47 @@
 pub mod initialize;
 pub mod mint;
+pub mod allowance;
 pub mod burn;
+pub mod redeem;
 pub mod transfer;
 pub mod freeze;
 pub mod compliance;
 pub mod roles;
+pub mod officers;
 pub mod hook;
+pub mod pause;
+pub mod flags;
+pub mod metadata;
+pub mod upgrade;
+pub mod supply;
+pub mod status;
+pub mod limits;
+pub mod activity;
+pub mod fees;
+pub mod changelog;
+pub mod multisig;
 
 pub use initialize::*;
 pub use mint::*;
+pub use allowance::*;
 pub use burn::*;
+pub use redeem::*;
 pub use transfer::*;
 pub use freeze::*;
 pub use compliance::*;
 pub use roles::*;
+pub use officers::*;
 pub use hook::*;
+pub use pause::*;
+pub use flags::*;
+pub use metadata::*;
+pub use upgrade::*;
+pub use supply::*;
+pub use status::*;
+pub use limits::*;
+pub use activity::*;
+pub use fees::*;
+pub use changelog::*;
+pub use multisig::*;
 
-pub use crate::state::{StablecoinConfig, Role};
+pub use crate::state::{StablecoinConfig, Role, FeatureFlag, StablecoinStatus, OperationCountsSnapshot, ChangeLogEntry, MultisigAction, MintStatsSnapshot, RoundingPolicy, DustPolicy};
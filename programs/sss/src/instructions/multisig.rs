@@ -0,0 +1,347 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{StablecoinState, MultisigConfig, PendingAction, MultisigAction, Role, ChangeLog, AdminAction};
+use crate::errors::SSSError;
+use crate::events::{AuthorityTransferred, Paused, Unpaused, MultisigActionProposed, MultisigActionApproved, MultisigActionExecuted};
+use crate::instructions::changelog::record_admin_action;
+use crate::instructions::roles::apply_role_change;
+
+/// Hand the stablecoin authority to an M-of-N multisig (authority only).
+/// From then on authority actions go through `propose_action` / `approve_action` /
+/// `execute_action`; `MultisigAction::TransferAuthority` hands it back to a single key.
+pub fn init_multisig_handler(
+    ctx: Context<InitMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    require!(signers.len() <= MultisigConfig::MAX_SIGNERS, SSSError::InvalidMultisigConfig);
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        SSSError::InvalidMultisigConfig
+    );
+    for (i, signer) in signers.iter().enumerate() {
+        require!(!signers[..i].contains(signer), SSSError::InvalidMultisigConfig);
+    }
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.stablecoin = state.mint;
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.action_count = 0;
+    multisig.bump = ctx.bumps.multisig;
+
+    let clock = Clock::get()?;
+    let old_authority = state.authority;
+    state.authority = multisig.key();
+    state.pending_authority = None;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::AuthorityTransferred,
+        old_authority,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(AuthorityTransferred {
+        mint: state.mint,
+        old_authority,
+        new_authority: state.authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "SSS: Authority handed to a {}-of-{} multisig",
+        multisig.threshold,
+        multisig.signers.len()
+    );
+    Ok(())
+}
+
+/// Propose an authority action (multisig signers only). The proposal counts as
+/// the proposer's approval.
+pub fn propose_action_handler(
+    ctx: Context<ProposeAction>,
+    action: MultisigAction,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let index = multisig.signer_index(&ctx.accounts.proposer.key())?;
+
+    let clock = Clock::get()?;
+    let pending = &mut ctx.accounts.pending_action;
+    pending.stablecoin = multisig.stablecoin;
+    pending.action_id = multisig.action_count;
+    pending.action = action;
+    pending.proposed_by = ctx.accounts.proposer.key();
+    pending.approvals = 1 << index;
+    pending.executed = false;
+    pending.created_at = clock.unix_timestamp;
+    pending.bump = ctx.bumps.pending_action;
+
+    multisig.action_count = multisig.action_count.checked_add(1).ok_or(SSSError::Overflow)?;
+
+    emit!(MultisigActionProposed {
+        mint: pending.stablecoin,
+        action_id: pending.action_id,
+        proposed_by: pending.proposed_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Proposed multisig action {}", pending.action_id);
+    Ok(())
+}
+
+/// Approve a pending action (multisig signers only, once each).
+pub fn approve_action_handler(
+    ctx: Context<ApproveAction>,
+    _action_id: u64,
+) -> Result<()> {
+    let index = ctx.accounts.multisig.signer_index(&ctx.accounts.approver.key())?;
+
+    let pending = &mut ctx.accounts.pending_action;
+    require!(!pending.executed, SSSError::ActionAlreadyExecuted);
+    require!(pending.approvals & (1 << index) == 0, SSSError::AlreadyApproved);
+
+    pending.approvals |= 1 << index;
+
+    let clock = Clock::get()?;
+    emit!(MultisigActionApproved {
+        mint: pending.stablecoin,
+        action_id: pending.action_id,
+        approved_by: ctx.accounts.approver.key(),
+        approvals: pending.approval_count(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "SSS: Multisig action {} has {} of {} approvals",
+        pending.action_id,
+        pending.approval_count(),
+        ctx.accounts.multisig.threshold
+    );
+    Ok(())
+}
+
+/// Execute a pending action once it has `threshold` approvals (multisig signers only).
+pub fn execute_action_handler(
+    ctx: Context<ExecuteAction>,
+    _action_id: u64,
+) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    multisig.signer_index(&ctx.accounts.executor.key())?;
+
+    let state = &mut ctx.accounts.stablecoin_state;
+    require!(state.authority == multisig.key(), SSSError::Unauthorized);
+
+    let pending = &mut ctx.accounts.pending_action;
+    require!(!pending.executed, SSSError::ActionAlreadyExecuted);
+    require!(
+        pending.approval_count() >= multisig.threshold,
+        SSSError::InsufficientApprovals
+    );
+    pending.executed = true;
+
+    let clock = Clock::get()?;
+    let actor = multisig.key();
+    let changelog = ctx.accounts.changelog.as_mut();
+
+    match pending.action.clone() {
+        MultisigAction::Pause => {
+            state.paused = true;
+            state.updated_at = clock.unix_timestamp;
+            record_admin_action(changelog, state.changelog_enabled, AdminAction::Paused, actor, clock.unix_timestamp)?;
+            emit!(Paused {
+                mint: state.mint,
+                paused_by: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::Unpause => {
+            state.paused = false;
+            state.updated_at = clock.unix_timestamp;
+            record_admin_action(changelog, state.changelog_enabled, AdminAction::Unpaused, actor, clock.unix_timestamp)?;
+            emit!(Unpaused {
+                mint: state.mint,
+                unpaused_by: actor,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        MultisigAction::UpdateRole { role, new_authority } => {
+            require!(!state.is_renounced(&role), SSSError::RoleRenounced);
+            if role == Role::ComplianceOfficer || role == Role::BlacklistAuthority {
+                require!(state.is_sss2, SSSError::SSS2Required);
+            }
+            apply_role_change(state, changelog, &role, new_authority, actor, clock.unix_timestamp)?;
+        }
+        MultisigAction::TransferAuthority { new_authority } => {
+            state.authority = new_authority;
+            state.updated_at = clock.unix_timestamp;
+            record_admin_action(
+                changelog,
+                state.changelog_enabled,
+                AdminAction::AuthorityTransferred,
+                actor,
+                clock.unix_timestamp,
+            )?;
+            emit!(AuthorityTransferred {
+                mint: state.mint,
+                old_authority: actor,
+                new_authority,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
+    emit!(MultisigActionExecuted {
+        mint: state.mint,
+        action_id: pending.action_id,
+        executed_by: ctx.accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Executed multisig action {}", pending.action_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitMultisig<'info> {
+    /// Stablecoin authority (handed to the multisig)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Multisig config PDA
+    #[account(
+        init,
+        payer = authority,
+        space = MultisigConfig::SPACE,
+        seeds = [b"multisig", mint.key().as_ref()],
+        bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAction<'info> {
+    /// Multisig signer proposing the action
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Multisig config PDA
+    #[account(
+        mut,
+        seeds = [b"multisig", mint.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+
+    /// Pending action PDA for the next action id
+    #[account(
+        init,
+        payer = proposer,
+        space = PendingAction::SPACE,
+        seeds = [b"pending_action", mint.key().as_ref(), &multisig.action_count.to_le_bytes()],
+        bump,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ApproveAction<'info> {
+    /// Multisig signer approving the action
+    pub approver: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Multisig config PDA
+    #[account(
+        seeds = [b"multisig", mint.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+
+    /// Pending action PDA
+    #[account(
+        mut,
+        seeds = [b"pending_action", mint.key().as_ref(), &action_id.to_le_bytes()],
+        bump = pending_action.bump,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+}
+
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ExecuteAction<'info> {
+    /// Multisig signer executing the action
+    pub executor: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Multisig config PDA
+    #[account(
+        seeds = [b"multisig", mint.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+
+    /// Pending action PDA
+    #[account(
+        mut,
+        seeds = [b"pending_action", mint.key().as_ref(), &action_id.to_le_bytes()],
+        bump = pending_action.bump,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{StablecoinState, ComplianceOfficers};
+use crate::errors::SSSError;
+use crate::events::{ComplianceOfficerAdded, ComplianceOfficerRemoved};
+
+/// Create the compliance officer list for a stablecoin.
+/// Seeds it with the existing `state.compliance_officer`, so coins created
+/// before the list existed keep their officer when they migrate.
+pub fn init_compliance_officers_handler(
+    ctx: Context<InitComplianceOfficers>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    let list = &mut ctx.accounts.compliance_officers;
+    list.stablecoin = state.mint;
+    list.officers = Vec::new();
+    list.bump = ctx.bumps.compliance_officers;
+
+    // A renounced officer role leaves nobody to seed
+    if state.compliance_officer != Pubkey::default() {
+        list.officers.push(state.compliance_officer);
+    }
+
+    msg!("SSS: Compliance officer list created with {} officer(s)", list.officers.len());
+    Ok(())
+}
+
+/// Add a compliance officer to the list (authority only).
+pub fn add_compliance_officer_handler(
+    ctx: Context<UpdateComplianceOfficers>,
+    officer: Pubkey,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    let list = &mut ctx.accounts.compliance_officers;
+    require!(!list.is_compliance_officer(&officer), SSSError::OfficerAlreadyListed);
+    require!(list.officers.len() < ComplianceOfficers::MAX_OFFICERS, SSSError::OfficerListFull);
+
+    list.officers.push(officer);
+
+    let clock = Clock::get()?;
+    emit!(ComplianceOfficerAdded {
+        mint: state.mint,
+        officer,
+        added_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Added compliance officer {}", officer);
+    Ok(())
+}
+
+/// Remove a compliance officer from the list (authority only).
+pub fn remove_compliance_officer_handler(
+    ctx: Context<UpdateComplianceOfficers>,
+    officer: Pubkey,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    let list = &mut ctx.accounts.compliance_officers;
+    let index = list
+        .officers
+        .iter()
+        .position(|key| key == &officer)
+        .ok_or(SSSError::OfficerNotListed)?;
+    list.officers.remove(index);
+
+    let clock = Clock::get()?;
+    emit!(ComplianceOfficerRemoved {
+        mint: state.mint,
+        officer,
+        removed_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Removed compliance officer {}", officer);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitComplianceOfficers<'info> {
+    /// Stablecoin authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        init,
+        payer = authority,
+        space = ComplianceOfficers::SPACE,
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateComplianceOfficers<'info> {
+    /// Stablecoin authority
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Compliance officer list PDA
+    #[account(
+        mut,
+        seeds = [b"compliance-officers", mint.key().as_ref()],
+        bump = compliance_officers.bump,
+    )]
+    pub compliance_officers: Account<'info, ComplianceOfficers>,
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{StablecoinState, ChangeLog, AdminAction};
+use crate::errors::SSSError;
+use crate::events::{Paused, Unpaused};
+use crate::instructions::changelog::record_admin_action;
+
+/// Pause the stablecoin — halts mint, burn, and transfer.
+/// Freeze/thaw and role management remain available for recovery.
+pub fn pause_handler(
+    ctx: Context<Pause>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.pauser.key() == state.pauser,
+        SSSError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    state.paused = true;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::Paused,
+        ctx.accounts.pauser.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(Paused {
+        mint: state.mint,
+        paused_by: ctx.accounts.pauser.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Paused {}", state.symbol);
+    Ok(())
+}
+
+/// Unpause the stablecoin — resumes mint, burn, and transfer.
+pub fn unpause_handler(
+    ctx: Context<Unpause>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.pauser.key() == state.pauser,
+        SSSError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    state.paused = false;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::Unpaused,
+        ctx.accounts.pauser.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(Unpaused {
+        mint: state.mint,
+        unpaused_by: ctx.accounts.pauser.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Unpaused {}", state.symbol);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// Pauser (defaults to the stablecoin authority)
+    #[account(mut)]
+    pub pauser: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
+
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    /// Pauser (defaults to the stablecoin authority)
+    #[account(mut)]
+    pub pauser: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
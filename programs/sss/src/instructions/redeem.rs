@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, Burn as SplBurn, burn};
+
+use crate::state::{StablecoinState, RedemptionRequest};
+use crate::errors::SSSError;
+use crate::format::format_ui_amount;
+use crate::events::{TokensBurned, RedemptionRequested, RedemptionFulfilled};
+
+/// Burn tokens and open a redemption request for off-chain reserve release.
+/// The request is keyed by holder and `reference`, so each reference is used once.
+pub fn redeem_handler(
+    ctx: Context<Redeem>,
+    amount: u64,
+    reference: String,
+) -> Result<()> {
+    require!(!ctx.accounts.stablecoin_state.paused, SSSError::ProgramPaused);
+    require!(amount > 0, SSSError::InvalidAmount);
+    require!(
+        reference.len() <= RedemptionRequest::MAX_REFERENCE_LEN,
+        SSSError::ReferenceTooLong
+    );
+    require!(
+        amount <= ctx.accounts.token_account.amount,
+        SSSError::InsufficientBalance
+    );
+
+    // Perform the burn via Token-2022
+    let cpi_accounts = SplBurn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.token_account.to_account_info(),
+        authority: ctx.accounts.holder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+    );
+    burn(cpi_ctx, amount)?;
+
+    // Update audit state
+    let clock = Clock::get()?;
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.total_burned = state.total_burned.checked_add(amount).ok_or(SSSError::Overflow)?;
+    state.burn_op_count = state.burn_op_count.saturating_add(1);
+    state.updated_at = clock.unix_timestamp;
+
+    let request = &mut ctx.accounts.redemption_request;
+    request.stablecoin = state.mint;
+    request.holder = ctx.accounts.holder.key();
+    request.amount = amount;
+    request.reference = reference.clone();
+    request.fulfilled = false;
+    request.requested_at = clock.unix_timestamp;
+    request.fulfilled_at = None;
+    request.bump = ctx.bumps.redemption_request;
+
+    emit!(TokensBurned {
+        mint: state.mint,
+        from: ctx.accounts.token_account.key(),
+        amount,
+        ui_amount: format_ui_amount(amount, state.decimals),
+        burned_by: ctx.accounts.holder.key(),
+        total_burned: state.total_burned,
+        memo: reference.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(RedemptionRequested {
+        mint: state.mint,
+        request: request.key(),
+        holder: request.holder,
+        amount,
+        reference,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Redemption of {} requested by {}", amount, request.holder);
+    Ok(())
+}
+
+/// Mark a redemption request as fulfilled once reserves have been released.
+pub fn fulfill_redemption_handler(
+    ctx: Context<FulfillRedemption>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    let request = &mut ctx.accounts.redemption_request;
+    require!(!request.fulfilled, SSSError::RedemptionAlreadyFulfilled);
+
+    let clock = Clock::get()?;
+    request.fulfilled = true;
+    request.fulfilled_at = Some(clock.unix_timestamp);
+
+    emit!(RedemptionFulfilled {
+        mint: state.mint,
+        request: request.key(),
+        holder: request.holder,
+        amount: request.amount,
+        fulfilled_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Fulfilled redemption {}", request.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, reference: String)]
+pub struct Redeem<'info> {
+    /// Holder redeeming tokens (pays for the request account)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Holder's token account to burn from
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Redemption request PDA
+    #[account(
+        init,
+        payer = holder,
+        space = RedemptionRequest::SPACE,
+        seeds = [b"redemption", mint.key().as_ref(), holder.key().as_ref(), reference.as_bytes()],
+        bump,
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FulfillRedemption<'info> {
+    /// Stablecoin authority (owner)
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Redemption request PDA to fulfill
+    #[account(
+        mut,
+        seeds = [
+            b"redemption",
+            mint.key().as_ref(),
+            redemption_request.holder.as_ref(),
+            redemption_request.reference.as_bytes(),
+        ],
+        bump = redemption_request.bump,
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+}
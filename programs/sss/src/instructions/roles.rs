@@ -1,14 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::Mint;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::{Mint, TokenInterface, SetAuthority, set_authority};
+use spl_token_2022::instruction::AuthorityType;
 
-use crate::state::{StablecoinState, Role};
+use crate::state::{StablecoinState, PendingRoleChange, Role, ChangeLog, AdminAction};
 use crate::errors::SSSError;
-use crate::events::RoleUpdated;
+use crate::events::{RoleUpdated, RoleChangeProposed, RoleRenouncedEvent, AuthorityTransferProposed, AuthorityTransferred, TokenAuthoritySynced};
+use crate::instructions::changelog::record_admin_action;
 
-/// Update role assignment for the stablecoin.
-/// Only the current authority can reassign roles.
-pub fn update_role_handler(
-    ctx: Context<UpdateRole>,
+/// Propose a role reassignment.
+/// The change executes after `role_change_delay` seconds via `execute_role_change`;
+/// with a delay of 0 it is applied immediately.
+pub fn propose_role_change_handler(
+    ctx: Context<ProposeRoleChange>,
     role: Role,
     new_authority: Pubkey,
 ) -> Result<()> {
@@ -19,6 +23,151 @@ pub fn update_role_handler(
         ctx.accounts.authority.key() == state.authority,
         SSSError::Unauthorized
     );
+    require!(!state.is_renounced(&role), SSSError::RoleRenounced);
+    if role == Role::ComplianceOfficer || role == Role::BlacklistAuthority {
+        require!(state.is_sss2, SSSError::SSS2Required);
+    }
+
+    let clock = Clock::get()?;
+    let effective_at = clock
+        .unix_timestamp
+        .checked_add(state.role_change_delay)
+        .ok_or(SSSError::Overflow)?;
+
+    let pending = &mut ctx.accounts.pending_role_change;
+    pending.stablecoin = state.mint;
+    pending.role = role.clone();
+    pending.new_authority = new_authority;
+    pending.proposed_by = ctx.accounts.authority.key();
+    pending.effective_at = effective_at;
+    pending.is_pending = true;
+    pending.bump = ctx.bumps.pending_role_change;
+
+    emit!(RoleChangeProposed {
+        mint: state.mint,
+        role: role_name(&role),
+        new_authority,
+        proposed_by: ctx.accounts.authority.key(),
+        effective_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Proposed {} change to {} (effective at {})", role_name(&role), new_authority, effective_at);
+
+    if state.role_change_delay == 0 {
+        pending.is_pending = false;
+        apply_role_change(
+            state,
+            ctx.accounts.changelog.as_mut(),
+            &role,
+            new_authority,
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Execute a proposed role change once its timelock has elapsed.
+pub fn execute_role_change_handler(
+    ctx: Context<ExecuteRoleChange>,
+    role: Role,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    let pending = &mut ctx.accounts.pending_role_change;
+    require!(pending.is_pending, SSSError::NoPendingRoleChange);
+
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp >= pending.effective_at, SSSError::TimelockNotElapsed);
+
+    // The role may have been renounced while the change was pending
+    require!(!state.is_renounced(&role), SSSError::RoleRenounced);
+
+    pending.is_pending = false;
+    apply_role_change(
+        state,
+        ctx.accounts.changelog.as_mut(),
+        &role,
+        pending.new_authority,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )
+}
+
+/// Reassign `role` to `new_authority`, log it to the change log and emit `RoleUpdated`.
+pub(crate) fn apply_role_change(
+    state: &mut StablecoinState,
+    changelog: Option<&mut Account<ChangeLog>>,
+    role: &Role,
+    new_authority: Pubkey,
+    updated_by: Pubkey,
+    now: i64,
+) -> Result<()> {
+    let old_authority = match role {
+        Role::MintAuthority => {
+            // Rate-limit rotations so a stolen key cannot keep rotating ahead of recovery
+            let elapsed = now.saturating_sub(state.last_mint_authority_change);
+            require!(
+                elapsed >= state.mint_authority_rotation_cooldown,
+                SSSError::RotationCooldownActive
+            );
+            state.last_mint_authority_change = now;
+            std::mem::replace(&mut state.mint_authority, new_authority)
+        }
+        Role::FreezeAuthority => std::mem::replace(&mut state.freeze_authority, new_authority),
+        Role::ComplianceOfficer => std::mem::replace(&mut state.compliance_officer, new_authority),
+        Role::Pauser => std::mem::replace(&mut state.pauser, new_authority),
+        Role::BlacklistAuthority => std::mem::replace(&mut state.blacklist_authority, new_authority),
+    };
+
+    state.updated_at = now;
+    record_admin_action(changelog, state.changelog_enabled, AdminAction::RoleUpdated, updated_by, now)?;
+
+    emit!(RoleUpdated {
+        mint: state.mint,
+        role: role_name(role),
+        old_authority,
+        new_authority,
+        updated_by,
+        timestamp: now,
+    });
+
+    msg!("SSS: Updated {} to {}", role_name(role), new_authority);
+    Ok(())
+}
+
+/// Name recorded for a role in events
+fn role_name(role: &Role) -> String {
+    match role {
+        Role::MintAuthority => "MINT_AUTHORITY",
+        Role::FreezeAuthority => "FREEZE_AUTHORITY",
+        Role::ComplianceOfficer => "COMPLIANCE_OFFICER",
+        Role::Pauser => "PAUSER",
+        Role::BlacklistAuthority => "BLACKLIST_AUTHORITY",
+    }
+    .to_string()
+}
+
+/// Permanently renounce a role by setting it to `Pubkey::default()`.
+/// Renouncing `MintAuthority` signals a fixed supply. This cannot be undone.
+pub fn renounce_role_handler(
+    ctx: Context<RenounceRole>,
+    role: Role,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    require!(!state.is_renounced(&role), SSSError::RoleRenounced);
 
     let clock = Clock::get()?;
     let old_authority;
@@ -27,39 +176,332 @@ pub fn update_role_handler(
     match role {
         Role::MintAuthority => {
             old_authority = state.mint_authority;
-            state.mint_authority = new_authority;
+            state.mint_authority = Pubkey::default();
             role_name = "MINT_AUTHORITY".to_string();
         },
         Role::FreezeAuthority => {
             old_authority = state.freeze_authority;
-            state.freeze_authority = new_authority;
+            state.freeze_authority = Pubkey::default();
             role_name = "FREEZE_AUTHORITY".to_string();
         },
         Role::ComplianceOfficer => {
             require!(state.is_sss2, SSSError::SSS2Required);
             old_authority = state.compliance_officer;
-            state.compliance_officer = new_authority;
+            state.compliance_officer = Pubkey::default();
             role_name = "COMPLIANCE_OFFICER".to_string();
         },
+        Role::Pauser => {
+            old_authority = state.pauser;
+            state.pauser = Pubkey::default();
+            role_name = "PAUSER".to_string();
+        },
+        Role::BlacklistAuthority => {
+            require!(state.is_sss2, SSSError::SSS2Required);
+            old_authority = state.blacklist_authority;
+            state.blacklist_authority = Pubkey::default();
+            role_name = "BLACKLIST_AUTHORITY".to_string();
+        },
     }
 
+    state.renounced_roles |= role.bit();
     state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::RoleRenounced,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
 
-    emit!(RoleUpdated {
+    emit!(RoleRenouncedEvent {
         mint: state.mint,
         role: role_name.clone(),
         old_authority,
+        renounced_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Renounced {}", role_name);
+    Ok(())
+}
+
+/// Propose a new stablecoin authority (step one of a two-step transfer).
+/// Proposing again before acceptance overwrites the pending key.
+pub fn propose_authority_transfer_handler(
+    ctx: Context<ProposeAuthorityTransfer>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    state.pending_authority = Some(new_authority);
+    state.updated_at = clock.unix_timestamp;
+
+    emit!(AuthorityTransferProposed {
+        mint: state.mint,
+        current_authority: state.authority,
+        pending_authority: new_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Proposed authority transfer to {}", new_authority);
+    Ok(())
+}
+
+/// Accept a pending authority transfer (step two).
+/// Only the pending key can accept.
+pub fn accept_authority_transfer_handler(
+    ctx: Context<AcceptAuthorityTransfer>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    let pending = state.pending_authority.ok_or(SSSError::NoPendingAuthority)?;
+    require!(
+        ctx.accounts.new_authority.key() == pending,
+        SSSError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    let old_authority = state.authority;
+    state.authority = pending;
+    state.pending_authority = None;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::AuthorityTransferred,
+        ctx.accounts.new_authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    emit!(AuthorityTransferred {
+        mint: state.mint,
+        old_authority,
+        new_authority: pending,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Authority transferred to {}", pending);
+    Ok(())
+}
+
+/// Hand the stablecoin authority to a PDA of an external governance program
+/// (authority only). `seeds`, bump included, must derive `new_authority` under
+/// `governance_program`, so a plain keypair cannot be designated. Authority-gated
+/// instructions then accept the PDA as a signer when the governance program
+/// invokes them with `invoke_signed`. Instructions where the authority pays rent
+/// (e.g. `propose_role_change`) need the PDA to be system-owned and funded.
+pub fn set_program_authority_handler(
+    ctx: Context<SetProgramAuthority>,
+    new_authority: Pubkey,
+    seeds: Vec<Vec<u8>>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    // The PDA must belong to another program; this program never signs as the authority
+    let governance_program = ctx.accounts.governance_program.key();
+    require!(governance_program != crate::ID, SSSError::InvalidPda);
+    let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+    let derived = Pubkey::create_program_address(&seeds, &governance_program)
+        .map_err(|_| SSSError::InvalidPda)?;
+    require!(derived == new_authority, SSSError::InvalidPda);
+
+    let clock = Clock::get()?;
+    let old_authority = state.authority;
+    state.authority = new_authority;
+    state.pending_authority = None;
+    state.updated_at = clock.unix_timestamp;
+    record_admin_action(
+        ctx.accounts.changelog.as_mut(),
+        state.changelog_enabled,
+        AdminAction::AuthorityTransferred,
+        old_authority,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(AuthorityTransferred {
+        mint: state.mint,
+        old_authority,
         new_authority,
-        updated_by: ctx.accounts.authority.key(),
         timestamp: clock.unix_timestamp,
     });
 
-    msg!("SSS: Updated {} to {}", role_name, new_authority);
+    msg!("SSS: Authority handed to {} (program {})", new_authority, governance_program);
     Ok(())
 }
 
+/// Point the Token-2022 mint authority at `state.mint_authority`.
+/// Role changes only update the state, so this is what lets a rotated mint authority mint.
+pub fn sync_mint_authority_handler(ctx: Context<SyncTokenAuthority>) -> Result<()> {
+    let current = ctx.accounts.mint.mint_authority;
+    let target = ctx.accounts.stablecoin_state.mint_authority;
+    sync_token_authority(ctx, AuthorityType::MintTokens, current, target)
+}
+
+/// Point the Token-2022 freeze authority at `state.freeze_authority`.
+pub fn sync_freeze_authority_handler(ctx: Context<SyncTokenAuthority>) -> Result<()> {
+    let current = ctx.accounts.mint.freeze_authority;
+    let target = ctx.accounts.stablecoin_state.freeze_authority;
+    sync_token_authority(ctx, AuthorityType::FreezeAccount, current, target)
+}
+
+/// Hand the Token-2022 freeze authority to the stablecoin PDA.
+/// Freezes and thaws are then signed by the program and gated only by the role
+/// checks, so the compliance officer can freeze and a rotated freeze authority
+/// takes effect without another sync. `sync_freeze_authority` hands it back.
+pub fn delegate_freeze_authority_handler(ctx: Context<SyncTokenAuthority>) -> Result<()> {
+    let current = ctx.accounts.mint.freeze_authority;
+    let target = ctx.accounts.stablecoin_state.key();
+    sync_token_authority(ctx, AuthorityType::FreezeAccount, current, target)
+}
+
+/// Move a Token-2022 authority from `current` to `target`.
+/// The current on-chain authority signs; when that is the stablecoin PDA
+/// (e.g. for mint allowances), the PDA signs and the stablecoin authority must call.
+fn sync_token_authority(
+    ctx: Context<SyncTokenAuthority>,
+    authority_type: AuthorityType,
+    current: COption<Pubkey>,
+    target: Pubkey,
+) -> Result<()> {
+    // A revoked authority can never be restored
+    let COption::Some(current) = current else {
+        return Err(SSSError::Unauthorized.into());
+    };
+
+    let state = &ctx.accounts.stablecoin_state;
+    let signer = ctx.accounts.current_authority.key();
+    let held_by_pda = current == state.key();
+    if held_by_pda {
+        require!(signer == state.authority, SSSError::Unauthorized);
+    } else {
+        require!(signer == current, SSSError::Unauthorized);
+    }
+
+    let mint_key = ctx.accounts.mint.key();
+    let bump = [state.bump];
+    let pda_seeds: &[&[&[u8]]] = &[&[b"stablecoin", mint_key.as_ref(), &bump]];
+    let (authority, signer_seeds) = if held_by_pda {
+        (ctx.accounts.stablecoin_state.to_account_info(), pda_seeds)
+    } else {
+        (ctx.accounts.current_authority.to_account_info(), &[][..])
+    };
+
+    let cpi_accounts = SetAuthority {
+        current_authority: authority,
+        account_or_mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    set_authority(cpi_ctx, authority_type, Some(target))?;
+
+    let authority_name = match authority_type {
+        AuthorityType::MintTokens => "MINT_AUTHORITY",
+        _ => "FREEZE_AUTHORITY",
+    };
+    let clock = Clock::get()?;
+    emit!(TokenAuthoritySynced {
+        mint: mint_key,
+        authority_type: authority_name.to_string(),
+        old_authority: current,
+        new_authority: target,
+        synced_by: signer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Synced Token-2022 {} to {}", authority_name, target);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct ProposeRoleChange<'info> {
+    /// Stablecoin authority (owner)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Pending change PDA for the role (created or overwritten)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PendingRoleChange::SPACE,
+        seeds = [b"role-change", mint.key().as_ref(), &[role.bit()]],
+        bump,
+    )]
+    pub pending_role_change: Account<'info, PendingRoleChange>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
+
 #[derive(Accounts)]
-pub struct UpdateRole<'info> {
+#[instruction(role: Role)]
+pub struct ExecuteRoleChange<'info> {
+    /// Stablecoin authority (owner)
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Pending change PDA for the role
+    #[account(
+        mut,
+        seeds = [b"role-change", mint.key().as_ref(), &[role.bit()]],
+        bump = pending_role_change.bump,
+    )]
+    pub pending_role_change: Account<'info, PendingRoleChange>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
+
+#[derive(Accounts)]
+pub struct RenounceRole<'info> {
     /// Stablecoin authority (owner)
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -74,4 +516,106 @@ pub struct UpdateRole<'info> {
         bump = stablecoin_state.bump,
     )]
     pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthorityTransfer<'info> {
+    /// Current stablecoin authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthorityTransfer<'info> {
+    /// Pending authority accepting the transfer
+    #[account(mut)]
+    pub new_authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
+
+#[derive(Accounts)]
+pub struct SetProgramAuthority<'info> {
+    /// Current stablecoin authority
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Governance program that owns the new authority PDA
+    /// CHECK: Only its address is used, to verify the PDA derivation
+    #[account(executable)]
+    pub governance_program: UncheckedAccount<'info>,
+
+    /// Change log PDA (required once `init_changelog` has run)
+    #[account(
+        mut,
+        seeds = [b"changelog", mint.key().as_ref()],
+        bump = changelog.bump,
+    )]
+    pub changelog: Option<Account<'info, ChangeLog>>,
+}
+
+#[derive(Accounts)]
+pub struct SyncTokenAuthority<'info> {
+    /// Current Token-2022 authority (or the stablecoin authority when the PDA holds it)
+    pub current_authority: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
 }
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{StablecoinState, StablecoinStatus, OperationCountsSnapshot, MintStats, MintStatsSnapshot};
+use crate::instructions::hook;
+
+/// Return a compact status snapshot as instruction return data.
+/// Read-only; intended to be called through a simulated transaction.
+pub fn get_state_handler(
+    ctx: Context<GetState>,
+) -> Result<StablecoinStatus> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    Ok(StablecoinStatus {
+        net_supply: state.net_supply(),
+        is_sss2: state.is_sss2,
+        paused: state.paused,
+        active_blacklist_count: state.active_blacklist_count,
+        mint_authority: state.mint_authority,
+        freeze_authority: state.freeze_authority,
+        compliance_officer: state.compliance_officer,
+    })
+}
+
+/// Return the lifetime mint/burn/freeze/seize operation counters as return data.
+pub fn get_operation_counts_handler(
+    ctx: Context<GetState>,
+) -> Result<OperationCountsSnapshot> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    Ok(OperationCountsSnapshot {
+        mint_op_count: state.mint_op_count,
+        burn_op_count: state.burn_op_count,
+        freeze_op_count: state.freeze_op_count,
+        seize_op_count: state.seize_op_count,
+    })
+}
+
+/// Return whether `address` is currently blacklisted as return data.
+/// A missing, removed or expired entry reads as `false`.
+pub fn is_blacklisted_handler(
+    ctx: Context<CheckBlacklist>,
+    address: Pubkey,
+) -> Result<bool> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    match &ctx.accounts.blacklist_entry {
+        Some(entry) => hook::is_blacklisted(entry, &state.mint, &address, Clock::get()?.unix_timestamp),
+        None => Ok(false),
+    }
+}
+
+#[derive(Accounts)]
+pub struct GetState<'info> {
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+}
+
+/// Return the cumulative amount and number of mints into `token_account` as return data.
+/// An account without a `MintStats` PDA reads as zero.
+pub fn get_mint_stats_handler(
+    ctx: Context<GetMintStats>,
+    _token_account: Pubkey,
+) -> Result<MintStatsSnapshot> {
+    let stats = match &ctx.accounts.mint_stats {
+        Some(info) => hook::load_entry::<MintStats>(info)?,
+        None => None,
+    };
+
+    Ok(match stats {
+        Some(stats) => MintStatsSnapshot {
+            cumulative_minted: stats.cumulative_minted,
+            mint_count: stats.mint_count,
+        },
+        None => MintStatsSnapshot {
+            cumulative_minted: 0,
+            mint_count: 0,
+        },
+    })
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct CheckBlacklist<'info> {
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Blacklist entry PDA for `address` (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), address.as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: Option<AccountInfo<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(token_account: Pubkey)]
+pub struct GetMintStats<'info> {
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Mint stats PDA for `token_account` (may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"mintstats", mint.key().as_ref(), token_account.as_ref()],
+        bump,
+    )]
+    pub mint_stats: Option<AccountInfo<'info>>,
+}
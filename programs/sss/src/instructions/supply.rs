@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{StablecoinState, ReserveAttestation, SupplySnapshot};
+use crate::errors::SSSError;
+use crate::events::{SupplyReconciled, ReservesAttested, SupplySnapshotTaken};
+
+/// Reconcile tracked supply against the real Token-2022 mint supply.
+/// Stores `mint.supply - net_supply()` in `supply_drift` (positive means the
+/// mint holds more than this program has tracked).
+pub fn reconcile_supply_handler(
+    ctx: Context<ReconcileSupply>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    let mint_supply = ctx.accounts.mint.supply;
+    let tracked_supply = state.net_supply();
+    let drift: i64 = (mint_supply as i128 - tracked_supply as i128)
+        .try_into()
+        .map_err(|_| SSSError::Overflow)?;
+
+    let clock = Clock::get()?;
+    state.supply_drift = drift;
+    state.updated_at = clock.unix_timestamp;
+
+    emit!(SupplyReconciled {
+        mint: state.mint,
+        mint_supply,
+        tracked_supply,
+        drift,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Reconciled supply — drift {}", drift);
+    Ok(())
+}
+
+/// Publish a proof-of-reserves attestation for `period`.
+/// Liabilities are snapshotted from the tracked net supply; each period can
+/// only be attested once.
+pub fn publish_attestation_handler(
+    ctx: Context<PublishAttestation>,
+    period: u64,
+    reserves: u64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.stablecoin = state.mint;
+    attestation.period = period;
+    attestation.reserves = reserves;
+    attestation.liabilities = state.net_supply();
+    attestation.attested_by = ctx.accounts.authority.key();
+    attestation.attested_at = clock.unix_timestamp;
+    attestation.bump = ctx.bumps.attestation;
+
+    let fully_backed = attestation.is_fully_backed();
+
+    emit!(ReservesAttested {
+        mint: state.mint,
+        period,
+        reserves,
+        liabilities: attestation.liabilities,
+        fully_backed,
+        attested_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Attested reserves {} against liabilities {} for period {}", reserves, attestation.liabilities, period);
+    Ok(())
+}
+
+/// Capture the supply figures for `epoch` in an immutable snapshot.
+/// Each epoch can only be captured once.
+pub fn take_snapshot_handler(
+    ctx: Context<TakeSnapshot>,
+    epoch: u64,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.stablecoin = state.mint;
+    snapshot.epoch = epoch;
+    snapshot.total_minted = state.total_minted;
+    snapshot.total_burned = state.total_burned;
+    snapshot.net_supply = state.net_supply();
+    snapshot.taken_by = ctx.accounts.authority.key();
+    snapshot.taken_at = clock.unix_timestamp;
+    snapshot.bump = ctx.bumps.snapshot;
+
+    emit!(SupplySnapshotTaken {
+        mint: state.mint,
+        epoch,
+        total_minted: snapshot.total_minted,
+        total_burned: snapshot.total_burned,
+        net_supply: snapshot.net_supply,
+        taken_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Captured supply snapshot {} (net supply {})", epoch, snapshot.net_supply);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReconcileSupply<'info> {
+    /// Stablecoin authority (owner)
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+}
+
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct PublishAttestation<'info> {
+    /// Stablecoin authority (owner)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Reserve attestation PDA for the period
+    #[account(
+        init,
+        payer = authority,
+        space = ReserveAttestation::SPACE,
+        seeds = [b"reserve", mint.key().as_ref(), &period.to_le_bytes()],
+        bump,
+    )]
+    pub attestation: Account<'info, ReserveAttestation>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct TakeSnapshot<'info> {
+    /// Stablecoin authority (owner)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Supply snapshot PDA for the epoch
+    #[account(
+        init,
+        payer = authority,
+        space = SupplySnapshot::SPACE,
+        seeds = [b"snapshot", mint.key().as_ref(), &epoch.to_le_bytes()],
+        bump,
+    )]
+    pub snapshot: Account<'info, SupplySnapshot>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
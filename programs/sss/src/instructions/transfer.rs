@@ -1,50 +1,430 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use spl_token_2022::extension::transfer_fee::instruction::transfer_checked_with_fee;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::Mint as MintState;
 
-use crate::state::StablecoinState;
+use crate::state::{StablecoinState, AccountActivity, KycRecord, MintLock, LimitExemption};
 use crate::errors::SSSError;
-use crate::events::TokensTransferred;
+use crate::events::{TokensTransferred, BatchTransferCompleted};
+use crate::instructions::hook::{is_blacklisted, load_entry};
+use crate::merkle::{allowlist_leaf, verify_allowlist_proof};
+use crate::validation::validate_amount;
+use crate::format::format_ui_amount;
 
 /// Transfer tokens between accounts.
-/// For SSS-2, the transfer hook will automatically check blacklists.
+/// For SSS-2, both parties are checked against the blacklist here as well as
+/// in the transfer hook, so the block holds even if the hook is not invoked.
+/// In allowlist mode with a merkle root set, `allowlist_proof` must prove the
+/// destination owner is a member. With a transfer cooldown set, `source_activity`
+/// is required and the source must not have sent anything within the cooldown.
+/// Amounts above a KYC tier threshold need the sender's `KycRecord` at that tier.
+/// With a mint lock period set, `source_lock` is required and the source must be
+/// past its `MintLock::unlock_at` (accounts never minted into have no lock).
+/// A sender with an active `LimitExemption` skips the transfer limits and cooldown.
+/// With `min_account_balance` set, a transfer that would leave the source holding
+/// dust is widened to the full balance (`SweepAll`) or refused (`Reject`).
 pub fn handler(
     ctx: Context<Transfer>,
     amount: u64,
+    memo: Option<String>,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
 ) -> Result<()> {
-    require!(amount > 0, SSSError::InvalidAmount);
+    process_transfer(ctx, amount, memo, allowlist_proof, false)
+}
+
+/// Transfer tokens with `transfer_checked_with_fee`, passing the fee the mint's
+/// transfer-fee extension charges for the current epoch. The fee must match the
+/// mirrored `transfer_fee_basis_points` / `max_transfer_fee`, and is reported in
+/// `TokensTransferred`. Same checks as `transfer`.
+pub fn transfer_with_fee_handler(
+    ctx: Context<Transfer>,
+    amount: u64,
+    memo: Option<String>,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+) -> Result<()> {
+    process_transfer(ctx, amount, memo, allowlist_proof, true)
+}
+
+fn process_transfer(
+    ctx: Context<Transfer>,
+    amount: u64,
+    memo: Option<String>,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+    with_fee: bool,
+) -> Result<()> {
+    require!(!ctx.accounts.stablecoin_state.paused, SSSError::ProgramPaused);
+    require!(!ctx.accounts.stablecoin_state.non_transferable, SSSError::NonTransferable);
+    // Sweep or refuse before the limit checks, so they see the amount actually moved
+    let amount = ctx
+        .accounts
+        .stablecoin_state
+        .dust_adjusted_amount(ctx.accounts.from.amount, amount)?;
+    validate_amount(amount, ctx.accounts.stablecoin_state.max_single_op_amount)?;
+    let memo = StablecoinState::validate_memo(memo)?;
+
+    // Exempt senders (e.g. market makers) skip the per-transfer limits and the cooldown
+    let limit_exempt = ctx
+        .accounts
+        .source_exemption
+        .as_ref()
+        .is_some_and(|exemption| exemption.exempt);
+    if !limit_exempt {
+        if let Some(limit) = ctx.accounts.stablecoin_state.max_transfer_amount {
+            require!(amount <= limit, SSSError::TransferLimitExceeded);
+        }
+        if let Some(minimum) = ctx.accounts.stablecoin_state.min_transfer_amount {
+            require!(amount >= minimum, SSSError::BelowMinimumTransfer);
+        }
+    }
+    require!(
+        ctx.accounts.from.key() != ctx.accounts.to.key(),
+        SSSError::SelfTransfer
+    );
+    require!(
+        ctx.accounts.to.owner != Pubkey::default(),
+        SSSError::InvalidDestination
+    );
+
+    let state = &ctx.accounts.stablecoin_state;
+
+    // Throttle rapid movements out of the same account
+    if let Some(cooldown) = state.transfer_cooldown.filter(|_| !limit_exempt) {
+        let activity = ctx
+            .accounts
+            .source_activity
+            .as_ref()
+            .ok_or(SSSError::MissingAccount)?;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now.saturating_sub(activity.last_outbound_at) >= cooldown,
+            SSSError::TransferCooldownActive
+        );
+    }
+
+    // Freshly minted tokens stay put until the lock expires
+    if state.mint_lock_period.is_some() {
+        let source_lock = ctx
+            .accounts
+            .source_lock
+            .as_ref()
+            .ok_or(SSSError::MissingAccount)?;
+        if let Some(lock) = load_entry::<MintLock>(source_lock)? {
+            require!(
+                Clock::get()?.unix_timestamp >= lock.unlock_at,
+                SSSError::TokensLocked
+            );
+        }
+    }
+
+    // Large transfers need a verified sender; a missing record counts as tier 0
+    let required_tier = state.required_kyc_tier(amount);
+    if required_tier > 0 {
+        let tier = ctx.accounts.source_kyc.as_ref().map_or(0, |record| record.tier);
+        require!(tier >= required_tier, SSSError::KycTierInsufficient);
+    }
+
+    // Allowlist mode with a merkle root: the destination owner must prove membership
+    if state.allowlist_mode && state.allowlist_root != [0u8; 32] {
+        let proof = allowlist_proof.ok_or(SSSError::TransferBlocked)?;
+        require!(
+            verify_allowlist_proof(&state.allowlist_root, &allowlist_leaf(&ctx.accounts.to.owner), &proof),
+            SSSError::TransferBlocked
+        );
+    }
+
+    // SSS-2: defense in depth, independent of the transfer hook
+    if state.is_sss2 {
+        let (Some(source_blacklist), Some(destination_blacklist)) = (
+            &ctx.accounts.source_blacklist,
+            &ctx.accounts.destination_blacklist,
+        ) else {
+            return Err(SSSError::MissingAccount.into());
+        };
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !is_blacklisted(source_blacklist, &state.mint, &ctx.accounts.from.owner, now)?,
+            SSSError::TransferBlocked
+        );
+        require!(
+            !is_blacklisted(destination_blacklist, &state.mint, &ctx.accounts.to.owner, now)?,
+            SSSError::TransferBlocked
+        );
+    }
 
     let decimals = ctx.accounts.mint.decimals;
 
-    // Perform transfer via Token-2022 (transfer_checked for safety)
-    let cpi_accounts = TransferChecked {
-        from: ctx.accounts.from.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.to.to_account_info(),
-        authority: ctx.accounts.owner.to_account_info(),
+    let fee = if with_fee {
+        // Refuse to charge a fee the program-side mirror does not expect
+        let fee = extension_transfer_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+        require!(fee == state.transfer_fee(amount), SSSError::TransferFeeMismatch);
+
+        let ix = transfer_checked_with_fee(
+            &ctx.accounts.token_program.key(),
+            &ctx.accounts.from.key(),
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.to.key(),
+            &ctx.accounts.owner.key(),
+            &[],
+            amount,
+            decimals,
+            fee,
+        )?;
+        invoke(
+            &ix,
+            &[
+                ctx.accounts.from.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.to.to_account_info(),
+                ctx.accounts.owner.to_account_info(),
+            ],
+        )?;
+        fee
+    } else {
+        // Perform transfer via Token-2022 (transfer_checked for safety)
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.from.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.to.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+        );
+        transfer_checked(cpi_ctx, amount, decimals)?;
+        0
     };
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        cpi_accounts,
-    );
-    transfer_checked(cpi_ctx, amount, decimals)?;
 
-    // Update timestamp
+    // Update audit state
     let state = &mut ctx.accounts.stablecoin_state;
+    let cumulative_volume = state.record_transfer_volume(amount)?;
     state.updated_at = Clock::get()?.unix_timestamp;
 
-    emit!(TokensTransferred {
-        mint: state.mint,
-        from: ctx.accounts.from.key(),
-        to: ctx.accounts.to.key(),
-        amount,
-        timestamp: state.updated_at,
-    });
+    // Dormancy tracking is opt-in per account
+    if let Some(activity) = ctx.accounts.source_activity.as_mut() {
+        activity.record_outbound(amount, state.updated_at);
+    }
+    if let Some(activity) = ctx.accounts.destination_activity.as_mut() {
+        activity.record_transfer(amount, state.updated_at);
+    }
+
+    if state.emit_events {
+        emit!(TokensTransferred {
+            mint: state.mint,
+            from: ctx.accounts.from.key(),
+            to: ctx.accounts.to.key(),
+            amount,
+            ui_amount: format_ui_amount(amount, state.decimals),
+            fee,
+            cumulative_volume,
+            memo,
+            timestamp: state.updated_at,
+        });
+    }
 
-    msg!("SSS: Transferred {} tokens", amount);
+    if with_fee {
+        msg!("SSS: Transferred {} tokens (fee {})", amount, fee);
+    } else {
+        msg!("SSS: Transferred {} tokens", amount);
+    }
 
     Ok(())
 }
 
+/// Transfer from one source to several destinations in one instruction.
+/// `amounts[i]` goes to the i-th destination in `remaining_accounts`; for SSS-2
+/// each destination token account is followed by its owner's blacklist entry PDA
+/// (which may not exist). Every leg gets the same limit, KYC and blacklist checks
+/// as `transfer`, and any failing leg aborts the whole batch. The recipient count
+/// is capped by `StablecoinState::transfer_batch_limit`. Batches cannot carry
+/// allowlist proofs, so they are refused while an allowlist root is set.
+/// With `min_account_balance` set, each leg is dust-adjusted against the source
+/// balance left by the legs before it.
+pub fn transfer_batch_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TransferBatch<'info>>,
+    amounts: Vec<u64>,
+    memo: Option<String>,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(!state.paused, SSSError::ProgramPaused);
+    require!(!state.non_transferable, SSSError::NonTransferable);
+    require!(!amounts.is_empty(), SSSError::InvalidAmount);
+    require!(amounts.len() <= state.transfer_batch_limit(), SSSError::BatchTooLarge);
+    let stride = if state.is_sss2 { 2 } else { 1 };
+    require!(
+        ctx.remaining_accounts.len() == amounts.len() * stride,
+        SSSError::BatchLengthMismatch
+    );
+    require!(
+        !(state.allowlist_mode && state.allowlist_root != [0u8; 32]),
+        SSSError::TransferBlocked
+    );
+    let memo = StablecoinState::validate_memo(memo)?;
+
+    // Sweep or refuse leg by leg, so the limit checks see the amounts actually moved
+    let mut balance = ctx.accounts.from.amount;
+    let amounts = amounts
+        .iter()
+        .map(|amount| {
+            let amount = state.dust_adjusted_amount(balance, *amount)?;
+            balance = balance.saturating_sub(amount);
+            Ok(amount)
+        })
+        .collect::<Result<Vec<u64>>>()?;
+
+    // Check the running total up front so an overflow aborts before any CPI
+    let batch_total = amounts
+        .iter()
+        .try_fold(0u64, |acc, amount| acc.checked_add(*amount))
+        .ok_or(SSSError::Overflow)?;
+
+    let limit_exempt = ctx
+        .accounts
+        .source_exemption
+        .as_ref()
+        .is_some_and(|exemption| exemption.exempt);
+    let now = Clock::get()?.unix_timestamp;
+
+    // Source-side checks apply once for the whole batch
+    if let Some(cooldown) = state.transfer_cooldown.filter(|_| !limit_exempt) {
+        let activity = ctx
+            .accounts
+            .source_activity
+            .as_ref()
+            .ok_or(SSSError::MissingAccount)?;
+        require!(
+            now.saturating_sub(activity.last_outbound_at) >= cooldown,
+            SSSError::TransferCooldownActive
+        );
+    }
+    if state.mint_lock_period.is_some() {
+        let source_lock = ctx
+            .accounts
+            .source_lock
+            .as_ref()
+            .ok_or(SSSError::MissingAccount)?;
+        if let Some(lock) = load_entry::<MintLock>(source_lock)? {
+            require!(now >= lock.unlock_at, SSSError::TokensLocked);
+        }
+    }
+    if state.is_sss2 {
+        let source_blacklist = ctx
+            .accounts
+            .source_blacklist
+            .as_ref()
+            .ok_or(SSSError::MissingAccount)?;
+        require!(
+            !is_blacklisted(source_blacklist, &state.mint, &ctx.accounts.from.owner, now)?,
+            SSSError::TransferBlocked
+        );
+    }
+
+    let mint_key = ctx.accounts.mint.key();
+    let decimals = ctx.accounts.mint.decimals;
+    let kyc_tier = ctx.accounts.source_kyc.as_ref().map_or(0, |record| record.tier);
+
+    for (i, amount) in amounts.iter().enumerate() {
+        let state = &ctx.accounts.stablecoin_state;
+        validate_amount(*amount, state.max_single_op_amount)?;
+        if !limit_exempt {
+            if let Some(limit) = state.max_transfer_amount {
+                require!(*amount <= limit, SSSError::TransferLimitExceeded);
+            }
+            if let Some(minimum) = state.min_transfer_amount {
+                require!(*amount >= minimum, SSSError::BelowMinimumTransfer);
+            }
+        }
+        require!(kyc_tier >= state.required_kyc_tier(*amount), SSSError::KycTierInsufficient);
+
+        let to_info = &ctx.remaining_accounts[i * stride];
+        let to = InterfaceAccount::<TokenAccount>::try_from(to_info)?;
+        require!(to.mint == mint_key, SSSError::MintMismatch);
+        require!(to_info.key() != ctx.accounts.from.key(), SSSError::SelfTransfer);
+        require!(to.owner != Pubkey::default(), SSSError::InvalidDestination);
+
+        if state.is_sss2 {
+            let blacklist_info = &ctx.remaining_accounts[i * stride + 1];
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"blacklist", mint_key.as_ref(), to.owner.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(blacklist_info.key(), expected, SSSError::InvalidPda);
+            require!(
+                !is_blacklisted(blacklist_info, &state.mint, &to.owner, now)?,
+                SSSError::TransferBlocked
+            );
+        }
+
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.from.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: to_info.clone(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+        );
+        transfer_checked(cpi_ctx, *amount, decimals)?;
+
+        let state = &mut ctx.accounts.stablecoin_state;
+        let cumulative_volume = state.record_transfer_volume(*amount)?;
+        if state.emit_events {
+            emit!(TokensTransferred {
+                mint: state.mint,
+                from: ctx.accounts.from.key(),
+                to: to_info.key(),
+                amount: *amount,
+                ui_amount: format_ui_amount(*amount, state.decimals),
+                fee: 0,
+                cumulative_volume,
+                memo: memo.clone(),
+                timestamp: now,
+            });
+        }
+    }
+
+    let state = &mut ctx.accounts.stablecoin_state;
+    state.updated_at = now;
+    if let Some(activity) = ctx.accounts.source_activity.as_mut() {
+        activity.record_outbound(batch_total, now);
+    }
+
+    if state.emit_events {
+        emit!(BatchTransferCompleted {
+            mint: state.mint,
+            from: ctx.accounts.from.key(),
+            recipients: amounts.len() as u8,
+            total_amount: batch_total,
+            transferred_by: ctx.accounts.owner.key(),
+            cumulative_volume: state.total_transfer_volume,
+            timestamp: now,
+        });
+    }
+
+    msg!("SSS: Batch transferred {} tokens to {} accounts", batch_total, amounts.len());
+
+    Ok(())
+}
+
+/// Fee the mint's transfer-fee extension charges on `amount` in the current epoch.
+fn extension_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let fee_config = mint_state
+        .get_extension::<TransferFeeConfig>()
+        .map_err(|_| SSSError::MissingExtension)?;
+    let fee = fee_config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or(SSSError::Overflow)?;
+    Ok(fee)
+}
+
 #[derive(Accounts)]
 pub struct Transfer<'info> {
     /// Owner of the source token account
@@ -80,6 +460,131 @@ pub struct Transfer<'info> {
     )]
     pub stablecoin_state: Account<'info, StablecoinState>,
 
+    /// Blacklist entry PDA for the source owner (required for SSS-2; may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), from.owner.as_ref()],
+        bump,
+    )]
+    pub source_blacklist: Option<AccountInfo<'info>>,
+
+    /// Blacklist entry PDA for the destination owner (required for SSS-2; may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), to.owner.as_ref()],
+        bump,
+    )]
+    pub destination_blacklist: Option<AccountInfo<'info>>,
+
+    /// Activity PDA for the source account (optional dormancy tracking)
+    #[account(
+        mut,
+        seeds = [b"activity", mint.key().as_ref(), from.key().as_ref()],
+        bump = source_activity.bump,
+    )]
+    pub source_activity: Option<Account<'info, AccountActivity>>,
+
+    /// Activity PDA for the destination account (optional dormancy tracking)
+    #[account(
+        mut,
+        seeds = [b"activity", mint.key().as_ref(), to.key().as_ref()],
+        bump = destination_activity.bump,
+    )]
+    pub destination_activity: Option<Account<'info, AccountActivity>>,
+
+    /// KYC record PDA for the source owner (needed above the tier thresholds)
+    #[account(
+        seeds = [b"kyc", mint.key().as_ref(), from.owner.as_ref()],
+        bump = source_kyc.bump,
+    )]
+    pub source_kyc: Option<Account<'info, KycRecord>>,
+
+    /// Mint lock PDA for the source account (required while a mint lock period is set; may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"mint_lock", mint.key().as_ref(), from.key().as_ref()],
+        bump,
+    )]
+    pub source_lock: Option<AccountInfo<'info>>,
+
+    /// Limit exemption PDA for the source owner (optional; skips transfer limits and cooldown)
+    #[account(
+        seeds = [b"limit_exempt", mint.key().as_ref(), from.owner.as_ref()],
+        bump = source_exemption.bump,
+    )]
+    pub source_exemption: Option<Account<'info, LimitExemption>>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct TransferBatch<'info> {
+    /// Owner of the source token account
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Source token account
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Blacklist entry PDA for the source owner (required for SSS-2; may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), from.owner.as_ref()],
+        bump,
+    )]
+    pub source_blacklist: Option<AccountInfo<'info>>,
+
+    /// Activity PDA for the source account (optional dormancy tracking)
+    #[account(
+        mut,
+        seeds = [b"activity", mint.key().as_ref(), from.key().as_ref()],
+        bump = source_activity.bump,
+    )]
+    pub source_activity: Option<Account<'info, AccountActivity>>,
+
+    /// KYC record PDA for the source owner (needed above the tier thresholds)
+    #[account(
+        seeds = [b"kyc", mint.key().as_ref(), from.owner.as_ref()],
+        bump = source_kyc.bump,
+    )]
+    pub source_kyc: Option<Account<'info, KycRecord>>,
+
+    /// Mint lock PDA for the source account (required while a mint lock period is set; may not exist)
+    /// CHECK: Address verified by seeds; loaded in the handler
+    #[account(
+        seeds = [b"mint_lock", mint.key().as_ref(), from.key().as_ref()],
+        bump,
+    )]
+    pub source_lock: Option<AccountInfo<'info>>,
+
+    /// Limit exemption PDA for the source owner (optional; skips transfer limits and cooldown)
+    #[account(
+        seeds = [b"limit_exempt", mint.key().as_ref(), from.owner.as_ref()],
+        bump = source_exemption.bump,
+    )]
+    pub source_exemption: Option<Account<'info, LimitExemption>>,
+
     /// Token-2022 program
     pub token_program: Interface<'info, TokenInterface>,
+    // remaining_accounts: destination token accounts, one per amount; for SSS-2 each
+    // is followed by the blacklist entry PDA of its owner
 }
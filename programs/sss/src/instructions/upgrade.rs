@@ -0,0 +1,320 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Mint, TokenInterface};
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use spl_token_2022::state::Mint as MintState;
+
+use crate::state::{StablecoinState, LegacyStablecoinState};
+use crate::errors::SSSError;
+use crate::events::{PresetUpgraded, StateMigrated, StateReallocated};
+
+/// Upgrade an SSS-1 stablecoin to SSS-2 in place.
+///
+/// Token-2022 extensions cannot be added to an existing mint, so the mint must
+/// already have been created with the permanent delegate and transfer hook
+/// extensions initialized (but dormant while the stablecoin ran as SSS-1).
+pub fn upgrade_to_sss2_handler(
+    ctx: Context<UpgradeToSSS2>,
+    compliance_officer: Pubkey,
+) -> Result<()> {
+    let state = &ctx.accounts.stablecoin_state;
+
+    require!(
+        ctx.accounts.authority.key() == state.authority,
+        SSSError::Unauthorized
+    );
+    state.check_config_unlocked()?;
+    require!(!state.is_sss2, SSSError::AlreadySSS2);
+    require!(!state.allowlist_mode, SSSError::ListModeConflict);
+
+    // Introspect the mint for the required extensions
+    {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+        let extensions = mint_state.get_extension_types()?;
+        require!(
+            extensions.contains(&ExtensionType::PermanentDelegate)
+                && extensions.contains(&ExtensionType::TransferHook),
+            SSSError::MissingExtension
+        );
+    }
+
+    let clock = Clock::get()?;
+    let state = &mut ctx.accounts.stablecoin_state;
+    let old_preset = state.preset_label();
+
+    state.is_sss2 = true;
+    state.permanent_delegate_enabled = true;
+    state.transfer_hook_enabled = true;
+    state.compliance_officer = compliance_officer;
+    state.blacklist_authority = compliance_officer;
+    state.updated_at = clock.unix_timestamp;
+
+    emit!(PresetUpgraded {
+        mint: state.mint,
+        old_preset,
+        new_preset: state.preset_label(),
+        compliance_officer,
+        upgraded_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Upgraded {} to {}", state.symbol, state.preset_label());
+    Ok(())
+}
+
+/// Migrate a stablecoin state account from an older layout to `CURRENT_VERSION`.
+///
+/// Grows the account to the current `SPACE` (the authority pays any extra rent),
+/// back-fills fields added since the stored version, and stamps the new version.
+/// Accounts from before the version byte existed are rebuilt from their legacy layout.
+pub fn migrate_state_handler(
+    ctx: Context<MigrateState>,
+) -> Result<()> {
+    let info = ctx.accounts.stablecoin_state.to_account_info();
+
+    let old_version = read_state_header(&info, &ctx.accounts.authority.key())?;
+    require!(old_version < StablecoinState::CURRENT_VERSION, SSSError::AlreadyMigrated);
+
+    // Read a pre-versioning account before its bytes are cleared below
+    let legacy = match old_version {
+        0 => Some(LegacyStablecoinState::deserialize(&mut &info.try_borrow_data()?[8..])?),
+        _ => None,
+    };
+
+    grow_state(
+        &info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    clear_state_tail(&info, old_version)?;
+
+    let mut state = StablecoinState::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    let clock = Clock::get()?;
+
+    // v0 → v1: the pre-versioning layout. Its fields are carried over; the pauser role
+    // falls to the authority and the mint rotation and daily windows start at creation.
+    if let Some(legacy) = legacy {
+        state.mint = legacy.mint;
+        state.authority = legacy.authority;
+        state.mint_authority = legacy.mint_authority;
+        state.freeze_authority = legacy.freeze_authority;
+        state.compliance_officer = legacy.compliance_officer;
+        state.pauser = legacy.authority;
+        state.last_mint_authority_change = legacy.created_at;
+        state.is_sss2 = legacy.is_sss2;
+        state.permanent_delegate_enabled = legacy.permanent_delegate_enabled;
+        state.transfer_hook_enabled = legacy.transfer_hook_enabled;
+        state.default_account_frozen = legacy.default_account_frozen;
+        state.mint_window_start = legacy.created_at;
+        state.total_minted = legacy.total_minted;
+        state.total_burned = legacy.total_burned;
+        state.created_at = legacy.created_at;
+        state.bump = legacy.bump;
+        state.name = legacy.name;
+        state.symbol = legacy.symbol;
+        state.decimals = legacy.decimals;
+    }
+
+    // Back-fill fields appended since `old_version`. Bytes past the stored layout were
+    // cleared above, so a step only needs code for non-zero defaults.
+    // v1 → v2: `last_migrated_at` (stamped below)
+    // v2 → v3: `interest_rate_bps` (zero)
+    // v3 → v4: `exempt_addresses` (empty: a zero length prefix)
+    // v4 → v5: `total_transfer_volume` (zero)
+    // v5 → v6: `min_transfer_amount` (`None`: a zero option tag)
+    // v6 → v7: `oracle` (`None`) and `max_peg_deviation_bps` (zero)
+    // v7 → v8: `mint_op_count`, `burn_op_count`, `freeze_op_count`, `seize_op_count` (zero)
+    // v8 → v9: `transfer_cooldown` (`None`)
+    // v9 → v10: `blacklist_authority` (the compliance officer, so blacklisting keeps working)
+    if old_version < 10 {
+        state.blacklist_authority = state.compliance_officer;
+    }
+    // v10 → v11: `seizure_treasury` (`None`: seizures go to the authority)
+    // v11 → v12: `clawback_window` (`None`: clawbacks disabled)
+    // v12 → v13: `max_single_op_amount` (`None`: uncapped)
+    // v13 → v14: `freeze_on_blacklist` (false)
+    // v14 → v15: `bitmap_blacklist` (false)
+    // v15 → v16: `emit_events` (true, so existing indexers keep receiving events)
+    if old_version < 16 {
+        state.emit_events = true;
+    }
+    // v16 → v17: `kyc_tier_thresholds` (all zero: no KYC gating)
+    // v17 → v18: `mint_fee_bps` (zero: no issuance fee) and `mint_fee_collector` (unset)
+    // v18 → v19: `mint_lock_period` (`None`: fresh mints transfer immediately)
+    // v19 → v20: `max_blacklist_entries` (`None`: unlimited)
+    // v20 → v21: `fee_authority` (the authority, so fee withdrawals have an owner)
+    if old_version < 21 {
+        state.fee_authority = state.authority;
+    }
+    // v21 → v22: `changelog_enabled` (false until `init_changelog`)
+    // v22 → v23: `daily_seize_limit` and `seize_cooldown` (`None`: unlimited), zeroed seize window
+    // v23 → v24: `non_transferable` (false)
+    // v24 → v25: `max_transfer_batch_size` (0: `MAX_BATCH_SIZE`)
+    // v25 → v26: `config_locked` (false)
+    // v26 → v27: `require_dual_seize` (false)
+    // v27 → v28: `blacklist_digest` (zeroes) and `digest_updated_at` (0)
+    // v28 → v29: `fee_rounding` (`RoundDown`, the previous mint fee rounding)
+    // v29 → v30: `blocked_transfer_count` (zero)
+    // v30 → v31: `min_account_balance` (`None`: unchecked) and `dust_policy` (`Reject`)
+    // v31 → v32: blacklist digest pass fields (zero `digest_snapshot_at`: no pass in progress)
+
+    state.version = StablecoinState::CURRENT_VERSION;
+    state.last_migrated_at = clock.unix_timestamp;
+    state.updated_at = clock.unix_timestamp;
+    state.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(StateMigrated {
+        mint: state.mint,
+        old_version,
+        new_version: state.version,
+        migrated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Migrated state from v{} to v{}", old_version, state.version);
+    Ok(())
+}
+
+/// Grow a stablecoin state account to the current `SPACE` without touching its fields.
+///
+/// Everything past the stored layout is zeroed after growing, so every field added
+/// since then reads as its zero default until `migrate_state` back-fills it.
+/// Pre-versioning accounts are refused (`LegacyStateLayout`): they are recognised
+/// by their size, so they must go through `migrate_state` instead.
+/// A no-op for accounts already at the target size.
+pub fn realloc_state_handler(
+    ctx: Context<MigrateState>,
+) -> Result<()> {
+    let info = ctx.accounts.stablecoin_state.to_account_info();
+    let version = read_state_header(&info, &ctx.accounts.authority.key())?;
+    require!(version != 0, SSSError::LegacyStateLayout);
+
+    let old_size = info.data_len();
+    if !grow_state(
+        &info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )? {
+        msg!("SSS: State already at {} bytes", old_size);
+        return Ok(());
+    }
+    clear_state_tail(&info, version)?;
+
+    let clock = Clock::get()?;
+    emit!(StateReallocated {
+        mint: ctx.accounts.mint.key(),
+        old_size: old_size as u32,
+        new_size: info.data_len() as u32,
+        reallocated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SSS: Reallocated state from {} to {} bytes", old_size, info.data_len());
+    Ok(())
+}
+
+/// Check the fixed-offset header shared by every state layout (discriminator,
+/// version, mint, authority) and the caller's authority; returns the stored version.
+/// Pre-versioning accounts are recognised by their size and reported as version 0.
+fn read_state_header(info: &AccountInfo, caller: &Pubkey) -> Result<u8> {
+    let data = info.try_borrow_data()?;
+    let (version, offset) = match data.len() {
+        LegacyStablecoinState::SPACE => (0, LegacyStablecoinState::AUTHORITY_OFFSET),
+        _ => (
+            data.get(8).copied().unwrap_or_default(),
+            StablecoinState::AUTHORITY_OFFSET,
+        ),
+    };
+    require!(
+        data.len() >= offset + 32 && data[..8] == StablecoinState::DISCRIMINATOR,
+        SSSError::InvalidPda
+    );
+    let authority = Pubkey::try_from(&data[offset..offset + 32]).map_err(|_| SSSError::InvalidPda)?;
+    require!(*caller == authority, SSSError::Unauthorized);
+    Ok(version)
+}
+
+/// Zero everything past the serialized `version` layout, so fields appended since
+/// then read as their zero defaults rather than stale bytes from a shrunk field.
+fn clear_state_tail(info: &AccountInfo, version: u8) -> Result<()> {
+    let mut data = info.try_borrow_mut_data()?;
+    let len = StablecoinState::layout_len(&data, version)?;
+    data[len..].fill(0);
+    Ok(())
+}
+
+/// Grow the state account to the current layout, topping up rent from `payer` first.
+/// Returns whether the account was resized.
+fn grow_state<'info>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<bool> {
+    let shortfall = Rent::get()?
+        .minimum_balance(StablecoinState::SPACE)
+        .saturating_sub(info.lamports());
+    if shortfall > 0 {
+        let cpi_ctx = CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: payer.clone(),
+                to: info.clone(),
+            },
+        );
+        transfer(cpi_ctx, shortfall)?;
+    }
+    if info.data_len() >= StablecoinState::SPACE {
+        return Ok(false);
+    }
+    info.realloc(StablecoinState::SPACE, true)?;
+    Ok(true)
+}
+
+#[derive(Accounts)]
+pub struct UpgradeToSSS2<'info> {
+    /// Stablecoin authority (owner)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint (must carry the SSS-2 extensions)
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_state.bump,
+    )]
+    pub stablecoin_state: Account<'info, StablecoinState>,
+
+    /// Token-2022 program
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateState<'info> {
+    /// Stablecoin authority (pays for any extra rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Stablecoin state PDA, possibly in an older layout
+    /// CHECK: Address verified by seeds and owner; the header is parsed in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump,
+    )]
+    pub stablecoin_state: UncheckedAccount<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -4,6 +4,13 @@ pub mod instructions;
 pub mod state;
 pub mod errors;
 pub mod events;
+pub mod merkle;
+pub mod bitmap;
+pub mod digest;
+pub mod oracle;
+pub mod validation;
+pub mod format;
+pub mod pda;
 
 use instructions::*;
 
@@ -30,39 +37,228 @@ pub mod sss {
         instructions::initialize::handler(ctx, config)
     }
 
-    /// Mint tokens to a specified account
+    /// Initialize a stablecoin, or do nothing if its state already exists
+    pub fn initialize_if_needed(
+        ctx: Context<InitializeIfNeeded>,
+        config: StablecoinConfig,
+    ) -> Result<()> {
+        instructions::initialize::initialize_if_needed_handler(ctx, config)
+    }
+
+    /// Upgrade an SSS-1 stablecoin to SSS-2 in place (mint must carry the extensions)
+    pub fn upgrade_to_sss2(
+        ctx: Context<UpgradeToSSS2>,
+        compliance_officer: Pubkey,
+    ) -> Result<()> {
+        instructions::upgrade::upgrade_to_sss2_handler(ctx, compliance_officer)
+    }
+
+    /// Migrate the stablecoin state from an older layout to the current version
+    pub fn migrate_state(
+        ctx: Context<MigrateState>,
+    ) -> Result<()> {
+        instructions::upgrade::migrate_state_handler(ctx)
+    }
+
+    /// Grow the stablecoin state account to the current size (no-op if already there)
+    pub fn realloc_state(
+        ctx: Context<MigrateState>,
+    ) -> Result<()> {
+        instructions::upgrade::realloc_state_handler(ctx)
+    }
+
+    /// Mint tokens to a specified account (optional memo recorded in the event)
     pub fn mint_to(
         ctx: Context<MintTo>,
         amount: u64,
+        memo: Option<String>,
+    ) -> Result<()> {
+        instructions::mint::handler(ctx, amount, memo)
+    }
+
+    /// Mint tokens to an owner's ATA, creating the ATA if needed (mint authority only)
+    pub fn mint_to_new(
+        ctx: Context<MintToNew>,
+        amount: u64,
+        memo: Option<String>,
+    ) -> Result<()> {
+        instructions::mint::mint_to_new_handler(ctx, amount, memo)
+    }
+
+    /// Mint tokens to multiple accounts (passed as remaining accounts)
+    pub fn mint_to_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintToBatch<'info>>,
+        amounts: Vec<u64>,
+        track_stats: bool,
+    ) -> Result<()> {
+        instructions::mint::batch_handler(ctx, amounts, track_stats)
+    }
+
+    /// Reverse the latest mint into an account within the clawback window (mint authority only)
+    pub fn clawback_mint(
+        ctx: Context<ClawbackMint>,
+    ) -> Result<()> {
+        instructions::mint::clawback_handler(ctx)
+    }
+
+    /// Grant or reset a delegate's minting budget (mint authority only)
+    pub fn grant_mint_allowance(
+        ctx: Context<GrantMintAllowance>,
+        delegate: Pubkey,
+        amount: u64,
     ) -> Result<()> {
-        instructions::mint::handler(ctx, amount)
+        instructions::allowance::grant_mint_allowance_handler(ctx, delegate, amount)
+    }
+
+    /// Mint against the caller's allowance
+    pub fn mint_with_allowance(
+        ctx: Context<MintWithAllowance>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::allowance::mint_with_allowance_handler(ctx, amount)
     }
 
     /// Burn tokens from a specified account  
     pub fn burn(
         ctx: Context<Burn>,
         amount: u64,
+        memo: Option<String>,
+    ) -> Result<()> {
+        instructions::burn::handler(ctx, amount, memo)
+    }
+
+    /// Burn tokens from any account via the permanent delegate (SSS-2 only)
+    pub fn burn_from(
+        ctx: Context<BurnFrom>,
+        amount: u64,
+        force: bool,
+    ) -> Result<()> {
+        instructions::burn::burn_from_handler(ctx, amount, force)
+    }
+
+    /// Burn tokens and open a redemption request
+    pub fn redeem(
+        ctx: Context<Redeem>,
+        amount: u64,
+        reference: String,
+    ) -> Result<()> {
+        instructions::redeem::redeem_handler(ctx, amount, reference)
+    }
+
+    /// Mark a redemption request as fulfilled (authority only)
+    pub fn fulfill_redemption(
+        ctx: Context<FulfillRedemption>,
     ) -> Result<()> {
-        instructions::burn::handler(ctx, amount)
+        instructions::redeem::fulfill_redemption_handler(ctx)
     }
 
     /// Transfer tokens between accounts
     pub fn transfer(
         ctx: Context<Transfer>,
         amount: u64,
+        memo: Option<String>,
+        allowlist_proof: Option<Vec<[u8; 32]>>,
     ) -> Result<()> {
-        instructions::transfer::handler(ctx, amount)
+        instructions::transfer::handler(ctx, amount, memo, allowlist_proof)
+    }
+
+    /// Transfer tokens, charging the Token-2022 transfer fee explicitly
+    pub fn transfer_with_fee(
+        ctx: Context<Transfer>,
+        amount: u64,
+        memo: Option<String>,
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+    ) -> Result<()> {
+        instructions::transfer::transfer_with_fee_handler(ctx, amount, memo, allowlist_proof)
+    }
+
+    /// Transfer from one source to several destinations in one instruction
+    pub fn transfer_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TransferBatch<'info>>,
+        amounts: Vec<u64>,
+        memo: Option<String>,
+    ) -> Result<()> {
+        instructions::transfer::transfer_batch_handler(ctx, amounts, memo)
+    }
+
+    /// Reconcile tracked supply against the Token-2022 mint supply
+    pub fn reconcile_supply(
+        ctx: Context<ReconcileSupply>,
+    ) -> Result<()> {
+        instructions::supply::reconcile_supply_handler(ctx)
+    }
+
+    /// Publish a proof-of-reserves attestation for a reporting period
+    pub fn publish_attestation(
+        ctx: Context<PublishAttestation>,
+        period: u64,
+        reserves: u64,
+    ) -> Result<()> {
+        instructions::supply::publish_attestation_handler(ctx, period, reserves)
+    }
+
+    /// Capture an immutable supply snapshot for an epoch
+    pub fn take_snapshot(
+        ctx: Context<TakeSnapshot>,
+        epoch: u64,
+    ) -> Result<()> {
+        instructions::supply::take_snapshot_handler(ctx, epoch)
+    }
+
+    /// Return a compact status snapshot (read via simulation)
+    pub fn get_state(
+        ctx: Context<GetState>,
+    ) -> Result<StablecoinStatus> {
+        instructions::status::get_state_handler(ctx)
+    }
+
+    /// Return the lifetime operation counters (read via simulation)
+    pub fn get_operation_counts(
+        ctx: Context<GetState>,
+    ) -> Result<OperationCountsSnapshot> {
+        instructions::status::get_operation_counts_handler(ctx)
+    }
+
+    /// Return whether an address is currently blacklisted (read via simulation)
+    pub fn is_blacklisted(
+        ctx: Context<CheckBlacklist>,
+        address: Pubkey,
+    ) -> Result<bool> {
+        instructions::status::is_blacklisted_handler(ctx, address)
+    }
+
+    /// Return the cumulative amount minted into a token account (read via simulation)
+    pub fn get_mint_stats(
+        ctx: Context<GetMintStats>,
+        token_account: Pubkey,
+    ) -> Result<MintStatsSnapshot> {
+        instructions::status::get_mint_stats_handler(ctx, token_account)
     }
 
     // ─────────────────────────────────────────────────
     // Freeze Operations
     // ─────────────────────────────────────────────────
 
-    /// Freeze a token account (prevents all transfers)
+    /// Freeze a token account (prevents all transfers), recording the reason
     pub fn freeze_account(
         ctx: Context<FreezeAccount>,
+        reason: String,
+    ) -> Result<()> {
+        instructions::freeze::freeze_handler(ctx, reason)
+    }
+
+    /// Freeze a token account as the compliance officer (SSS-2 only)
+    pub fn freeze_by_compliance(
+        ctx: Context<FreezeByCompliance>,
+    ) -> Result<()> {
+        instructions::freeze::freeze_by_compliance_handler(ctx)
+    }
+
+    /// Register a new token account, freezing it if accounts default to frozen
+    pub fn register_account(
+        ctx: Context<RegisterAccount>,
     ) -> Result<()> {
-        instructions::freeze::freeze_handler(ctx)
+        instructions::freeze::register_account_handler(ctx)
     }
 
     /// Thaw (unfreeze) a token account
@@ -72,51 +268,668 @@ pub mod sss {
         instructions::freeze::thaw_handler(ctx)
     }
 
+    /// Approve a token account to receive transfers under default-frozen mode (SSS-2 only)
+    pub fn approve_account(
+        ctx: Context<ApproveAccount>,
+    ) -> Result<()> {
+        instructions::freeze::approve_account_handler(ctx)
+    }
+
+    /// Freeze several token accounts (passed as remaining accounts)
+    pub fn freeze_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FreezeBatch<'info>>,
+        reason: String,
+    ) -> Result<()> {
+        instructions::freeze::freeze_batch_handler(ctx, reason)
+    }
+
+    /// Thaw several token accounts (passed as remaining accounts)
+    pub fn thaw_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FreezeBatch<'info>>,
+    ) -> Result<()> {
+        instructions::freeze::thaw_batch_handler(ctx)
+    }
+
     // ─────────────────────────────────────────────────
     // SSS-2: Compliance Operations
     // ─────────────────────────────────────────────────
 
-    /// Add an address to the blacklist (SSS-2 only)
+    /// Add an address to the blacklist with a reason and optional expiry (SSS-2 only)
     pub fn blacklist_add(
         ctx: Context<BlacklistAdd>,
         address: Pubkey,
+        reason_code: u16,
+        note: String,
+        expires_at: Option<i64>,
     ) -> Result<()> {
-        instructions::compliance::blacklist_add_handler(ctx, address)
+        instructions::compliance::blacklist_add_handler(ctx, address, reason_code, note, expires_at)
     }
 
-    /// Remove an address from the blacklist (SSS-2 only)
+    /// Blacklist several addresses in one call (entry PDAs as remaining accounts)
+    pub fn blacklist_add_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BlacklistAddBatch<'info>>,
+        addresses: Vec<Pubkey>,
+        reason_code: u16,
+        note: String,
+        expires_at: Vec<Option<i64>>,
+    ) -> Result<()> {
+        instructions::compliance::blacklist_add_batch_handler(ctx, addresses, reason_code, note, expires_at)
+    }
+
+    /// Remove an address from the blacklist, optionally thawing its account (SSS-2 only)
     pub fn blacklist_remove(
         ctx: Context<BlacklistRemove>,
         address: Pubkey,
+        thaw_on_remove: bool,
+    ) -> Result<()> {
+        instructions::compliance::blacklist_remove_handler(ctx, address, thaw_on_remove)
+    }
+
+    /// Deactivate a batch of blacklist entries after a false-positive push (SSS-2, authority only)
+    pub fn clear_all_blacklist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClearAllBlacklist<'info>>,
+    ) -> Result<()> {
+        instructions::compliance::clear_all_blacklist_handler(ctx)
+    }
+
+    /// Start a paged keccak digest of the active blacklist set (SSS-2 only)
+    pub fn begin_blacklist_digest(ctx: Context<ComputeBlacklistDigest>) -> Result<()> {
+        instructions::compliance::begin_blacklist_digest_handler(ctx)
+    }
+
+    /// Fold a page of blacklist entries into the digest pass in progress (SSS-2 only)
+    pub fn compute_blacklist_digest<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ComputeBlacklistDigest<'info>>,
+    ) -> Result<()> {
+        instructions::compliance::compute_blacklist_digest_handler(ctx)
+    }
+
+    /// Create the blacklist bitmap checked by the hook in bitmap mode (SSS-2 only)
+    pub fn init_blacklist_bitmap(
+        ctx: Context<InitBlacklistBitmap>,
+    ) -> Result<()> {
+        instructions::compliance::init_blacklist_bitmap_handler(ctx)
+    }
+
+    /// Flag an address in the blacklist bitmap (SSS-2 only)
+    pub fn bitmap_blacklist_add(
+        ctx: Context<UpdateBlacklistBitmap>,
+        address: Pubkey,
+    ) -> Result<()> {
+        instructions::compliance::bitmap_blacklist_add_handler(ctx, address)
+    }
+
+    /// Clear an address's bit in the blacklist bitmap (SSS-2 only)
+    pub fn bitmap_blacklist_remove(
+        ctx: Context<UpdateBlacklistBitmap>,
+        address: Pubkey,
+    ) -> Result<()> {
+        instructions::compliance::bitmap_blacklist_remove_handler(ctx, address)
+    }
+
+    /// Add an address to the allowlist (allowlist mode only)
+    pub fn allowlist_add(
+        ctx: Context<AllowlistAdd>,
+        address: Pubkey,
+    ) -> Result<()> {
+        instructions::compliance::allowlist_add_handler(ctx, address)
+    }
+
+    /// Remove an address from the allowlist (allowlist mode only)
+    pub fn allowlist_remove(
+        ctx: Context<AllowlistRemove>,
+        address: Pubkey,
+    ) -> Result<()> {
+        instructions::compliance::allowlist_remove_handler(ctx, address)
+    }
+
+    /// Set the merkle root of allowlisted owners (allowlist mode only)
+    pub fn set_allowlist_root(
+        ctx: Context<SetAllowlistRoot>,
+        root: [u8; 32],
+    ) -> Result<()> {
+        instructions::compliance::set_allowlist_root_handler(ctx, root)
+    }
+
+    /// Set an owner's verified KYC tier (compliance officer only)
+    pub fn kyc_set_tier(
+        ctx: Context<KycSetTier>,
+        owner: Pubkey,
+        tier: u8,
+    ) -> Result<()> {
+        instructions::compliance::kyc_set_tier_handler(ctx, owner, tier)
+    }
+
+    /// Temporarily block an owner's transfers pending review (SSS-2 only)
+    pub fn place_hold(
+        ctx: Context<PlaceHold>,
+        owner: Pubkey,
+        reason: String,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::compliance::place_hold_handler(ctx, owner, reason, expires_at)
+    }
+
+    /// Lift a compliance hold before it expires (SSS-2 only)
+    pub fn release_hold(
+        ctx: Context<ReleaseHold>,
+        owner: Pubkey,
     ) -> Result<()> {
-        instructions::compliance::blacklist_remove_handler(ctx, address)
+        instructions::compliance::release_hold_handler(ctx, owner)
     }
 
     /// Seize tokens from a blacklisted account via permanent delegate (SSS-2 only)
-    pub fn seize_tokens(
-        ctx: Context<SeizeTokens>,
+    pub fn seize_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SeizeTokens<'info>>,
+        amount: u64,
+        seize_all: bool,
+        reason: String,
+        seized_at: i64,
+    ) -> Result<()> {
+        instructions::compliance::seize_tokens_handler(ctx, amount, seize_all, reason, seized_at)
+    }
+
+    /// Propose a seizure for a second compliance officer to approve (SSS-2 only)
+    pub fn propose_seizure(
+        ctx: Context<ProposeSeizure>,
         amount: u64,
+        seize_all: bool,
+        reason: String,
+        seized_at: i64,
     ) -> Result<()> {
-        instructions::compliance::seize_tokens_handler(ctx, amount)
+        instructions::compliance::propose_seizure_handler(ctx, amount, seize_all, reason, seized_at)
+    }
+
+    /// Approve and execute a proposed seizure as a different compliance officer
+    pub fn approve_seizure<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApproveSeizure<'info>>,
+        seized_at: i64,
+    ) -> Result<()> {
+        instructions::compliance::approve_seizure_handler(ctx, seized_at)
+    }
+
+    /// Move tokens between two accounts under legal order via permanent delegate (SSS-2 only)
+    pub fn force_transfer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ForceTransfer<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::compliance::force_transfer_handler(ctx, amount)
+    }
+
+    /// Burn seized tokens held in a treasury account (SSS-2 only)
+    pub fn burn_seized(
+        ctx: Context<BurnSeized>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::compliance::burn_seized_handler(ctx, amount)
+    }
+
+    /// Return seized tokens from the treasury to a rightful owner (SSS-2, authority only)
+    pub fn reissue_seized<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReissueSeized<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::compliance::reissue_seized_handler(ctx, amount)
+    }
+
+    /// Set or clear the owner that seized tokens must be sent to (SSS-2, authority only)
+    pub fn set_seizure_treasury(
+        ctx: Context<SetSeizureTreasury>,
+        treasury: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::compliance::set_seizure_treasury_handler(ctx, treasury)
     }
 
     // ─────────────────────────────────────────────────
     // Role Management
     // ─────────────────────────────────────────────────
 
-    /// Update role assignment (MINT_AUTHORITY, FREEZE_AUTHORITY, COMPLIANCE_OFFICER)
-    pub fn update_role(
-        ctx: Context<UpdateRole>,
+    /// Propose a role reassignment, subject to the role-change timelock
+    pub fn propose_role_change(
+        ctx: Context<ProposeRoleChange>,
+        role: Role,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::roles::propose_role_change_handler(ctx, role, new_authority)
+    }
+
+    /// Execute a proposed role change after its timelock elapses
+    pub fn execute_role_change(
+        ctx: Context<ExecuteRoleChange>,
+        role: Role,
+    ) -> Result<()> {
+        instructions::roles::execute_role_change_handler(ctx, role)
+    }
+
+    /// Permanently renounce a role (e.g. mint authority for a fixed supply)
+    pub fn renounce_role(
+        ctx: Context<RenounceRole>,
         role: Role,
+    ) -> Result<()> {
+        instructions::roles::renounce_role_handler(ctx, role)
+    }
+
+    /// Propose a new stablecoin authority (two-step transfer, step one)
+    pub fn propose_authority_transfer(
+        ctx: Context<ProposeAuthorityTransfer>,
         new_authority: Pubkey,
     ) -> Result<()> {
-        instructions::roles::update_role_handler(ctx, role, new_authority)
+        instructions::roles::propose_authority_transfer_handler(ctx, new_authority)
+    }
+
+    /// Accept a pending authority transfer (two-step transfer, step two)
+    pub fn accept_authority_transfer(
+        ctx: Context<AcceptAuthorityTransfer>,
+    ) -> Result<()> {
+        instructions::roles::accept_authority_transfer_handler(ctx)
+    }
+
+    /// Hand the authority to a governance program PDA (`seeds` must derive it)
+    pub fn set_program_authority(
+        ctx: Context<SetProgramAuthority>,
+        new_authority: Pubkey,
+        seeds: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        instructions::roles::set_program_authority_handler(ctx, new_authority, seeds)
+    }
+
+    /// Align the Token-2022 mint authority with the stablecoin's mint authority role
+    pub fn sync_mint_authority(
+        ctx: Context<SyncTokenAuthority>,
+    ) -> Result<()> {
+        instructions::roles::sync_mint_authority_handler(ctx)
+    }
+
+    /// Align the Token-2022 freeze authority with the stablecoin's freeze authority role
+    pub fn sync_freeze_authority(
+        ctx: Context<SyncTokenAuthority>,
+    ) -> Result<()> {
+        instructions::roles::sync_freeze_authority_handler(ctx)
+    }
+
+    /// Hand the Token-2022 freeze authority to the stablecoin PDA
+    pub fn delegate_freeze_authority(
+        ctx: Context<SyncTokenAuthority>,
+    ) -> Result<()> {
+        instructions::roles::delegate_freeze_authority_handler(ctx)
+    }
+
+    /// Create the compliance officer list, seeded with the current officer
+    pub fn init_compliance_officers(
+        ctx: Context<InitComplianceOfficers>,
+    ) -> Result<()> {
+        instructions::officers::init_compliance_officers_handler(ctx)
+    }
+
+    /// Add a compliance officer to the list
+    pub fn add_compliance_officer(
+        ctx: Context<UpdateComplianceOfficers>,
+        officer: Pubkey,
+    ) -> Result<()> {
+        instructions::officers::add_compliance_officer_handler(ctx, officer)
+    }
+
+    /// Remove a compliance officer from the list
+    pub fn remove_compliance_officer(
+        ctx: Context<UpdateComplianceOfficers>,
+        officer: Pubkey,
+    ) -> Result<()> {
+        instructions::officers::remove_compliance_officer_handler(ctx, officer)
+    }
+
+    // ─────────────────────────────────────────────────
+    // Metadata
+    // ─────────────────────────────────────────────────
+
+    /// Update name, symbol, and/or URI (only provided fields change)
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        instructions::metadata::update_metadata_handler(ctx, name, symbol, uri)
+    }
+
+    /// Release a registered symbol once the coin is wound down (zero net supply)
+    pub fn release_symbol(
+        ctx: Context<ReleaseSymbol>,
+        symbol: String,
+    ) -> Result<()> {
+        instructions::metadata::release_symbol_handler(ctx, symbol)
+    }
+
+    // ─────────────────────────────────────────────────
+    // Limits
+    // ─────────────────────────────────────────────────
+
+    /// Set or clear the per-transfer maximum amount
+    pub fn set_transfer_limit(
+        ctx: Context<SetLimit>,
+        max_transfer_amount: Option<u64>,
+    ) -> Result<()> {
+        instructions::limits::set_transfer_limit_handler(ctx, max_transfer_amount)
+    }
+
+    /// Set or clear the per-transfer minimum amount
+    pub fn set_min_transfer(
+        ctx: Context<SetLimit>,
+        min_transfer_amount: Option<u64>,
+    ) -> Result<()> {
+        instructions::limits::set_min_transfer_handler(ctx, min_transfer_amount)
+    }
+
+    /// Set or clear the minimum interval between transfers out of one account
+    pub fn set_transfer_cooldown(
+        ctx: Context<SetLimit>,
+        transfer_cooldown: Option<i64>,
+    ) -> Result<()> {
+        instructions::limits::set_transfer_cooldown_handler(ctx, transfer_cooldown)
+    }
+
+    /// Set or clear how long a mint stays reversible via `clawback_mint`
+    pub fn set_clawback_window(
+        ctx: Context<SetLimit>,
+        clawback_window: Option<i64>,
+    ) -> Result<()> {
+        instructions::limits::set_clawback_window_handler(ctx, clawback_window)
+    }
+
+    /// Set or clear how long freshly minted tokens stay locked in the destination
+    pub fn set_mint_lock_period(
+        ctx: Context<SetLimit>,
+        mint_lock_period: Option<i64>,
+    ) -> Result<()> {
+        instructions::limits::set_mint_lock_period_handler(ctx, mint_lock_period)
+    }
+
+    /// Set or clear the cap on active blacklist entries
+    pub fn set_max_blacklist_entries(
+        ctx: Context<SetLimit>,
+        max_blacklist_entries: Option<u64>,
+    ) -> Result<()> {
+        instructions::limits::set_max_blacklist_entries_handler(ctx, max_blacklist_entries)
+    }
+
+    /// Set who may harvest and withdraw withheld transfer fees
+    pub fn set_fee_authority(
+        ctx: Context<SetLimit>,
+        fee_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::limits::set_fee_authority_handler(ctx, fee_authority)
+    }
+
+    /// Exempt an owner from per-transfer limits and the transfer cooldown
+    pub fn set_limit_exempt(
+        ctx: Context<SetLimitExempt>,
+        owner: Pubkey,
+        exempt: bool,
+    ) -> Result<()> {
+        instructions::limits::set_limit_exempt_handler(ctx, owner, exempt)
+    }
+
+    /// Harvest withheld transfer fees from token accounts onto the mint
+    pub fn harvest_withheld_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HarvestWithheldFees<'info>>,
+    ) -> Result<()> {
+        instructions::fees::harvest_withheld_fees_handler(ctx)
+    }
+
+    /// Withdraw the fees withheld on the mint to a fee collector
+    pub fn withdraw_withheld_fees(
+        ctx: Context<WithdrawWithheldFees>,
+    ) -> Result<()> {
+        instructions::fees::withdraw_withheld_fees_handler(ctx)
+    }
+
+    /// Set or clear the cap on a single mint, burn, transfer or seizure
+    pub fn set_max_single_op_amount(
+        ctx: Context<SetLimit>,
+        max_single_op_amount: Option<u64>,
+    ) -> Result<()> {
+        instructions::limits::set_max_single_op_amount_handler(ctx, max_single_op_amount)
+    }
+
+    /// Set the transfer amounts above which each KYC tier is required
+    pub fn set_kyc_thresholds(
+        ctx: Context<SetLimit>,
+        thresholds: [u64; 3],
+    ) -> Result<()> {
+        instructions::limits::set_kyc_thresholds_handler(ctx, thresholds)
+    }
+
+    /// Set or clear the rolling 24h mint limit
+    pub fn set_daily_mint_limit(
+        ctx: Context<SetLimit>,
+        daily_mint_limit: Option<u64>,
+    ) -> Result<()> {
+        instructions::limits::set_daily_mint_limit_handler(ctx, daily_mint_limit)
+    }
+
+    /// Set or clear the rolling 24h cap on seized tokens
+    pub fn set_daily_seize_limit(
+        ctx: Context<SetLimit>,
+        daily_seize_limit: Option<u64>,
+    ) -> Result<()> {
+        instructions::limits::set_daily_seize_limit_handler(ctx, daily_seize_limit)
+    }
+
+    /// Set or clear the minimum interval between two seizures
+    pub fn set_seize_cooldown(
+        ctx: Context<SetLimit>,
+        seize_cooldown: Option<i64>,
+    ) -> Result<()> {
+        instructions::limits::set_seize_cooldown_handler(ctx, seize_cooldown)
+    }
+
+    /// Set the maximum recipients per `transfer_batch` (0 = default cap)
+    pub fn set_transfer_batch_size(
+        ctx: Context<SetLimit>,
+        max_recipients: u8,
+    ) -> Result<()> {
+        instructions::limits::set_transfer_batch_size_handler(ctx, max_recipients)
+    }
+
+    /// Update the mirrored transfer-fee basis points and maximum fee
+    pub fn set_transfer_fee(
+        ctx: Context<SetLimit>,
+        basis_points: u16,
+        max_fee: u64,
+    ) -> Result<()> {
+        instructions::limits::set_transfer_fee_handler(ctx, basis_points, max_fee)
+    }
+
+    /// Set the issuance fee on `mint_to` / `mint_to_new` and its collector account
+    pub fn set_mint_fee(
+        ctx: Context<SetLimit>,
+        basis_points: u16,
+        collector: Pubkey,
+    ) -> Result<()> {
+        instructions::limits::set_mint_fee_handler(ctx, basis_points, collector)
+    }
+
+    /// Choose how the issuance fee is rounded (down, up or to nearest)
+    pub fn set_fee_rounding(
+        ctx: Context<SetLimit>,
+        policy: RoundingPolicy,
+    ) -> Result<()> {
+        instructions::limits::set_fee_rounding_handler(ctx, policy)
+    }
+
+    /// Set or clear the minimum non-zero balance a transfer may leave, and the dust policy
+    pub fn set_min_account_balance(
+        ctx: Context<SetLimit>,
+        min_account_balance: Option<u64>,
+        policy: DustPolicy,
+    ) -> Result<()> {
+        instructions::limits::set_min_account_balance_handler(ctx, min_account_balance, policy)
+    }
+
+    /// Update the mirrored interest rate (basis points, may be negative)
+    pub fn set_interest_rate(
+        ctx: Context<SetLimit>,
+        rate_bps: i16,
+    ) -> Result<()> {
+        instructions::limits::set_interest_rate_handler(ctx, rate_bps)
+    }
+
+    /// Set or clear the price oracle and the allowed peg deviation for minting
+    pub fn set_oracle(
+        ctx: Context<SetLimit>,
+        oracle: Option<Pubkey>,
+        max_peg_deviation_bps: u16,
+    ) -> Result<()> {
+        instructions::limits::set_oracle_handler(ctx, oracle, max_peg_deviation_bps)
+    }
+
+    // ─────────────────────────────────────────────────
+    // Activity Tracking
+    // ─────────────────────────────────────────────────
+
+    /// Start tracking transfer activity for a token account
+    pub fn track_activity(
+        ctx: Context<TrackActivity>,
+    ) -> Result<()> {
+        instructions::activity::track_activity_handler(ctx)
+    }
+
+    /// Flag a tracked account as dormant after `threshold` seconds of inactivity
+    pub fn mark_dormant(
+        ctx: Context<MarkDormant>,
+        threshold: i64,
+    ) -> Result<()> {
+        instructions::activity::mark_dormant_handler(ctx, threshold)
+    }
+
+    // ─────────────────────────────────────────────────
+    // Emergency Controls
+    // ─────────────────────────────────────────────────
+
+    /// Pause the stablecoin (halts mint, burn, and transfer)
+    pub fn pause(
+        ctx: Context<Pause>,
+    ) -> Result<()> {
+        instructions::pause::pause_handler(ctx)
+    }
+
+    /// Unpause the stablecoin
+    pub fn unpause(
+        ctx: Context<Unpause>,
+    ) -> Result<()> {
+        instructions::pause::unpause_handler(ctx)
+    }
+
+    /// Toggle a stablecoin-level feature flag (paused, allowlist mode, default frozen)
+    pub fn set_feature_flag(
+        ctx: Context<SetFeatureFlag>,
+        flag: FeatureFlag,
+        value: bool,
+    ) -> Result<()> {
+        instructions::flags::set_feature_flag_handler(ctx, flag, value)
+    }
+
+    /// Toggle mint, burn and transfer event emission (authority only)
+    pub fn set_event_emission(
+        ctx: Context<SetFeatureFlag>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::flags::set_event_emission_handler(ctx, enabled)
+    }
+
+    /// Permanently lock metadata, feature flags and limits (irreversible)
+    pub fn lock_config(ctx: Context<SetFeatureFlag>) -> Result<()> {
+        instructions::flags::lock_config_handler(ctx)
+    }
+
+    // ─────────────────────────────────────────────────
+    // Change Log
+    // ─────────────────────────────────────────────────
+
+    /// Create the ring buffer of recent admin actions
+    pub fn init_changelog(
+        ctx: Context<InitChangeLog>,
+    ) -> Result<()> {
+        instructions::changelog::init_changelog_handler(ctx)
+    }
+
+    /// Return the recorded admin actions, oldest first (read via simulation)
+    pub fn get_changelog(
+        ctx: Context<GetChangeLog>,
+    ) -> Result<Vec<ChangeLogEntry>> {
+        instructions::changelog::get_changelog_handler(ctx)
+    }
+
+    // ─────────────────────────────────────────────────
+    // Multisig
+    // ─────────────────────────────────────────────────
+
+    /// Hand the authority to an M-of-N multisig
+    pub fn init_multisig(
+        ctx: Context<InitMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::multisig::init_multisig_handler(ctx, signers, threshold)
+    }
+
+    /// Propose an authority action for multisig approval
+    pub fn propose_action(
+        ctx: Context<ProposeAction>,
+        action: MultisigAction,
+    ) -> Result<()> {
+        instructions::multisig::propose_action_handler(ctx, action)
+    }
+
+    /// Approve a pending multisig action
+    pub fn approve_action(
+        ctx: Context<ApproveAction>,
+        action_id: u64,
+    ) -> Result<()> {
+        instructions::multisig::approve_action_handler(ctx, action_id)
+    }
+
+    /// Execute a multisig action that has reached its threshold
+    pub fn execute_action(
+        ctx: Context<ExecuteAction>,
+        action_id: u64,
+    ) -> Result<()> {
+        instructions::multisig::execute_action_handler(ctx, action_id)
     }
 
     // ─────────────────────────────────────────────────
     // Transfer Hook (SSS-2)
     // ─────────────────────────────────────────────────
 
+    /// Create the ExtraAccountMetaList PDA so Token-2022 can invoke the hook
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        instructions::hook::initialize_extra_account_meta_list_handler(ctx)
+    }
+
+    /// Rewrite an existing ExtraAccountMetaList with the current extra accounts
+    pub fn update_extra_account_meta_list(
+        ctx: Context<UpdateExtraAccountMetaList>,
+    ) -> Result<()> {
+        instructions::hook::update_extra_account_meta_list_handler(ctx)
+    }
+
+    /// Exempt an owner (e.g. treasury) from transfer hook list checks
+    pub fn add_exempt_address(
+        ctx: Context<UpdateExemptAddresses>,
+        address: Pubkey,
+    ) -> Result<()> {
+        instructions::hook::add_exempt_address_handler(ctx, address)
+    }
+
+    /// Remove an owner's transfer hook exemption
+    pub fn remove_exempt_address(
+        ctx: Context<UpdateExemptAddresses>,
+        address: Pubkey,
+    ) -> Result<()> {
+        instructions::hook::remove_exempt_address_handler(ctx, address)
+    }
+
     /// Execute the transfer hook — checks blacklist before every transfer
     pub fn transfer_hook(
         ctx: Context<TransferHook>,
@@ -125,6 +938,14 @@ pub mod sss {
         instructions::hook::transfer_hook_handler(ctx, amount)
     }
 
+    /// Count a transfer the hook rejects, re-checking that it is still blocked
+    pub fn report_blocked_transfer(
+        ctx: Context<ReportBlockedTransfer>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::hook::report_blocked_transfer_handler(ctx, amount)
+    }
+
     /// Fallback for the transfer hook interface
     pub fn fallback<'info>(
         program_id: &Pubkey,
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Domain separators so a leaf hash can never be passed off as an inner node
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf hash for an allowlisted owner: `sha256(0x00 || owner)`
+pub fn allowlist_leaf(owner: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, owner.as_ref()]).to_bytes()
+}
+
+/// Verify `leaf` is included under `root`.
+/// Pairs are hashed in sorted order as `sha256(0x01 || min || max)`, so the
+/// proof does not need to carry left/right positions.
+pub fn verify_allowlist_proof(root: &[u8; 32], leaf: &[u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(*leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+        hashv(&[NODE_PREFIX, &left, &right]).to_bytes()
+    });
+    computed == *root
+}
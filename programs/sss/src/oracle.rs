@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SSSError;
+use crate::state::StablecoinState;
+
+/// Offsets into a Pyth-style (v2) price account; Switchboard feeds can be
+/// bridged into the same layout by a relayer
+const MAGIC: u32 = 0xa1b2_c3d4;
+const MAGIC_OFFSET: usize = 0;
+const EXPO_OFFSET: usize = 20;
+const PRICE_OFFSET: usize = 208;
+const STATUS_OFFSET: usize = 224;
+/// Aggregate status for a live price
+const STATUS_TRADING: u32 = 1;
+/// Largest exponent magnitude accepted; keeps `10^-expo` inside an i128
+const MAX_EXPO: i32 = 18;
+
+fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N]> {
+    data.get(offset..offset + N)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| error!(SSSError::InvalidOracle))
+}
+
+/// Read `(price, expo)` from a price account, rejecting feeds that are not trading
+pub fn read_price(price_account: &AccountInfo) -> Result<(i64, i32)> {
+    let data = price_account.try_borrow_data()?;
+
+    require!(
+        u32::from_le_bytes(read_bytes(&data, MAGIC_OFFSET)?) == MAGIC,
+        SSSError::InvalidOracle
+    );
+    require!(
+        u32::from_le_bytes(read_bytes(&data, STATUS_OFFSET)?) == STATUS_TRADING,
+        SSSError::InvalidOracle
+    );
+
+    let expo = i32::from_le_bytes(read_bytes(&data, EXPO_OFFSET)?);
+    let price = i64::from_le_bytes(read_bytes(&data, PRICE_OFFSET)?);
+    require!((-MAX_EXPO..=0).contains(&expo), SSSError::InvalidOracle);
+    require!(price > 0, SSSError::InvalidOracle);
+
+    Ok((price, expo))
+}
+
+/// Distance of `price * 10^expo` from 1.0, in basis points
+pub fn peg_deviation_bps(price: i64, expo: i32) -> u128 {
+    let peg = 10i128.pow(expo.unsigned_abs());
+    let distance = (price as i128 - peg).unsigned_abs();
+    distance * 10_000 / peg as u128
+}
+
+/// Reject minting when the configured oracle reports the coin off its peg.
+/// A no-op when no oracle is configured.
+pub fn check_peg(state: &StablecoinState, price_account: Option<&AccountInfo>) -> Result<()> {
+    let Some(oracle) = state.oracle else {
+        return Ok(());
+    };
+    let price_account = price_account.ok_or(SSSError::MissingAccount)?;
+    require!(price_account.key() == oracle, SSSError::InvalidOracle);
+
+    let (price, expo) = read_price(price_account)?;
+    let deviation = peg_deviation_bps(price, expo);
+    require!(
+        deviation <= state.max_peg_deviation_bps as u128,
+        SSSError::PegDeviationExceeded
+    );
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{allocate, assign, transfer, Allocate, Assign, Transfer};
+
+/// Create a program-owned PDA from inside a handler, the way Anchor's `init` does:
+/// top the address up to rent exemption, then allocate and assign it. Unlike a bare
+/// `create_account`, this still succeeds when someone has pre-funded the address.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(target.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: target.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: target.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
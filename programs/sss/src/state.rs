@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::SSSError;
+use crate::format::apply_rounding;
+
 /// Configuration for creating a new stablecoin
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct StablecoinConfig {
@@ -18,6 +21,32 @@ pub struct StablecoinConfig {
     pub enable_transfer_hook: bool,
     /// Whether new accounts default to frozen (SSS-2: usually true)
     pub default_account_frozen: bool,
+    /// Require both parties to be allowlisted (mutually exclusive with the SSS-2 blacklist)
+    pub allowlist_mode: bool,
+    /// Freeze the target's token account when it is blacklisted (SSS-2 only)
+    pub freeze_on_blacklist: bool,
+    /// Reject peer-to-peer transfers; mint, burn and seizure still work
+    pub non_transferable: bool,
+    // ── Role assignments (default to the deployer when `None`) ──
+    /// Initial mint authority
+    pub mint_authority: Option<Pubkey>,
+    /// Initial freeze authority
+    pub freeze_authority: Option<Pubkey>,
+    /// Initial compliance officer (SSS-2 only)
+    pub compliance_officer: Option<Pubkey>,
+    // ── Limits ──
+    /// Maximum amount per transfer (`None` = unlimited)
+    pub max_transfer_amount: Option<u64>,
+    // ── Transfer fee (mirrors the Token-2022 transfer-fee extension) ──
+    /// Transfer fee in basis points (max 10_000)
+    pub transfer_fee_basis_points: u16,
+    /// Maximum fee charged per transfer, in base units
+    pub max_transfer_fee: u64,
+    // ── Governance ──
+    /// Seconds a proposed role change waits before it can execute (0 = immediate)
+    pub role_change_delay: i64,
+    /// Minimum seconds between mint authority rotations (0 = no cooldown)
+    pub mint_authority_rotation_cooldown: i64,
 }
 
 impl StablecoinConfig {
@@ -40,16 +69,32 @@ impl StablecoinConfig {
 #[account]
 #[derive(Debug)]
 pub struct StablecoinState {
+    /// Layout version (see `CURRENT_VERSION`); kept first so `migrate_state` can read it from any layout
+    pub version: u8,
     /// The mint address for this stablecoin
     pub mint: Pubkey,
     /// Authority who deployed this stablecoin
     pub authority: Pubkey,
+    /// Proposed new authority awaiting acceptance (two-step transfer)
+    pub pending_authority: Option<Pubkey>,
     /// Mint authority (can mint new tokens)
     pub mint_authority: Pubkey,
     /// Freeze authority (can freeze/thaw accounts)
     pub freeze_authority: Pubkey,
-    /// Compliance officer (SSS-2: manages blacklist, can seize tokens)
+    /// Primary compliance officer (SSS-2). Only seeds `ComplianceOfficers`;
+    /// every compliance check reads that list, so changing this role does not
+    /// grant or revoke anything on its own
     pub compliance_officer: Pubkey,
+    /// Pauser (can trip and clear the emergency pause)
+    pub pauser: Pubkey,
+    /// Bitfield of permanently renounced roles (see `Role::bit`)
+    pub renounced_roles: u8,
+    /// Seconds a proposed role change waits before it can execute
+    pub role_change_delay: i64,
+    /// Minimum seconds between mint authority rotations
+    pub mint_authority_rotation_cooldown: i64,
+    /// Timestamp of the last mint authority change (initialization counts)
+    pub last_mint_authority_change: i64,
     /// Whether this is SSS-2 compliant
     pub is_sss2: bool,
     /// Permanent delegate enabled
@@ -58,10 +103,34 @@ pub struct StablecoinState {
     pub transfer_hook_enabled: bool,
     /// Default account frozen on creation
     pub default_account_frozen: bool,
+    /// Global pause switch (halts mint, burn, and transfer)
+    pub paused: bool,
+    /// Allowlist mode (transfers only between allowlisted wallets)
+    pub allowlist_mode: bool,
+    /// Merkle root of allowlisted owners (all zeros = unset)
+    pub allowlist_root: [u8; 32],
+    /// Maximum amount per transfer (`None` = unlimited)
+    pub max_transfer_amount: Option<u64>,
+    /// Transfer fee in basis points (mirror of the transfer-fee extension)
+    pub transfer_fee_basis_points: u16,
+    /// Maximum fee per transfer (mirror of the transfer-fee extension)
+    pub max_transfer_fee: u64,
+    /// Maximum amount mintable per rolling 24h window (`None` = unlimited)
+    pub daily_mint_limit: Option<u64>,
+    /// Amount minted in the current window
+    pub minted_today: u64,
+    /// Start of the current mint window
+    pub mint_window_start: i64,
     /// Total supply minted (tracked for audit)
     pub total_minted: u64,
     /// Total supply burned (tracked for audit)
     pub total_burned: u64,
+    /// Lifetime total seized via the permanent delegate (tracked for audit)
+    pub total_seized: u64,
+    /// Mint supply minus tracked net supply at the last reconciliation
+    pub supply_drift: i64,
+    /// Number of currently active blacklist entries
+    pub active_blacklist_count: u64,
     /// Creation timestamp
     pub created_at: i64,
     /// Last update timestamp
@@ -72,35 +141,498 @@ pub struct StablecoinState {
     pub name: String,
     /// Symbol  
     pub symbol: String,
+    /// Metadata URI
+    pub uri: String,
     /// Decimals
     pub decimals: u8,
+    // ── v2 ── fields added after v1 are appended here and back-filled by `migrate_state`
+    /// Timestamp of the last `migrate_state` run (0 = never migrated)
+    pub last_migrated_at: i64,
+    // ── v3 ──
+    /// Interest rate in basis points (mirror of the interest-bearing extension)
+    pub interest_rate_bps: i16,
+    // ── v4 ──
+    /// Owners the transfer hook always allows (e.g. treasury, mint authority)
+    pub exempt_addresses: Vec<Pubkey>,
+    // ── v5 ──
+    /// Lifetime volume moved through `transfer` (u128 so realistic volume cannot overflow)
+    pub total_transfer_volume: u128,
+    // ── v6 ──
+    /// Minimum amount per transfer, blocking dust (`None` = no minimum)
+    pub min_transfer_amount: Option<u64>,
+    // ── v7 ──
+    /// Price feed consulted before minting (`None` = no peg check)
+    pub oracle: Option<Pubkey>,
+    /// Largest allowed distance from the $1 peg, in basis points
+    pub max_peg_deviation_bps: u16,
+    // ── v8 ──
+    /// Lifetime operation counters for governance dashboards (saturating)
+    pub mint_op_count: u64,
+    pub burn_op_count: u64,
+    pub freeze_op_count: u64,
+    pub seize_op_count: u64,
+    // ── v9 ──
+    /// Minimum seconds between transfers out of one account (`None` = no cooldown)
+    pub transfer_cooldown: Option<i64>,
+    // ── v10 ──
+    /// Role allowed to add and remove blacklist entries (defaults to the compliance officer)
+    pub blacklist_authority: Pubkey,
+    // ── v11 ──
+    /// Owner whose token accounts may receive seized tokens (`None` = the authority)
+    pub seizure_treasury: Option<Pubkey>,
+    // ── v12 ──
+    /// Seconds after a `mint_to` during which it can be clawed back (`None` = disabled)
+    pub clawback_window: Option<i64>,
+    // ── v13 ──
+    /// Cap on a single mint, burn, transfer or seizure (`None` = uncapped)
+    pub max_single_op_amount: Option<u64>,
+    // ── v14 ──
+    /// Freeze the target's token account as part of `blacklist_add`
+    pub freeze_on_blacklist: bool,
+    // ── v15 ──
+    /// Check the `BlacklistBitmap` in the transfer hook instead of per-address entries
+    pub bitmap_blacklist: bool,
+    // ── v16 ──
+    /// Emit mint, burn and transfer events (compliance events always emit)
+    pub emit_events: bool,
+    // ── v17 ──
+    /// Transfer amounts above `kyc_tier_thresholds[i]` need a sender KYC tier of at least
+    /// `i + 1` (zero entries are unused)
+    pub kyc_tier_thresholds: [u64; 3],
+    // ── v18 ──
+    /// Issuance fee taken from each `mint_to` / `mint_to_new`, in basis points
+    pub mint_fee_bps: u16,
+    /// Token account that receives the issuance fee
+    pub mint_fee_collector: Pubkey,
+    // ── v19 ──
+    /// Seconds newly minted tokens stay locked in the destination (`None` = no lock)
+    pub mint_lock_period: Option<i64>,
+    // ── v20 ──
+    /// Cap on `active_blacklist_count` (`None` = unlimited)
+    pub max_blacklist_entries: Option<u64>,
+    // ── v21 ──
+    /// Signer allowed to harvest and withdraw withheld transfer fees
+    pub fee_authority: Pubkey,
+    // ── v22 ──
+    /// Admin actions must be appended to the `ChangeLog` (set by `init_changelog`)
+    pub changelog_enabled: bool,
+    // ── v23 ──
+    /// Maximum amount seizable per rolling 24h window (`None` = unlimited)
+    pub daily_seize_limit: Option<u64>,
+    /// Amount seized in the current window
+    pub seized_today: u64,
+    /// Start of the current seize window
+    pub seize_window_start: i64,
+    /// Minimum seconds between two seizures (`None` = no cooldown)
+    pub seize_cooldown: Option<i64>,
+    /// Time of the last seizure
+    pub last_seize_at: i64,
+    // ── v24 ──
+    /// Soulbound mode: `transfer` and the transfer hook reject peer-to-peer transfers
+    pub non_transferable: bool,
+
+    // ── v25 ──
+    /// Maximum recipients per `transfer_batch` (0 = `MAX_BATCH_SIZE`)
+    pub max_transfer_batch_size: u8,
+
+    // ── v26 ──
+    /// Set once by `lock_config`; configuration setters are refused from then on
+    pub config_locked: bool,
+
+    // ── v27 ──
+    /// Seizures need a second compliance officer (`propose_seizure` / `approve_seizure`)
+    pub require_dual_seize: bool,
+
+    // ── v28 ──
+    /// Last `compute_blacklist_digest` commitment (all zeroes until computed)
+    pub blacklist_digest: [u8; 32],
+    /// When `blacklist_digest` was last computed
+    pub digest_updated_at: i64,
+
+    // ── v29 ──
+    /// Rounding applied to the issuance fee (`RoundDown` reads from zeroed bytes)
+    pub fee_rounding: RoundingPolicy,
+
+    // ── v30 ──
+    /// Rejected transfers recorded through `report_blocked_transfer`
+    pub blocked_transfer_count: u64,
+
+    // ── v31 ──
+    /// Smallest non-zero balance a transfer may leave in the source (`None` = unchecked)
+    pub min_account_balance: Option<u64>,
+    /// What a transfer that would leave dust does (`Reject` reads from zeroed bytes)
+    pub dust_policy: DustPolicy,
+
+    // ── v32 ──
+    /// When the `compute_blacklist_digest` pass in progress started (0 = none);
+    /// entry expiry is judged at this instant for the whole pass
+    pub digest_snapshot_at: i64,
+    /// Running digest of the pass in progress (see `digest::fold_address`)
+    pub digest_accumulator: [u8; 32],
+    /// Last address folded by the pass in progress; pages continue strictly after it
+    pub digest_cursor: Option<Pubkey>,
+    /// Active entries visited by the pass in progress, expired ones included
+    pub digest_active_seen: u64,
+    /// Active entries the pass in progress left out because they had expired
+    pub digest_expired_seen: u64,
 }
 
 impl StablecoinState {
+    /// Layout version stamped on new accounts and targeted by `migrate_state`
+    pub const CURRENT_VERSION: u8 = 32;
+    /// Byte offset of `authority`, which sits at the same position in every versioned layout
+    pub const AUTHORITY_OFFSET: usize = 8 + 1 + 32;
+
     pub const MAX_NAME_LEN: usize = 32;
     pub const MAX_SYMBOL_LEN: usize = 10;
+    pub const MAX_URI_LEN: usize = 200;
+    pub const MAX_DECIMALS: u8 = 9;
+    pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+    /// Upper bound on `max_peg_deviation_bps` (a 100% band)
+    pub const MAX_PEG_DEVIATION_BPS: u16 = 10_000;
+    /// Bound on the mirrored interest rate, in either direction
+    pub const MAX_INTEREST_RATE_BPS: i16 = 5_000;
+    pub const MAX_MEMO_LEN: usize = 128;
+    /// Maximum recipients per batch instruction (keeps CPIs within compute limits)
+    pub const MAX_BATCH_SIZE: usize = 15;
+    /// Maximum owners exempt from transfer hook list checks
+    pub const MAX_EXEMPT_ADDRESSES: usize = 8;
+    /// Highest KYC tier (and number of tier thresholds)
+    pub const MAX_KYC_TIER: u8 = 3;
+    /// Length of the daily mint window
+    pub const MINT_WINDOW_SECONDS: i64 = 24 * 60 * 60;
     pub const SPACE: usize = 8  // discriminator
+        + 1   // version
         + 32  // mint
         + 32  // authority
+        + 1 + 32 // pending_authority (option tag + pubkey)
         + 32  // mint_authority
         + 32  // freeze_authority
         + 32  // compliance_officer
+        + 32  // pauser
+        + 1   // renounced_roles
+        + 8   // role_change_delay
+        + 8   // mint_authority_rotation_cooldown
+        + 8   // last_mint_authority_change
         + 1   // is_sss2
         + 1   // permanent_delegate_enabled
         + 1   // transfer_hook_enabled
         + 1   // default_account_frozen
+        + 1   // paused
+        + 1   // allowlist_mode
+        + 32  // allowlist_root
+        + 1 + 8 // max_transfer_amount (option tag + u64)
+        + 2   // transfer_fee_basis_points
+        + 8   // max_transfer_fee
+        + 1 + 8 // daily_mint_limit (option tag + u64)
+        + 8   // minted_today
+        + 8   // mint_window_start
         + 8   // total_minted
         + 8   // total_burned
+        + 8   // total_seized
+        + 8   // supply_drift
+        + 8   // active_blacklist_count
         + 8   // created_at
         + 8   // updated_at
         + 1   // bump
         + 4 + Self::MAX_NAME_LEN   // name (string prefix + data)
         + 4 + Self::MAX_SYMBOL_LEN // symbol (string prefix + data)
-        + 1;  // decimals
+        + 4 + Self::MAX_URI_LEN    // uri (string prefix + data)
+        + 1   // decimals
+        + 8   // last_migrated_at
+        + 2   // interest_rate_bps
+        + 4 + 32 * Self::MAX_EXEMPT_ADDRESSES // exempt_addresses
+        + 16  // total_transfer_volume
+        + 1 + 8 // min_transfer_amount (option tag + u64)
+        + 1 + 32 // oracle (option tag + pubkey)
+        + 2   // max_peg_deviation_bps
+        + 8   // mint_op_count
+        + 8   // burn_op_count
+        + 8   // freeze_op_count
+        + 8   // seize_op_count
+        + 1 + 8 // transfer_cooldown (option tag + i64)
+        + 32  // blacklist_authority
+        + 1 + 32 // seizure_treasury (option tag + pubkey)
+        + 1 + 8 // clawback_window (option tag + i64)
+        + 1 + 8 // max_single_op_amount (option tag + u64)
+        + 1   // freeze_on_blacklist
+        + 1   // bitmap_blacklist
+        + 1   // emit_events
+        + 8 * Self::MAX_KYC_TIER as usize // kyc_tier_thresholds
+        + 2   // mint_fee_bps
+        + 32  // mint_fee_collector
+        + 1 + 8 // mint_lock_period (option tag + i64)
+        + 1 + 8 // max_blacklist_entries (option tag + u64)
+        + 32  // fee_authority
+        + 1   // changelog_enabled
+        + 1 + 8 // daily_seize_limit (option tag + u64)
+        + 8   // seized_today
+        + 8   // seize_window_start
+        + 1 + 8 // seize_cooldown (option tag + i64)
+        + 8   // last_seize_at
+        + 1   // non_transferable
+        + 1   // max_transfer_batch_size
+        + 1   // config_locked
+        + 1   // require_dual_seize
+        + 32  // blacklist_digest
+        + 8   // digest_updated_at
+        + 1   // fee_rounding
+        + 8   // blocked_transfer_count
+        + 1 + 8 // min_account_balance (option tag + u64)
+        + 1   // dust_policy
+        + 8   // digest_snapshot_at
+        + 32  // digest_accumulator
+        + 1 + 32 // digest_cursor (option tag + pubkey)
+        + 8   // digest_active_seen
+        + 8;  // digest_expired_seen
+
+    /// Serialized shape of every field in order, tagged with the version that added it.
+    /// `layout_len` walks this to find where an older layout ends; extend it with each
+    /// new version alongside `SPACE`.
+    const LAYOUT: &[(u8, FieldShape)] = &[
+        (1, FieldShape::Fixed(1 + 32 + 32)), // version, mint, authority
+        (1, FieldShape::Option(32)),         // pending_authority
+        (1, FieldShape::Fixed(32 * 4 + 1 + 8 * 3 + 6 + 32)), // roles .. allowlist_root
+        (1, FieldShape::Option(8)),          // max_transfer_amount
+        (1, FieldShape::Fixed(2 + 8)),       // transfer_fee_basis_points, max_transfer_fee
+        (1, FieldShape::Option(8)),          // daily_mint_limit
+        (1, FieldShape::Fixed(8 * 9 + 1)),   // minted_today .. bump
+        (1, FieldShape::String),             // name
+        (1, FieldShape::String),             // symbol
+        (1, FieldShape::String),             // uri
+        (1, FieldShape::Fixed(1)),           // decimals
+        (2, FieldShape::Fixed(8)),           // last_migrated_at
+        (3, FieldShape::Fixed(2)),           // interest_rate_bps
+        (4, FieldShape::Vec(32)),            // exempt_addresses
+        (5, FieldShape::Fixed(16)),          // total_transfer_volume
+        (6, FieldShape::Option(8)),          // min_transfer_amount
+        (7, FieldShape::Option(32)),         // oracle
+        (7, FieldShape::Fixed(2)),           // max_peg_deviation_bps
+        (8, FieldShape::Fixed(8 * 4)),       // operation counters
+        (9, FieldShape::Option(8)),          // transfer_cooldown
+        (10, FieldShape::Fixed(32)),         // blacklist_authority
+        (11, FieldShape::Option(32)),        // seizure_treasury
+        (12, FieldShape::Option(8)),         // clawback_window
+        (13, FieldShape::Option(8)),         // max_single_op_amount
+        (14, FieldShape::Fixed(1)),          // freeze_on_blacklist
+        (15, FieldShape::Fixed(1)),          // bitmap_blacklist
+        (16, FieldShape::Fixed(1)),          // emit_events
+        (17, FieldShape::Fixed(8 * 3)),      // kyc_tier_thresholds
+        (18, FieldShape::Fixed(2 + 32)),     // mint_fee_bps, mint_fee_collector
+        (19, FieldShape::Option(8)),         // mint_lock_period
+        (20, FieldShape::Option(8)),         // max_blacklist_entries
+        (21, FieldShape::Fixed(32)),         // fee_authority
+        (22, FieldShape::Fixed(1)),          // changelog_enabled
+        (23, FieldShape::Option(8)),         // daily_seize_limit
+        (23, FieldShape::Fixed(8 + 8)),      // seized_today, seize_window_start
+        (23, FieldShape::Option(8)),         // seize_cooldown
+        (23, FieldShape::Fixed(8)),          // last_seize_at
+        (24, FieldShape::Fixed(1)),          // non_transferable
+        (25, FieldShape::Fixed(1)),          // max_transfer_batch_size
+        (26, FieldShape::Fixed(1)),          // config_locked
+        (27, FieldShape::Fixed(1)),          // require_dual_seize
+        (28, FieldShape::Fixed(32 + 8)),     // blacklist_digest, digest_updated_at
+        (29, FieldShape::Fixed(1)),          // fee_rounding
+        (30, FieldShape::Fixed(8)),          // blocked_transfer_count
+        (31, FieldShape::Option(8)),         // min_account_balance
+        (31, FieldShape::Fixed(1)),          // dust_policy
+        (32, FieldShape::Fixed(8 + 32)),     // digest_snapshot_at, digest_accumulator
+        (32, FieldShape::Option(32)),        // digest_cursor
+        (32, FieldShape::Fixed(8 + 8)),      // digest_active_seen, digest_expired_seen
+    ];
+
+    /// Bytes (discriminator included) that the serialized `version` layout occupies
+    /// in `data`. Borsh only writes a field's current length, so anything past this
+    /// may be stale from a longer string, vector or `Some` and must be cleared before
+    /// fields appended since `version` are read. Version 0 (pre-versioning) is just
+    /// the discriminator, as that layout is rebuilt from `LegacyStablecoinState`.
+    pub fn layout_len(data: &[u8], version: u8) -> Result<usize> {
+        let read_u32 = |at: usize| -> Result<usize> {
+            let bytes = data.get(at..at + 4).ok_or(SSSError::InvalidStateLayout)?;
+            Ok(u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+        };
+
+        let mut len = 8;
+        for (_, shape) in Self::LAYOUT.iter().filter(|(since, _)| *since <= version) {
+            len += match *shape {
+                FieldShape::Fixed(size) => size,
+                FieldShape::Option(size) => match data.get(len) {
+                    Some(0) => 1,
+                    Some(1) => 1 + size,
+                    _ => return err!(SSSError::InvalidStateLayout),
+                },
+                FieldShape::String => 4 + read_u32(len)?,
+                FieldShape::Vec(size) => 4 + read_u32(len)?.saturating_mul(size),
+            };
+        }
+        require!(len <= data.len(), SSSError::InvalidStateLayout);
+        Ok(len)
+    }
+    /// Whether `owner` bypasses transfer hook list checks
+    pub fn is_exempt(&self, owner: &Pubkey) -> bool {
+        self.exempt_addresses.contains(owner)
+    }
+
+    /// Recipient cap for `transfer_batch`, falling back to `MAX_BATCH_SIZE`
+    pub fn transfer_batch_limit(&self) -> usize {
+        match self.max_transfer_batch_size {
+            0 => Self::MAX_BATCH_SIZE,
+            size => (size as usize).min(Self::MAX_BATCH_SIZE),
+        }
+    }
+
+    /// Owner that seized tokens must be sent to
+    pub fn seizure_treasury_owner(&self) -> Pubkey {
+        self.seizure_treasury.unwrap_or(self.authority)
+    }
+
+    /// Minimum sender KYC tier for a transfer of `amount` (0 = no KYC needed)
+    pub fn required_kyc_tier(&self, amount: u64) -> u8 {
+        self.kyc_tier_thresholds
+            .iter()
+            .enumerate()
+            .filter(|(_, threshold)| **threshold > 0 && amount > **threshold)
+            .map(|(i, _)| i as u8 + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Transfer fee on `amount` per the mirrored settings, rounded up and capped like the
+    /// extension (`fee_rounding` does not apply, or the fee would stop matching the extension)
+    pub fn transfer_fee(&self, amount: u64) -> u64 {
+        let fee = apply_rounding(
+            amount as u128 * self.transfer_fee_basis_points as u128,
+            RoundingPolicy::RoundUp,
+        );
+        (fee as u64).min(self.max_transfer_fee)
+    }
+
+    /// Amount a transfer of `amount` out of a `balance` should actually move.
+    /// A transfer that would leave a non-zero remainder below `min_account_balance`
+    /// is widened to the full balance under `SweepAll` and refused under `Reject`.
+    pub fn dust_adjusted_amount(&self, balance: u64, amount: u64) -> Result<u64> {
+        let Some(minimum) = self.min_account_balance else {
+            return Ok(amount);
+        };
+        let remaining = balance.saturating_sub(amount);
+        if remaining == 0 || remaining >= minimum {
+            return Ok(amount);
+        }
+        match self.dust_policy {
+            DustPolicy::Reject => err!(SSSError::WouldLeaveDust),
+            DustPolicy::SweepAll => Ok(balance),
+        }
+    }
+
+    /// Issuance fee on a mint of `amount`, rounded per `fee_rounding`; `amount - fee` is the net mint
+    pub fn mint_fee(&self, amount: u64) -> u64 {
+        apply_rounding(amount as u128 * self.mint_fee_bps as u128, self.fee_rounding) as u64
+    }
+
+    /// Fail with `ConfigLocked` once `lock_config` has run
+    pub fn check_config_unlocked(&self) -> Result<()> {
+        require!(!self.config_locked, SSSError::ConfigLocked);
+        Ok(())
+    }
+
+    /// Fail with `BlacklistFull` if `additional` new entries would exceed `max_blacklist_entries`
+    pub fn check_blacklist_capacity(&self, additional: u64) -> Result<()> {
+        if let Some(max) = self.max_blacklist_entries {
+            require!(
+                self.active_blacklist_count.saturating_add(additional) <= max,
+                SSSError::BlacklistFull
+            );
+        }
+        Ok(())
+    }
+
+    /// Drop the `compute_blacklist_digest` pass in progress, if any. Called on every
+    /// blacklist change, since a pass commits to the set as it stood when it began.
+    pub fn abandon_blacklist_digest(&mut self) {
+        self.digest_snapshot_at = 0;
+        self.digest_cursor = None;
+    }
 
     pub fn net_supply(&self) -> u64 {
         self.total_minted.saturating_sub(self.total_burned)
     }
+
+    /// Add `amount` to the lifetime seized total, returning the new cumulative value.
+    pub fn record_seizure(&mut self, amount: u64) -> Result<u64> {
+        self.total_seized = self.total_seized.checked_add(amount).ok_or(SSSError::Overflow)?;
+        Ok(self.total_seized)
+    }
+
+    /// Move `amount` of seized tokens into the burned total once they are destroyed.
+    pub fn record_seized_burn(&mut self, amount: u64) -> Result<()> {
+        self.total_seized = self.total_seized.checked_sub(amount).ok_or(SSSError::InsufficientBalance)?;
+        self.total_burned = self.total_burned.checked_add(amount).ok_or(SSSError::Overflow)?;
+        Ok(())
+    }
+
+    /// Take `amount` out of the seized total once it is returned to a rightful owner.
+    pub fn record_seized_reissue(&mut self, amount: u64) -> Result<()> {
+        self.total_seized = self.total_seized.checked_sub(amount).ok_or(SSSError::InsufficientBalance)?;
+        Ok(())
+    }
+
+    /// Add `amount` to the lifetime transfer volume, returning the new cumulative value.
+    pub fn record_transfer_volume(&mut self, amount: u64) -> Result<u128> {
+        self.total_transfer_volume = self
+            .total_transfer_volume
+            .checked_add(amount as u128)
+            .ok_or(SSSError::Overflow)?;
+        Ok(self.total_transfer_volume)
+    }
+
+    /// Validate an optional movement memo, mapping `None` to an empty string.
+    pub fn validate_memo(memo: Option<String>) -> Result<String> {
+        let memo = memo.unwrap_or_default();
+        require!(memo.len() <= Self::MAX_MEMO_LEN, SSSError::MemoTooLong);
+        Ok(memo)
+    }
+
+    /// Account `amount` against the daily mint window, resetting it once a day has elapsed.
+    pub fn record_daily_mint(&mut self, amount: u64, now: i64) -> Result<()> {
+        if now.saturating_sub(self.mint_window_start) >= Self::MINT_WINDOW_SECONDS {
+            self.mint_window_start = now;
+            self.minted_today = 0;
+        }
+
+        let minted_today = self.minted_today.checked_add(amount).ok_or(SSSError::Overflow)?;
+        if let Some(limit) = self.daily_mint_limit {
+            require!(minted_today <= limit, SSSError::DailyMintLimitExceeded);
+        }
+        self.minted_today = minted_today;
+        Ok(())
+    }
+
+    /// Enforce the seize cooldown and account `amount` against the daily seize window,
+    /// which shares the mint window's length.
+    pub fn record_daily_seize(&mut self, amount: u64, now: i64) -> Result<()> {
+        if let Some(cooldown) = self.seize_cooldown {
+            require!(
+                now.saturating_sub(self.last_seize_at) >= cooldown,
+                SSSError::SeizeCooldownActive
+            );
+        }
+        if now.saturating_sub(self.seize_window_start) >= Self::MINT_WINDOW_SECONDS {
+            self.seize_window_start = now;
+            self.seized_today = 0;
+        }
+
+        let seized_today = self.seized_today.checked_add(amount).ok_or(SSSError::Overflow)?;
+        if let Some(limit) = self.daily_seize_limit {
+            require!(seized_today <= limit, SSSError::DailySeizeLimitExceeded);
+        }
+        self.seized_today = seized_today;
+        self.last_seize_at = now;
+        Ok(())
+    }
+
+    pub fn is_renounced(&self, role: &Role) -> bool {
+        self.renounced_roles & role.bit() != 0
+    }
 }
 
 /// Blacklist entry — stores a blacklisted address for SSS-2 stablecoins
@@ -119,22 +651,1023 @@ pub struct BlacklistEntry {
     pub is_active: bool,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Issuer-defined reason code (e.g. sanctions list, court order)
+    pub reason_code: u16,
+    /// Free-form audit note
+    pub note: String,
+    /// Optional expiry timestamp; the entry lapses automatically once reached
+    pub expires_at: Option<i64>,
 }
 
 impl BlacklistEntry {
+    pub const MAX_NOTE_LEN: usize = 128;
     pub const SPACE: usize = 8  // discriminator
         + 32  // stablecoin
         + 32  // blacklisted_address
         + 32  // added_by
         + 8   // added_at
         + 1   // is_active
+        + 1   // bump
+        + 2   // reason_code
+        + 4 + Self::MAX_NOTE_LEN  // note (string prefix + data)
+        + 1 + 8; // expires_at (option tag + i64)
+
+    /// Returns true if the entry is active and has not expired at `now`
+    pub fn is_effective(&self, now: i64) -> bool {
+        self.is_active && !matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+}
+
+/// Compliance hold — a temporary, review-pending block on one owner, short of a blacklisting
+#[account]
+#[derive(Debug)]
+pub struct ComplianceHold {
+    /// The stablecoin this hold belongs to
+    pub stablecoin: Pubkey,
+    /// The held wallet address
+    pub owner: Pubkey,
+    /// Compliance officer who placed the hold
+    pub placed_by: Pubkey,
+    /// Timestamp when placed
+    pub placed_at: i64,
+    /// Optional expiry timestamp; the hold lapses automatically once reached
+    pub expires_at: Option<i64>,
+    /// Why the account is held
+    pub reason: String,
+    /// Whether the hold is in place (cleared by `release_hold`)
+    pub is_active: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ComplianceHold {
+    pub const MAX_REASON_LEN: usize = 128;
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // owner
+        + 32  // placed_by
+        + 8   // placed_at
+        + 1 + 8 // expires_at (option tag + i64)
+        + 4 + Self::MAX_REASON_LEN  // reason (string prefix + data)
+        + 1   // is_active
+        + 1;  // bump
+
+    /// Returns true if the hold is in place and has not expired at `now`
+    pub fn is_effective(&self, now: i64) -> bool {
+        self.is_active && !matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+}
+
+/// Blacklist bitmap — one bit per hashed owner, so the transfer hook checks a single
+/// fixed account instead of a per-address PDA. Owners sharing a bit are blocked together.
+#[account]
+pub struct BlacklistBitmap {
+    /// The stablecoin this bitmap belongs to
+    pub stablecoin: Pubkey,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Number of bits currently set
+    pub flagged_bits: u32,
+    /// Bit array addressed by `bitmap::bit_index` (`BYTES` long)
+    pub bits: [u8; 1024],
+}
+
+impl BlacklistBitmap {
+    pub const BYTES: usize = 1024;
+    pub const BITS: usize = Self::BYTES * 8;
+    /// Byte offset of `bits`, read directly by the transfer hook
+    pub const BITS_OFFSET: usize = 8 + 32 + 1 + 4;
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 1   // bump
+        + 4   // flagged_bits
+        + Self::BYTES; // bits
+
+    pub fn is_set(&self, index: usize) -> bool {
+        self.bits[index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Set or clear bit `index`, returning whether it changed
+    pub fn update(&mut self, index: usize, flagged: bool) -> bool {
+        if self.is_set(index) == flagged {
+            return false;
+        }
+        self.bits[index / 8] ^= 1 << (index % 8);
+        if flagged {
+            self.flagged_bits += 1;
+        } else {
+            self.flagged_bits -= 1;
+        }
+        true
+    }
+}
+
+/// Allowlist entry — stores an approved address for allowlist-mode stablecoins
+#[account]
+#[derive(Debug)]
+pub struct AllowlistEntry {
+    /// The stablecoin this allowlist entry belongs to
+    pub stablecoin: Pubkey,
+    /// The approved wallet address
+    pub allowlisted_address: Pubkey,
+    /// Who added this entry
+    pub added_by: Pubkey,
+    /// Timestamp when added
+    pub added_at: i64,
+    /// Whether this entry is active
+    pub is_active: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AllowlistEntry {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // allowlisted_address
+        + 32  // added_by
+        + 8   // added_at
+        + 1   // is_active
+        + 1;  // bump
+}
+
+/// Reserve attestation — a proof-of-reserves snapshot for one reporting period
+#[account]
+#[derive(Debug)]
+pub struct ReserveAttestation {
+    /// The stablecoin this attestation belongs to
+    pub stablecoin: Pubkey,
+    /// Issuer-defined reporting period (e.g. YYYYMM)
+    pub period: u64,
+    /// Off-chain reserves backing the token, in token base units
+    pub reserves: u64,
+    /// Net supply at the time of attestation
+    pub liabilities: u64,
+    /// Who published the attestation
+    pub attested_by: Pubkey,
+    /// Timestamp when published
+    pub attested_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ReserveAttestation {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 8   // period
+        + 8   // reserves
+        + 8   // liabilities
+        + 32  // attested_by
+        + 8   // attested_at
+        + 1;  // bump
+
+    /// Returns true if reserves cover the outstanding supply
+    pub fn is_fully_backed(&self) -> bool {
+        self.reserves >= self.liabilities
+    }
+}
+
+/// Supply snapshot — immutable audit checkpoint of the supply figures for one epoch
+#[account]
+#[derive(Debug)]
+pub struct SupplySnapshot {
+    /// The stablecoin this snapshot belongs to
+    pub stablecoin: Pubkey,
+    /// Issuer-defined epoch (e.g. YYYYMMDD); part of the PDA seeds
+    pub epoch: u64,
+    /// Lifetime minted at capture time
+    pub total_minted: u64,
+    /// Lifetime burned at capture time
+    pub total_burned: u64,
+    /// Net supply at capture time
+    pub net_supply: u64,
+    /// Who captured the snapshot
+    pub taken_by: Pubkey,
+    /// Timestamp of capture
+    pub taken_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SupplySnapshot {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 8   // epoch
+        + 8   // total_minted
+        + 8   // total_burned
+        + 8   // net_supply
+        + 32  // taken_by
+        + 8   // taken_at
+        + 1;  // bump
+}
+
+/// Seizure record — audit trail for a single enforcement action
+#[account]
+#[derive(Debug)]
+pub struct SeizureRecord {
+    /// The stablecoin this seizure belongs to
+    pub stablecoin: Pubkey,
+    /// Token account tokens were seized from
+    pub target: Pubkey,
+    /// Token account that received the seized tokens
+    pub destination: Pubkey,
+    /// Amount seized
+    pub amount: u64,
+    /// Compliance officer who performed the seizure
+    pub officer: Pubkey,
+    /// Legal basis or case reference
+    pub reason: String,
+    /// Timestamp of the seizure (also part of the PDA seeds)
+    pub seized_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SeizureRecord {
+    pub const MAX_REASON_LEN: usize = 128;
+    /// How far the caller-supplied `seized_at` may lag the on-chain clock
+    pub const MAX_TIMESTAMP_LAG: i64 = 60;
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // target
+        + 32  // destination
+        + 8   // amount
+        + 32  // officer
+        + 4 + Self::MAX_REASON_LEN  // reason (string prefix + data)
+        + 8   // seized_at
+        + 1;  // bump
+}
+
+/// Reissue record — links seized tokens returned to a rightful owner to their seizure
+#[account]
+#[derive(Debug)]
+pub struct ReissueRecord {
+    /// The stablecoin this reissue belongs to
+    pub stablecoin: Pubkey,
+    /// `SeizureRecord` the tokens were confiscated under (also part of the PDA seeds)
+    pub seizure: Pubkey,
+    /// Treasury token account the tokens left
+    pub treasury: Pubkey,
+    /// Token account of the rightful owner
+    pub recipient: Pubkey,
+    /// Amount reissued
+    pub amount: u64,
+    /// Authority that reissued the tokens
+    pub reissued_by: Pubkey,
+    /// Compliance officer who co-signed (set when `require_dual_seize` applied)
+    pub co_signer: Option<Pubkey>,
+    /// When the tokens were reissued
+    pub reissued_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ReissueRecord {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // seizure
+        + 32  // treasury
+        + 32  // recipient
+        + 8   // amount
+        + 32  // reissued_by
+        + 1 + 32  // co_signer
+        + 8   // reissued_at
+        + 1;  // bump
+}
+
+/// Seizure awaiting a second compliance officer under `require_dual_seize`
+#[account]
+#[derive(Debug)]
+pub struct PendingSeizure {
+    /// The stablecoin this seizure belongs to
+    pub stablecoin: Pubkey,
+    /// Token account to seize from
+    pub target: Pubkey,
+    /// Token account that will receive the seized tokens
+    pub destination: Pubkey,
+    /// Amount to seize (ignored when `seize_all` is set)
+    pub amount: u64,
+    /// Seize the whole balance at approval time
+    pub seize_all: bool,
+    /// Legal basis or case reference
+    pub reason: String,
+    /// Timestamp of the proposal (also part of the PDA and seizure record seeds)
+    pub seized_at: i64,
+    /// Compliance officer who proposed the seizure
+    pub proposed_by: Pubkey,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PendingSeizure {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // target
+        + 32  // destination
+        + 8   // amount
+        + 1   // seize_all
+        + 4 + SeizureRecord::MAX_REASON_LEN  // reason (string prefix + data)
+        + 8   // seized_at
+        + 32  // proposed_by
+        + 1;  // bump
+}
+
+/// Frozen account record — why and by whom a token account was frozen
+#[account]
+#[derive(Debug)]
+pub struct FrozenAccountRecord {
+    /// The stablecoin this record belongs to
+    pub stablecoin: Pubkey,
+    /// Token account that was frozen
+    pub token_account: Pubkey,
+    /// Authority that froze the account
+    pub frozen_by: Pubkey,
+    /// Timestamp of the most recent freeze
+    pub frozen_at: i64,
+    /// Why the account was frozen
+    pub reason: String,
+    /// Timestamp of the thaw that followed (`None` while frozen)
+    pub thawed_at: Option<i64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl FrozenAccountRecord {
+    pub const MAX_REASON_LEN: usize = 128;
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // token_account
+        + 32  // frozen_by
+        + 8   // frozen_at
+        + 4 + Self::MAX_REASON_LEN  // reason (string prefix + data)
+        + 1 + 8 // thawed_at (option tag + i64)
+        + 1;  // bump
+}
+
+/// Thaw approval — marks a token account as cleared to receive transfers while
+/// `default_account_frozen` is set (the permissioned-transfer model)
+#[account]
+#[derive(Debug)]
+pub struct ThawApproval {
+    /// The stablecoin this approval belongs to
+    pub stablecoin: Pubkey,
+    /// Approved token account
+    pub token_account: Pubkey,
+    /// Freeze authority that approved the account
+    pub approved_by: Pubkey,
+    /// Timestamp of the approval
+    pub approved_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ThawApproval {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // token_account
+        + 32  // approved_by
+        + 8   // approved_at
+        + 1;  // bump
+}
+
+/// Redemption request — a burn awaiting off-chain reserve release
+#[account]
+#[derive(Debug)]
+pub struct RedemptionRequest {
+    /// The stablecoin this request belongs to
+    pub stablecoin: Pubkey,
+    /// Holder who redeemed
+    pub holder: Pubkey,
+    /// Amount burned
+    pub amount: u64,
+    /// Client-supplied reference (e.g. bank wire ID); part of the PDA seeds
+    pub reference: String,
+    /// Whether reserves have been released
+    pub fulfilled: bool,
+    /// Timestamp of the request
+    pub requested_at: i64,
+    /// Timestamp of fulfillment
+    pub fulfilled_at: Option<i64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RedemptionRequest {
+    /// Seeds are capped at 32 bytes, which bounds the reference
+    pub const MAX_REFERENCE_LEN: usize = 32;
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // holder
+        + 8   // amount
+        + 4 + Self::MAX_REFERENCE_LEN  // reference (string prefix + data)
+        + 1   // fulfilled
+        + 8   // requested_at
+        + 1 + 8 // fulfilled_at (option tag + i64)
+        + 1;  // bump
+}
+
+/// Account activity — last transfer seen for a token account (dormancy reporting)
+#[account]
+#[derive(Debug)]
+pub struct AccountActivity {
+    /// The stablecoin this record belongs to
+    pub stablecoin: Pubkey,
+    /// Tracked token account
+    pub token_account: Pubkey,
+    /// Timestamp of the last transfer in or out (tracking start if none yet)
+    pub last_transfer_at: i64,
+    /// Amount of the last transfer
+    pub last_amount: u64,
+    /// Whether the account has been flagged dormant
+    pub dormant: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Timestamp of the last transfer out of the account (0 if none yet).
+    /// The transfer cooldown checks this, so inbound transfers cannot restart it.
+    pub last_outbound_at: i64,
+}
+
+impl AccountActivity {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // token_account
+        + 8   // last_transfer_at
+        + 8   // last_amount
+        + 1   // dormant
+        + 1   // bump
+        + 8;  // last_outbound_at
+
+    /// Record a transfer at `now` and clear any dormancy flag
+    pub fn record_transfer(&mut self, amount: u64, now: i64) {
+        self.last_transfer_at = now;
+        self.last_amount = amount;
+        self.dormant = false;
+    }
+
+    /// Record a transfer out of the account at `now`
+    pub fn record_outbound(&mut self, amount: u64, now: i64) {
+        self.record_transfer(amount, now);
+        self.last_outbound_at = now;
+    }
+}
+
+/// KYC record — the verified KYC tier of an owner, checked on large transfers
+#[account]
+#[derive(Debug)]
+pub struct KycRecord {
+    /// The stablecoin this record belongs to
+    pub stablecoin: Pubkey,
+    /// Wallet the tier applies to
+    pub owner: Pubkey,
+    /// Verified tier (0 = none, up to `StablecoinState::MAX_KYC_TIER`)
+    pub tier: u8,
+    /// Compliance officer who set the tier
+    pub set_by: Pubkey,
+    /// Timestamp of the latest change
+    pub updated_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl KycRecord {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // owner
+        + 1   // tier
+        + 32  // set_by
+        + 8   // updated_at
+        + 1;  // bump
+}
+
+/// Limit exemption — lets an owner (e.g. a market maker) skip per-transfer limits and the cooldown
+#[account]
+#[derive(Debug)]
+pub struct LimitExemption {
+    /// The stablecoin this exemption belongs to
+    pub stablecoin: Pubkey,
+    /// Wallet the exemption applies to
+    pub owner: Pubkey,
+    /// Whether the owner is currently exempt
+    pub exempt: bool,
+    /// Authority who last changed the exemption
+    pub set_by: Pubkey,
+    /// Timestamp of the latest change
+    pub updated_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl LimitExemption {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // owner
+        + 1   // exempt
+        + 32  // set_by
+        + 8   // updated_at
+        + 1;  // bump
+}
+
+/// Mint lock — blocks transfers out of a token account until fresh mints unlock
+#[account]
+#[derive(Debug)]
+pub struct MintLock {
+    /// The stablecoin this lock belongs to
+    pub stablecoin: Pubkey,
+    /// Token account that received the mint
+    pub token_account: Pubkey,
+    /// Earliest timestamp at which the account may transfer again
+    pub unlock_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl MintLock {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // token_account
+        + 8   // unlock_at
+        + 1;  // bump
+
+    /// Lock the account for `period` seconds from `now`, never shortening an existing lock
+    pub fn extend(&mut self, now: i64, period: i64) {
+        self.unlock_at = self.unlock_at.max(now.saturating_add(period));
+    }
+}
+
+/// Mint stats — cumulative issuance into one token account, for KYC/AML reporting
+#[account]
+#[derive(Debug)]
+pub struct MintStats {
+    /// The stablecoin these stats belong to
+    pub stablecoin: Pubkey,
+    /// Token account the mints went to
+    pub token_account: Pubkey,
+    /// Total net amount minted into the account
+    pub cumulative_minted: u64,
+    /// Number of mints into the account
+    pub mint_count: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl MintStats {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // token_account
+        + 8   // cumulative_minted
+        + 8   // mint_count
+        + 1;  // bump
+
+    /// Add one mint of `amount`
+    pub fn record(&mut self, amount: u64) -> Result<()> {
+        self.cumulative_minted = self.cumulative_minted.checked_add(amount).ok_or(SSSError::Overflow)?;
+        self.mint_count = self.mint_count.checked_add(1).ok_or(SSSError::Overflow)?;
+        Ok(())
+    }
+}
+
+/// One administrative action in the `ChangeLog`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ChangeLogEntry {
+    /// `AdminAction::code` of the action
+    pub action_code: u8,
+    /// Signer who performed the action
+    pub actor: Pubkey,
+    /// Timestamp of the action
+    pub timestamp: i64,
+}
+
+/// Change log — ring buffer of the most recent administrative actions
+#[account]
+#[derive(Debug)]
+pub struct ChangeLog {
+    /// The stablecoin this log belongs to
+    pub stablecoin: Pubkey,
+    /// Slot the next entry is written to
+    pub next_index: u8,
+    /// Number of entries written, up to `CAPACITY`
+    pub len: u8,
+    /// Entries in write order, wrapping at `CAPACITY`
+    pub entries: [ChangeLogEntry; 16],
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ChangeLog {
+    /// Number of entries kept before the oldest is overwritten
+    pub const CAPACITY: usize = 16;
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 1   // next_index
+        + 1   // len
+        + (1 + 32 + 8) * Self::CAPACITY // entries
+        + 1;  // bump
+
+    /// Append an entry, overwriting the oldest once the buffer is full
+    pub fn record(&mut self, action: AdminAction, actor: Pubkey, timestamp: i64) {
+        self.entries[self.next_index as usize] = ChangeLogEntry {
+            action_code: action.code(),
+            actor,
+            timestamp,
+        };
+        self.next_index = ((self.next_index as usize + 1) % Self::CAPACITY) as u8;
+        self.len = (self.len as usize + 1).min(Self::CAPACITY) as u8;
+    }
+
+    /// Entries from oldest to newest
+    pub fn ordered(&self) -> Vec<ChangeLogEntry> {
+        let start = if (self.len as usize) < Self::CAPACITY { 0 } else { self.next_index as usize };
+        (0..self.len as usize)
+            .map(|i| self.entries[(start + i) % Self::CAPACITY])
+            .collect()
+    }
+}
+
+/// Pending role change — a timelocked role reassignment awaiting execution
+#[account]
+#[derive(Debug)]
+pub struct PendingRoleChange {
+    /// The stablecoin this change belongs to
+    pub stablecoin: Pubkey,
+    /// Role being reassigned
+    pub role: Role,
+    /// Proposed new holder of the role
+    pub new_authority: Pubkey,
+    /// Who proposed the change
+    pub proposed_by: Pubkey,
+    /// Earliest timestamp at which the change can execute
+    pub effective_at: i64,
+    /// Whether a change is waiting to execute
+    pub is_pending: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PendingRoleChange {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 1   // role
+        + 32  // new_authority
+        + 32  // proposed_by
+        + 8   // effective_at
+        + 1   // is_pending
         + 1;  // bump
 }
 
+/// Multisig config — M-of-N signers that hold the stablecoin authority
+#[account]
+#[derive(Debug)]
+pub struct MultisigConfig {
+    /// The stablecoin this multisig governs
+    pub stablecoin: Pubkey,
+    /// Keys allowed to propose, approve and execute actions (at most `MAX_SIGNERS`)
+    pub signers: Vec<Pubkey>,
+    /// Approvals needed before an action can execute
+    pub threshold: u8,
+    /// Id of the next proposed action (also its PDA seed)
+    pub action_count: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl MultisigConfig {
+    pub const MAX_SIGNERS: usize = 10;
+
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 4 + 32 * Self::MAX_SIGNERS  // signers
+        + 1   // threshold
+        + 8   // action_count
+        + 1;  // bump
+
+    /// Position of `key` in `signers`, which is also its bit in `PendingAction::approvals`
+    pub fn signer_index(&self, key: &Pubkey) -> Result<usize> {
+        self.signers
+            .iter()
+            .position(|signer| signer == key)
+            .ok_or_else(|| error!(SSSError::Unauthorized))
+    }
+}
+
+/// Pending multisig action — collects approvals until the threshold is met
+#[account]
+#[derive(Debug)]
+pub struct PendingAction {
+    /// The stablecoin this action belongs to
+    pub stablecoin: Pubkey,
+    /// Sequential id from `MultisigConfig::action_count`
+    pub action_id: u64,
+    /// The action to execute
+    pub action: MultisigAction,
+    /// Signer who proposed the action
+    pub proposed_by: Pubkey,
+    /// Bitmask of approving signers, indexed like `MultisigConfig::signers`
+    pub approvals: u16,
+    /// Whether the action has executed
+    pub executed: bool,
+    /// When the action was proposed
+    pub created_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PendingAction {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 8   // action_id
+        + 1 + 1 + 32 // action (variant tag + largest payload)
+        + 32  // proposed_by
+        + 2   // approvals
+        + 1   // executed
+        + 8   // created_at
+        + 1;  // bump
+
+    /// Number of signers that have approved
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+}
+
+/// Compliance officer list — every key allowed to blacklist and seize
+#[account]
+#[derive(Debug)]
+pub struct ComplianceOfficers {
+    /// The stablecoin this list belongs to
+    pub stablecoin: Pubkey,
+    /// Current officers (at most `MAX_OFFICERS`)
+    pub officers: Vec<Pubkey>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ComplianceOfficers {
+    pub const MAX_OFFICERS: usize = 10;
+
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 4 + 32 * Self::MAX_OFFICERS  // officers
+        + 1;  // bump
+
+    /// Whether `key` is on the officer list
+    pub fn is_compliance_officer(&self, key: &Pubkey) -> bool {
+        self.officers.contains(key)
+    }
+}
+
+/// Symbol registry — one per symbol, so two stablecoins under this program
+/// cannot share a ticker
+#[account]
+#[derive(Debug)]
+pub struct SymbolRegistry {
+    /// The registered symbol (also the PDA seed)
+    pub symbol: String,
+    /// The mint that owns this symbol
+    pub mint: Pubkey,
+    /// Timestamp when registered
+    pub registered_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SymbolRegistry {
+    pub const SPACE: usize = 8  // discriminator
+        + 4 + StablecoinState::MAX_SYMBOL_LEN // symbol (string prefix + data)
+        + 32  // mint
+        + 8   // registered_at
+        + 1;  // bump
+}
+
+/// Mint allowance — a capped minting budget for a delegate (e.g. a partner program)
+#[account]
+#[derive(Debug)]
+pub struct MintAllowance {
+    /// The stablecoin this allowance belongs to
+    pub stablecoin: Pubkey,
+    /// Delegate allowed to mint
+    pub delegate: Pubkey,
+    /// Amount the delegate may still mint
+    pub remaining: u64,
+    /// Mint authority that granted the allowance
+    pub granted_by: Pubkey,
+    /// Timestamp of the latest grant
+    pub granted_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl MintAllowance {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // delegate
+        + 8   // remaining
+        + 32  // granted_by
+        + 8   // granted_at
+        + 1;  // bump
+}
+
+/// Clawback record — the latest `mint_to` into a token account, reversible
+/// within the stablecoin's `clawback_window`
+#[account]
+#[derive(Debug)]
+pub struct ClawbackRecord {
+    /// The stablecoin this record belongs to
+    pub stablecoin: Pubkey,
+    /// Token account that received the mint
+    pub destination: Pubkey,
+    /// Amount still reversible (zeroed once clawed back)
+    pub amount: u64,
+    /// Timestamp of the mint
+    pub minted_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ClawbackRecord {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // stablecoin
+        + 32  // destination
+        + 8   // amount
+        + 8   // minted_at
+        + 1;  // bump
+}
+
+/// Compact status snapshot returned by `get_state`.
+/// Decoupled from the `StablecoinState` account layout so indexers get a stable read API.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct StablecoinStatus {
+    pub net_supply: u64,
+    pub is_sss2: bool,
+    pub paused: bool,
+    pub active_blacklist_count: u64,
+    pub mint_authority: Pubkey,
+    pub freeze_authority: Pubkey,
+    pub compliance_officer: Pubkey,
+}
+
+/// Operation counters returned by `get_operation_counts`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct OperationCountsSnapshot {
+    pub mint_op_count: u64,
+    pub burn_op_count: u64,
+    pub freeze_op_count: u64,
+    pub seize_op_count: u64,
+}
+
+/// Per-account issuance totals returned by `get_mint_stats`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct MintStatsSnapshot {
+    pub cumulative_minted: u64,
+    pub mint_count: u64,
+}
+
 /// Role types for role management
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub enum Role {
     MintAuthority,
     FreezeAuthority,
     ComplianceOfficer,
+    Pauser,
+    BlacklistAuthority,
+}
+
+impl Role {
+    /// Bit used for this role in `StablecoinState::renounced_roles`
+    pub fn bit(&self) -> u8 {
+        match self {
+            Role::MintAuthority => 1 << 0,
+            Role::FreezeAuthority => 1 << 1,
+            Role::ComplianceOfficer => 1 << 2,
+            Role::Pauser => 1 << 3,
+            Role::BlacklistAuthority => 1 << 4,
+        }
+    }
+}
+
+/// Administrative actions recorded in the `ChangeLog`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum AdminAction {
+    Paused,
+    Unpaused,
+    RoleUpdated,
+    RoleRenounced,
+    AuthorityTransferred,
+    FeatureFlagChanged,
+    LimitChanged,
+}
+
+impl AdminAction {
+    /// Code stored in `ChangeLogEntry::action_code`
+    pub fn code(&self) -> u8 {
+        match self {
+            AdminAction::Paused => 1,
+            AdminAction::Unpaused => 2,
+            AdminAction::RoleUpdated => 3,
+            AdminAction::RoleRenounced => 4,
+            AdminAction::AuthorityTransferred => 5,
+            AdminAction::FeatureFlagChanged => 6,
+            AdminAction::LimitChanged => 7,
+        }
+    }
+}
+
+/// Authority actions that can be executed through the multisig
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum MultisigAction {
+    Pause,
+    Unpause,
+    UpdateRole { role: Role, new_authority: Pubkey },
+    TransferAuthority { new_authority: Pubkey },
+}
+
+/// How a `StablecoinState` field is laid out by Borsh (see `StablecoinState::layout_len`)
+#[derive(Clone, Copy, Debug)]
+pub enum FieldShape {
+    /// Always this many bytes
+    Fixed(usize),
+    /// One tag byte, then this many bytes when `Some`
+    Option(usize),
+    /// A u32 length prefix, then that many bytes
+    String,
+    /// A u32 length prefix, then that many elements of this size
+    Vec(usize),
+}
+
+/// The stablecoin state as laid out before `version` was introduced (read by
+/// `migrate_state` only; these accounts are exactly `SPACE` bytes long).
+#[derive(AnchorDeserialize, Debug)]
+pub struct LegacyStablecoinState {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub mint_authority: Pubkey,
+    pub freeze_authority: Pubkey,
+    pub compliance_officer: Pubkey,
+    pub is_sss2: bool,
+    pub permanent_delegate_enabled: bool,
+    pub transfer_hook_enabled: bool,
+    pub default_account_frozen: bool,
+    pub total_minted: u64,
+    pub total_burned: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl LegacyStablecoinState {
+    /// Byte offset of `authority` (no version byte in front of `mint`)
+    pub const AUTHORITY_OFFSET: usize = 8 + 32;
+    pub const SPACE: usize = 8  // discriminator
+        + 32 * 5 // mint, authority, mint_authority, freeze_authority, compliance_officer
+        + 4   // is_sss2, permanent_delegate_enabled, transfer_hook_enabled, default_account_frozen
+        + 8 * 4 // total_minted, total_burned, created_at, updated_at
+        + 1   // bump
+        + 4 + StablecoinState::MAX_NAME_LEN   // name
+        + 4 + StablecoinState::MAX_SYMBOL_LEN // symbol
+        + 1;  // decimals
+}
+
+/// Rounding direction for basis-point fee math (see `format::apply_rounding`).
+/// `RoundDown` comes first so migrated accounts keep the original fee math.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum RoundingPolicy {
+    RoundDown,
+    RoundUp,
+    /// Half rounds up
+    Nearest,
+}
+
+/// Handling of a transfer that would leave the source below `min_account_balance`.
+/// `Reject` comes first so migrated accounts refuse rather than sweep.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum DustPolicy {
+    /// Fail with `WouldLeaveDust`
+    Reject,
+    /// Move the whole balance instead
+    SweepAll,
+}
+
+/// Stablecoin-level flags toggled via `set_feature_flag`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum FeatureFlag {
+    Paused,
+    AllowlistMode,
+    DefaultAccountFrozen,
+    FreezeOnBlacklist,
+    BitmapBlacklist,
+    DualSeize,
+}
+
+impl FeatureFlag {
+    /// Name recorded in `ConfigUpdated` events
+    pub fn name(&self) -> &'static str {
+        match self {
+            FeatureFlag::Paused => "paused",
+            FeatureFlag::AllowlistMode => "allowlist_mode",
+            FeatureFlag::DefaultAccountFrozen => "default_account_frozen",
+            FeatureFlag::FreezeOnBlacklist => "freeze_on_blacklist",
+            FeatureFlag::BitmapBlacklist => "bitmap_blacklist",
+            FeatureFlag::DualSeize => "require_dual_seize",
+        }
+    }
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SSSError;
+use crate::state::StablecoinState;
+
+/// Check a single-operation amount: non-zero, and at most `max` when a cap is set
+pub fn validate_amount(amount: u64, max: Option<u64>) -> Result<()> {
+    require!(amount > 0, SSSError::InvalidAmount);
+    if let Some(max) = max {
+        require!(amount <= max, SSSError::AmountTooLarge);
+    }
+    Ok(())
+}
+
+/// Check a token name: at most `MAX_NAME_LEN` bytes, non-empty, printable ASCII only
+pub fn validate_name(name: &str) -> Result<()> {
+    require!(name.len() <= StablecoinState::MAX_NAME_LEN, SSSError::NameTooLong);
+    require!(
+        !name.is_empty() && name.bytes().all(|b| b.is_ascii_graphic() || b == b' '),
+        SSSError::InvalidMetadataFormat
+    );
+    Ok(())
+}
+
+/// Check a token symbol: at most `MAX_SYMBOL_LEN` bytes, non-empty, uppercase letters and digits only
+pub fn validate_symbol(symbol: &str) -> Result<()> {
+    require!(symbol.len() <= StablecoinState::MAX_SYMBOL_LEN, SSSError::SymbolTooLong);
+    require!(
+        !symbol.is_empty() && symbol.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()),
+        SSSError::InvalidMetadataFormat
+    );
+    Ok(())
+}
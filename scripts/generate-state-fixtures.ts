@@ -0,0 +1,188 @@
+/**
+ * Writes the stablecoin state fixtures that `anchor test` preloads from
+ * `tests/fixtures/accounts` (see `[[test.validator.account_dir]]` in Anchor.toml):
+ *
+ * - `legacy-*`: a state account in the layout from before `version` existed
+ * - `stale-v1-*`: a v1 state account whose bytes past the v1 layout are stale
+ * - `realloc-v1-*`: the same, for `realloc_state` (which leaves the version alone)
+ * - `peg-feed-*`: Pyth v2 price accounts for the peg oracle tests
+ *
+ * Keys come from fixed seeds so the tests can rebuild them. The state accounts are
+ * PDAs of the program, so re-run this after the program id changes:
+ *
+ *   npx ts-node scripts/generate-state-fixtures.ts
+ */
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { createHash } from "crypto";
+import { mkdirSync, readFileSync, writeFileSync } from "fs";
+import { join } from "path";
+
+const PROGRAM_ID = new PublicKey(
+    /sss = "(\w+)"/.exec(readFileSync(join(__dirname, "../Anchor.toml"), "utf8"))![1]
+);
+const OUT_DIR = join(__dirname, "../tests/fixtures/accounts");
+
+/** Deterministic fixture keypair (tests/sss.ts derives the same ones) */
+const fixtureKeypair = (label: string) =>
+    Keypair.fromSeed(createHash("sha256").update(label).digest());
+
+const authority = fixtureKeypair("sss-legacy-authority").publicKey;
+const discriminator = createHash("sha256").update("account:StablecoinState").digest().subarray(0, 8);
+const STALE = 0xab;
+const CREATED_AT = 1_700_000_000;
+
+/** Rent-exempt minimum under the default rent parameters */
+const rentExempt = (len: number) => (128 + len) * 6_960;
+
+class Writer {
+    private parts: Buffer[] = [];
+    u8(value: number) {
+        this.parts.push(Buffer.from([value]));
+        return this;
+    }
+    u16(value: number) {
+        const buf = Buffer.alloc(2);
+        buf.writeUInt16LE(value);
+        this.parts.push(buf);
+        return this;
+    }
+    i64(value: number) {
+        const buf = Buffer.alloc(8);
+        buf.writeBigInt64LE(BigInt(value));
+        this.parts.push(buf);
+        return this;
+    }
+    key(key: PublicKey) {
+        this.parts.push(key.toBuffer());
+        return this;
+    }
+    zeros(len: number) {
+        this.parts.push(Buffer.alloc(len));
+        return this;
+    }
+    str(value: string) {
+        const len = Buffer.alloc(4);
+        len.writeUInt32LE(Buffer.byteLength(value));
+        this.parts.push(len, Buffer.from(value));
+        return this;
+    }
+    /** The written bytes, padded to `space` with stale bytes */
+    finish(space: number) {
+        const data = Buffer.concat(this.parts);
+        return Buffer.concat([data, Buffer.alloc(space - data.length, STALE)]);
+    }
+}
+
+/** A bare 82-byte Token-2022 mint with `authority` as mint authority */
+const mintData = (supply: number) => {
+    const data = Buffer.alloc(82);
+    data.writeUInt32LE(1, 0);
+    authority.toBuffer().copy(data, 4);
+    data.writeBigUInt64LE(BigInt(supply), 36);
+    data[44] = 6; // decimals
+    data[45] = 1; // is_initialized
+    return data;
+};
+
+const writeAccount = (name: string, pubkey: PublicKey, owner: PublicKey, data: Buffer) => {
+    const account = {
+        pubkey: pubkey.toBase58(),
+        account: {
+            lamports: rentExempt(data.length),
+            data: [data.toString("base64"), "base64"],
+            owner: owner.toBase58(),
+            executable: false,
+            rentEpoch: 0,
+            space: data.length,
+        },
+    };
+    writeFileSync(join(OUT_DIR, `${name}.json`), JSON.stringify(account, null, 2) + "\n");
+};
+
+const statePda = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("stablecoin"), mint.toBuffer()], PROGRAM_ID);
+
+mkdirSync(OUT_DIR, { recursive: true });
+
+// Pre-versioning layout: 256 bytes, no version byte, no uri
+{
+    const mint = fixtureKeypair("sss-legacy-mint").publicKey;
+    const [state, bump] = statePda(mint);
+    const data = new Writer()
+        .key(mint)
+        .key(authority) // authority
+        .key(authority) // mint_authority
+        .key(authority) // freeze_authority
+        .key(authority) // compliance_officer
+        .zeros(4) // SSS-1: no delegate, hook or default freeze
+        .i64(5_000_000) // total_minted
+        .i64(1_000_000) // total_burned
+        .i64(CREATED_AT)
+        .i64(CREATED_AT + 600) // updated_at
+        .u8(bump)
+        .str("Legacy Dollar")
+        .str("LGCY")
+        .u8(6);
+    writeAccount("legacy-mint", mint, TOKEN_2022_PROGRAM_ID, mintData(4_000_000));
+    writeAccount("legacy-state", state, PROGRAM_ID, Buffer.concat([discriminator, data.finish(256 - 8)]));
+}
+
+// v1 layout (653 bytes) with stale bytes past `decimals`
+const writeV1State = (prefix: string, mintLabel: string, name: string, symbol: string, totalMinted: number) => {
+    const mint = fixtureKeypair(mintLabel).publicKey;
+    const [state, bump] = statePda(mint);
+    const data = new Writer()
+        .u8(1) // version
+        .key(mint)
+        .key(authority)
+        .u8(0) // pending_authority: None
+        .key(authority) // mint_authority
+        .key(authority) // freeze_authority
+        .key(authority) // compliance_officer
+        .key(authority) // pauser
+        .u8(0) // renounced_roles
+        .i64(0) // role_change_delay
+        .i64(0) // mint_authority_rotation_cooldown
+        .i64(CREATED_AT) // last_mint_authority_change
+        .zeros(6) // preset, pause and allowlist flags
+        .zeros(32) // allowlist_root
+        .u8(0) // max_transfer_amount: None
+        .u16(0) // transfer_fee_basis_points
+        .i64(0) // max_transfer_fee
+        .u8(0) // daily_mint_limit: None
+        .i64(0) // minted_today
+        .i64(CREATED_AT) // mint_window_start
+        .i64(totalMinted)
+        .zeros(8 * 4) // total_burned, total_seized, supply_drift, active_blacklist_count
+        .i64(CREATED_AT)
+        .i64(CREATED_AT) // updated_at
+        .u8(bump)
+        .str(name)
+        .str(symbol)
+        .str("")
+        .u8(6);
+    writeAccount(`${prefix}-mint`, mint, TOKEN_2022_PROGRAM_ID, mintData(totalMinted));
+    writeAccount(`${prefix}-state`, state, PROGRAM_ID, Buffer.concat([discriminator, data.finish(653 - 8)]));
+};
+
+writeV1State("stale-v1", "sss-stale-v1-mint", "Stale Dollar", "STAL", 7_000_000);
+writeV1State("realloc-v1", "sss-realloc-v1-mint", "Realloc Dollar", "RALC", 3_000_000);
+
+// Pyth v2 price accounts (only the fields `oracle.rs` reads are filled in)
+const PYTH_PROGRAM_ID = new PublicKey("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+const PYTH_PRICE_ACCOUNT_LEN = 3_312;
+
+const writePriceFeed = (name: string, label: string, price: number, expo: number) => {
+    const data = Buffer.alloc(PYTH_PRICE_ACCOUNT_LEN);
+    data.writeUInt32LE(0xa1b2c3d4, 0); // magic
+    data.writeUInt32LE(2, 4); // version
+    data.writeUInt32LE(3, 8); // account type: price
+    data.writeInt32LE(expo, 20);
+    data.writeBigInt64LE(BigInt(price), 208); // aggregate price
+    data.writeUInt32LE(1, 224); // aggregate status: trading
+    writeAccount(name, fixtureKeypair(label).publicKey, PYTH_PROGRAM_ID, data);
+};
+
+writePriceFeed("peg-feed-on", "sss-peg-feed-on", 9_990, -4); // 0.9990: 10 bps off peg
+writePriceFeed("peg-feed-off", "sss-peg-feed-off", 9_700, -4); // 0.9700: 300 bps off peg
@@ -10,7 +10,7 @@
 
 import { Program, AnchorProvider, web3, BN } from "@coral-xyz/anchor";
 import { PublicKey, Keypair, SystemProgram, Connection, Transaction } from "@solana/web3.js";
-import { TOKEN_2022_PROGRAM_ID, getAssociatedTokenAddress, createAssociatedTokenAccountInstruction } from "@solana/spl-token";
+import { TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddress, getAssociatedTokenAddressSync, createAssociatedTokenAccountInstruction } from "@solana/spl-token";
 
 // ──────────────────────────────────────────────────────
 // Types
@@ -28,6 +28,17 @@ export interface StablecoinConfig {
   enablePermanentDelegate: boolean;
   enableTransferHook: boolean;
   defaultAccountFrozen: boolean;
+  allowlistMode: boolean;
+  freezeOnBlacklist?: boolean;
+  nonTransferable?: boolean;
+  mintAuthority?: PublicKey | null;
+  freezeAuthority?: PublicKey | null;
+  complianceOfficer?: PublicKey | null;
+  maxTransferAmount?: BN | null;
+  transferFeeBasisPoints?: number;
+  maxTransferFee?: BN;
+  roleChangeDelay?: BN;
+  mintAuthorityRotationCooldown?: BN;
 }
 
 /** Role types for role management */
@@ -35,26 +46,125 @@ export enum Role {
   MintAuthority = "MintAuthority",
   FreezeAuthority = "FreezeAuthority",
   ComplianceOfficer = "ComplianceOfficer",
+  Pauser = "Pauser",
+  BlacklistAuthority = "BlacklistAuthority",
 }
 
 /** On-chain stablecoin state */
 export interface StablecoinState {
+  version: number;
   mint: PublicKey;
   authority: PublicKey;
+  pendingAuthority: PublicKey | null;
   mintAuthority: PublicKey;
   freezeAuthority: PublicKey;
   complianceOfficer: PublicKey;
+  pauser: PublicKey;
+  renouncedRoles: number;
+  roleChangeDelay: BN;
+  mintAuthorityRotationCooldown: BN;
+  lastMintAuthorityChange: BN;
   isSss2: boolean;
   permanentDelegateEnabled: boolean;
   transferHookEnabled: boolean;
   defaultAccountFrozen: boolean;
+  paused: boolean;
+  allowlistMode: boolean;
+  allowlistRoot: number[];
+  maxTransferAmount: BN | null;
+  transferFeeBasisPoints: number;
+  maxTransferFee: BN;
+  dailyMintLimit: BN | null;
+  mintedToday: BN;
+  mintWindowStart: BN;
   totalMinted: BN;
   totalBurned: BN;
+  totalSeized: BN;
+  supplyDrift: BN;
+  activeBlacklistCount: BN;
   createdAt: BN;
   updatedAt: BN;
   name: string;
   symbol: string;
   decimals: number;
+  lastMigratedAt: BN;
+  interestRateBps: number;
+  exemptAddresses: PublicKey[];
+  totalTransferVolume: BN;
+  minTransferAmount: BN | null;
+  oracle: PublicKey | null;
+  maxPegDeviationBps: number;
+  mintOpCount: BN;
+  burnOpCount: BN;
+  freezeOpCount: BN;
+  seizeOpCount: BN;
+  transferCooldown: BN | null;
+  blacklistAuthority: PublicKey;
+  seizureTreasury: PublicKey | null;
+  clawbackWindow: BN | null;
+  maxSingleOpAmount: BN | null;
+  freezeOnBlacklist: boolean;
+  bitmapBlacklist: boolean;
+  emitEvents: boolean;
+  kycTierThresholds: BN[];
+  mintFeeBps: number;
+  mintFeeCollector: PublicKey;
+  mintLockPeriod: BN | null;
+  maxBlacklistEntries: BN | null;
+  feeAuthority: PublicKey;
+  changelogEnabled: boolean;
+  dailySeizeLimit: BN | null;
+  seizedToday: BN;
+  seizeWindowStart: BN;
+  seizeCooldown: BN | null;
+  lastSeizeAt: BN;
+  nonTransferable: boolean;
+  maxTransferBatchSize: number;
+  configLocked: boolean;
+  requireDualSeize: boolean;
+  blacklistDigest: number[];
+  digestUpdatedAt: BN;
+  feeRounding: RoundingPolicy;
+  blockedTransferCount: BN;
+  minAccountBalance: BN | null;
+  dustPolicy: DustPolicy;
+  digestSnapshotAt: BN;
+  digestAccumulator: number[];
+  digestCursor: PublicKey | null;
+  digestActiveSeen: BN;
+  digestExpiredSeen: BN;
+}
+
+/** Compact status snapshot returned by the get_state instruction */
+export interface StablecoinStatus {
+  netSupply: BN;
+  isSss2: boolean;
+  paused: boolean;
+  activeBlacklistCount: BN;
+  mintAuthority: PublicKey;
+  freezeAuthority: PublicKey;
+  complianceOfficer: PublicKey;
+}
+
+/** Per-account issuance totals returned by the get_mint_stats instruction */
+export interface MintStats {
+  cumulativeMinted: BN;
+  mintCount: BN;
+}
+
+/** One admin action returned by the get_changelog instruction */
+export interface ChangeLogEntry {
+  actionCode: number;
+  actor: PublicKey;
+  timestamp: BN;
+}
+
+/** Lifetime operation counters returned by the get_operation_counts instruction */
+export interface OperationCounts {
+  mintOpCount: BN;
+  burnOpCount: BN;
+  freezeOpCount: BN;
+  seizeOpCount: BN;
 }
 
 // ──────────────────────────────────────────────────────
@@ -76,6 +186,7 @@ export function createSSS1Config(
     enablePermanentDelegate: false,
     enableTransferHook: false,
     defaultAccountFrozen: false,
+    allowlistMode: false,
   };
 }
 
@@ -94,6 +205,7 @@ export function createSSS2Config(
     enablePermanentDelegate: true,
     enableTransferHook: true,
     defaultAccountFrozen: true,
+    allowlistMode: false,
   };
 }
 
@@ -126,6 +238,265 @@ export function findBlacklistEntryPDA(
   );
 }
 
+/** Derive the frozen account record PDA for a token account */
+export function findFrozenAccountRecordPDA(
+  mint: PublicKey,
+  tokenAccount: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("frozen"), mint.toBuffer(), tokenAccount.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the thaw approval PDA the hook requires for destinations in default-frozen mode */
+export function findThawApprovalPDA(
+  mint: PublicKey,
+  tokenAccount: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("approved"), mint.toBuffer(), tokenAccount.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the compliance officer list PDA */
+export function findComplianceOfficersPDA(
+  mint: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("compliance-officers"), mint.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the mint allowance PDA for a delegate */
+export function findMintAllowancePDA(
+  mint: PublicKey,
+  delegate: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("allowance"), mint.toBuffer(), delegate.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the blacklist bitmap PDA checked by the hook in bitmap mode */
+export function findBlacklistBitmapPDA(
+  mint: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("blacklist-bitmap"), mint.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the clawback record PDA for a token account */
+export function findClawbackRecordPDA(
+  mint: PublicKey,
+  tokenAccount: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("clawback"), mint.toBuffer(), tokenAccount.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the mint lock PDA for a token account */
+export function findMintLockPDA(
+  mint: PublicKey,
+  tokenAccount: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("mint_lock"), mint.toBuffer(), tokenAccount.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the mint stats PDA for a token account */
+export function findMintStatsPDA(
+  mint: PublicKey,
+  tokenAccount: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("mintstats"), mint.toBuffer(), tokenAccount.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the limit exemption PDA for an owner */
+export function findLimitExemptionPDA(
+  mint: PublicKey,
+  owner: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("limit_exempt"), mint.toBuffer(), owner.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the change log PDA for a stablecoin */
+export function findChangeLogPDA(
+  mint: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("changelog"), mint.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the compliance hold PDA for an owner */
+export function findComplianceHoldPDA(
+  mint: PublicKey,
+  owner: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("hold"), mint.toBuffer(), owner.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the KYC record PDA for an owner */
+export function findKycRecordPDA(
+  mint: PublicKey,
+  owner: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("kyc"), mint.toBuffer(), owner.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the symbol registry PDA (one per symbol across all stablecoins) */
+export function findSymbolRegistryPDA(
+  symbol: string,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("symbol"), Buffer.from(symbol)],
+    programId
+  );
+}
+
+/** Find the redemption request PDA for a holder and reference */
+export function findRedemptionRequestPDA(
+  mint: PublicKey,
+  holder: PublicKey,
+  reference: string,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("redemption"), mint.toBuffer(), holder.toBuffer(), Buffer.from(reference)],
+    programId
+  );
+}
+
+/** Find the seizure record PDA for a seizure at `seizedAt` (unix seconds) */
+export function findSeizureRecordPDA(
+  mint: PublicKey,
+  targetAccount: PublicKey,
+  seizedAt: BN,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("seizure"), mint.toBuffer(), targetAccount.toBuffer(), seizedAt.toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+}
+
+export function findPendingSeizurePDA(
+  mint: PublicKey,
+  targetAccount: PublicKey,
+  seizedAt: BN,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pending_seizure"), mint.toBuffer(), targetAccount.toBuffer(), seizedAt.toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+}
+
+/** Derive the reissue record PDA for a seizure record */
+export function findReissueRecordPDA(
+  mint: PublicKey,
+  seizureRecord: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("reissue"), mint.toBuffer(), seizureRecord.toBuffer()],
+    programId
+  );
+}
+
+/** Bit used for each role in on-chain seeds and the renounced-roles bitfield */
+const ROLE_BITS: Record<Role, number> = {
+  [Role.MintAuthority]: 1,
+  [Role.FreezeAuthority]: 2,
+  [Role.ComplianceOfficer]: 4,
+  [Role.Pauser]: 8,
+  [Role.BlacklistAuthority]: 16,
+};
+
+/** Find the pending role change PDA for a role */
+export function findPendingRoleChangePDA(
+  mint: PublicKey,
+  role: Role,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("role-change"), mint.toBuffer(), Buffer.from([ROLE_BITS[role]])],
+    programId
+  );
+}
+
+/** Derive the multisig config PDA for a stablecoin */
+export function findMultisigPDA(
+  mint: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("multisig"), mint.toBuffer()],
+    programId
+  );
+}
+
+/** Derive the pending multisig action PDA for an action id */
+export function findPendingActionPDA(
+  mint: PublicKey,
+  actionId: BN,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pending_action"), mint.toBuffer(), actionId.toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+}
+
+/** Authority action executed through the multisig (Anchor enum encoding) */
+export type MultisigAction =
+  | { pause: {} }
+  | { unpause: {} }
+  | { updateRole: { role: Record<string, {}>; newAuthority: PublicKey } }
+  | { transferAuthority: { newAuthority: PublicKey } };
+
+/** Rounding applied to the issuance fee (Anchor enum encoding) */
+export type RoundingPolicy = { roundDown: {} } | { roundUp: {} } | { nearest: {} };
+
+/** Handling of a transfer that would leave dust in the source (Anchor enum encoding) */
+export type DustPolicy = { reject: {} } | { sweepAll: {} };
+
 // ──────────────────────────────────────────────────────
 // Client
 // ──────────────────────────────────────────────────────
@@ -148,7 +519,7 @@ export function findBlacklistEntryPDA(
  * const config2 = createSSS2Config("Regulated USD", "RUSD");
  * const { mint: mint2 } = await client.initialize(config2);
  * await client.blacklistAdd(mint2, suspiciousAddress);
- * await client.seizeTokens(mint2, targetAccount, treasuryAccount, 500_000);
+ * await client.seizeTokens(mint2, targetAccount, treasuryAccount, targetOwner, 500_000, "Case 2024-17");
  * ```
  */
 export class SSSClient {
@@ -191,11 +562,23 @@ export class SSSClient {
         enablePermanentDelegate: config.enablePermanentDelegate,
         enableTransferHook: config.enableTransferHook,
         defaultAccountFrozen: config.defaultAccountFrozen,
+        allowlistMode: config.allowlistMode,
+        freezeOnBlacklist: config.freezeOnBlacklist ?? false,
+        nonTransferable: config.nonTransferable ?? false,
+        mintAuthority: config.mintAuthority ?? null,
+        freezeAuthority: config.freezeAuthority ?? null,
+        complianceOfficer: config.complianceOfficer ?? null,
+        maxTransferAmount: config.maxTransferAmount ?? null,
+        transferFeeBasisPoints: config.transferFeeBasisPoints ?? 0,
+        maxTransferFee: config.maxTransferFee ?? new BN(0),
+        roleChangeDelay: config.roleChangeDelay ?? new BN(0),
+        mintAuthorityRotationCooldown: config.mintAuthorityRotationCooldown ?? new BN(0),
       })
       .accounts({
         authority: this.wallet,
         mint: mint.publicKey,
         stablecoinState,
+        symbolRegistry: findSymbolRegistryPDA(config.symbol)[0],
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: web3.SYSVAR_RENT_PUBKEY,
@@ -206,183 +589,1481 @@ export class SSSClient {
     return { mint: mint.publicKey, stablecoinState, txSignature };
   }
 
-  /** Mint tokens to a token account */
-  async mintTo(
-    mint: PublicKey,
-    tokenAccount: PublicKey,
-    amount: number | BN
-  ): Promise<string> {
-    const [stablecoinState] = findStablecoinStatePDA(mint);
-    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+  /** Initialize `mint` as a stablecoin unless its state already exists (safe to re-run) */
+  async initializeIfNeeded(mint: Keypair, config: StablecoinConfig): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint.publicKey);
 
     return this.program.methods
-      .mintTo(amountBN)
+      .initializeIfNeeded({
+        name: config.name,
+        symbol: config.symbol,
+        uri: config.uri,
+        decimals: config.decimals,
+        enablePermanentDelegate: config.enablePermanentDelegate,
+        enableTransferHook: config.enableTransferHook,
+        defaultAccountFrozen: config.defaultAccountFrozen,
+        allowlistMode: config.allowlistMode,
+        freezeOnBlacklist: config.freezeOnBlacklist ?? false,
+        nonTransferable: config.nonTransferable ?? false,
+        mintAuthority: config.mintAuthority ?? null,
+        freezeAuthority: config.freezeAuthority ?? null,
+        complianceOfficer: config.complianceOfficer ?? null,
+        maxTransferAmount: config.maxTransferAmount ?? null,
+        transferFeeBasisPoints: config.transferFeeBasisPoints ?? 0,
+        maxTransferFee: config.maxTransferFee ?? new BN(0),
+        roleChangeDelay: config.roleChangeDelay ?? new BN(0),
+        mintAuthorityRotationCooldown: config.mintAuthorityRotationCooldown ?? new BN(0),
+      })
       .accounts({
-        mintAuthority: this.wallet,
-        mint,
-        tokenAccount,
+        authority: this.wallet,
+        mint: mint.publicKey,
         stablecoinState,
+        symbolRegistry: findSymbolRegistryPDA(config.symbol)[0],
         tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: web3.SYSVAR_RENT_PUBKEY,
       })
+      .signers([mint])
       .rpc();
   }
 
-  /** Burn tokens from a token account */
-  async burn(
-    mint: PublicKey,
-    tokenAccount: PublicKey,
-    amount: number | BN
-  ): Promise<string> {
+  /** Release the coin's symbol so another mint can register it (requires zero net supply) */
+  async releaseSymbol(mint: PublicKey, symbol: string): Promise<string> {
     const [stablecoinState] = findStablecoinStatePDA(mint);
-    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+    const [symbolRegistry] = findSymbolRegistryPDA(symbol);
 
     return this.program.methods
-      .burn(amountBN)
+      .releaseSymbol(symbol)
       .accounts({
-        owner: this.wallet,
+        authority: this.wallet,
         mint,
-        tokenAccount,
         stablecoinState,
-        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        symbolRegistry,
       })
       .rpc();
   }
 
-  /** Transfer tokens between accounts */
-  async transfer(
-    mint: PublicKey,
-    from: PublicKey,
-    to: PublicKey,
-    amount: number | BN
-  ): Promise<string> {
+  /** Migrate the stablecoin state from an older layout to the current version */
+  async migrateState(mint: PublicKey): Promise<string> {
     const [stablecoinState] = findStablecoinStatePDA(mint);
-    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
 
     return this.program.methods
-      .transfer(amountBN)
+      .migrateState()
       .accounts({
-        owner: this.wallet,
+        authority: this.wallet,
         mint,
-        from,
-        to,
         stablecoinState,
-        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
 
-  // ── Freeze Operations ──
-
-  /** Freeze a token account */
-  async freezeAccount(mint: PublicKey, tokenAccount: PublicKey): Promise<string> {
+  /** Grow the stablecoin state account to the current size without migrating fields (no-op if already there) */
+  async reallocState(mint: PublicKey): Promise<string> {
     const [stablecoinState] = findStablecoinStatePDA(mint);
 
     return this.program.methods
-      .freezeAccount()
+      .reallocState()
       .accounts({
-        freezeAuthority: this.wallet,
+        authority: this.wallet,
         mint,
-        tokenAccount,
         stablecoinState,
-        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
 
-  /** Thaw (unfreeze) a token account */
-  async thawAccount(mint: PublicKey, tokenAccount: PublicKey): Promise<string> {
+  /**
+   * Mint tokens to a token account (SSS-2 requires `recipientOwner` for the blacklist check;
+   * pass `oracle` once a price feed is configured with `setOracle`; `trackStats` adds the
+   * mint to the account's cumulative issuance, see `getMintStats`)
+   */
+  async mintTo(
+    mint: PublicKey,
+    tokenAccount: PublicKey,
+    amount: number | BN,
+    memo?: string,
+    recipientOwner?: PublicKey,
+    oracle?: PublicKey,
+    feeCollector?: PublicKey,
+    trackStats?: boolean
+  ): Promise<string> {
     const [stablecoinState] = findStablecoinStatePDA(mint);
+    const { clawbackWindow, mintLockPeriod } = await this.getStablecoinState(mint);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
 
     return this.program.methods
-      .thawAccount()
+      .mintTo(amountBN, memo ?? null)
       .accounts({
-        freezeAuthority: this.wallet,
+        mintAuthority: this.wallet,
         mint,
         tokenAccount,
         stablecoinState,
+        recipientBlacklist: recipientOwner ? findBlacklistEntryPDA(mint, recipientOwner)[0] : null,
+        oracle: oracle ?? null,
+        clawbackRecord: clawbackWindow ? findClawbackRecordPDA(mint, tokenAccount)[0] : null,
+        mintLock: mintLockPeriod ? findMintLockPDA(mint, tokenAccount)[0] : null,
+        mintStats: trackStats ? findMintStatsPDA(mint, tokenAccount)[0] : null,
+        feeCollector: feeCollector ?? null,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
 
-  // ── SSS-2: Compliance Operations ──
-
-  /** Add an address to the blacklist (SSS-2 only) */
-  async blacklistAdd(mint: PublicKey, address: PublicKey): Promise<string> {
+  /**
+   * Mint tokens to `owner`'s associated token account, creating it if needed
+   * (`trackStats` adds the mint to the account's cumulative issuance, as in `mintTo`)
+   */
+  async mintToNew(
+    mint: PublicKey,
+    owner: PublicKey,
+    amount: number | BN,
+    memo?: string,
+    oracle?: PublicKey,
+    feeCollector?: PublicKey,
+    trackStats?: boolean
+  ): Promise<string> {
     const [stablecoinState] = findStablecoinStatePDA(mint);
-    const [blacklistEntry] = findBlacklistEntryPDA(mint, address);
+    const tokenAccount = getAssociatedTokenAddressSync(mint, owner, true, TOKEN_2022_PROGRAM_ID);
+    const { clawbackWindow, mintLockPeriod } = await this.getStablecoinState(mint);
+    const [recipientBlacklist] = findBlacklistEntryPDA(mint, owner);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
 
     return this.program.methods
-      .blacklistAdd(address)
+      .mintToNew(amountBN, memo ?? null)
       .accounts({
-        complianceOfficer: this.wallet,
+        mintAuthority: this.wallet,
         mint,
+        owner,
+        tokenAccount,
         stablecoinState,
-        blacklistEntry,
+        recipientBlacklist,
+        oracle: oracle ?? null,
+        clawbackRecord: clawbackWindow ? findClawbackRecordPDA(mint, tokenAccount)[0] : null,
+        mintLock: mintLockPeriod ? findMintLockPDA(mint, tokenAccount)[0] : null,
+        mintStats: trackStats ? findMintStatsPDA(mint, tokenAccount)[0] : null,
+        feeCollector: feeCollector ?? null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
 
-  /** Remove an address from the blacklist (SSS-2 only) */
-  async blacklistRemove(mint: PublicKey, address: PublicKey): Promise<string> {
+  /** Reverse the latest `mintTo` into a token account (within the clawback window) */
+  async clawbackMint(mint: PublicKey, tokenAccount: PublicKey): Promise<string> {
     const [stablecoinState] = findStablecoinStatePDA(mint);
-    const [blacklistEntry] = findBlacklistEntryPDA(mint, address);
+    const [clawbackRecord] = findClawbackRecordPDA(mint, tokenAccount);
 
     return this.program.methods
-      .blacklistRemove(address)
+      .clawbackMint()
       .accounts({
-        complianceOfficer: this.wallet,
+        mintAuthority: this.wallet,
+        mint,
+        tokenAccount,
+        stablecoinState,
+        clawbackRecord,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /** Set (or clear with `null`) how many seconds a mint stays reversible */
+  async setClawbackWindow(mint: PublicKey, clawbackWindow: number | BN | null): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const windowBN = typeof clawbackWindow === "number" ? new BN(clawbackWindow) : clawbackWindow;
+
+    return this.program.methods
+      .setClawbackWindow(windowBN)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Set (or clear with `null`) how many seconds minted tokens stay locked in the destination */
+  async setMintLockPeriod(mint: PublicKey, mintLockPeriod: number | BN | null): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const periodBN = typeof mintLockPeriod === "number" ? new BN(mintLockPeriod) : mintLockPeriod;
+
+    return this.program.methods
+      .setMintLockPeriod(periodBN)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Set (or clear with `null`) the cap on active blacklist entries */
+  async setMaxBlacklistEntries(mint: PublicKey, maxBlacklistEntries: number | BN | null): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const maxBN = typeof maxBlacklistEntries === "number" ? new BN(maxBlacklistEntries) : maxBlacklistEntries;
+
+    return this.program.methods
+      .setMaxBlacklistEntries(maxBN)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Designate who may harvest and withdraw withheld transfer fees (authority only) */
+  async setFeeAuthority(mint: PublicKey, feeAuthority: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .setFeeAuthority(feeAuthority)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Harvest withheld transfer fees from `tokenAccounts` onto the mint (fee authority only; max 15) */
+  async harvestWithheldFees(mint: PublicKey, tokenAccounts: PublicKey[]): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .harvestWithheldFees()
+      .accounts({
+        feeAuthority: this.wallet,
+        mint,
+        stablecoinState,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        tokenAccounts.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }))
+      )
+      .rpc();
+  }
+
+  /** Withdraw the fees withheld on the mint to `feeCollector` (fee authority only) */
+  async withdrawWithheldFees(mint: PublicKey, feeCollector: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .withdrawWithheldFees()
+      .accounts({
+        feeAuthority: this.wallet,
+        mint,
+        stablecoinState,
+        feeCollector,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /** Exempt `owner` from per-transfer limits and the transfer cooldown, or lift the exemption */
+  async setLimitExempt(mint: PublicKey, owner: PublicKey, exempt: boolean): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [limitExemption] = findLimitExemptionPDA(mint, owner);
+
+    return this.program.methods
+      .setLimitExempt(owner, exempt)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        limitExemption,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /** Grant or reset a delegate's minting budget (mint authority only; 0 revokes) */
+  async grantMintAllowance(
+    mint: PublicKey,
+    delegate: PublicKey,
+    amount: number | BN
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [mintAllowance] = findMintAllowancePDA(mint, delegate);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+    return this.program.methods
+      .grantMintAllowance(delegate, amountBN)
+      .accounts({
+        mintAuthority: this.wallet,
+        mint,
+        stablecoinState,
+        mintAllowance,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Mint against the wallet's allowance. The Token-2022 mint authority must be the
+   * stablecoin state PDA, which signs the mint.
+   */
+  async mintWithAllowance(
+    mint: PublicKey,
+    tokenAccount: PublicKey,
+    amount: number | BN,
+    recipientOwner?: PublicKey,
+    oracle?: PublicKey
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [mintAllowance] = findMintAllowancePDA(mint, this.wallet);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+    return this.program.methods
+      .mintWithAllowance(amountBN)
+      .accounts({
+        delegate: this.wallet,
+        mint,
+        tokenAccount,
+        stablecoinState,
+        mintAllowance,
+        recipientBlacklist: recipientOwner ? findBlacklistEntryPDA(mint, recipientOwner)[0] : null,
+        oracle: oracle ?? null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /** Burn tokens from a token account */
+  async burn(
+    mint: PublicKey,
+    tokenAccount: PublicKey,
+    amount: number | BN,
+    memo?: string
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+    return this.program.methods
+      .burn(amountBN, memo ?? null)
+      .accounts({
+        owner: this.wallet,
+        mint,
+        tokenAccount,
+        stablecoinState,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /** Burn tokens and open a redemption request keyed by `reference` (max 32 bytes) */
+  async redeem(
+    mint: PublicKey,
+    tokenAccount: PublicKey,
+    amount: number | BN,
+    reference: string
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [redemptionRequest] = findRedemptionRequestPDA(mint, this.wallet, reference);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+    return this.program.methods
+      .redeem(amountBN, reference)
+      .accounts({
+        holder: this.wallet,
+        mint,
+        tokenAccount,
+        stablecoinState,
+        redemptionRequest,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /** Mark a redemption request as fulfilled (authority only) */
+  async fulfillRedemption(mint: PublicKey, holder: PublicKey, reference: string): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [redemptionRequest] = findRedemptionRequestPDA(mint, holder, reference);
+
+    return this.program.methods
+      .fulfillRedemption()
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        redemptionRequest,
+      })
+      .rpc();
+  }
+
+  /** Transfer tokens between accounts */
+  async transfer(
+    mint: PublicKey,
+    from: PublicKey,
+    to: PublicKey,
+    amount: number | BN,
+    memo?: string,
+    destinationOwner?: PublicKey,
+    allowlistProof?: Buffer[],
+    withKycRecord?: boolean,
+    withLimitExemption?: boolean
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+    // SSS-2 coins require both blacklist PDAs; pass the destination owner to include them
+    return this.program.methods
+      .transfer(amountBN, memo ?? null, allowlistProof ? allowlistProof.map((node) => [...node]) : null)
+      .accounts({
+        owner: this.wallet,
+        mint,
+        from,
+        to,
+        stablecoinState,
+        sourceBlacklist: destinationOwner ? findBlacklistEntryPDA(mint, this.wallet)[0] : null,
+        destinationBlacklist: destinationOwner ? findBlacklistEntryPDA(mint, destinationOwner)[0] : null,
+        sourceActivity: null,
+        destinationActivity: null,
+        sourceKyc: withKycRecord ? findKycRecordPDA(mint, this.wallet)[0] : null,
+        sourceLock: findMintLockPDA(mint, from)[0],
+        sourceExemption: withLimitExemption ? findLimitExemptionPDA(mint, this.wallet)[0] : null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /** Transfer tokens, charging the mint's Token-2022 transfer fee explicitly (same accounts as `transfer`) */
+  async transferWithFee(
+    mint: PublicKey,
+    from: PublicKey,
+    to: PublicKey,
+    amount: number | BN,
+    memo?: string,
+    destinationOwner?: PublicKey,
+    allowlistProof?: Buffer[],
+    withKycRecord?: boolean,
+    withLimitExemption?: boolean
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+    return this.program.methods
+      .transferWithFee(amountBN, memo ?? null, allowlistProof ? allowlistProof.map((node) => [...node]) : null)
+      .accounts({
+        owner: this.wallet,
+        mint,
+        from,
+        to,
+        stablecoinState,
+        sourceBlacklist: destinationOwner ? findBlacklistEntryPDA(mint, this.wallet)[0] : null,
+        destinationBlacklist: destinationOwner ? findBlacklistEntryPDA(mint, destinationOwner)[0] : null,
+        sourceActivity: null,
+        destinationActivity: null,
+        sourceKyc: withKycRecord ? findKycRecordPDA(mint, this.wallet)[0] : null,
+        sourceLock: findMintLockPDA(mint, from)[0],
+        sourceExemption: withLimitExemption ? findLimitExemptionPDA(mint, this.wallet)[0] : null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /**
+   * Transfer from one token account to several recipients in one instruction.
+   * SSS-2 coins need each recipient's `owner` so its blacklist PDA can be passed.
+   */
+  async transferBatch(
+    mint: PublicKey,
+    from: PublicKey,
+    recipients: { to: PublicKey; amount: number | BN; owner?: PublicKey }[],
+    memo?: string,
+    withKycRecord?: boolean,
+    withLimitExemption?: boolean
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const withBlacklist = recipients.every((recipient) => recipient.owner !== undefined);
+
+    return this.program.methods
+      .transferBatch(
+        recipients.map(({ amount }) => (typeof amount === "number" ? new BN(amount) : amount)),
+        memo ?? null
+      )
+      .accounts({
+        owner: this.wallet,
+        mint,
+        from,
+        stablecoinState,
+        sourceBlacklist: withBlacklist ? findBlacklistEntryPDA(mint, this.wallet)[0] : null,
+        sourceActivity: null,
+        sourceKyc: withKycRecord ? findKycRecordPDA(mint, this.wallet)[0] : null,
+        sourceLock: findMintLockPDA(mint, from)[0],
+        sourceExemption: withLimitExemption ? findLimitExemptionPDA(mint, this.wallet)[0] : null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        recipients.flatMap(({ to, owner }) => [
+          { pubkey: to, isWritable: true, isSigner: false },
+          ...(withBlacklist && owner
+            ? [{ pubkey: findBlacklistEntryPDA(mint, owner)[0], isWritable: false, isSigner: false }]
+            : []),
+        ])
+      )
+      .rpc();
+  }
+
+  // ── Freeze Operations ──
+
+  /** Freeze a token account */
+  async freezeAccount(mint: PublicKey, tokenAccount: PublicKey, reason: string = ""): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [frozenRecord] = findFrozenAccountRecordPDA(mint, tokenAccount);
+
+    return this.program.methods
+      .freezeAccount(reason)
+      .accounts({
+        freezeAuthority: this.wallet,
+        mint,
+        tokenAccount,
+        stablecoinState,
+        frozenRecord,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+
+  /** Register a new token account; frozen if the stablecoin defaults accounts to frozen */
+  async registerAccount(mint: PublicKey, tokenAccount: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .registerAccount()
+      .accounts({
+        freezeAuthority: this.wallet,
+        mint,
+        tokenAccount,
+        stablecoinState,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /** Approve a token account to receive transfers while accounts are frozen by default (SSS-2 only) */
+  async approveAccount(mint: PublicKey, tokenAccount: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [thawApproval] = findThawApprovalPDA(mint, tokenAccount);
+
+    return this.program.methods
+      .approveAccount()
+      .accounts({
+        freezeAuthority: this.wallet,
+        mint,
+        tokenAccount,
+        stablecoinState,
+        thawApproval,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Thaw (unfreeze) a token account.
+   * For SSS-2 pass the account `owner` so their blacklist entry can be checked;
+   * blacklisted owners must be removed from the blacklist first.
+   */
+  async thawAccount(mint: PublicKey, tokenAccount: PublicKey, owner?: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [frozenRecordPDA] = findFrozenAccountRecordPDA(mint, tokenAccount);
+    // Accounts frozen by default or by compliance have no record
+    const frozenRecord = (await this.program.provider.connection.getAccountInfo(frozenRecordPDA))
+      ? frozenRecordPDA
+      : null;
+
+    return this.program.methods
+      .thawAccount()
+      .accounts({
+        freezeAuthority: this.wallet,
+        mint,
+        tokenAccount,
+        stablecoinState,
+        frozenRecord,
+        ownerBlacklist: owner ? findBlacklistEntryPDA(mint, owner)[0] : null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+
+  // ── SSS-2: Compliance Operations ──
+
+  /**
+   * Add an address to the blacklist with an audit reason (SSS-2 only).
+   * With `freezeOnBlacklist` enabled, pass `freezeTokenAccount`; the wallet must also be the freeze authority.
+   */
+  async blacklistAdd(
+    mint: PublicKey,
+    address: PublicKey,
+    reasonCode: number = 0,
+    note: string = "",
+    expiresAt: number | null = null,
+    freezeTokenAccount?: PublicKey
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [blacklistEntry] = findBlacklistEntryPDA(mint, address);
+    const freeze = freezeTokenAccount !== undefined;
+
+    return this.program.methods
+      .blacklistAdd(address, reasonCode, note, expiresAt === null ? null : new BN(expiresAt))
+      .accounts({
+        blacklistAuthority: this.wallet,
+        mint,
+        stablecoinState,
+        blacklistEntry,
+        tokenAccount: freeze ? freezeTokenAccount : null,
+        freezeAuthority: freeze ? this.wallet : null,
+        tokenProgram: freeze ? TOKEN_2022_PROGRAM_ID : null,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /** Remove an address from the blacklist, optionally thawing its token account (SSS-2 only) */
+  async blacklistRemove(
+    mint: PublicKey,
+    address: PublicKey,
+    thawTokenAccount?: PublicKey
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [blacklistEntry] = findBlacklistEntryPDA(mint, address);
+    const thawOnRemove = thawTokenAccount !== undefined;
+
+    return this.program.methods
+      .blacklistRemove(address, thawOnRemove)
+      .accounts({
+        blacklistAuthority: this.wallet,
+        mint,
+        stablecoinState,
+        blacklistEntry,
+        tokenAccount: thawOnRemove ? thawTokenAccount : null,
+        freezeAuthority: thawOnRemove ? this.wallet : null,
+        tokenProgram: thawOnRemove ? TOKEN_2022_PROGRAM_ID : null,
+      })
+      .rpc();
+  }
+
+  /** Create the blacklist bitmap used by the hook once `bitmapBlacklist` is enabled (SSS-2 only) */
+  async initBlacklistBitmap(mint: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [blacklistBitmap] = findBlacklistBitmapPDA(mint);
+
+    return this.program.methods
+      .initBlacklistBitmap()
+      .accounts({
+        blacklistAuthority: this.wallet,
+        mint,
+        stablecoinState,
+        blacklistBitmap,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /** Flag (or clear) an address's bit in the blacklist bitmap (SSS-2 only) */
+  async setBitmapBlacklisted(mint: PublicKey, address: PublicKey, flagged: boolean): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [blacklistBitmap] = findBlacklistBitmapPDA(mint);
+    const method = flagged ? "bitmapBlacklistAdd" : "bitmapBlacklistRemove";
+
+    return this.program.methods[method](address)
+      .accounts({
+        blacklistAuthority: this.wallet,
+        mint,
+        stablecoinState,
+        blacklistBitmap,
+      })
+      .rpc();
+  }
+
+  /** Deactivate the blacklist entries for `addresses` in one call (SSS-2, authority only; max 15) */
+  async clearAllBlacklist(mint: PublicKey, addresses: PublicKey[]): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .clearAllBlacklist()
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+      })
+      .remainingAccounts(
+        addresses.map((address) => ({
+          pubkey: findBlacklistEntryPDA(mint, address)[0],
+          isWritable: true,
+          isSigner: false,
+        }))
+      )
+      .rpc();
+  }
+
+  /**
+   * Store a keccak commitment to the blacklisted `addresses` on the state (SSS-2 only).
+   * Pass every active entry, expired ones included: the digest is only stored once all
+   * of them are visited, and the program leaves the expired ones out of it. Addresses
+   * are sorted here and sent in pages of `pageSize` after `begin_blacklist_digest`.
+   * Returns the signature of the last transaction.
+   */
+  async computeBlacklistDigest(
+    mint: PublicKey,
+    addresses: PublicKey[],
+    pageSize: number = 20
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const accounts = { blacklistAuthority: this.wallet, mint, stablecoinState };
+    const sorted = [...addresses].sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+
+    let signature = await this.program.methods.beginBlacklistDigest().accounts(accounts).rpc();
+    for (let i = 0; i < sorted.length; i += pageSize) {
+      signature = await this.program.methods
+        .computeBlacklistDigest()
+        .accounts(accounts)
+        .remainingAccounts(
+          sorted.slice(i, i + pageSize).map((address) => ({
+            pubkey: findBlacklistEntryPDA(mint, address)[0],
+            isWritable: false,
+            isSigner: false,
+          }))
+        )
+        .rpc();
+    }
+    return signature;
+  }
+
+  /** Seize tokens from a blacklisted account (SSS-2 only) */
+  async seizeTokens(
+    mint: PublicKey,
+    targetAccount: PublicKey,
+    destinationAccount: PublicKey,
+    targetOwner: PublicKey,
+    amount: number | BN,
+    reason: string,
+    seizeAll: boolean = false
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+    const [blacklistEntry] = findBlacklistEntryPDA(mint, targetOwner);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+    const seizedAt = new BN(Math.floor(Date.now() / 1000));
+    const [seizureRecord] = findSeizureRecordPDA(mint, targetAccount, seizedAt);
+
+    return this.program.methods
+      .seizeTokens(amountBN, seizeAll, reason, seizedAt)
+      .accounts({
+        complianceOfficer: this.wallet,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+        blacklistEntry,
+        targetAccount,
+        destinationAccount,
+        seizureRecord,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Propose a seizure for a second compliance officer to approve (required once
+   * `requireDualSeize` is set); resolves to the `seizedAt` that keys the proposal.
+   */
+  async proposeSeizure(
+    mint: PublicKey,
+    targetAccount: PublicKey,
+    destinationAccount: PublicKey,
+    targetOwner: PublicKey,
+    amount: number | BN,
+    reason: string,
+    seizeAll: boolean = false
+  ): Promise<BN> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+    const [blacklistEntry] = findBlacklistEntryPDA(mint, targetOwner);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+    const seizedAt = new BN(Math.floor(Date.now() / 1000));
+    const [pendingSeizure] = findPendingSeizurePDA(mint, targetAccount, seizedAt);
+
+    await this.program.methods
+      .proposeSeizure(amountBN, seizeAll, reason, seizedAt)
+      .accounts({
+        complianceOfficer: this.wallet,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+        blacklistEntry,
+        targetAccount,
+        destinationAccount,
+        pendingSeizure,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    return seizedAt;
+  }
+
+  /** Approve and execute a proposed seizure; the caller must not be the proposer */
+  async approveSeizure(
+    mint: PublicKey,
+    targetAccount: PublicKey,
+    targetOwner: PublicKey,
+    seizedAt: BN
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+    const [blacklistEntry] = findBlacklistEntryPDA(mint, targetOwner);
+    const [pendingSeizure] = findPendingSeizurePDA(mint, targetAccount, seizedAt);
+    const [seizureRecord] = findSeizureRecordPDA(mint, targetAccount, seizedAt);
+    const { proposedBy, destination } = await this.program.account.pendingSeizure.fetch(pendingSeizure);
+
+    return this.program.methods
+      .approveSeizure(seizedAt)
+      .accounts({
+        complianceOfficer: this.wallet,
+        proposer: proposedBy,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+        blacklistEntry,
+        targetAccount,
+        destinationAccount: destination,
+        pendingSeizure,
+        seizureRecord,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /** Move tokens between two accounts under legal order (SSS-2 only) */
+  async forceTransfer(
+    mint: PublicKey,
+    source: PublicKey,
+    destination: PublicKey,
+    amount: number | BN
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+    return this.program.methods
+      .forceTransfer(amountBN)
+      .accounts({
+        complianceOfficer: this.wallet,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+        source,
+        destination,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /** Burn seized tokens held in a treasury account (SSS-2 only) */
+  async burnSeized(
+    mint: PublicKey,
+    treasury: PublicKey,
+    amount: number | BN
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+    return this.program.methods
+      .burnSeized(amountBN)
+      .accounts({
+        complianceOfficer: this.wallet,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+        treasury,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /**
+   * Return seized tokens from `treasury` to a rightful owner's token account
+   * (SSS-2, authority only). Pass `coSigner`, a different compliance officer,
+   * while `requireDualSeize` is set.
+   */
+  async reissueSeized(
+    mint: PublicKey,
+    seizureRecord: PublicKey,
+    treasury: PublicKey,
+    recipientAccount: PublicKey,
+    recipientOwner: PublicKey,
+    amount: number | BN,
+    coSigner?: Keypair
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+    const [reissueRecord] = findReissueRecordPDA(mint, seizureRecord);
+    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+    return this.program.methods
+      .reissueSeized(amountBN)
+      .accounts({
+        authority: this.wallet,
+        coSigner: coSigner ? coSigner.publicKey : null,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+        seizureRecord,
+        treasury,
+        recipientAccount,
+        recipientBlacklist: findBlacklistEntryPDA(mint, recipientOwner)[0],
+        reissueRecord,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(coSigner ? [coSigner] : [])
+      .rpc();
+  }
+
+  /** Record an owner's verified KYC tier (compliance officer only) */
+  async kycSetTier(mint: PublicKey, owner: PublicKey, tier: number): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+    const [kycRecord] = findKycRecordPDA(mint, owner);
+
+    return this.program.methods
+      .kycSetTier(owner, tier)
+      .accounts({
+        complianceOfficer: this.wallet,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+        kycRecord,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /** Block an owner's transfers pending review, optionally until `expiresAt` (unix seconds; SSS-2 only) */
+  async placeHold(mint: PublicKey, owner: PublicKey, reason: string, expiresAt?: number | BN): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+    const [complianceHold] = findComplianceHoldPDA(mint, owner);
+    const expiresBN = typeof expiresAt === "number" ? new BN(expiresAt) : expiresAt ?? null;
+
+    return this.program.methods
+      .placeHold(owner, reason, expiresBN)
+      .accounts({
+        complianceOfficer: this.wallet,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+        complianceHold,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /** Lift an owner's compliance hold (SSS-2 only) */
+  async releaseHold(mint: PublicKey, owner: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+    const [complianceHold] = findComplianceHoldPDA(mint, owner);
+
+    return this.program.methods
+      .releaseHold(owner)
+      .accounts({
+        complianceOfficer: this.wallet,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+        complianceHold,
+      })
+      .rpc();
+  }
+
+  /** Set (or clear with `null`) the owner that seized tokens must be sent to (SSS-2 only) */
+  async setSeizureTreasury(mint: PublicKey, treasury: PublicKey | null): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .setSeizureTreasury(treasury)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+      })
+      .rpc();
+  }
+
+  /** Exempt an owner (e.g. treasury) from transfer hook list checks */
+  async addExemptAddress(mint: PublicKey, address: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .addExemptAddress(address)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+      })
+      .rpc();
+  }
+
+  /** Remove an owner's transfer hook exemption */
+  async removeExemptAddress(mint: PublicKey, address: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .removeExemptAddress(address)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+      })
+      .rpc();
+  }
+
+  /** Set (or clear with `null`) the price oracle checked before minting */
+  async setOracle(
+    mint: PublicKey,
+    oracle: PublicKey | null,
+    maxPegDeviationBps: number
+  ): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .setOracle(oracle, maxPegDeviationBps)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Set (or clear with `null`) the cap on a single mint, burn, transfer or seizure */
+  async setMaxSingleOpAmount(mint: PublicKey, maxSingleOpAmount: number | BN | null): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const maxBN = typeof maxSingleOpAmount === "number" ? new BN(maxSingleOpAmount) : maxSingleOpAmount;
+
+    return this.program.methods
+      .setMaxSingleOpAmount(maxBN)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Set (or clear with `null`) the rolling 24h cap on seized tokens */
+  async setDailySeizeLimit(mint: PublicKey, dailySeizeLimit: number | BN | null): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const limitBN = typeof dailySeizeLimit === "number" ? new BN(dailySeizeLimit) : dailySeizeLimit;
+
+    return this.program.methods
+      .setDailySeizeLimit(limitBN)
+      .accounts({
+        authority: this.wallet,
         mint,
         stablecoinState,
-        blacklistEntry,
+        changelog: await this.findChangeLog(mint),
       })
       .rpc();
   }
 
-  /** Seize tokens from a blacklisted account (SSS-2 only) */
-  async seizeTokens(
+  /** Set (or clear with `null`) the minimum seconds between two seizures */
+  async setSeizeCooldown(mint: PublicKey, seizeCooldown: number | BN | null): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const cooldownBN = typeof seizeCooldown === "number" ? new BN(seizeCooldown) : seizeCooldown;
+
+    return this.program.methods
+      .setSeizeCooldown(cooldownBN)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Set the maximum recipients per `transferBatch` (0 restores the default of 15) */
+  async setTransferBatchSize(mint: PublicKey, maxRecipients: number): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .setTransferBatchSize(maxRecipients)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Set the issuance fee (basis points) on `mintTo` / `mintToNew` and the token account it goes to */
+  async setMintFee(mint: PublicKey, basisPoints: number, collector: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .setMintFee(basisPoints, collector)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Choose how the issuance fee is rounded; the transfer fee always rounds up */
+  async setFeeRounding(mint: PublicKey, policy: RoundingPolicy): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .setFeeRounding(policy)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /**
+   * Set (or clear with `null`) the smallest non-zero balance a transfer may leave in the
+   * source; `sweepAll` moves the whole balance instead, `reject` refuses the transfer
+   */
+  async setMinAccountBalance(
     mint: PublicKey,
-    targetAccount: PublicKey,
-    treasuryAccount: PublicKey,
-    targetOwner: PublicKey,
-    amount: number | BN
+    minimum: number | BN | null,
+    policy: DustPolicy = { reject: {} }
   ): Promise<string> {
     const [stablecoinState] = findStablecoinStatePDA(mint);
-    const [blacklistEntry] = findBlacklistEntryPDA(mint, targetOwner);
-    const amountBN = typeof amount === "number" ? new BN(amount) : amount;
 
     return this.program.methods
-      .seizeTokens(amountBN)
+      .setMinAccountBalance(minimum === null ? null : typeof minimum === "number" ? new BN(minimum) : minimum, policy)
       .accounts({
-        complianceOfficer: this.wallet,
+        authority: this.wallet,
         mint,
         stablecoinState,
-        blacklistEntry,
-        targetAccount,
-        treasuryAccount,
-        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Set the transfer amounts above which KYC tiers 1..3 are required (0 = unused) */
+  async setKycThresholds(mint: PublicKey, thresholds: (number | BN)[]): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .setKycThresholds(thresholds.map((threshold) => (typeof threshold === "number" ? new BN(threshold) : threshold)))
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Turn mint, burn and transfer events on or off (compliance events always emit) */
+  async setEventEmission(mint: PublicKey, enabled: boolean): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .setEventEmission(enabled)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Permanently lock metadata, feature flags and limits (irreversible) */
+  async lockConfig(mint: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .lockConfig()
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Create the ring buffer of recent admin actions (authority only) */
+  async initChangelog(mint: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [changelog] = findChangeLogPDA(mint);
+
+    return this.program.methods
+      .initChangelog()
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        changelog,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
 
+  /** Change log PDA to pass to admin instructions, or null if the log was never created */
+  private async findChangeLog(mint: PublicKey): Promise<PublicKey | null> {
+    const state = await this.getStablecoinState(mint);
+    return state.changelogEnabled ? findChangeLogPDA(mint)[0] : null;
+  }
+
   // ── Role Management ──
 
-  /** Update a role assignment */
-  async updateRole(
+  /** Create the compliance officer list, seeded with the current officer */
+  async initComplianceOfficers(mint: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+
+    return this.program.methods
+      .initComplianceOfficers()
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /** Add a compliance officer to the list */
+  async addComplianceOfficer(mint: PublicKey, officer: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+
+    return this.program.methods
+      .addComplianceOfficer(officer)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+      })
+      .rpc();
+  }
+
+  /** Remove a compliance officer from the list */
+  async removeComplianceOfficer(mint: PublicKey, officer: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [complianceOfficers] = findComplianceOfficersPDA(mint);
+
+    return this.program.methods
+      .removeComplianceOfficer(officer)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        complianceOfficers,
+      })
+      .rpc();
+  }
+
+  /** Propose a role reassignment (applied immediately when the role-change delay is 0) */
+  async proposeRoleChange(
     mint: PublicKey,
     role: Role,
     newAuthority: PublicKey
   ): Promise<string> {
     const [stablecoinState] = findStablecoinStatePDA(mint);
-    const roleArg = { [role.toLowerCase()]: {} };
+    const [pendingRoleChange] = findPendingRoleChangePDA(mint, role);
+    const roleArg = { [role.charAt(0).toLowerCase() + role.slice(1)]: {} };
+
+    return this.program.methods
+      .proposeRoleChange(roleArg, newAuthority)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        pendingRoleChange,
+        systemProgram: SystemProgram.programId,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Execute a proposed role change once its timelock has elapsed */
+  async executeRoleChange(mint: PublicKey, role: Role): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [pendingRoleChange] = findPendingRoleChangePDA(mint, role);
+    const roleArg = { [role.charAt(0).toLowerCase() + role.slice(1)]: {} };
+
+    return this.program.methods
+      .executeRoleChange(roleArg)
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        pendingRoleChange,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Point the Token-2022 mint authority at the stablecoin's mint authority (signed by the current holder) */
+  async syncMintAuthority(mint: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .syncMintAuthority()
+      .accounts({
+        currentAuthority: this.wallet,
+        mint,
+        stablecoinState,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /** Point the Token-2022 freeze authority at the stablecoin's freeze authority (signed by the current holder) */
+  async syncFreezeAuthority(mint: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .syncFreezeAuthority()
+      .accounts({
+        currentAuthority: this.wallet,
+        mint,
+        stablecoinState,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /** Hand the Token-2022 freeze authority to the stablecoin PDA (signed by the current holder) */
+  async delegateFreezeAuthority(mint: PublicKey): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .delegateFreezeAuthority()
+      .accounts({
+        currentAuthority: this.wallet,
+        mint,
+        stablecoinState,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  /**
+   * Hand the authority to the PDA `governanceProgram` derives from `seeds` (authority only).
+   * Authority actions must then be invoked by the governance program via CPI; resolves to the PDA.
+   */
+  async setProgramAuthority(mint: PublicKey, governanceProgram: PublicKey, seeds: Buffer[]): Promise<PublicKey> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [newAuthority, bump] = PublicKey.findProgramAddressSync(seeds, governanceProgram);
+
+    await this.program.methods
+      .setProgramAuthority(newAuthority, [...seeds, Buffer.from([bump])])
+      .accounts({
+        authority: this.wallet,
+        mint,
+        stablecoinState,
+        governanceProgram,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+    return newAuthority;
+  }
+
+  // ── Multisig ──
+
+  /** Hand the authority to an M-of-N multisig of `signers` (authority only) */
+  async initMultisig(mint: PublicKey, signers: PublicKey[], threshold: number): Promise<string> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [multisig] = findMultisigPDA(mint);
 
     return this.program.methods
-      .updateRole(roleArg, newAuthority)
+      .initMultisig(signers, threshold)
       .accounts({
         authority: this.wallet,
         mint,
         stablecoinState,
+        multisig,
+        systemProgram: SystemProgram.programId,
+        changelog: await this.findChangeLog(mint),
+      })
+      .rpc();
+  }
+
+  /** Propose a multisig action; resolves to the new action's id */
+  async proposeAction(mint: PublicKey, action: MultisigAction): Promise<BN> {
+    const [multisig] = findMultisigPDA(mint);
+    const { actionCount } = await this.program.account.multisigConfig.fetch(multisig);
+    const [pendingAction] = findPendingActionPDA(mint, actionCount as BN);
+
+    await this.program.methods
+      .proposeAction(action)
+      .accounts({
+        proposer: this.wallet,
+        mint,
+        multisig,
+        pendingAction,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    return actionCount as BN;
+  }
+
+  /** Approve a pending multisig action */
+  async approveAction(mint: PublicKey, actionId: number | BN): Promise<string> {
+    const idBN = typeof actionId === "number" ? new BN(actionId) : actionId;
+    const [multisig] = findMultisigPDA(mint);
+    const [pendingAction] = findPendingActionPDA(mint, idBN);
+
+    return this.program.methods
+      .approveAction(idBN)
+      .accounts({
+        approver: this.wallet,
+        mint,
+        multisig,
+        pendingAction,
+      })
+      .rpc();
+  }
+
+  /** Execute a multisig action that has reached its threshold */
+  async executeAction(mint: PublicKey, actionId: number | BN): Promise<string> {
+    const idBN = typeof actionId === "number" ? new BN(actionId) : actionId;
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [multisig] = findMultisigPDA(mint);
+    const [pendingAction] = findPendingActionPDA(mint, idBN);
+
+    return this.program.methods
+      .executeAction(idBN)
+      .accounts({
+        executor: this.wallet,
+        mint,
+        stablecoinState,
+        multisig,
+        pendingAction,
+        changelog: await this.findChangeLog(mint),
       })
       .rpc();
   }
@@ -395,15 +2076,55 @@ export class SSSClient {
     return this.program.account.stablecoinState.fetch(stablecoinStatePDA) as Promise<StablecoinState>;
   }
 
-  /** Check if an address is blacklisted */
+  /** Read a layout-independent status snapshot via a simulated get_state call */
+  async getStatus(mint: PublicKey): Promise<StablecoinStatus> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .getState()
+      .accounts({ mint, stablecoinState })
+      .view() as Promise<StablecoinStatus>;
+  }
+
+  /** Read the mint/burn/freeze/seize operation counters via simulation */
+  async getOperationCounts(mint: PublicKey): Promise<OperationCounts> {
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+
+    return this.program.methods
+      .getOperationCounts()
+      .accounts({ mint, stablecoinState })
+      .view() as Promise<OperationCounts>;
+  }
+
+  /** Check if an address is currently blacklisted (honours expiry) via a simulated is_blacklisted call */
   async isBlacklisted(mint: PublicKey, address: PublicKey): Promise<boolean> {
-    const [blacklistEntryPDA] = findBlacklistEntryPDA(mint, address);
-    try {
-      const entry = await this.program.account.blacklistEntry.fetch(blacklistEntryPDA);
-      return (entry as any).isActive;
-    } catch {
-      return false;
-    }
+    const [stablecoinState] = findStablecoinStatePDA(mint);
+    const [blacklistEntry] = findBlacklistEntryPDA(mint, address);
+
+    return this.program.methods
+      .isBlacklisted(address)
+      .accounts({ mint, stablecoinState, blacklistEntry })
+      .view() as Promise<boolean>;
+  }
+
+  /** Read the recorded admin actions, oldest first, via a simulated get_changelog call */
+  async getChangelog(mint: PublicKey): Promise<ChangeLogEntry[]> {
+    const [changelog] = findChangeLogPDA(mint);
+
+    return this.program.methods
+      .getChangelog()
+      .accounts({ mint, changelog })
+      .view() as Promise<ChangeLogEntry[]>;
+  }
+
+  /** Read the cumulative amount and number of mints into a token account via simulation */
+  async getMintStats(mint: PublicKey, tokenAccount: PublicKey): Promise<MintStats> {
+    const [mintStats] = findMintStatsPDA(mint, tokenAccount);
+
+    return this.program.methods
+      .getMintStats(tokenAccount)
+      .accounts({ mint, mintStats })
+      .view() as Promise<MintStats>;
   }
 
   /** Get the net supply (minted - burned) */
@@ -423,5 +2144,6 @@ export {
   createSSS2Config,
   findStablecoinStatePDA,
   findBlacklistEntryPDA,
+  findComplianceOfficersPDA,
   PROGRAM_ID,
 };
@@ -1,8 +1,31 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, PublicKey, SystemProgram, Transaction, TransactionInstruction } from "@solana/web3.js";
+import {
+    TOKEN_2022_PROGRAM_ID,
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    getAccount,
+    createAssociatedTokenAccountInstruction,
+    createInitializeMintInstruction,
+    createInitializePermanentDelegateInstruction,
+    createInitializeTransferFeeConfigInstruction,
+    createInitializeTransferHookInstruction,
+    createSetAuthorityInstruction,
+    createTransferCheckedWithTransferHookInstruction,
+    AuthorityType,
+    ExtensionType,
+    getMintLen,
+    getTransferFeeAmount,
+    getTransferFeeConfig,
+    getAssociatedTokenAddressSync,
+    getMint,
+    addExtraAccountMetasForExecute,
+    getExtraAccountMetaAddress,
+    getExtraAccountMetas,
+    resolveExtraAccountMeta,
+} from "@solana/spl-token";
 import { assert } from "chai";
+import { createHash } from "crypto";
 
 /**
  * Integration tests for the Solana Stablecoin Standard (SSS)
@@ -22,6 +45,90 @@ describe("Solana Stablecoin Standard", () => {
     const program = anchor.workspace.Sss;
     const authority = provider.wallet;
 
+    const findStablecoinState = (mint: PublicKey): PublicKey =>
+        PublicKey.findProgramAddressSync(
+            [Buffer.from("stablecoin"), mint.toBuffer()],
+            program.programId
+        )[0];
+
+    const findPendingRoleChange = (mint: PublicKey, roleBit: number): PublicKey =>
+        PublicKey.findProgramAddressSync(
+            [Buffer.from("role-change"), mint.toBuffer(), Buffer.from([roleBit])],
+            program.programId
+        )[0];
+
+    const findComplianceOfficers = (mint: PublicKey): PublicKey =>
+        PublicKey.findProgramAddressSync(
+            [Buffer.from("compliance-officers"), mint.toBuffer()],
+            program.programId
+        )[0];
+
+    const findSymbolRegistry = (symbol: string): PublicKey =>
+        PublicKey.findProgramAddressSync(
+            [Buffer.from("symbol"), Buffer.from(symbol)],
+            program.programId
+        )[0];
+
+    // Fixture accounts preloaded from tests/fixtures/accounts (see scripts/generate-state-fixtures.ts)
+    const fixtureKeypair = (label: string) => Keypair.fromSeed(createHash("sha256").update(label).digest());
+
+    const sss1Config = (name: string, symbol: string) => ({
+        name,
+        symbol,
+        uri: "",
+        decimals: 6,
+        enablePermanentDelegate: false,
+        enableTransferHook: false,
+        defaultAccountFrozen: false,
+        allowlistMode: false,
+        freezeOnBlacklist: false,
+        nonTransferable: false,
+        mintAuthority: null,
+        freezeAuthority: null,
+        complianceOfficer: null,
+        maxTransferAmount: null,
+        transferFeeBasisPoints: 0,
+        maxTransferFee: new anchor.BN(0),
+        roleChangeDelay: new anchor.BN(0),
+        mintAuthorityRotationCooldown: new anchor.BN(0),
+    });
+
+    const sss2Config = (name: string, symbol: string) => ({
+        ...sss1Config(name, symbol),
+        enablePermanentDelegate: true,
+        enableTransferHook: true,
+        defaultAccountFrozen: true,
+    });
+
+    const initStablecoin = async (mint: Keypair, config: Record<string, unknown>) => {
+        await program.methods
+            .initialize(config)
+            .accounts({
+                authority: authority.publicKey,
+                mint: mint.publicKey,
+                stablecoinState: findStablecoinState(mint.publicKey),
+                symbolRegistry: findSymbolRegistry(config.symbol as string),
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            })
+            .signers([mint])
+            .rpc();
+    };
+
+    const initComplianceOfficers = async (mint: Keypair) => {
+        await program.methods
+            .initComplianceOfficers()
+            .accounts({
+                authority: authority.publicKey,
+                mint: mint.publicKey,
+                stablecoinState: findStablecoinState(mint.publicKey),
+                complianceOfficers: findComplianceOfficers(mint.publicKey),
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+    };
+
     // ─────────────────────────────────────────────────
     // SSS-1: Minimal Stablecoin Tests
     // ─────────────────────────────────────────────────
@@ -47,6 +154,17 @@ describe("Solana Stablecoin Standard", () => {
                 enablePermanentDelegate: false,
                 enableTransferHook: false,
                 defaultAccountFrozen: false,
+                allowlistMode: false,
+                freezeOnBlacklist: false,
+                nonTransferable: false,
+                mintAuthority: null,
+                freezeAuthority: null,
+                complianceOfficer: null,
+                maxTransferAmount: null,
+                transferFeeBasisPoints: 0,
+                maxTransferFee: new anchor.BN(0),
+                roleChangeDelay: new anchor.BN(0),
+                mintAuthorityRotationCooldown: new anchor.BN(0),
             };
 
             await program.methods
@@ -55,6 +173,7 @@ describe("Solana Stablecoin Standard", () => {
                     authority: authority.publicKey,
                     mint: mint.publicKey,
                     stablecoinState: stablecoinStatePDA,
+                    symbolRegistry: findSymbolRegistry(config.symbol),
                     tokenProgram: TOKEN_2022_PROGRAM_ID,
                     systemProgram: SystemProgram.programId,
                     rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -65,6 +184,7 @@ describe("Solana Stablecoin Standard", () => {
             const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
             assert.equal(state.name, "Test Dollar");
             assert.equal(state.symbol, "TSTD");
+            assert.equal(state.uri, "https://example.com/metadata.json");
             assert.equal(state.decimals, 6);
             assert.isFalse(state.isSss2);
             assert.isFalse(state.permanentDelegateEnabled);
@@ -78,12 +198,18 @@ describe("Solana Stablecoin Standard", () => {
             const amount = new anchor.BN(1_000_000); // 1 TSTD
 
             await program.methods
-                .mintTo(amount)
+                .mintTo(amount, null)
                 .accounts({
                     mintAuthority: authority.publicKey,
                     mint: mint.publicKey,
                     // tokenAccount: recipientTokenAccount,
                     stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    feeCollector: null,
+                    mintStats: null,
                     tokenProgram: TOKEN_2022_PROGRAM_ID,
                 })
                 .rpc();
@@ -100,223 +226,9875 @@ describe("Solana Stablecoin Standard", () => {
             assert.ok(true, "Transfer test placeholder");
         });
 
-        it("burns tokens", async () => {
-            const amount = new anchor.BN(250_000); // 0.25 TSTD
-
-            // Burn would be tested with actual token accounts
-            assert.ok(true, "Burn test placeholder");
-        });
-
-        it("freezes and thaws a token account", async () => {
-            // Freeze/thaw test
-            assert.ok(true, "Freeze/thaw test placeholder");
-        });
+        const transferFromAuthority = (to: PublicKey) => {
+            const from = getAssociatedTokenAddressSync(mint.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID);
+            return program.methods
+                .transfer(new anchor.BN(100), null, null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    from,
+                    to,
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    destinationBlacklist: null,
+                    sourceActivity: null,
+                    destinationActivity: null,
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+        };
 
-        it("rejects minting from non-authority", async () => {
-            const fakeAuthority = Keypair.generate();
-            const amount = new anchor.BN(1_000_000);
+        it("rejects a transfer to the same account", async () => {
+            const ata = getAssociatedTokenAddressSync(mint.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID);
+            await program.methods
+                .mintToNew(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner: authority.publicKey,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
 
             try {
-                await program.methods
-                    .mintTo(amount)
-                    .accounts({
-                        mintAuthority: fakeAuthority.publicKey,
-                        mint: mint.publicKey,
-                        stablecoinState: stablecoinStatePDA,
-                        tokenProgram: TOKEN_2022_PROGRAM_ID,
-                    })
-                    .signers([fakeAuthority])
-                    .rpc();
-                assert.fail("Should have thrown Unauthorized error");
+                await transferFromAuthority(ata);
+                assert.fail("Should have thrown SelfTransfer error");
             } catch (err) {
-                assert.include(err.message, "Unauthorized");
+                assert.include(err.message, "SelfTransfer");
             }
         });
 
-        it("rejects zero-amount mint", async () => {
+        it("rejects a transfer to an account owned by the zero address", async () => {
+            const burnAta = getAssociatedTokenAddressSync(mint.publicKey, PublicKey.default, true, TOKEN_2022_PROGRAM_ID);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        burnAta,
+                        PublicKey.default,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+
             try {
-                await program.methods
-                    .mintTo(new anchor.BN(0))
-                    .accounts({
-                        mintAuthority: authority.publicKey,
-                        mint: mint.publicKey,
-                        stablecoinState: stablecoinStatePDA,
-                        tokenProgram: TOKEN_2022_PROGRAM_ID,
-                    })
-                    .rpc();
-                assert.fail("Should have thrown InvalidAmount error");
+                await transferFromAuthority(burnAta);
+                assert.fail("Should have thrown InvalidDestination error");
             } catch (err) {
-                assert.include(err.message, "InvalidAmount");
+                assert.include(err.message, "InvalidDestination");
             }
         });
-    });
 
-    // ─────────────────────────────────────────────────
-    // SSS-2: Compliant Stablecoin Tests
-    // ─────────────────────────────────────────────────
+        it("burns tokens", async () => {
+            const amount = new anchor.BN(250_000); // 0.25 TSTD
 
-    describe("SSS-2 — Compliant Stablecoin", () => {
-        const mint = Keypair.generate();
-        let stablecoinStatePDA: PublicKey;
-        const suspiciousWallet = Keypair.generate();
+            // Burn would be tested with actual token accounts
+            assert.ok(true, "Burn test placeholder");
+        });
 
-        before(async () => {
-            [stablecoinStatePDA] = PublicKey.findProgramAddressSync(
-                [Buffer.from("stablecoin"), mint.publicKey.toBuffer()],
-                program.programId
-            );
+        it("reconciles tracked supply against the mint", async () => {
+            // Out-of-band burn requires a token account; drift is asserted from account data
+            await program.methods
+                .reconcileSupply()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.supplyDrift.toNumber(), 0);
         });
 
-        it("initializes an SSS-2 compliant stablecoin", async () => {
-            const config = {
-                name: "Regulated USD",
-                symbol: "RUSD",
-                uri: "",
-                decimals: 6,
-                enablePermanentDelegate: true,
-                enableTransferHook: true,
-                defaultAccountFrozen: true,
-            };
+        const findAttestation = (period: number) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("reserve"), mint.publicKey.toBuffer(), new anchor.BN(period).toArrayLike(Buffer, "le", 8)],
+                program.programId
+            )[0];
 
-            await program.methods
-                .initialize(config)
+        const publishAttestation = (period: number, reserves: anchor.BN) =>
+            program.methods
+                .publishAttestation(new anchor.BN(period), reserves)
                 .accounts({
                     authority: authority.publicKey,
                     mint: mint.publicKey,
                     stablecoinState: stablecoinStatePDA,
-                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    attestation: findAttestation(period),
                     systemProgram: SystemProgram.programId,
-                    rent: anchor.web3.SYSVAR_RENT_PUBKEY,
                 })
-                .signers([mint])
                 .rpc();
 
+        it("publishes a fully backed reserve attestation", async () => {
             const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
-            assert.isTrue(state.isSss2);
-            assert.isTrue(state.permanentDelegateEnabled);
-            assert.isTrue(state.transferHookEnabled);
-            assert.isTrue(state.defaultAccountFrozen);
+            const netSupply = state.totalMinted.sub(state.totalBurned);
+
+            await publishAttestation(202401, netSupply);
+
+            const attestation = await program.account.reserveAttestation.fetch(findAttestation(202401));
+            assert.equal(attestation.liabilities.toString(), netSupply.toString());
+            assert.ok(attestation.attestedBy.equals(authority.publicKey));
+            assert.isTrue(attestation.reserves.gte(attestation.liabilities));
         });
 
-        it("adds an address to the blacklist", async () => {
-            const [blacklistEntry] = PublicKey.findProgramAddressSync(
-                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), suspiciousWallet.publicKey.toBuffer()],
+        it("publishes an under-backed reserve attestation", async () => {
+            // Relies on the 1_000_000 minted above
+            await publishAttestation(202402, new anchor.BN(500_000));
+
+            const attestation = await program.account.reserveAttestation.fetch(findAttestation(202402));
+            assert.equal(attestation.reserves.toNumber(), 500_000);
+            assert.isFalse(attestation.reserves.gte(attestation.liabilities));
+        });
+
+        const findSnapshot = (epoch: number) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("snapshot"), mint.publicKey.toBuffer(), new anchor.BN(epoch).toArrayLike(Buffer, "le", 8)],
                 program.programId
-            );
+            )[0];
 
-            await program.methods
-                .blacklistAdd(suspiciousWallet.publicKey)
+        const takeSnapshot = (epoch: number) =>
+            program.methods
+                .takeSnapshot(new anchor.BN(epoch))
                 .accounts({
-                    complianceOfficer: authority.publicKey,
+                    authority: authority.publicKey,
                     mint: mint.publicKey,
                     stablecoinState: stablecoinStatePDA,
-                    blacklistEntry,
+                    snapshot: findSnapshot(epoch),
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
 
-            const entry = await program.account.blacklistEntry.fetch(blacklistEntry);
-            assert.isTrue(entry.isActive);
-            assert.ok(entry.blacklistedAddress.equals(suspiciousWallet.publicKey));
+        it("captures a supply snapshot", async () => {
+            await takeSnapshot(20240101);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            const snapshot = await program.account.supplySnapshot.fetch(findSnapshot(20240101));
+            assert.equal(snapshot.epoch.toNumber(), 20240101);
+            assert.equal(snapshot.totalMinted.toString(), state.totalMinted.toString());
+            assert.equal(snapshot.totalBurned.toString(), state.totalBurned.toString());
+            assert.equal(snapshot.netSupply.toString(), state.totalMinted.sub(state.totalBurned).toString());
         });
 
-        it("blocks transfers to a blacklisted address", async () => {
-            // Transfer hook should block this
-            assert.ok(true, "Transfer hook blocking test placeholder");
+        it("keeps earlier snapshots unchanged as supply moves", async () => {
+            const first = await program.account.supplySnapshot.fetch(findSnapshot(20240101));
+
+            await program.methods
+                .mintTo(new anchor.BN(250_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    feeCollector: null,
+                    mintStats: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+            await takeSnapshot(20240102);
+
+            const second = await program.account.supplySnapshot.fetch(findSnapshot(20240102));
+            const unchanged = await program.account.supplySnapshot.fetch(findSnapshot(20240101));
+            assert.equal(second.netSupply.sub(first.netSupply).toNumber(), 250_000);
+            assert.equal(unchanged.netSupply.toString(), first.netSupply.toString());
         });
 
-        it("seizes tokens from a blacklisted account", async () => {
-            // Seize test — requires permanent delegate
-            assert.ok(true, "Seize test placeholder");
+        it("rejects a second snapshot for the same epoch", async () => {
+            try {
+                await takeSnapshot(20240101);
+                assert.fail("Should have failed on the existing snapshot");
+            } catch (err) {
+                // SystemProgram AccountAlreadyInUse from the snapshot `init`
+                assert.include(err.message, "0x0");
+            }
         });
 
-        it("removes an address from the blacklist", async () => {
-            const [blacklistEntry] = PublicKey.findProgramAddressSync(
-                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), suspiciousWallet.publicKey.toBuffer()],
-                program.programId
-            );
+        const redemptionRequest = PublicKey.findProgramAddressSync(
+            [Buffer.from("redemption"), mint.publicKey.toBuffer(), authority.publicKey.toBuffer(), Buffer.from("WIRE-0001")],
+            program.programId
+        )[0];
+
+        const fulfillRedemption = () =>
+            program.methods
+                .fulfillRedemption()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    redemptionRequest,
+                })
+                .rpc();
 
+        it("redeems tokens into a pending redemption request", async () => {
+            const holderAta = getAssociatedTokenAddressSync(mint.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID);
             await program.methods
-                .blacklistRemove(suspiciousWallet.publicKey)
+                .mintToNew(new anchor.BN(1_000), null)
                 .accounts({
-                    complianceOfficer: authority.publicKey,
+                    mintAuthority: authority.publicKey,
                     mint: mint.publicKey,
+                    owner: authority.publicKey,
+                    tokenAccount: holderAta,
                     stablecoinState: stablecoinStatePDA,
-                    blacklistEntry,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
                 })
                 .rpc();
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            const balanceBefore = (await getAccount(provider.connection, holderAta, undefined, TOKEN_2022_PROGRAM_ID)).amount;
 
-            const entry = await program.account.blacklistEntry.fetch(blacklistEntry);
-            assert.isFalse(entry.isActive);
+            await program.methods
+                .redeem(new anchor.BN(400), "WIRE-0001")
+                .accounts({
+                    holder: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: holderAta,
+                    stablecoinState: stablecoinStatePDA,
+                    redemptionRequest,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const request = await program.account.redemptionRequest.fetch(redemptionRequest);
+            assert.ok(request.holder.equals(authority.publicKey));
+            assert.equal(request.amount.toNumber(), 400);
+            assert.equal(request.reference, "WIRE-0001");
+            assert.isFalse(request.fulfilled);
+            assert.isNull(request.fulfilledAt);
+
+            const account = await getAccount(provider.connection, holderAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(balanceBefore - account.amount), 400);
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.totalBurned.sub(before.totalBurned).toNumber(), 400);
         });
 
-        it("rejects blacklist operations on SSS-1 tokens", async () => {
-            // Create SSS-1 token and try blacklist operations
-            assert.ok(true, "SSS-1 blacklist rejection test placeholder");
+        it("fulfills a redemption request", async () => {
+            await fulfillRedemption();
+
+            const request = await program.account.redemptionRequest.fetch(redemptionRequest);
+            assert.isTrue(request.fulfilled);
+            assert.isNotNull(request.fulfilledAt);
+
+            try {
+                await fulfillRedemption();
+                assert.fail("Should have thrown RedemptionAlreadyFulfilled error");
+            } catch (err) {
+                assert.include(err.message, "RedemptionAlreadyFulfilled");
+            }
         });
 
-        it("rejects compliance operations from non-compliance-officer", async () => {
-            const fakeOfficer = Keypair.generate();
-            const [blacklistEntry] = PublicKey.findProgramAddressSync(
-                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), suspiciousWallet.publicKey.toBuffer()],
+        const activityReceiver = Keypair.generate().publicKey;
+        const activityOf = (tokenAccount: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("activity"), mint.publicKey.toBuffer(), tokenAccount.toBuffer()],
                 program.programId
-            );
+            )[0];
 
-            try {
+        const markDormant = (tokenAccount: PublicKey, threshold: number) =>
+            program.methods
+                .markDormant(new anchor.BN(threshold))
+                .accounts({
+                    complianceOfficer: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers: findComplianceOfficers(mint.publicKey),
+                    activity: activityOf(tokenAccount),
+                })
+                .rpc();
+
+        it("updates account activity on transfer", async () => {
+            const from = getAssociatedTokenAddressSync(mint.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID);
+            const to = getAssociatedTokenAddressSync(mint.publicKey, activityReceiver, false, TOKEN_2022_PROGRAM_ID);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        to,
+                        activityReceiver,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+            for (const tokenAccount of [from, to]) {
                 await program.methods
-                    .blacklistAdd(suspiciousWallet.publicKey)
+                    .trackActivity()
                     .accounts({
-                        complianceOfficer: fakeOfficer.publicKey,
+                        payer: authority.publicKey,
                         mint: mint.publicKey,
+                        tokenAccount,
                         stablecoinState: stablecoinStatePDA,
-                        blacklistEntry,
+                        activity: activityOf(tokenAccount),
                         systemProgram: SystemProgram.programId,
                     })
-                    .signers([fakeOfficer])
                     .rpc();
-                assert.fail("Should have thrown Unauthorized error");
-            } catch (err) {
-                assert.include(err.message, "Unauthorized");
             }
-        });
-    });
 
-    // ─────────────────────────────────────────────────
-    // Role Management Tests
-    // ─────────────────────────────────────────────────
+            await program.methods
+                .transfer(new anchor.BN(250), null, null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    from,
+                    to,
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    destinationBlacklist: null,
+                    sourceActivity: activityOf(from),
+                    destinationActivity: activityOf(to),
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
 
-    describe("Role Management", () => {
-        it("updates mint authority", async () => {
-            const newAuthority = Keypair.generate();
-            assert.ok(true, "Role update test placeholder");
+            const source = await program.account.accountActivity.fetch(activityOf(from));
+            const destination = await program.account.accountActivity.fetch(activityOf(to));
+            assert.equal(source.lastAmount.toNumber(), 250);
+            assert.equal(destination.lastAmount.toNumber(), 250);
+            assert.equal(source.lastOutboundAt.toNumber(), source.lastTransferAt.toNumber());
+            assert.equal(destination.lastOutboundAt.toNumber(), 0);
         });
 
-        it("updates freeze authority", async () => {
-            assert.ok(true, "Freeze authority update test placeholder");
-        });
+        it("marks an inactive account dormant", async () => {
+            await initComplianceOfficers(mint);
+            const to = getAssociatedTokenAddressSync(mint.publicKey, activityReceiver, false, TOKEN_2022_PROGRAM_ID);
 
-        it("updates compliance officer (SSS-2 only)", async () => {
-            assert.ok(true, "Compliance officer update test placeholder");
+            try {
+                await markDormant(to, 3_600);
+                assert.fail("Should have thrown AccountNotDormant error");
+            } catch (err) {
+                assert.include(err.message, "AccountNotDormant");
+            }
+
+            await new Promise((resolve) => setTimeout(resolve, 3_000));
+            await markDormant(to, 2);
+
+            const activity = await program.account.accountActivity.fetch(activityOf(to));
+            assert.isTrue(activity.dormant);
         });
 
-        it("rejects role update from non-authority", async () => {
-            assert.ok(true, "Unauthorized role update rejection placeholder");
+        it("freezes and thaws a token account", async () => {
+            // Freeze/thaw test
+            assert.ok(true, "Freeze/thaw test placeholder");
         });
-    });
 
-    // ─────────────────────────────────────────────────
-    // Edge Cases
-    // ─────────────────────────────────────────────────
+        it("records the freeze reason and the thaw time", async () => {
+            // Funded by the activity tests above
+            const tokenAccount = getAssociatedTokenAddressSync(mint.publicKey, activityReceiver, false, TOKEN_2022_PROGRAM_ID);
+            const frozenRecord = PublicKey.findProgramAddressSync(
+                [Buffer.from("frozen"), mint.publicKey.toBuffer(), tokenAccount.toBuffer()],
+                program.programId
+            )[0];
 
-    describe("Edge Cases", () => {
-        it("rejects names longer than 32 characters", async () => {
-            assert.ok(true, "Name length validation placeholder");
+            await program.methods
+                .freezeAccount("Suspicious activity")
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount,
+                    stablecoinState: stablecoinStatePDA,
+                    frozenRecord,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            let record = await program.account.frozenAccountRecord.fetch(frozenRecord);
+            assert.equal(record.reason, "Suspicious activity");
+            assert.ok(record.frozenBy.equals(authority.publicKey));
+            assert.isAbove(record.frozenAt.toNumber(), 0);
+            assert.isNull(record.thawedAt);
+
+            await program.methods
+                .thawAccount()
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount,
+                    stablecoinState: stablecoinStatePDA,
+                    frozenRecord,
+                    ownerBlacklist: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            record = await program.account.frozenAccountRecord.fetch(frozenRecord);
+            assert.isAtLeast(record.thawedAt.toNumber(), record.frozenAt.toNumber());
+            const account = await getAccount(provider.connection, tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.isFalse(account.isFrozen);
         });
 
-        it("rejects symbols longer than 10 characters", async () => {
+        it("leaves a newly registered SSS-1 account unfrozen", async () => {
+            const owner = Keypair.generate().publicKey;
+            const tokenAccount = getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        tokenAccount,
+                        owner,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+
+            await program.methods
+                .registerAccount()
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount,
+                    stablecoinState: stablecoinStatePDA,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            const account = await getAccount(provider.connection, tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.isFalse(account.isFrozen);
+        });
+
+        it("records a memo in the mint event", async () => {
+            let memo: string | undefined;
+            const listener = program.addEventListener("tokensMinted", (event) => {
+                memo = event.memo;
+            });
+
+            // Requires a destination token account for the mint to land
+            await program.methods
+                .mintTo(new anchor.BN(1_000), "INV-2024-0001")
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    feeCollector: null,
+                    mintStats: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            await program.removeEventListener(listener);
+            assert.equal(memo, "INV-2024-0001");
+        });
+
+        it("rejects an over-length memo", async () => {
+            try {
+                await program.methods
+                    .mintTo(new anchor.BN(1_000), "m".repeat(129))
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        feeCollector: null,
+                        mintStats: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown MemoTooLong error");
+            } catch (err) {
+                assert.include(err.message, "MemoTooLong");
+            }
+        });
+
+        it("rejects minting from non-authority", async () => {
+            const fakeAuthority = Keypair.generate();
+            const amount = new anchor.BN(1_000_000);
+
+            try {
+                await program.methods
+                    .mintTo(amount, null)
+                    .accounts({
+                        mintAuthority: fakeAuthority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        feeCollector: null,
+                        mintStats: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .signers([fakeAuthority])
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("rejects zero-amount mint", async () => {
+            try {
+                await program.methods
+                    .mintTo(new anchor.BN(0), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        feeCollector: null,
+                        mintStats: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown InvalidAmount error");
+            } catch (err) {
+                assert.include(err.message, "InvalidAmount");
+            }
+        });
+
+        it("mints to a new owner, creating their ATA", async () => {
+            const owner = Keypair.generate().publicKey;
+            const ata = getAssociatedTokenAddressSync(
+                mint.publicKey,
+                owner,
+                false,
+                TOKEN_2022_PROGRAM_ID
+            );
+            assert.isNull(await provider.connection.getAccountInfo(ata));
+
+            await program.methods
+                .mintToNew(new anchor.BN(2_500), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const account = await getAccount(provider.connection, ata, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.ok(account.owner.equals(owner));
+            assert.equal(Number(account.amount), 2_500);
+        });
+
+        it("rejects burning from another mint's token account", async () => {
+            const otherMint = Keypair.generate();
+            await initStablecoin(otherMint, sss1Config("Other Dollar", "OTHD"));
+
+            const otherAta = getAssociatedTokenAddressSync(
+                otherMint.publicKey,
+                authority.publicKey,
+                false,
+                TOKEN_2022_PROGRAM_ID
+            );
+            await program.methods
+                .mintToNew(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: otherMint.publicKey,
+                    owner: authority.publicKey,
+                    tokenAccount: otherAta,
+                    stablecoinState: findStablecoinState(otherMint.publicKey),
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            try {
+                await program.methods
+                    .burn(new anchor.BN(1_000), null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        tokenAccount: otherAta,
+                        stablecoinState: stablecoinStatePDA,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown MintMismatch error");
+            } catch (err) {
+                assert.include(err.message, "MintMismatch");
+            }
+        });
+
+        it("rejects burning more than the account balance", async () => {
+            const ata = getAssociatedTokenAddressSync(
+                mint.publicKey,
+                authority.publicKey,
+                false,
+                TOKEN_2022_PROGRAM_ID
+            );
+            await program.methods
+                .mintToNew(new anchor.BN(500), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner: authority.publicKey,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const account = await getAccount(provider.connection, ata, undefined, TOKEN_2022_PROGRAM_ID);
+            const tooMuch = new anchor.BN(account.amount.toString()).addn(1);
+
+            try {
+                await program.methods
+                    .burn(tooMuch, null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        tokenAccount: ata,
+                        stablecoinState: stablecoinStatePDA,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown InsufficientBalance error");
+            } catch (err) {
+                assert.include(err.message, "InsufficientBalance");
+            }
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // SSS-2: Compliant Stablecoin Tests
+    // ─────────────────────────────────────────────────
+
+    describe("SSS-2 — Compliant Stablecoin", () => {
+        const mint = Keypair.generate();
+        let stablecoinStatePDA: PublicKey;
+        const suspiciousWallet = Keypair.generate();
+
+        const holderAtaOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+        const entryOf = (prefix: string, key: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from(prefix), mint.publicKey.toBuffer(), key.toBuffer()],
+                program.programId
+            )[0];
+
+        // A funded holder that can send and receive through the hook: accounts start
+        // frozen under the default account state, so the ATA is thawed and approved
+        const openHolder = async (amount: number) => {
+            const holder = Keypair.generate();
+            const ata = holderAtaOf(holder.publicKey);
+            await provider.connection.confirmTransaction(
+                await provider.connection.requestAirdrop(holder.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+            );
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        ata,
+                        holder.publicKey,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+            await program.methods
+                .thawAccount()
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    frozenRecord: null,
+                    ownerBlacklist: entryOf("blacklist", holder.publicKey),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+            await program.methods
+                .approveAccount()
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    thawApproval: entryOf("approved", ata),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            await program.methods
+                .mintTo(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: entryOf("blacklist", holder.publicKey),
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            return holder;
+        };
+
+        // A plain Token-2022 transfer of 100 tokens, which runs the hook
+        const hookTransfer = async (from: Keypair, to: PublicKey) =>
+            provider.sendAndConfirm(
+                new Transaction().add(
+                    await createTransferCheckedWithTransferHookInstruction(
+                        provider.connection,
+                        holderAtaOf(from.publicKey),
+                        mint.publicKey,
+                        holderAtaOf(to),
+                        from.publicKey,
+                        BigInt(100),
+                        6,
+                        [],
+                        undefined,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                ),
+                [from]
+            );
+
+        const expectBlocked = async (from: Keypair, to: PublicKey) => {
+            try {
+                await hookTransfer(from, to);
+                assert.fail("Should have thrown TransferBlocked error");
+            } catch (err) {
+                assert.include(err.logs.join("\n"), "TransferBlocked");
+            }
+        };
+
+        const holderBalance = async (owner: PublicKey) =>
+            Number((await getAccount(provider.connection, holderAtaOf(owner), undefined, TOKEN_2022_PROGRAM_ID)).amount);
+
+        const blacklistOwner = (owner: PublicKey) =>
+            program.methods
+                .blacklistAdd(owner, 1, "", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: entryOf("blacklist", owner),
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        before(async () => {
+            [stablecoinStatePDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("stablecoin"), mint.publicKey.toBuffer()],
+                program.programId
+            );
+        });
+
+        it("initializes an SSS-2 compliant stablecoin", async () => {
+            const config = {
+                name: "Regulated USD",
+                symbol: "RUSD",
+                uri: "",
+                decimals: 6,
+                enablePermanentDelegate: true,
+                enableTransferHook: true,
+                defaultAccountFrozen: true,
+                allowlistMode: false,
+                freezeOnBlacklist: false,
+                nonTransferable: false,
+                mintAuthority: null,
+                freezeAuthority: null,
+                complianceOfficer: null,
+                maxTransferAmount: null,
+                transferFeeBasisPoints: 0,
+                maxTransferFee: new anchor.BN(0),
+                roleChangeDelay: new anchor.BN(0),
+                mintAuthorityRotationCooldown: new anchor.BN(0),
+            };
+
+            await program.methods
+                .initialize(config)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    symbolRegistry: findSymbolRegistry(config.symbol),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                    rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+                })
+                .signers([mint])
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(state.isSss2);
+            assert.isTrue(state.permanentDelegateEnabled);
+            assert.isTrue(state.transferHookEnabled);
+            assert.isTrue(state.defaultAccountFrozen);
+        });
+
+        it("seeds the compliance officer list from the current officer", async () => {
+            await initComplianceOfficers(mint);
+
+            const list = await program.account.complianceOfficers.fetch(findComplianceOfficers(mint.publicKey));
+            assert.equal(list.officers.length, 1);
+            assert.ok(list.officers[0].equals(authority.publicKey));
+        });
+
+        it("adds an address to the blacklist", async () => {
+            const [blacklistEntry] = PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), suspiciousWallet.publicKey.toBuffer()],
+                program.programId
+            );
+
+            await program.methods
+                .blacklistAdd(suspiciousWallet.publicKey, 1, "OFAC SDN match", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry,
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const entry = await program.account.blacklistEntry.fetch(blacklistEntry);
+            assert.isTrue(entry.isActive);
+            assert.ok(entry.blacklistedAddress.equals(suspiciousWallet.publicKey));
+            assert.equal(entry.reasonCode, 1);
+            assert.equal(entry.note, "OFAC SDN match");
+        });
+
+        it("initializes the transfer hook extra account metas", async () => {
+            const extraAccountMetaList = getExtraAccountMetaAddress(mint.publicKey, program.programId);
+
+            await program.methods
+                .initializeExtraAccountMetaList()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    extraAccountMetaList,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const info = await provider.connection.getAccountInfo(extraAccountMetaList);
+            const metas = getExtraAccountMetas(info);
+            assert.equal(metas.length, 10);
+
+            // The first extra account resolves to the stablecoin state PDA from the mint key
+            const previousMetas = [
+                { pubkey: PublicKey.default, isSigner: false, isWritable: false },
+                { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+            ];
+            const resolved = await resolveExtraAccountMeta(
+                provider.connection,
+                metas[0],
+                previousMetas,
+                Buffer.alloc(0),
+                program.programId
+            );
+            assert.ok(resolved.pubkey.equals(stablecoinStatePDA));
+            // Read-only: the hook never writes the state
+            assert.isFalse(resolved.isWritable);
+
+            // The last extra account is the blacklist bitmap, also derived from the mint key
+            const bitmap = await resolveExtraAccountMeta(
+                provider.connection,
+                metas[5],
+                previousMetas,
+                Buffer.alloc(0),
+                program.programId
+            );
+            const [bitmapPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist-bitmap"), mint.publicKey.toBuffer()],
+                program.programId
+            );
+            assert.ok(bitmap.pubkey.equals(bitmapPDA));
+
+            // The destination's thaw approval is derived from the mint and destination keys
+            const destination = Keypair.generate().publicKey;
+            const approval = await resolveExtraAccountMeta(
+                provider.connection,
+                metas[6],
+                [...previousMetas, { pubkey: destination, isSigner: false, isWritable: false }],
+                Buffer.alloc(0),
+                program.programId
+            );
+            const [approvalPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("approved"), mint.publicKey.toBuffer(), destination.toBuffer()],
+                program.programId
+            );
+            assert.ok(approval.pubkey.equals(approvalPDA));
+
+            // The source's mint lock is derived from the mint and source keys
+            const source = Keypair.generate().publicKey;
+            const lock = await resolveExtraAccountMeta(
+                provider.connection,
+                metas[9],
+                [{ pubkey: source, isSigner: false, isWritable: false }, ...previousMetas.slice(1)],
+                Buffer.alloc(0),
+                program.programId
+            );
+            const [lockPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("mint_lock"), mint.publicKey.toBuffer(), source.toBuffer()],
+                program.programId
+            );
+            assert.ok(lock.pubkey.equals(lockPDA));
+            assert.isFalse(lock.isWritable);
+        });
+
+        it("resolves blacklist metas to the owners' blacklist PDAs", async () => {
+            const source = await openHolder(1);
+            const destination = await openHolder(1);
+            const metas = getExtraAccountMetas(
+                await provider.connection.getAccountInfo(getExtraAccountMetaAddress(mint.publicKey, program.programId))
+            );
+            // The owner seeds are read from the token accounts' data
+            const executeAccounts = [holderAtaOf(source.publicKey), mint.publicKey, holderAtaOf(destination.publicKey)].map(
+                (pubkey) => ({ pubkey, isSigner: false, isWritable: false })
+            );
+
+            const senderEntry = await resolveExtraAccountMeta(
+                provider.connection,
+                metas[1],
+                executeAccounts,
+                Buffer.alloc(0),
+                program.programId
+            );
+            const recipientEntry = await resolveExtraAccountMeta(
+                provider.connection,
+                metas[2],
+                executeAccounts,
+                Buffer.alloc(0),
+                program.programId
+            );
+            assert.ok(senderEntry.pubkey.equals(entryOf("blacklist", source.publicKey)));
+            assert.ok(recipientEntry.pubkey.equals(entryOf("blacklist", destination.publicKey)));
+        });
+
+        describe("with an expiring blacklist entry", () => {
+            let sender: Keypair;
+            let receiver: Keypair;
+            let expiresAt: number;
+
+            // The cluster clock lags wall time on localnet, so expiry is measured against it
+            const chainNow = async () =>
+                Number(
+                    (await provider.connection.getAccountInfo(anchor.web3.SYSVAR_CLOCK_PUBKEY)).data.readBigInt64LE(32)
+                );
+
+            before(async () => {
+                sender = await openHolder(1_000);
+                receiver = await openHolder(1);
+                expiresAt = (await chainNow()) + 5;
+                await program.methods
+                    .blacklistAdd(sender.publicKey, 1, "Temporary order", new anchor.BN(expiresAt))
+                    .accounts({
+                        blacklistAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        blacklistEntry: entryOf("blacklist", sender.publicKey),
+                        tokenAccount: null,
+                        freezeAuthority: null,
+                        tokenProgram: null,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+            });
+
+            it("blocks transfers from a blacklisted address before expiry", async () => {
+                await expectBlocked(sender, receiver.publicKey);
+                assert.equal(await holderBalance(receiver.publicKey), 1);
+            });
+
+            it("allows transfers once the blacklist entry expires", async () => {
+                while ((await chainNow()) < expiresAt) {
+                    await new Promise((resolve) => setTimeout(resolve, 1_000));
+                }
+
+                await hookTransfer(sender, receiver.publicKey);
+
+                // The entry is still recorded as active; only its expiry lifted the block
+                const entry = await program.account.blacklistEntry.fetch(entryOf("blacklist", sender.publicKey));
+                assert.isTrue(entry.isActive);
+                assert.equal(await holderBalance(receiver.publicKey), 101);
+            });
+        });
+
+        it("rejects a blacklist expiry in the past", async () => {
+            const wallet = Keypair.generate();
+            const [blacklistEntry] = PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), wallet.publicKey.toBuffer()],
+                program.programId
+            );
+
+            try {
+                await program.methods
+                    .blacklistAdd(wallet.publicKey, 0, "", new anchor.BN(1))
+                    .accounts({
+                        blacklistAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        blacklistEntry,
+                        tokenAccount: null,
+                        freezeAuthority: null,
+                        tokenProgram: null,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown InvalidExpiry error");
+            } catch (err) {
+                assert.include(err.message, "InvalidExpiry");
+            }
+        });
+
+        describe("compliance holds", () => {
+            let heldOwner: Keypair;
+            let heldWallet: PublicKey;
+            let counterparty: Keypair;
+            const holdOf = (owner: PublicKey) =>
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from("hold"), mint.publicKey.toBuffer(), owner.toBuffer()],
+                    program.programId
+                )[0];
+
+            const placeHold = (owner: PublicKey, reason: string, expiresAt: anchor.BN | null) =>
+                program.methods
+                    .placeHold(owner, reason, expiresAt)
+                    .accounts({
+                        complianceOfficer: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        complianceOfficers: findComplianceOfficers(mint.publicKey),
+                        complianceHold: holdOf(owner),
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+
+            const releaseHold = (owner: PublicKey) =>
+                program.methods
+                    .releaseHold(owner)
+                    .accounts({
+                        complianceOfficer: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        complianceOfficers: findComplianceOfficers(mint.publicKey),
+                        complianceHold: holdOf(owner),
+                    })
+                    .rpc();
+
+            before(async () => {
+                // Funded before the hold is placed
+                heldOwner = await openHolder(1_000);
+                heldWallet = heldOwner.publicKey;
+                counterparty = await openHolder(1_000);
+            });
+
+            it("places a hold without blacklisting the owner", async () => {
+                await placeHold(heldWallet, "Pending source-of-funds review", null);
+
+                const hold = await program.account.complianceHold.fetch(holdOf(heldWallet));
+                assert.isTrue(hold.isActive);
+                assert.ok(hold.owner.equals(heldWallet));
+                assert.equal(hold.reason, "Pending source-of-funds review");
+                assert.isNull(hold.expiresAt);
+
+                const [blacklistEntry] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), mint.publicKey.toBuffer(), heldWallet.toBuffer()],
+                    program.programId
+                );
+                assert.isNull(await provider.connection.getAccountInfo(blacklistEntry));
+            });
+
+            it("rejects a second hold while one is active", async () => {
+                try {
+                    await placeHold(heldWallet, "Duplicate", null);
+                    assert.fail("Should have thrown HoldAlreadyActive error");
+                } catch (err) {
+                    assert.include(err.message, "HoldAlreadyActive");
+                }
+            });
+
+            it("blocks transfers involving a held owner", async () => {
+                await expectBlocked(heldOwner, counterparty.publicKey);
+                await expectBlocked(counterparty, heldWallet);
+
+                assert.equal(await holderBalance(heldWallet), 1_000);
+                assert.equal(await holderBalance(counterparty.publicKey), 1_000);
+            });
+
+            it("releases the hold", async () => {
+                await releaseHold(heldWallet);
+
+                const hold = await program.account.complianceHold.fetch(holdOf(heldWallet));
+                assert.isFalse(hold.isActive);
+
+                try {
+                    await releaseHold(heldWallet);
+                    assert.fail("Should have thrown NoActiveHold error");
+                } catch (err) {
+                    assert.include(err.message, "NoActiveHold");
+                }
+            });
+
+            it("treats an expired hold as released", async () => {
+                const wallet = Keypair.generate().publicKey;
+                const now = Math.floor(Date.now() / 1000);
+                await placeHold(wallet, "Short review", new anchor.BN(now + 2));
+                await new Promise((resolve) => setTimeout(resolve, 3_000));
+
+                // An expired hold no longer blocks, so it cannot be released but can be re-placed
+                try {
+                    await releaseHold(wallet);
+                    assert.fail("Should have thrown NoActiveHold error");
+                } catch (err) {
+                    assert.include(err.message, "NoActiveHold");
+                }
+                await placeHold(wallet, "Second review", null);
+                const hold = await program.account.complianceHold.fetch(holdOf(wallet));
+                assert.equal(hold.reason, "Second review");
+            });
+
+            it("allows transfers again after release or expiry", async () => {
+                // The hold on heldWallet was released above
+                await hookTransfer(heldOwner, counterparty.publicKey);
+                assert.equal(await holderBalance(heldWallet), 900);
+
+                const expiring = await openHolder(1_000);
+                const now = Math.floor(Date.now() / 1000);
+                await placeHold(expiring.publicKey, "Short review", new anchor.BN(now + 2));
+                await expectBlocked(expiring, counterparty.publicKey);
+                await new Promise((resolve) => setTimeout(resolve, 3_000));
+
+                await hookTransfer(expiring, counterparty.publicKey);
+                assert.equal(await holderBalance(expiring.publicKey), 900);
+                assert.equal(await holderBalance(counterparty.publicKey), 1_200);
+            });
+        });
+
+        it("blocks transfers to a blacklisted address", async () => {
+            const sender = await openHolder(1_000);
+            const recipient = await openHolder(1);
+            await blacklistOwner(recipient.publicKey);
+
+            await expectBlocked(sender, recipient.publicKey);
+            assert.equal(await holderBalance(recipient.publicKey), 1);
+        });
+
+        it("blocks a blacklisted source through the interface Execute", async () => {
+            // A plain Token-2022 transfer_checked reaches the hook through the Execute fallback
+            const sender = await openHolder(1_000);
+            const recipient = await openHolder(1);
+            await blacklistOwner(sender.publicKey);
+
+            await expectBlocked(sender, recipient.publicKey);
+            assert.equal(await holderBalance(sender.publicKey), 1_000);
+        });
+
+        it("starts with no blocked transfers counted", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.blockedTransferCount.toNumber(), 0);
+        });
+
+        describe("blocked transfer reports", () => {
+            const flaggedOwner = Keypair.generate().publicKey;
+            const cleanOwners = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+            const ataOf = (owner: PublicKey) =>
+                getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            const pdaOf = (prefix: string, key: PublicKey) =>
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from(prefix), mint.publicKey.toBuffer(), key.toBuffer()],
+                    program.programId
+                )[0];
+
+            const report = (sourceOwner: PublicKey, destinationOwner: PublicKey, signer: Keypair | null = null) =>
+                program.methods
+                    .reportBlockedTransfer(new anchor.BN(1_000))
+                    .accounts({
+                        reporter: signer ? signer.publicKey : authority.publicKey,
+                        mint: mint.publicKey,
+                        source: ataOf(sourceOwner),
+                        destination: ataOf(destinationOwner),
+                        stablecoinState: stablecoinStatePDA,
+                        complianceOfficers: findComplianceOfficers(mint.publicKey),
+                        senderBlacklist: pdaOf("blacklist", sourceOwner),
+                        recipientBlacklist: pdaOf("blacklist", destinationOwner),
+                        sourceAllowlist: pdaOf("allowlist", sourceOwner),
+                        destinationAllowlist: pdaOf("allowlist", destinationOwner),
+                        blacklistBitmap: PublicKey.findProgramAddressSync(
+                            [Buffer.from("blacklist-bitmap"), mint.publicKey.toBuffer()],
+                            program.programId
+                        )[0],
+                        destinationApproval: pdaOf("approved", ataOf(destinationOwner)),
+                        sourceHold: pdaOf("hold", sourceOwner),
+                        destinationHold: pdaOf("hold", destinationOwner),
+                    })
+                    .signers(signer ? [signer] : [])
+                    .rpc();
+
+            before(async () => {
+                // Accounts start frozen under the default account state, so create the ATAs directly
+                const owners = [flaggedOwner, ...cleanOwners];
+                await provider.sendAndConfirm(
+                    new Transaction().add(
+                        ...owners.map((owner) =>
+                            createAssociatedTokenAccountInstruction(
+                                authority.publicKey,
+                                ataOf(owner),
+                                owner,
+                                mint.publicKey,
+                                TOKEN_2022_PROGRAM_ID
+                            )
+                        )
+                    )
+                );
+                await program.methods
+                    .blacklistAdd(flaggedOwner, 1, "Reported transfers", null)
+                    .accounts({
+                        blacklistAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        blacklistEntry: pdaOf("blacklist", flaggedOwner),
+                        tokenAccount: null,
+                        freezeAuthority: null,
+                        tokenProgram: null,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+                // Clean destinations need a thaw approval under default_account_frozen
+                for (const owner of cleanOwners) {
+                    await program.methods
+                        .approveAccount()
+                        .accounts({
+                            freezeAuthority: authority.publicKey,
+                            mint: mint.publicKey,
+                            tokenAccount: ataOf(owner),
+                            stablecoinState: stablecoinStatePDA,
+                            thawApproval: pdaOf("approved", ataOf(owner)),
+                            tokenProgram: TOKEN_2022_PROGRAM_ID,
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .rpc();
+                }
+            });
+
+            it("counts a reported transfer from a blacklisted owner", async () => {
+                const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+                await report(flaggedOwner, cleanOwners[0]);
+
+                const after = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+                assert.equal(
+                    after.blockedTransferCount.toNumber(),
+                    before.blockedTransferCount.toNumber() + 1
+                );
+            });
+
+            it("refuses to count a transfer the hook would allow", async () => {
+                try {
+                    await report(cleanOwners[0], cleanOwners[1]);
+                    assert.fail("Should have thrown TransferNotBlocked error");
+                } catch (err) {
+                    assert.include(err.message, "TransferNotBlocked");
+                }
+            });
+
+            it("rejects reports from outside the compliance roles", async () => {
+                const outsider = Keypair.generate();
+
+                try {
+                    await report(flaggedOwner, cleanOwners[0], outsider);
+                    assert.fail("Should have thrown Unauthorized error");
+                } catch (err) {
+                    assert.include(err.message, "Unauthorized");
+                }
+            });
+        });
+
+        it("exempts an owner from transfer hook checks", async () => {
+            const treasuryOwner = Keypair.generate().publicKey;
+
+            await program.methods
+                .addExemptAddress(treasuryOwner)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                })
+                .rpc();
+
+            let state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.exemptAddresses.some((key) => key.equals(treasuryOwner)));
+
+            try {
+                await program.methods
+                    .addExemptAddress(treasuryOwner)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown AlreadyExempt error");
+            } catch (err) {
+                assert.include(err.message, "AlreadyExempt");
+            }
+
+            await program.methods
+                .removeExemptAddress(treasuryOwner)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                })
+                .rpc();
+
+            state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isFalse(state.exemptAddresses.some((key) => key.equals(treasuryOwner)));
+        });
+
+        describe("exempt owners", () => {
+            let treasuryHolder: Keypair;
+            let flaggedHolder: Keypair;
+            let cleanHolder: Keypair;
+
+            const blacklist = (owner: PublicKey) =>
+                program.methods
+                    .blacklistAdd(owner, 1, "Exemption check", null)
+                    .accounts({
+                        blacklistAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        blacklistEntry: entryOf("blacklist", owner),
+                        tokenAccount: null,
+                        freezeAuthority: null,
+                        tokenProgram: null,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+
+            before(async () => {
+                // Funded before listing, since minting to a blacklisted owner is refused
+                treasuryHolder = await openHolder(1_000);
+                flaggedHolder = await openHolder(1_000);
+                cleanHolder = await openHolder(1_000);
+                await blacklist(treasuryHolder.publicKey);
+                await blacklist(flaggedHolder.publicKey);
+                await program.methods
+                    .addExemptAddress(treasuryHolder.publicKey)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                    })
+                    .rpc();
+            });
+
+            it("lets an exempt source bypass a blacklist block", async () => {
+                await hookTransfer(treasuryHolder, cleanHolder.publicKey);
+
+                assert.equal(await holderBalance(treasuryHolder.publicKey), 900);
+                assert.equal(await holderBalance(cleanHolder.publicKey), 1_100);
+            });
+
+            it("still blocks a non-exempt blacklisted source", async () => {
+                await expectBlocked(flaggedHolder, cleanHolder.publicKey);
+                assert.equal(await holderBalance(flaggedHolder.publicKey), 1_000);
+            });
+
+            it("still blocks an exempt owner sending to a blacklisted destination", async () => {
+                await expectBlocked(treasuryHolder, flaggedHolder.publicKey);
+                assert.equal(await holderBalance(flaggedHolder.publicKey), 1_000);
+            });
+        });
+
+        it("rejects exemptions from a non-authority", async () => {
+            const outsider = Keypair.generate();
+
+            try {
+                await program.methods
+                    .addExemptAddress(outsider.publicKey)
+                    .accounts({
+                        authority: outsider.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                    })
+                    .signers([outsider])
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("rejects unrecognized fallback instructions", async () => {
+            const ix = new TransactionInstruction({
+                programId: program.programId,
+                keys: [],
+                data: Buffer.from([1, 2, 3, 4, 5, 6, 7, 8]),
+            });
+
+            try {
+                await provider.sendAndConfirm(new Transaction().add(ix));
+                assert.fail("Should have rejected unknown instruction data");
+            } catch (err) {
+                assert.notInclude(err.message, "Should have rejected");
+            }
+        });
+
+        it("designates and clears the seizure treasury", async () => {
+            const escrowOwner = Keypair.generate().publicKey;
+            const setTreasury = (treasury: PublicKey | null) =>
+                program.methods
+                    .setSeizureTreasury(treasury)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                    })
+                    .rpc();
+
+            await setTreasury(escrowOwner);
+            let state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.seizureTreasury.equals(escrowOwner));
+
+            await setTreasury(null);
+            state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.seizureTreasury);
+        });
+
+        it("rejects setting the seizure treasury from a non-authority", async () => {
+            const fakeAuthority = Keypair.generate();
+
+            try {
+                await program.methods
+                    .setSeizureTreasury(fakeAuthority.publicKey)
+                    .accounts({
+                        authority: fakeAuthority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                    })
+                    .signers([fakeAuthority])
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("sets and clears the daily seize limit and seize cooldown", async () => {
+            const setSeizeLimits = async (limit: number | null, cooldown: number | null) => {
+                await program.methods
+                    .setDailySeizeLimit(limit === null ? null : new anchor.BN(limit))
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .rpc();
+                await program.methods
+                    .setSeizeCooldown(cooldown === null ? null : new anchor.BN(cooldown))
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .rpc();
+            };
+
+            await setSeizeLimits(1_000, 3_600);
+            let state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.dailySeizeLimit.toNumber(), 1_000);
+            assert.equal(state.seizeCooldown.toNumber(), 3_600);
+
+            await setSeizeLimits(null, null);
+            state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.dailySeizeLimit);
+            assert.isNull(state.seizeCooldown);
+        });
+
+        it("rejects a negative seize cooldown", async () => {
+            try {
+                await program.methods
+                    .setSeizeCooldown(new anchor.BN(-1))
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown InvalidDelay error");
+            } catch (err) {
+                assert.include(err.message, "InvalidDelay");
+            }
+        });
+
+        it("toggles dual-control seizure", async () => {
+            const setDualSeize = (value: boolean) =>
+                program.methods
+                    .setFeatureFlag({ dualSeize: {} }, value)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .rpc();
+
+            await setDualSeize(true);
+            let state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(state.requireDualSeize);
+
+            await setDualSeize(false);
+            state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isFalse(state.requireDualSeize);
+        });
+
+        it("burns from a holder via the permanent delegate", async () => {
+            const holder = await openHolder(1_000);
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            // The holder does not sign; the state PDA burns as permanent delegate
+            await program.methods
+                .burnFrom(new anchor.BN(400), false)
+                .accounts({
+                    caller: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: holderAtaOf(holder.publicKey),
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers: findComplianceOfficers(mint.publicKey),
+                    blacklistEntry: entryOf("blacklist", holder.publicKey),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            assert.equal(await holderBalance(holder.publicKey), 600);
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.totalBurned.sub(before.totalBurned).toNumber(), 400);
+        });
+
+        it("rejects burnFrom from an unauthorized caller", async () => {
+            const fakeCaller = Keypair.generate();
+
+            try {
+                await program.methods
+                    .burnFrom(new anchor.BN(1_000), false)
+                    .accounts({
+                        caller: fakeCaller.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        complianceOfficers: findComplianceOfficers(mint.publicKey),
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .signers([fakeCaller])
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("blacklists a batch of fresh addresses", async () => {
+            const wallets = [Keypair.generate(), Keypair.generate()];
+            const entries = wallets.map((w) =>
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), mint.publicKey.toBuffer(), w.publicKey.toBuffer()],
+                    program.programId
+                )[0]
+            );
+
+            const expiry = Math.floor(Date.now() / 1000) + 3_600;
+            await program.methods
+                .blacklistAddBatch(wallets.map((w) => w.publicKey), 2, "Sanctions list update", [
+                    null,
+                    new anchor.BN(expiry),
+                ])
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .remainingAccounts(entries.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+                .rpc();
+
+            for (const entryPDA of entries) {
+                const entry = await program.account.blacklistEntry.fetch(entryPDA);
+                assert.isTrue(entry.isActive);
+                assert.equal(entry.reasonCode, 2);
+            }
+            const [permanent, expiring] = await Promise.all(entries.map((e) => program.account.blacklistEntry.fetch(e)));
+            assert.isNull(permanent.expiresAt);
+            assert.equal(expiring.expiresAt.toNumber(), expiry);
+        });
+
+        it("creates batch entries whose address was pre-funded", async () => {
+            const wallet = Keypair.generate().publicKey;
+            const [entryPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), wallet.toBuffer()],
+                program.programId
+            );
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    SystemProgram.transfer({ fromPubkey: authority.publicKey, toPubkey: entryPDA, lamports: 1_000_000 })
+                )
+            );
+
+            await program.methods
+                .blacklistAddBatch([wallet], 0, "Pre-funded", [null])
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .remainingAccounts([{ pubkey: entryPDA, isWritable: true, isSigner: false }])
+                .rpc();
+
+            const entry = await program.account.blacklistEntry.fetch(entryPDA);
+            assert.isTrue(entry.isActive);
+            assert.isTrue(entry.blacklistedAddress.equals(wallet));
+        });
+
+        it("rejects a batch whose expiries do not match its addresses", async () => {
+            try {
+                await program.methods
+                    .blacklistAddBatch([Keypair.generate().publicKey], 0, "", [])
+                    .accounts({
+                        blacklistAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .remainingAccounts([{ pubkey: Keypair.generate().publicKey, isWritable: true, isSigner: false }])
+                    .rpc();
+                assert.fail("Should have thrown BatchLengthMismatch error");
+            } catch (err) {
+                assert.include(err.message, "BatchLengthMismatch");
+            }
+        });
+
+        it("skips already-blacklisted addresses in a batch", async () => {
+            const fresh = Keypair.generate();
+            const addresses = [suspiciousWallet.publicKey, fresh.publicKey];
+            const entries = addresses.map((address) =>
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), mint.publicKey.toBuffer(), address.toBuffer()],
+                    program.programId
+                )[0]
+            );
+
+            // suspiciousWallet is still active from the earlier test
+            await program.methods
+                .blacklistAddBatch(addresses, 0, "", [null, null])
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .remainingAccounts(entries.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+                .rpc();
+
+            const existing = await program.account.blacklistEntry.fetch(entries[0]);
+            assert.equal(existing.note, "OFAC SDN match");
+            const added = await program.account.blacklistEntry.fetch(entries[1]);
+            assert.isTrue(added.isActive);
+        });
+
+        describe("blacklist digest", () => {
+            const digestAccounts = () => ({
+                blacklistAuthority: authority.publicKey,
+                mint: mint.publicKey,
+                stablecoinState: stablecoinStatePDA,
+            });
+
+            const beginDigest = () => program.methods.beginBlacklistDigest().accounts(digestAccounts()).rpc();
+
+            const digestPage = (entryPDAs: PublicKey[]) =>
+                program.methods
+                    .computeBlacklistDigest()
+                    .accounts(digestAccounts())
+                    .remainingAccounts(entryPDAs.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
+                    .rpc();
+
+            // Runs a whole pass and returns the BlacklistDigestUpdated event that completes it
+            const runDigest = async (pages: PublicKey[][]) => {
+                let updated: any;
+                const listener = program.addEventListener("blacklistDigestUpdated", (event) => {
+                    updated = event;
+                });
+                await beginDigest();
+                for (const page of pages) {
+                    await digestPage(page);
+                }
+                await program.removeEventListener(listener);
+                return updated;
+            };
+
+            // Every active entry for the mint (expired ones included), in ascending address order
+            const activeEntries = async () => {
+                const entries = await program.account.blacklistEntry.all([
+                    { memcmp: { offset: 8, bytes: mint.publicKey.toBase58() } },
+                ]);
+                return entries
+                    .filter((entry) => entry.account.isActive)
+                    .sort((a, b) =>
+                        Buffer.compare(a.account.blacklistedAddress.toBuffer(), b.account.blacklistedAddress.toBuffer())
+                    )
+                    .map((entry) => entry.publicKey);
+            };
+
+            it("computes a stable digest over every active entry", async () => {
+                const entries = await activeEntries();
+                const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+                assert.equal(entries.length, state.activeBlacklistCount.toNumber());
+                assert.isAtLeast(entries.length, 2);
+
+                const event = await runDigest([entries]);
+                const first = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+                assert.notDeepEqual(first.blacklistDigest, new Array(32).fill(0));
+                assert.isAbove(first.digestUpdatedAt.toNumber(), 0);
+                assert.equal(first.digestSnapshotAt.toNumber(), 0);
+                assert.equal(event.entryCount + event.expiredCount, entries.length);
+
+                await runDigest([entries]);
+                const second = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+                assert.deepEqual(second.blacklistDigest, first.blacklistDigest);
+            });
+
+            it("gives the same digest when the entries are split across pages", async () => {
+                const entries = await activeEntries();
+                await runDigest([entries]);
+                const whole = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+                await runDigest(entries.map((entry) => [entry]));
+                const paged = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+                assert.deepEqual(paged.blacklistDigest, whole.blacklistDigest);
+            });
+
+            it("keeps the previous digest until every active entry is visited", async () => {
+                const entries = await activeEntries();
+                const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+                await beginDigest();
+                await digestPage(entries.slice(1));
+
+                const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+                assert.isAbove(state.digestSnapshotAt.toNumber(), 0);
+                assert.equal(state.digestActiveSeen.toNumber(), entries.length - 1);
+                assert.deepEqual(state.blacklistDigest, before.blacklistDigest);
+            });
+
+            it("rejects entries out of address order", async () => {
+                const entries = await activeEntries();
+                await beginDigest();
+
+                try {
+                    await digestPage([...entries].reverse());
+                    assert.fail("Should have thrown BlacklistNotSorted error");
+                } catch (err) {
+                    assert.include(err.message, "BlacklistNotSorted");
+                }
+            });
+
+            it("rejects a page that goes back before the previous one", async () => {
+                const entries = await activeEntries();
+                await beginDigest();
+                await digestPage(entries.slice(1));
+
+                try {
+                    await digestPage([entries[0]]);
+                    assert.fail("Should have thrown BlacklistNotSorted error");
+                } catch (err) {
+                    assert.include(err.message, "BlacklistNotSorted");
+                }
+            });
+
+            it("rejects a repeated entry", async () => {
+                const entries = await activeEntries();
+                await beginDigest();
+
+                try {
+                    await digestPage([entries[0], ...entries]);
+                    assert.fail("Should have thrown BlacklistNotSorted error");
+                } catch (err) {
+                    assert.include(err.message, "BlacklistNotSorted");
+                }
+            });
+
+            it("rejects a page once the pass has completed", async () => {
+                const entries = await activeEntries();
+                await runDigest([entries]);
+
+                try {
+                    await digestPage(entries);
+                    assert.fail("Should have thrown NoDigestInProgress error");
+                } catch (err) {
+                    assert.include(err.message, "NoDigestInProgress");
+                }
+            });
+
+            it("abandons the pass when the blacklist changes", async () => {
+                const entries = await activeEntries();
+                await beginDigest();
+                await blacklistOwner(Keypair.generate().publicKey);
+
+                try {
+                    await digestPage(entries);
+                    assert.fail("Should have thrown NoDigestInProgress error");
+                } catch (err) {
+                    assert.include(err.message, "NoDigestInProgress");
+                }
+            });
+
+            it("leaves entries that had expired when the pass began out of the digest", async () => {
+                const chainNow = async () =>
+                    Number(
+                        (await provider.connection.getAccountInfo(anchor.web3.SYSVAR_CLOCK_PUBKEY)).data.readBigInt64LE(32)
+                    );
+                const before = await runDigest([await activeEntries()]);
+
+                const lapsing = Keypair.generate().publicKey;
+                const expiresAt = (await chainNow()) + 2;
+                await program.methods
+                    .blacklistAdd(lapsing, 1, "Temporary order", new anchor.BN(expiresAt))
+                    .accounts({
+                        blacklistAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        blacklistEntry: entryOf("blacklist", lapsing),
+                        tokenAccount: null,
+                        freezeAuthority: null,
+                        tokenProgram: null,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+                while ((await chainNow()) < expiresAt) {
+                    await new Promise((resolve) => setTimeout(resolve, 1_000));
+                }
+
+                // The lapsed entry must still be passed, but the committed set is unchanged
+                const after = await runDigest([await activeEntries()]);
+                assert.deepEqual(after.digest, before.digest);
+                assert.equal(after.entryCount, before.entryCount);
+                assert.equal(after.expiredCount, before.expiredCount + 1);
+            });
+        });
+
+        it("rejects a blacklist digest from a non-blacklist authority", async () => {
+            const outsider = Keypair.generate();
+
+            try {
+                await program.methods
+                    .computeBlacklistDigest()
+                    .accounts({
+                        blacklistAuthority: outsider.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                    })
+                    .signers([outsider])
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("rejects a blacklist batch longer than 15 entries", async () => {
+            const addresses = Array.from({ length: 16 }, () => Keypair.generate().publicKey);
+
+            try {
+                await program.methods
+                    .blacklistAddBatch(addresses, 0, "", addresses.map(() => null))
+                    .accounts({
+                        blacklistAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown BatchTooLarge error");
+            } catch (err) {
+                assert.include(err.message, "BatchTooLarge");
+            }
+        });
+
+        it("removes an address from the blacklist", async () => {
+            const [blacklistEntry] = PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), suspiciousWallet.publicKey.toBuffer()],
+                program.programId
+            );
+
+            await program.methods
+                .blacklistRemove(suspiciousWallet.publicKey, false)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry,
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                })
+                .rpc();
+
+            const entry = await program.account.blacklistEntry.fetch(blacklistEntry);
+            assert.isFalse(entry.isActive);
+        });
+
+        it("removes an address from the blacklist and thaws its account", async () => {
+            // New accounts on this mint start frozen
+            const owner = Keypair.generate().publicKey;
+            const tokenAccount = holderAtaOf(owner);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        tokenAccount,
+                        owner,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+            await blacklistOwner(owner);
+
+            await program.methods
+                .blacklistRemove(owner, true)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: entryOf("blacklist", owner),
+                    tokenAccount,
+                    freezeAuthority: authority.publicKey,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            const entry = await program.account.blacklistEntry.fetch(entryOf("blacklist", owner));
+            assert.isFalse(entry.isActive);
+            const account = await getAccount(provider.connection, tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.isFalse(account.isFrozen);
+        });
+
+        it("freezes a newly registered account by default", async () => {
+            const owner = Keypair.generate().publicKey;
+            const tokenAccount = getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        tokenAccount,
+                        owner,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+
+            await program.methods
+                .registerAccount()
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount,
+                    stablecoinState: stablecoinStatePDA,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            const account = await getAccount(provider.connection, tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.isTrue(account.isFrozen);
+        });
+
+        it("lets the compliance officer freeze an account", async () => {
+            // The officer holds no Token-2022 authority; the mint's freeze authority stays with the authority
+            const officer = Keypair.generate();
+            const officerMint = Keypair.generate();
+            const officerState = findStablecoinState(officerMint.publicKey);
+            await initStablecoin(officerMint, {
+                ...sss2Config("Officer Dollar", "CFRZ"),
+                defaultAccountFrozen: false,
+                complianceOfficer: officer.publicKey,
+            });
+            await initComplianceOfficers(officerMint);
+
+            const owner = Keypair.generate().publicKey;
+            const tokenAccount = getAssociatedTokenAddressSync(officerMint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        tokenAccount,
+                        owner,
+                        officerMint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+
+            const freezeByOfficer = () =>
+                program.methods
+                    .freezeByCompliance()
+                    .accounts({
+                        complianceOfficer: officer.publicKey,
+                        mint: officerMint.publicKey,
+                        tokenAccount,
+                        stablecoinState: officerState,
+                        complianceOfficers: findComplianceOfficers(officerMint.publicKey),
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .signers([officer])
+                    .rpc();
+
+            try {
+                await freezeByOfficer();
+                assert.fail("Should have thrown FreezeAuthorityNotDelegated error");
+            } catch (err) {
+                assert.include(err.message, "FreezeAuthorityNotDelegated");
+            }
+
+            await program.methods
+                .delegateFreezeAuthority()
+                .accounts({
+                    currentAuthority: authority.publicKey,
+                    mint: officerMint.publicKey,
+                    stablecoinState: officerState,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+            const mintInfo = await getMint(provider.connection, officerMint.publicKey, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.ok(mintInfo.freezeAuthority.equals(officerState));
+
+            await freezeByOfficer();
+
+            const account = await getAccount(provider.connection, tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.isTrue(account.isFrozen);
+        });
+
+        it("rejects compliance freeze on SSS-1 tokens", async () => {
+            const sss1Mint = Keypair.generate();
+            await initStablecoin(sss1Mint, sss1Config("Minimal Dollar", "MFRZ"));
+            await initComplianceOfficers(sss1Mint);
+
+            try {
+                await program.methods
+                    .freezeByCompliance()
+                    .accounts({
+                        complianceOfficer: authority.publicKey,
+                        mint: sss1Mint.publicKey,
+                        stablecoinState: findStablecoinState(sss1Mint.publicKey),
+                        complianceOfficers: findComplianceOfficers(sss1Mint.publicKey),
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown SSS2Required error");
+            } catch (err) {
+                assert.include(err.message, "SSS2Required");
+            }
+        });
+
+        it("rejects blacklist operations on SSS-1 tokens", async () => {
+            const sss1Mint = Keypair.generate();
+            await initStablecoin(sss1Mint, sss1Config("Unlisted Dollar", "NBLK"));
+
+            try {
+                await program.methods
+                    .blacklistAdd(suspiciousWallet.publicKey, 1, "", null)
+                    .accounts({
+                        blacklistAuthority: authority.publicKey,
+                        mint: sss1Mint.publicKey,
+                        stablecoinState: findStablecoinState(sss1Mint.publicKey),
+                        blacklistEntry: PublicKey.findProgramAddressSync(
+                            [Buffer.from("blacklist"), sss1Mint.publicKey.toBuffer(), suspiciousWallet.publicKey.toBuffer()],
+                            program.programId
+                        )[0],
+                        tokenAccount: null,
+                        freezeAuthority: null,
+                        tokenProgram: null,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown SSS2Required error");
+            } catch (err) {
+                assert.include(err.message, "SSS2Required");
+            }
+        });
+
+        it("rejects compliance operations from non-compliance-officer", async () => {
+            const fakeOfficer = Keypair.generate();
+            const [blacklistEntry] = PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), suspiciousWallet.publicKey.toBuffer()],
+                program.programId
+            );
+
+            try {
+                await program.methods
+                    .blacklistAdd(suspiciousWallet.publicKey, 0, "", null)
+                    .accounts({
+                        blacklistAuthority: fakeOfficer.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        blacklistEntry,
+                        tokenAccount: null,
+                        freezeAuthority: null,
+                        tokenProgram: null,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .signers([fakeOfficer])
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+    });
+
+    describe("Seizure", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const complianceOfficers = findComplianceOfficers(mint.publicKey);
+        // Seized tokens land in the authority's account while no treasury is designated
+        const treasury = getAssociatedTokenAddressSync(mint.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+        const findBlacklistEntry = (owner: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), owner.toBuffer()],
+                program.programId
+            )[0];
+
+        const findSeizureRecord = (target: PublicKey, seizedAt: number) =>
+            PublicKey.findProgramAddressSync(
+                [
+                    Buffer.from("seizure"),
+                    mint.publicKey.toBuffer(),
+                    target.toBuffer(),
+                    new anchor.BN(seizedAt).toArrayLike(Buffer, "le", 8),
+                ],
+                program.programId
+            )[0];
+
+        const balanceOf = async (account: PublicKey) =>
+            (await getAccount(provider.connection, account, undefined, TOKEN_2022_PROGRAM_ID)).amount;
+
+        // seizedAt may not run ahead of the cluster clock, which lags wall time on localnet
+        const chainNow = async () =>
+            Number(
+                (await provider.connection.getAccountInfo(anchor.web3.SYSVAR_CLOCK_PUBKEY)).data.readBigInt64LE(32)
+            );
+
+        // Hook extras for a movement out of `source`, signed by the state PDA as permanent delegate
+        const hookAccounts = async (source: PublicKey, destination: PublicKey) => {
+            const ix = new TransactionInstruction({
+                programId: TOKEN_2022_PROGRAM_ID,
+                keys: [source, mint.publicKey, destination, stablecoinStatePDA].map((pubkey) => ({
+                    pubkey,
+                    isSigner: false,
+                    isWritable: false,
+                })),
+            });
+            await addExtraAccountMetasForExecute(
+                provider.connection,
+                ix,
+                program.programId,
+                source,
+                mint.publicKey,
+                destination,
+                stablecoinStatePDA,
+                0
+            );
+            return ix.keys.slice(4);
+        };
+
+        const fund = async (owner: PublicKey, amount: anchor.BN | number) => {
+            await program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: findBlacklistEntry(owner),
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            return ataOf(owner);
+        };
+
+        const blacklist = (owner: PublicKey) =>
+            program.methods
+                .blacklistAdd(owner, 1, "Court order", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: findBlacklistEntry(owner),
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        // A fresh owner holding `amount` with an active blacklist entry; returns their token account
+        const blacklistedHolder = async (amount: anchor.BN | number) => {
+            const owner = Keypair.generate().publicKey;
+            const account = await fund(owner, amount);
+            await blacklist(owner);
+            return account;
+        };
+
+        // Seizure records are keyed by target and second, so each successful seizure uses a fresh target
+        const seize = async (
+            target: PublicKey,
+            amount: anchor.BN | number,
+            seizeAll = false,
+            destination: PublicKey = treasury
+        ) => {
+            const seizedAt = await chainNow();
+            const { owner } = await getAccount(provider.connection, target, undefined, TOKEN_2022_PROGRAM_ID);
+            await program.methods
+                .seizeTokens(new anchor.BN(amount), seizeAll, "Court order", new anchor.BN(seizedAt))
+                .accounts({
+                    complianceOfficer: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers,
+                    blacklistEntry: findBlacklistEntry(owner),
+                    targetAccount: target,
+                    destinationAccount: destination,
+                    seizureRecord: findSeizureRecord(target, seizedAt),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .remainingAccounts(await hookAccounts(target, destination))
+                .rpc();
+            return findSeizureRecord(target, seizedAt);
+        };
+
+        before(async () => {
+            await initStablecoin(mint, { ...sss2Config("Seized Dollar", "SZD"), defaultAccountFrozen: false });
+            await initComplianceOfficers(mint);
+            await program.methods
+                .initializeExtraAccountMetaList()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    extraAccountMetaList: getExtraAccountMetaAddress(mint.publicKey, program.programId),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        treasury,
+                        authority.publicKey,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+        });
+
+        it("drains the full balance with seizeAll", async () => {
+            const target = await blacklistedHolder(1_000);
+            const treasuryBefore = await balanceOf(treasury);
+
+            const record = await seize(target, 0, true);
+
+            assert.equal(Number(await balanceOf(target)), 0);
+            assert.equal(Number((await balanceOf(treasury)) - treasuryBefore), 1_000);
+            const seizure = await program.account.seizureRecord.fetch(record);
+            assert.equal(seizure.amount.toNumber(), 1_000);
+        });
+
+        it("seizes an explicit partial amount", async () => {
+            const target = await blacklistedHolder(1_000);
+            const treasuryBefore = await balanceOf(treasury);
+
+            await seize(target, 400);
+
+            assert.equal(Number(await balanceOf(target)), 600);
+            assert.equal(Number((await balanceOf(treasury)) - treasuryBefore), 400);
+        });
+
+        it("rejects seizing more than the balance", async () => {
+            const target = await blacklistedHolder(1_000);
+
+            try {
+                await seize(target, 1_001);
+                assert.fail("Should have thrown InsufficientBalance error");
+            } catch (err) {
+                assert.include(err.message, "InsufficientBalance");
+            }
+            assert.equal(Number(await balanceOf(target)), 1_000);
+        });
+
+        it("seizes into a designated escrow treasury", async () => {
+            const escrowOwner = Keypair.generate().publicKey;
+            const escrow = ataOf(escrowOwner);
+            const setTreasury = (owner: PublicKey | null) =>
+                program.methods
+                    .setSeizureTreasury(owner)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                    })
+                    .rpc();
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        escrow,
+                        escrowOwner,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+            const target = await blacklistedHolder(750);
+
+            await setTreasury(escrowOwner);
+            try {
+                await seize(target, 750, false, escrow);
+            } finally {
+                await setTreasury(null);
+            }
+
+            assert.equal(Number(await balanceOf(escrow)), 750);
+            assert.equal(Number(await balanceOf(target)), 0);
+        });
+
+        it("rejects seizing into a foreign treasury", async () => {
+            // Owned by neither the authority nor a designated treasury, e.g. the officer's own wallet
+            const foreignOwner = Keypair.generate().publicKey;
+            const foreign = ataOf(foreignOwner);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        foreign,
+                        foreignOwner,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+            const target = await blacklistedHolder(500);
+
+            try {
+                await seize(target, 500, false, foreign);
+                assert.fail("Should have thrown InvalidTreasury error");
+            } catch (err) {
+                assert.include(err.message, "InvalidTreasury");
+            }
+
+            assert.equal(Number(await balanceOf(target)), 500);
+            assert.equal(Number(await balanceOf(foreign)), 0);
+        });
+
+        it("records the seizure for audit", async () => {
+            const target = await blacklistedHolder(300);
+
+            const record = await seize(target, 300);
+
+            const seizure = await program.account.seizureRecord.fetch(record);
+            assert.ok(seizure.stablecoin.equals(mint.publicKey));
+            assert.ok(seizure.target.equals(target));
+            assert.ok(seizure.destination.equals(treasury));
+            assert.ok(seizure.officer.equals(authority.publicKey));
+            assert.equal(seizure.amount.toNumber(), 300);
+            assert.equal(seizure.reason, "Court order");
+        });
+
+        it("accumulates the lifetime seized total", async () => {
+            const first = await blacklistedHolder(100);
+            const second = await blacklistedHolder(250);
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            let captured: any;
+            const listener = program.addEventListener("tokensSeized", (event) => {
+                captured = event;
+            });
+            await seize(first, 100);
+            await seize(second, 250);
+            await program.removeEventListener(listener);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.totalSeized.sub(before.totalSeized).toNumber(), 350);
+            assert.equal(captured.cumulativeSeized.toString(), state.totalSeized.toString());
+        });
+
+        it("guards the seized total against overflow", async () => {
+            // Supply cannot exceed u64::MAX either, so the same 2^63 tokens are seized twice:
+            // back out of the treasury to a clean holder, who is then blacklisted
+            const half = new anchor.BN(2).pow(new anchor.BN(63));
+            await seize(await blacklistedHolder(half), 0, true);
+
+            const owner = Keypair.generate().publicKey;
+            const target = ataOf(owner);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        target,
+                        owner,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    ),
+                    await createTransferCheckedWithTransferHookInstruction(
+                        provider.connection,
+                        treasury,
+                        mint.publicKey,
+                        target,
+                        authority.publicKey,
+                        BigInt(half.toString()),
+                        6,
+                        [],
+                        undefined,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+            await blacklist(owner);
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            try {
+                await seize(target, 0, true);
+                assert.fail("Should have thrown Overflow error");
+            } catch (err) {
+                assert.include(err.message, "Overflow");
+            }
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.totalSeized.toString(), before.totalSeized.toString());
+            assert.equal((await balanceOf(target)).toString(), half.toString());
+        });
+
+        const burnSeized = (amount: anchor.BN | number, account: PublicKey = treasury) =>
+            program.methods
+                .burnSeized(new anchor.BN(amount))
+                .accounts({
+                    complianceOfficer: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers,
+                    treasury: account,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+        it("burns seized tokens from the treasury", async () => {
+            const target = await blacklistedHolder(500);
+            const treasuryBefore = await balanceOf(treasury);
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            await seize(target, 500);
+            await burnSeized(500);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.totalSeized.toString(), before.totalSeized.toString());
+            assert.equal(state.totalBurned.sub(before.totalBurned).toNumber(), 500);
+            assert.equal(await balanceOf(treasury), treasuryBefore);
+        });
+
+        it("rejects burning more than the treasury holds", async () => {
+            const held = await balanceOf(treasury);
+
+            try {
+                await burnSeized(new anchor.BN(held.toString()).addn(1));
+                assert.fail("Should have thrown InsufficientBalance error");
+            } catch (err) {
+                assert.include(err.message, "InsufficientBalance");
+            }
+            assert.equal(await balanceOf(treasury), held);
+        });
+
+        it("rejects burning from an account outside the seizure treasury", async () => {
+            // A seizure has already happened, so total_seized alone would not stop this
+            const holder = await fund(Keypair.generate().publicKey, 500);
+
+            try {
+                await burnSeized(100, holder);
+                assert.fail("Should have thrown InvalidTreasury error");
+            } catch (err) {
+                assert.include(err.message, "InvalidTreasury");
+            }
+            assert.equal(Number(await balanceOf(holder)), 500);
+        });
+
+        const forceTransfer = async (source: PublicKey, destination: PublicKey, amount: anchor.BN | number) =>
+            program.methods
+                .forceTransfer(new anchor.BN(amount))
+                .accounts({
+                    complianceOfficer: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers,
+                    source,
+                    destination,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .remainingAccounts(await hookAccounts(source, destination))
+                .rpc();
+
+        it("force-transfers between two third-party accounts", async () => {
+            const source = await fund(Keypair.generate().publicKey, 1_000);
+            const destination = await fund(Keypair.generate().publicKey, 1_000);
+
+            await forceTransfer(source, destination, 300);
+
+            assert.equal(Number(await balanceOf(source)), 700);
+            assert.equal(Number(await balanceOf(destination)), 1_300);
+        });
+
+        it("rejects a forced transfer above the source balance", async () => {
+            const source = await fund(Keypair.generate().publicKey, 1_000);
+            const destination = await fund(Keypair.generate().publicKey, 1_000);
+
+            try {
+                await forceTransfer(source, destination, 1_001);
+                assert.fail("Should have thrown InsufficientBalance error");
+            } catch (err) {
+                assert.include(err.message, "InsufficientBalance");
+            }
+            assert.equal(Number(await balanceOf(source)), 1_000);
+        });
+
+        it("rejects seizing into the target account itself", async () => {
+            const target = await blacklistedHolder(500);
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            try {
+                await seize(target, 100, false, target);
+                assert.fail("Should have thrown DuplicateAccount error");
+            } catch (err) {
+                assert.include(err.message, "DuplicateAccount");
+            }
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.totalSeized.toString(), before.totalSeized.toString());
+            assert.equal(Number(await balanceOf(target)), 500);
+        });
+
+        it("rejects a forced transfer back into the source account", async () => {
+            const source = await fund(Keypair.generate().publicKey, 500);
+
+            try {
+                await forceTransfer(source, source, 100);
+                assert.fail("Should have thrown DuplicateAccount error");
+            } catch (err) {
+                assert.include(err.message, "DuplicateAccount");
+            }
+            assert.equal(Number(await balanceOf(source)), 500);
+        });
+
+        const findReissueRecord = (seizureRecord: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("reissue"), mint.publicKey.toBuffer(), seizureRecord.toBuffer()],
+                program.programId
+            )[0];
+
+        const reissue = async (
+            seizureRecord: PublicKey,
+            recipient: PublicKey,
+            amount: number,
+            coSigner: Keypair | null = null,
+            coSignerKey: PublicKey | null = coSigner ? coSigner.publicKey : null
+        ) => {
+            const { owner } = await getAccount(provider.connection, recipient, undefined, TOKEN_2022_PROGRAM_ID);
+            await program.methods
+                .reissueSeized(new anchor.BN(amount))
+                .accounts({
+                    authority: authority.publicKey,
+                    coSigner: coSignerKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers,
+                    seizureRecord,
+                    treasury,
+                    recipientAccount: recipient,
+                    recipientBlacklist: findBlacklistEntry(owner),
+                    reissueRecord: findReissueRecord(seizureRecord),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .remainingAccounts(await hookAccounts(treasury, recipient))
+                .signers(coSigner ? [coSigner] : [])
+                .rpc();
+        };
+
+        it("reissues seized tokens to a rightful owner", async () => {
+            const seizureRecord = await seize(await blacklistedHolder(500), 500);
+            const recipient = await fund(Keypair.generate().publicKey, 1);
+            const treasuryBefore = await balanceOf(treasury);
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            await reissue(seizureRecord, recipient, 500);
+
+            assert.equal(Number(await balanceOf(recipient)), 501);
+            assert.equal(Number(treasuryBefore - (await balanceOf(treasury))), 500);
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(before.totalSeized.sub(state.totalSeized).toNumber(), 500);
+            const record = await program.account.reissueRecord.fetch(findReissueRecord(seizureRecord));
+            assert.ok(record.seizure.equals(seizureRecord));
+            assert.ok(record.recipient.equals(recipient));
+            assert.equal(record.amount.toNumber(), 500);
+            assert.isNull(record.coSigner);
+        });
+
+        it("rejects reissuing more than the original seizure", async () => {
+            const seizureRecord = await seize(await blacklistedHolder(200), 200);
+            const recipient = await fund(Keypair.generate().publicKey, 1);
+
+            try {
+                await reissue(seizureRecord, recipient, 201);
+                assert.fail("Should have thrown ReissueExceedsSeizure error");
+            } catch (err) {
+                assert.include(err.message, "ReissueExceedsSeizure");
+            }
+            assert.equal(Number(await balanceOf(recipient)), 1);
+        });
+
+        const setSeizeLimit = (limit: number | null) =>
+            program.methods
+                .setDailySeizeLimit(limit === null ? null : new anchor.BN(limit))
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        const setSeizeCooldown = (cooldown: number | null) =>
+            program.methods
+                .setSeizeCooldown(cooldown === null ? null : new anchor.BN(cooldown))
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        it("stops seizing once the daily seize limit is reached", async () => {
+            // Earlier seizures in this window already count toward the limit
+            const { seizedToday } = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            await setSeizeLimit(seizedToday.toNumber() + 500);
+
+            await seize(await blacklistedHolder(1_000), 300);
+            const target = await blacklistedHolder(1_000);
+            try {
+                await seize(target, 300);
+                assert.fail("Should have thrown DailySeizeLimitExceeded error");
+            } catch (err) {
+                assert.include(err.message, "DailySeizeLimitExceeded");
+            }
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.seizedToday.sub(seizedToday).toNumber(), 300);
+            assert.equal(Number(await balanceOf(target)), 1_000);
+
+            await setSeizeLimit(null);
+        });
+
+        it("enforces the seize cooldown between seizures", async () => {
+            await seize(await blacklistedHolder(1_000), 100);
+            const { lastSeizeAt } = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            await setSeizeCooldown(3_600);
+
+            const target = await blacklistedHolder(1_000);
+            try {
+                await seize(target, 100);
+                assert.fail("Should have thrown SeizeCooldownActive error");
+            } catch (err) {
+                assert.include(err.message, "SeizeCooldownActive");
+            }
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.lastSeizeAt.toNumber(), lastSeizeAt.toNumber());
+            assert.equal(Number(await balanceOf(target)), 1_000);
+
+            await setSeizeCooldown(null);
+            await seize(target, 100);
+            assert.equal(Number(await balanceOf(target)), 900);
+        });
+
+        describe("dual control", () => {
+            const secondOfficer = Keypair.generate();
+
+            const findPendingSeizure = (target: PublicKey, seizedAt: number) =>
+                PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("pending_seizure"),
+                        mint.publicKey.toBuffer(),
+                        target.toBuffer(),
+                        new anchor.BN(seizedAt).toArrayLike(Buffer, "le", 8),
+                    ],
+                    program.programId
+                )[0];
+
+            const setDualSeize = (value: boolean) =>
+                program.methods
+                    .setFeatureFlag({ dualSeize: {} }, value)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .rpc();
+
+            // Proposed by the authority, the first listed officer; returns the proposal's seizedAt
+            const propose = async (target: PublicKey, amount: number) => {
+                const seizedAt = await chainNow();
+                const { owner } = await getAccount(provider.connection, target, undefined, TOKEN_2022_PROGRAM_ID);
+                await program.methods
+                    .proposeSeizure(new anchor.BN(amount), false, "Court order", new anchor.BN(seizedAt))
+                    .accounts({
+                        complianceOfficer: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        complianceOfficers,
+                        blacklistEntry: findBlacklistEntry(owner),
+                        targetAccount: target,
+                        destinationAccount: treasury,
+                        pendingSeizure: findPendingSeizure(target, seizedAt),
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+                return seizedAt;
+            };
+
+            const approve = async (target: PublicKey, seizedAt: number, signer: Keypair | null = null) => {
+                const { owner } = await getAccount(provider.connection, target, undefined, TOKEN_2022_PROGRAM_ID);
+                await program.methods
+                    .approveSeizure(new anchor.BN(seizedAt))
+                    .accounts({
+                        complianceOfficer: signer ? signer.publicKey : authority.publicKey,
+                        proposer: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        complianceOfficers,
+                        blacklistEntry: findBlacklistEntry(owner),
+                        targetAccount: target,
+                        destinationAccount: treasury,
+                        pendingSeizure: findPendingSeizure(target, seizedAt),
+                        seizureRecord: findSeizureRecord(target, seizedAt),
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .remainingAccounts(await hookAccounts(target, treasury))
+                    .signers(signer ? [signer] : [])
+                    .rpc();
+            };
+
+            before(async () => {
+                await provider.connection.confirmTransaction(
+                    await provider.connection.requestAirdrop(secondOfficer.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+                );
+                await program.methods
+                    .addComplianceOfficer(secondOfficer.publicKey)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        complianceOfficers,
+                    })
+                    .rpc();
+                await setDualSeize(true);
+            });
+
+            it("rejects a direct seizure while dual control is required", async () => {
+                const target = await blacklistedHolder(200);
+
+                try {
+                    await seize(target, 100);
+                    assert.fail("Should have thrown DualSeizeRequired error");
+                } catch (err) {
+                    assert.include(err.message, "DualSeizeRequired");
+                }
+                assert.equal(Number(await balanceOf(target)), 200);
+            });
+
+            it("seizes once a second officer approves the proposal", async () => {
+                const target = await blacklistedHolder(500);
+
+                const seizedAt = await propose(target, 100);
+                assert.equal(Number(await balanceOf(target)), 500);
+                await approve(target, seizedAt, secondOfficer);
+
+                assert.equal(Number(await balanceOf(target)), 400);
+                const record = await program.account.seizureRecord.fetch(findSeizureRecord(target, seizedAt));
+                assert.ok(record.officer.equals(secondOfficer.publicKey));
+                assert.equal(record.amount.toNumber(), 100);
+                assert.isNull(await provider.connection.getAccountInfo(findPendingSeizure(target, seizedAt)));
+            });
+
+            it("rejects approval by the proposing officer", async () => {
+                const target = await blacklistedHolder(500);
+                const seizedAt = await propose(target, 100);
+
+                try {
+                    await approve(target, seizedAt);
+                    assert.fail("Should have thrown SameOfficerApproval error");
+                } catch (err) {
+                    assert.include(err.message, "SameOfficerApproval");
+                }
+                assert.equal(Number(await balanceOf(target)), 500);
+            });
+
+            it("requires a compliance co-signer to reissue under dual control", async () => {
+                const target = await blacklistedHolder(300);
+                const seizedAt = await propose(target, 300);
+                await approve(target, seizedAt, secondOfficer);
+                const seizureRecord = findSeizureRecord(target, seizedAt);
+                const recipient = await fund(Keypair.generate().publicKey, 1);
+
+                try {
+                    await reissue(seizureRecord, recipient, 300);
+                    assert.fail("Should have thrown MissingAccount error");
+                } catch (err) {
+                    assert.include(err.message, "MissingAccount");
+                }
+
+                // The authority is a listed officer but cannot co-sign its own reissue
+                try {
+                    await reissue(seizureRecord, recipient, 300, null, authority.publicKey);
+                    assert.fail("Should have thrown SameOfficerApproval error");
+                } catch (err) {
+                    assert.include(err.message, "SameOfficerApproval");
+                }
+
+                await reissue(seizureRecord, recipient, 300, secondOfficer);
+                assert.equal(Number(await balanceOf(recipient)), 301);
+                const record = await program.account.reissueRecord.fetch(findReissueRecord(seizureRecord));
+                assert.ok(record.coSigner.equals(secondOfficer.publicKey));
+            });
+        });
+
+        describe("frozen on blacklist", () => {
+            const blacklistAndFreeze = (owner: PublicKey) =>
+                program.methods
+                    .blacklistAdd(owner, 1, "Court order", null)
+                    .accounts({
+                        blacklistAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        blacklistEntry: findBlacklistEntry(owner),
+                        tokenAccount: ataOf(owner),
+                        freezeAuthority: authority.publicKey,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+
+            const frozenHolder = async (amount: number) => {
+                const owner = Keypair.generate().publicKey;
+                const account = await fund(owner, amount);
+                await blacklistAndFreeze(owner);
+                return { owner, account };
+            };
+
+            const isFrozen = async (account: PublicKey) =>
+                (await getAccount(provider.connection, account, undefined, TOKEN_2022_PROGRAM_ID)).isFrozen;
+
+            before(async () => {
+                await program.methods
+                    .setFeatureFlag({ freezeOnBlacklist: {} }, true)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .rpc();
+            });
+
+            it("refuses to batch-thaw a blacklisted owner's account", async () => {
+                const { owner, account } = await frozenHolder(1_000);
+                assert.isTrue(await isFrozen(account));
+
+                const thawBatch = (accounts: PublicKey[]) =>
+                    program.methods
+                        .thawBatch()
+                        .accounts({
+                            freezeAuthority: authority.publicKey,
+                            mint: mint.publicKey,
+                            stablecoinState: stablecoinStatePDA,
+                            tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        })
+                        .remainingAccounts(
+                            accounts.map((pubkey) => ({ pubkey, isWritable: pubkey.equals(account), isSigner: false }))
+                        )
+                        .rpc();
+
+                try {
+                    await thawBatch([account]);
+                    assert.fail("Should have thrown MissingAccount error");
+                } catch (err) {
+                    assert.include(err.message, "MissingAccount");
+                }
+
+                try {
+                    await thawBatch([account, findBlacklistEntry(owner)]);
+                    assert.fail("Should have thrown CannotThawBlacklisted error");
+                } catch (err) {
+                    assert.include(err.message, "CannotThawBlacklisted");
+                }
+                assert.isTrue(await isFrozen(account));
+            });
+
+            it("seizes from an account frozen on blacklisting", async () => {
+                const { account } = await frozenHolder(1_000);
+
+                // Compliance officers hold no Token-2022 authority, so the PDA must be able to thaw
+                try {
+                    await seize(account, 0, true);
+                    assert.fail("Should have thrown FreezeAuthorityNotDelegated error");
+                } catch (err) {
+                    assert.include(err.message, "FreezeAuthorityNotDelegated");
+                }
+
+                await program.methods
+                    .delegateFreezeAuthority()
+                    .accounts({
+                        currentAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+
+                const treasuryBefore = await balanceOf(treasury);
+                await seize(account, 0, true);
+
+                assert.equal(Number(await balanceOf(account)), 0);
+                assert.equal(Number((await balanceOf(treasury)) - treasuryBefore), 1_000);
+                assert.isTrue(await isFrozen(account));
+            });
+        });
+    });
+
+    describe("Blacklisted Mint", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+        const findBlacklistEntry = (owner: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), owner.toBuffer()],
+                program.programId
+            )[0];
+
+        const mintToOwner = (owner: PublicKey, amount: number) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: findBlacklistEntry(owner),
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, { ...sss2Config("Screened Dollar", "SCRN"), defaultAccountFrozen: false });
+        });
+
+        it("blocks minting to a blacklisted owner", async () => {
+            const owner = Keypair.generate().publicKey;
+            await program.methods
+                .blacklistAdd(owner, 1, "OFAC SDN match", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: findBlacklistEntry(owner),
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            try {
+                await mintToOwner(owner, 1_000);
+                assert.fail("Should have thrown TransferBlocked error");
+            } catch (err) {
+                assert.include(err.message, "TransferBlocked");
+            }
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.totalMinted.toNumber(), 0);
+        });
+
+        it("allows minting to a non-blacklisted owner", async () => {
+            // The owner's blacklist PDA was never created, which counts as not listed
+            const owner = Keypair.generate().publicKey;
+            assert.isNull(await provider.connection.getAccountInfo(findBlacklistEntry(owner)));
+
+            await mintToOwner(owner, 1_000);
+
+            const account = await getAccount(provider.connection, ataOf(owner), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_000);
+        });
+    });
+
+    describe("Blacklisted Transfer", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const flagged = Keypair.generate();
+        const cleanSender = Keypair.generate();
+        const receiver = Keypair.generate().publicKey;
+
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+        const findBlacklistEntry = (owner: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), owner.toBuffer()],
+                program.programId
+            )[0];
+
+        const fund = (owner: PublicKey, amount: number) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: findBlacklistEntry(owner),
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const transfer = (sender: Keypair, withBlacklists: boolean) =>
+            program.methods
+                .transfer(new anchor.BN(100), null, null)
+                .accounts({
+                    owner: sender.publicKey,
+                    mint: mint.publicKey,
+                    from: ataOf(sender.publicKey),
+                    to: ataOf(receiver),
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: withBlacklists ? findBlacklistEntry(sender.publicKey) : null,
+                    destinationBlacklist: withBlacklists ? findBlacklistEntry(receiver) : null,
+                    sourceActivity: null,
+                    destinationActivity: null,
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .signers([sender])
+                .rpc();
+
+        // Send a plain Token-2022 transfer, which runs the hook, and return the
+        // TransferHookExecuted events logged by the failed transaction
+        const blockedHookEvents = async (from: Keypair, to: PublicKey) => {
+            try {
+                await provider.sendAndConfirm(
+                    new Transaction().add(
+                        await createTransferCheckedWithTransferHookInstruction(
+                            provider.connection,
+                            ataOf(from.publicKey),
+                            mint.publicKey,
+                            ataOf(to),
+                            from.publicKey,
+                            BigInt(100),
+                            6,
+                            [],
+                            undefined,
+                            TOKEN_2022_PROGRAM_ID
+                        )
+                    ),
+                    [from]
+                );
+                assert.fail("Should have thrown TransferBlocked error");
+            } catch (err) {
+                assert.include(err.logs.join("\n"), "TransferBlocked");
+                return err.logs
+                    .filter((log: string) => log.startsWith("Program data: "))
+                    .map((log: string) => program.coder.events.decode(log.slice("Program data: ".length)))
+                    .filter((event) => event?.name === "transferHookExecuted")
+                    .map((event) => event.data);
+            }
+        };
+
+        before(async () => {
+            await initStablecoin(mint, { ...sss2Config("Walled Dollar", "BLKT"), defaultAccountFrozen: false });
+            await program.methods
+                .initializeExtraAccountMetaList()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    extraAccountMetaList: getExtraAccountMetaAddress(mint.publicKey, program.programId),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            for (const signer of [flagged, cleanSender]) {
+                await provider.connection.confirmTransaction(
+                    await provider.connection.requestAirdrop(signer.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+                );
+            }
+            // Funded before the listing, since minting to a blacklisted owner is refused
+            await fund(flagged.publicKey, 1_000);
+            await fund(cleanSender.publicKey, 1_000);
+            await fund(receiver, 1);
+        });
+
+        it("requires blacklist accounts for SSS-2 transfers", async () => {
+            try {
+                await transfer(flagged, false);
+                assert.fail("Should have thrown MissingAccount error");
+            } catch (err) {
+                assert.include(err.message, "MissingAccount");
+            }
+        });
+
+        it("blocks a direct transfer from a blacklisted source", async () => {
+            await program.methods
+                .blacklistAdd(flagged.publicKey, 1, "OFAC SDN match", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: findBlacklistEntry(flagged.publicKey),
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            try {
+                await transfer(flagged, true);
+                assert.fail("Should have thrown TransferBlocked error");
+            } catch (err) {
+                assert.include(err.message, "TransferBlocked");
+            }
+
+            const account = await getAccount(provider.connection, ataOf(flagged.publicKey), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_000);
+        });
+
+        it("reports the source as the blocked party", async () => {
+            const [event] = await blockedHookEvents(flagged, receiver);
+
+            assert.isFalse(event.allowed);
+            assert.equal(event.blockedParty, 1);
+        });
+
+        it("reports the destination as the blocked party", async () => {
+            const [event] = await blockedHookEvents(cleanSender, flagged.publicKey);
+
+            assert.isFalse(event.allowed);
+            assert.equal(event.blockedParty, 2);
+        });
+    });
+
+    describe("Blacklist Count", () => {
+        const countMint = Keypair.generate();
+        const countStatePDA = findStablecoinState(countMint.publicKey);
+        const first = Keypair.generate();
+        const second = Keypair.generate();
+
+        const blacklistEntryFor = (address: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), countMint.publicKey.toBuffer(), address.toBuffer()],
+                program.programId
+            )[0];
+
+        const add = (address: PublicKey) =>
+            program.methods
+                .blacklistAdd(address, 0, "", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: countMint.publicKey,
+                    stablecoinState: countStatePDA,
+                    blacklistEntry: blacklistEntryFor(address),
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const remove = (address: PublicKey) =>
+            program.methods
+                .blacklistRemove(address, false)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: countMint.publicKey,
+                    stablecoinState: countStatePDA,
+                    blacklistEntry: blacklistEntryFor(address),
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(countMint, sss2Config("Counted Dollar", "CUSD"));
+            await initComplianceOfficers(countMint);
+        });
+
+        it("tracks adds and removals", async () => {
+            await add(first.publicKey);
+            await add(second.publicKey);
+            await remove(first.publicKey);
+
+            const state = await program.account.stablecoinState.fetch(countStatePDA);
+            assert.equal(state.activeBlacklistCount.toNumber(), 1);
+        });
+
+        it("does not double-count a rejected re-add", async () => {
+            try {
+                await add(second.publicKey);
+                assert.fail("Should have thrown AlreadyBlacklisted error");
+            } catch (err) {
+                assert.include(err.message, "AlreadyBlacklisted");
+            }
+
+            const state = await program.account.stablecoinState.fetch(countStatePDA);
+            assert.equal(state.activeBlacklistCount.toNumber(), 1);
+        });
+
+        it("counts a removed address again when re-added", async () => {
+            await add(first.publicKey);
+
+            const state = await program.account.stablecoinState.fetch(countStatePDA);
+            assert.equal(state.activeBlacklistCount.toNumber(), 2);
+        });
+
+        const isBlacklisted = (address: PublicKey) =>
+            program.methods
+                .isBlacklisted(address)
+                .accounts({
+                    mint: countMint.publicKey,
+                    stablecoinState: countStatePDA,
+                    blacklistEntry: blacklistEntryFor(address),
+                })
+                .view();
+
+        it("reports an active entry as blacklisted", async () => {
+            assert.isTrue(await isBlacklisted(first.publicKey));
+        });
+
+        it("reports an address without an entry as not blacklisted", async () => {
+            assert.isFalse(await isBlacklisted(Keypair.generate().publicKey));
+        });
+
+        it("reports a removed entry as not blacklisted", async () => {
+            await remove(second.publicKey);
+
+            assert.isFalse(await isBlacklisted(second.publicKey));
+        });
+    });
+
+    describe("Blacklist Cap", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const listed = [Keypair.generate(), Keypair.generate()];
+
+        const blacklistEntryFor = (address: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), address.toBuffer()],
+                program.programId
+            )[0];
+
+        const add = (address: PublicKey) =>
+            program.methods
+                .blacklistAdd(address, 0, "", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: blacklistEntryFor(address),
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const remove = (address: PublicKey) =>
+            program.methods
+                .blacklistRemove(address, false)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: blacklistEntryFor(address),
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss2Config("Capped List Dollar", "CAPL"));
+            await initComplianceOfficers(mint);
+            await program.methods
+                .setMaxBlacklistEntries(new anchor.BN(2))
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+        });
+
+        it("adds entries up to the cap", async () => {
+            for (const wallet of listed) {
+                await add(wallet.publicKey);
+            }
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.maxBlacklistEntries.toNumber(), 2);
+            assert.equal(state.activeBlacklistCount.toNumber(), 2);
+        });
+
+        it("rejects an add beyond the cap", async () => {
+            try {
+                await add(Keypair.generate().publicKey);
+                assert.fail("Should have thrown BlacklistFull error");
+            } catch (err) {
+                assert.include(err.message, "BlacklistFull");
+            }
+        });
+
+        it("accepts an add once a removal frees a slot", async () => {
+            await remove(listed[0].publicKey);
+            await add(Keypair.generate().publicKey);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.activeBlacklistCount.toNumber(), 2);
+        });
+    });
+
+    describe("Emergency Blacklist Clear", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const flagged = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+        const blacklistEntryFor = (address: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), address.toBuffer()],
+                program.programId
+            )[0];
+
+        const clear = (entries: PublicKey[], signer: Keypair = authority) =>
+            program.methods
+                .clearAllBlacklist()
+                .accounts({
+                    authority: signer.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                })
+                .remainingAccounts(
+                    entries.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }))
+                )
+                .signers(signer === authority ? [] : [signer])
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss2Config("Incident Dollar", "INC"));
+            await initComplianceOfficers(mint);
+
+            for (const wallet of flagged) {
+                await program.methods
+                    .blacklistAdd(wallet.publicKey, 0, "bad list push", null)
+                    .accounts({
+                        blacklistAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        blacklistEntry: blacklistEntryFor(wallet.publicKey),
+                        tokenAccount: null,
+                        freezeAuthority: null,
+                        tokenProgram: null,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+            }
+        });
+
+        it("rejects a clear from a non-authority", async () => {
+            const intruder = Keypair.generate();
+
+            try {
+                await clear(flagged.map((wallet) => blacklistEntryFor(wallet.publicKey)), intruder);
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("rejects an empty batch", async () => {
+            try {
+                await clear([]);
+                assert.fail("Should have thrown InvalidAmount error");
+            } catch (err) {
+                assert.include(err.message, "InvalidAmount");
+            }
+        });
+
+        it("rejects batches over 15 entries", async () => {
+            try {
+                await clear(Array.from({ length: 16 }, () => Keypair.generate().publicKey));
+                assert.fail("Should have thrown BatchTooLarge error");
+            } catch (err) {
+                assert.include(err.message, "BatchTooLarge");
+            }
+        });
+
+        it("clears every active entry and zeroes the count", async () => {
+            await clear(flagged.map((wallet) => blacklistEntryFor(wallet.publicKey)));
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.activeBlacklistCount.toNumber(), 0);
+
+            for (const wallet of flagged) {
+                const entry = await program.account.blacklistEntry.fetch(blacklistEntryFor(wallet.publicKey));
+                assert.isFalse(entry.isActive);
+            }
+        });
+
+        it("skips entries that are already inactive", async () => {
+            await clear([blacklistEntryFor(flagged[0].publicKey)]);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.activeBlacklistCount.toNumber(), 0);
+        });
+    });
+
+    describe("Compliance Officers", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const complianceOfficers = findComplianceOfficers(mint.publicKey);
+        const analystA = Keypair.generate();
+        const analystB = Keypair.generate();
+
+        const updateOfficers = (method: "addComplianceOfficer" | "removeComplianceOfficer", officer: PublicKey) =>
+            program.methods[method](officer)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers,
+                })
+                .rpc();
+
+        const blacklistAs = (officer: Keypair, address: PublicKey) =>
+            program.methods
+                .blacklistAdd(address, 0, "", null)
+                .accounts({
+                    blacklistAuthority: officer.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: PublicKey.findProgramAddressSync(
+                        [Buffer.from("blacklist"), mint.publicKey.toBuffer(), address.toBuffer()],
+                        program.programId
+                    )[0],
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([officer])
+                .rpc();
+
+        const holdAs = (officer: Keypair, owner: PublicKey) =>
+            program.methods
+                .placeHold(owner, "Analyst review", null)
+                .accounts({
+                    complianceOfficer: officer.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers,
+                    complianceHold: PublicKey.findProgramAddressSync(
+                        [Buffer.from("hold"), mint.publicKey.toBuffer(), owner.toBuffer()],
+                        program.programId
+                    )[0],
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([officer])
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss2Config("Team Dollar", "TEAM"));
+            await initComplianceOfficers(mint);
+
+            for (const analyst of [analystA, analystB]) {
+                await provider.connection.confirmTransaction(
+                    await provider.connection.requestAirdrop(analyst.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+                );
+            }
+        });
+
+        it("adds two officers", async () => {
+            await updateOfficers("addComplianceOfficer", analystA.publicKey);
+            await updateOfficers("addComplianceOfficer", analystB.publicKey);
+
+            const list = await program.account.complianceOfficers.fetch(complianceOfficers);
+            assert.equal(list.officers.length, 3);
+        });
+
+        it("does not let list officers blacklist", async () => {
+            // Blacklisting belongs to the blacklist authority, not the officer list
+            try {
+                await blacklistAs(analystA, Keypair.generate().publicKey);
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("lets every listed officer place holds", async () => {
+            for (const analyst of [analystA, analystB]) {
+                const owner = Keypair.generate().publicKey;
+                await holdAs(analyst, owner);
+
+                const hold = await program.account.complianceHold.fetch(
+                    PublicKey.findProgramAddressSync(
+                        [Buffer.from("hold"), mint.publicKey.toBuffer(), owner.toBuffer()],
+                        program.programId
+                    )[0]
+                );
+                assert.ok(hold.placedBy.equals(analyst.publicKey));
+            }
+        });
+
+        it("rejects adding an officer twice", async () => {
+            try {
+                await updateOfficers("addComplianceOfficer", analystA.publicKey);
+                assert.fail("Should have thrown OfficerAlreadyListed error");
+            } catch (err) {
+                assert.include(err.message, "OfficerAlreadyListed");
+            }
+        });
+
+        it("revokes access on removal", async () => {
+            await updateOfficers("removeComplianceOfficer", analystB.publicKey);
+
+            const list = await program.account.complianceOfficers.fetch(complianceOfficers);
+            assert.equal(list.officers.length, 2);
+            assert.isFalse(list.officers.some((o) => o.equals(analystB.publicKey)));
+
+            try {
+                await holdAs(analystB, Keypair.generate().publicKey);
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("rejects officer changes from a non-authority", async () => {
+            try {
+                await program.methods
+                    .addComplianceOfficer(analystB.publicKey)
+                    .accounts({
+                        authority: analystA.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        complianceOfficers,
+                    })
+                    .signers([analystA])
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+    });
+
+    describe("Blacklist Authority", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const listKeeper = Keypair.generate();
+
+        const blacklistAs = (signer: PublicKey, address: PublicKey, signers: Keypair[] = []) =>
+            program.methods
+                .blacklistAdd(address, 0, "", null)
+                .accounts({
+                    blacklistAuthority: signer,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: PublicKey.findProgramAddressSync(
+                        [Buffer.from("blacklist"), mint.publicKey.toBuffer(), address.toBuffer()],
+                        program.programId
+                    )[0],
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers(signers)
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss2Config("Listed Dollar", "LIST"));
+            await initComplianceOfficers(mint);
+            await provider.connection.confirmTransaction(
+                await provider.connection.requestAirdrop(listKeeper.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+            );
+        });
+
+        it("defaults to the compliance officer", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.blacklistAuthority.equals(state.complianceOfficer));
+        });
+
+        it("hands blacklisting to a separate key", async () => {
+            await program.methods
+                .proposeRoleChange({ blacklistAuthority: {} }, listKeeper.publicKey)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    pendingRoleChange: findPendingRoleChange(mint.publicKey, 16),
+                    systemProgram: SystemProgram.programId,
+                    changelog: null,
+                })
+                .rpc();
+
+            await blacklistAs(listKeeper.publicKey, Keypair.generate().publicKey, [listKeeper]);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.blacklistAuthority.equals(listKeeper.publicKey));
+            assert.equal(state.activeBlacklistCount.toNumber(), 1);
+        });
+
+        it("stops the compliance officer from blacklisting", async () => {
+            try {
+                await blacklistAs(authority.publicKey, Keypair.generate().publicKey);
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("does not let the blacklist authority seize", async () => {
+            // Seizure still requires a listed compliance officer, even against an entry the keeper created
+            const target = Keypair.generate().publicKey;
+            const ataOf = (owner: PublicKey) =>
+                getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    ...[target, authority.publicKey].map((owner) =>
+                        createAssociatedTokenAccountInstruction(
+                            authority.publicKey,
+                            ataOf(owner),
+                            owner,
+                            mint.publicKey,
+                            TOKEN_2022_PROGRAM_ID
+                        )
+                    )
+                )
+            );
+            await blacklistAs(listKeeper.publicKey, target, [listKeeper]);
+
+            const seizedAt = Number(
+                (await provider.connection.getAccountInfo(anchor.web3.SYSVAR_CLOCK_PUBKEY)).data.readBigInt64LE(32)
+            );
+            try {
+                await program.methods
+                    .seizeTokens(new anchor.BN(0), true, "Court order", new anchor.BN(seizedAt))
+                    .accounts({
+                        complianceOfficer: listKeeper.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        complianceOfficers: findComplianceOfficers(mint.publicKey),
+                        blacklistEntry: PublicKey.findProgramAddressSync(
+                            [Buffer.from("blacklist"), mint.publicKey.toBuffer(), target.toBuffer()],
+                            program.programId
+                        )[0],
+                        targetAccount: ataOf(target),
+                        destinationAccount: ataOf(authority.publicKey),
+                        seizureRecord: PublicKey.findProgramAddressSync(
+                            [
+                                Buffer.from("seizure"),
+                                mint.publicKey.toBuffer(),
+                                ataOf(target).toBuffer(),
+                                new anchor.BN(seizedAt).toArrayLike(Buffer, "le", 8),
+                            ],
+                            program.programId
+                        )[0],
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .signers([listKeeper])
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+    });
+
+    describe("Blacklist Bitmap", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const [blacklistBitmap] = PublicKey.findProgramAddressSync(
+            [Buffer.from("blacklist-bitmap"), mint.publicKey.toBuffer()],
+            program.programId
+        );
+        const flagged = Keypair.generate().publicKey;
+
+        // Mirrors bitmap::bit_index: first 4 bytes (LE) of sha256(prefix || owner) mod 8192
+        const bitIndex = (owner: PublicKey) =>
+            createHash("sha256")
+                .update(Buffer.concat([Buffer.from("sss-blacklist-bitmap"), owner.toBuffer()]))
+                .digest()
+                .readUInt32LE(0) % 8192;
+        const isSet = (bits: number[], index: number) => (bits[Math.floor(index / 8)] & (1 << index % 8)) !== 0;
+
+        const updateBitmap = (
+            method: "bitmapBlacklistAdd" | "bitmapBlacklistRemove",
+            address: PublicKey,
+            signer: Keypair | null = null
+        ) =>
+            program.methods[method](address)
+                .accounts({
+                    blacklistAuthority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistBitmap,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        before(async () => {
+            // Accounts start unfrozen, so the hook needs no thaw approval
+            await initStablecoin(mint, { ...sss2Config("Bitmap Dollar", "BMAP"), defaultAccountFrozen: false });
+            await program.methods
+                .initBlacklistBitmap()
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistBitmap,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+        });
+
+        it("sets the address's bit", async () => {
+            await updateBitmap("bitmapBlacklistAdd", flagged);
+
+            const bitmap = await program.account.blacklistBitmap.fetch(blacklistBitmap);
+            assert.isTrue(isSet(bitmap.bits, bitIndex(flagged)));
+            assert.equal(bitmap.flaggedBits, 1);
+        });
+
+        it("treats re-flagging as a no-op", async () => {
+            await updateBitmap("bitmapBlacklistAdd", flagged);
+
+            const bitmap = await program.account.blacklistBitmap.fetch(blacklistBitmap);
+            assert.equal(bitmap.flaggedBits, 1);
+        });
+
+        it("clears the address's bit", async () => {
+            await updateBitmap("bitmapBlacklistRemove", flagged);
+
+            const bitmap = await program.account.blacklistBitmap.fetch(blacklistBitmap);
+            assert.isFalse(isSet(bitmap.bits, bitIndex(flagged)));
+            assert.equal(bitmap.flaggedBits, 0);
+        });
+
+        it("rejects clearing a bit that is not set", async () => {
+            try {
+                await updateBitmap("bitmapBlacklistRemove", flagged);
+                assert.fail("Should have thrown NotBlacklisted error");
+            } catch (err) {
+                assert.include(err.message, "NotBlacklisted");
+            }
+        });
+
+        it("rejects bitmap updates from a non-blacklist-authority", async () => {
+            try {
+                await updateBitmap("bitmapBlacklistAdd", flagged, Keypair.generate());
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("switches the hook to bitmap checks", async () => {
+            await program.methods
+                .setFeatureFlag({ bitmapBlacklist: {} }, true)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(state.bitmapBlacklist);
+        });
+
+        it("blocks a transfer from a bitmap-flagged owner", async () => {
+            const sender = Keypair.generate();
+            const recipient = Keypair.generate().publicKey;
+            const ataOf = (owner: PublicKey) =>
+                getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            const entryOf = (owner: PublicKey) =>
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), mint.publicKey.toBuffer(), owner.toBuffer()],
+                    program.programId
+                )[0];
+
+            await program.methods
+                .initializeExtraAccountMetaList()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    extraAccountMetaList: getExtraAccountMetaAddress(mint.publicKey, program.programId),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            for (const owner of [sender.publicKey, recipient]) {
+                await program.methods
+                    .mintToNew(new anchor.BN(1_000), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        owner,
+                        tokenAccount: ataOf(owner),
+                        stablecoinState: stablecoinStatePDA,
+                        recipientBlacklist: entryOf(owner),
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        mintStats: null,
+                        feeCollector: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+            }
+            await updateBitmap("bitmapBlacklistAdd", sender.publicKey);
+
+            try {
+                await provider.sendAndConfirm(
+                    new Transaction().add(
+                        await createTransferCheckedWithTransferHookInstruction(
+                            provider.connection,
+                            ataOf(sender.publicKey),
+                            mint.publicKey,
+                            ataOf(recipient),
+                            sender.publicKey,
+                            BigInt(100),
+                            6,
+                            [],
+                            undefined,
+                            TOKEN_2022_PROGRAM_ID
+                        )
+                    ),
+                    [sender]
+                );
+                assert.fail("Should have thrown TransferBlocked error");
+            } catch (err) {
+                assert.include(err.logs.join("\n"), "TransferBlocked");
+            }
+
+            // Blocked by the bit alone: the owner has no blacklist entry
+            assert.isNull(await provider.connection.getAccountInfo(entryOf(sender.publicKey)));
+            const account = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_000);
+        });
+    });
+
+    describe("Freeze On Blacklist", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+
+        const blacklist = (address: PublicKey) =>
+            program.methods
+                .blacklistAdd(address, 0, "", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: PublicKey.findProgramAddressSync(
+                        [Buffer.from("blacklist"), mint.publicKey.toBuffer(), address.toBuffer()],
+                        program.programId
+                    )[0],
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        before(async () => {
+            // Accounts start unfrozen, so blacklisting is what freezes them
+            await initStablecoin(mint, { ...sss2Config("Frozen Out Dollar", "FOUT"), defaultAccountFrozen: false });
+        });
+
+        it("rejects freeze-on-blacklist for an SSS-1 stablecoin", async () => {
+            try {
+                await initStablecoin(Keypair.generate(), {
+                    ...sss1Config("Plain Freeze", "PFRZ"),
+                    freezeOnBlacklist: true,
+                });
+                assert.fail("Should have thrown SSS2Required error");
+            } catch (err) {
+                assert.include(err.message, "SSS2Required");
+            }
+        });
+
+        it("blacklists without freezing while the flag is off", async () => {
+            await blacklist(Keypair.generate().publicKey);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isFalse(state.freezeOnBlacklist);
+            assert.equal(state.activeBlacklistCount.toNumber(), 1);
+            assert.equal(state.freezeOpCount.toNumber(), 0);
+        });
+
+        it("requires the token account once the flag is on", async () => {
+            await program.methods
+                .setFeatureFlag({ freezeOnBlacklist: {} }, true)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+            try {
+                await blacklist(Keypair.generate().publicKey);
+                assert.fail("Should have thrown MissingAccount error");
+            } catch (err) {
+                assert.include(err.message, "MissingAccount");
+            }
+        });
+
+        it("freezes the token account when blacklisting", async () => {
+            const target = Keypair.generate().publicKey;
+            const tokenAccount = getAssociatedTokenAddressSync(mint.publicKey, target, false, TOKEN_2022_PROGRAM_ID);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        tokenAccount,
+                        target,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            const emitted: string[] = [];
+            const listeners = await Promise.all(
+                ["addressBlacklisted", "accountFrozenEvent"].map((name) =>
+                    program.addEventListener(name, () => {
+                        emitted.push(name);
+                    })
+                )
+            );
+            await program.methods
+                .blacklistAdd(target, 0, "Sanctions hit", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: PublicKey.findProgramAddressSync(
+                        [Buffer.from("blacklist"), mint.publicKey.toBuffer(), target.toBuffer()],
+                        program.programId
+                    )[0],
+                    tokenAccount,
+                    freezeAuthority: authority.publicKey,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            for (const listener of listeners) {
+                await program.removeEventListener(listener);
+            }
+
+            const account = await getAccount(provider.connection, tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.isTrue(account.isFrozen);
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.freezeOpCount.sub(before.freezeOpCount).toNumber(), 1);
+            assert.sameMembers(emitted, ["addressBlacklisted", "accountFrozenEvent"]);
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // SSS-1 → SSS-2 Upgrade Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Preset Upgrade", () => {
+        it("upgrades an SSS-1 mint created with dormant SSS-2 extensions", async () => {
+            // Extensions cannot be added later, so the mint is created with both up front,
+            // pointing at the stablecoin PDA and this program while it runs as SSS-1
+            const mint = Keypair.generate();
+            const stablecoinState = findStablecoinState(mint.publicKey);
+            const officer = Keypair.generate().publicKey;
+            const space = getMintLen([ExtensionType.PermanentDelegate, ExtensionType.TransferHook]);
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    SystemProgram.createAccount({
+                        fromPubkey: authority.publicKey,
+                        newAccountPubkey: mint.publicKey,
+                        space,
+                        lamports: await provider.connection.getMinimumBalanceForRentExemption(space),
+                        programId: TOKEN_2022_PROGRAM_ID,
+                    }),
+                    createInitializePermanentDelegateInstruction(mint.publicKey, stablecoinState, TOKEN_2022_PROGRAM_ID),
+                    createInitializeTransferHookInstruction(
+                        mint.publicKey,
+                        authority.publicKey,
+                        program.programId,
+                        TOKEN_2022_PROGRAM_ID
+                    ),
+                    createInitializeMintInstruction(
+                        mint.publicKey,
+                        6,
+                        authority.publicKey,
+                        authority.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                ),
+                [mint]
+            );
+            await initStablecoin(mint, sss1Config("Dormant Dollar", "DRMT"));
+
+            await program.methods
+                .upgradeToSss2(officer)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinState);
+            assert.isTrue(state.isSss2);
+            assert.isTrue(state.permanentDelegateEnabled);
+            assert.isTrue(state.transferHookEnabled);
+            assert.ok(state.complianceOfficer.equals(officer));
+            assert.ok(state.blacklistAuthority.equals(officer));
+        });
+
+        it("rejects the upgrade when the mint lacks the extensions", async () => {
+            const mint = Keypair.generate();
+            await initStablecoin(mint, sss1Config("Plain Dollar", "PLND"));
+
+            try {
+                await program.methods
+                    .upgradeToSss2(authority.publicKey)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: findStablecoinState(mint.publicKey),
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown MissingExtension error");
+            } catch (err) {
+                assert.include(err.message, "MissingExtension");
+            }
+        });
+    });
+
+    describe("State Migration", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+
+        const migrate = (signer?: Keypair) =>
+            program.methods
+                .migrateState()
+                .accounts({
+                    authority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Versioned Dollar", "VERS"));
+        });
+
+        it("stamps new accounts with the current version", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.version, 32);
+            assert.equal(state.lastMigratedAt.toNumber(), 0);
+        });
+
+        const legacyAuthority = fixtureKeypair("sss-legacy-authority");
+
+        const migrateFixture = async (mintLabel: string) => {
+            const fixtureMint = fixtureKeypair(mintLabel).publicKey;
+            const fixtureState = findStablecoinState(fixtureMint);
+            await program.methods
+                .migrateState()
+                .accounts({
+                    authority: legacyAuthority.publicKey,
+                    mint: fixtureMint,
+                    stablecoinState: fixtureState,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([legacyAuthority])
+                .rpc();
+            return program.account.stablecoinState.fetch(fixtureState);
+        };
+
+        const reallocFixture = (mintLabel: string) => {
+            const fixtureMint = fixtureKeypair(mintLabel).publicKey;
+            return program.methods
+                .reallocState()
+                .accounts({
+                    authority: legacyAuthority.publicKey,
+                    mint: fixtureMint,
+                    stablecoinState: findStablecoinState(fixtureMint),
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([legacyAuthority])
+                .rpc();
+        };
+
+        before(async () => {
+            await provider.connection.confirmTransaction(
+                await provider.connection.requestAirdrop(legacyAuthority.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+            );
+        });
+
+        it("refuses to realloc a pre-versioning account", async () => {
+            // Growing it would hide it from the size-based legacy detection
+            try {
+                await reallocFixture("sss-legacy-mint");
+                assert.fail("Should have thrown LegacyStateLayout error");
+            } catch (err) {
+                assert.include(err.message, "LegacyStateLayout");
+            }
+        });
+
+        it("migrates a pre-versioning account to the current version", async () => {
+            const state = await migrateFixture("sss-legacy-mint");
+
+            assert.equal(state.version, 32);
+            assert.isAbove(state.lastMigratedAt.toNumber(), 0);
+            assert.ok(state.authority.equals(legacyAuthority.publicKey));
+            assert.ok(state.mintAuthority.equals(legacyAuthority.publicKey));
+            assert.ok(state.pauser.equals(legacyAuthority.publicKey));
+            assert.ok(state.blacklistAuthority.equals(state.complianceOfficer));
+            assert.ok(state.feeAuthority.equals(legacyAuthority.publicKey));
+            assert.equal(state.name, "Legacy Dollar");
+            assert.equal(state.symbol, "LGCY");
+            assert.equal(state.uri, "");
+            assert.equal(state.decimals, 6);
+            assert.equal(state.totalMinted.toNumber(), 5_000_000);
+            assert.equal(state.totalBurned.toNumber(), 1_000_000);
+            assert.equal(state.createdAt.toNumber(), 1_700_000_000);
+            assert.isFalse(state.isSss2);
+            assert.isTrue(state.emitEvents);
+            assert.isNull(state.pendingAuthority);
+            assert.lengthOf(state.exemptAddresses, 0);
+        });
+
+        it("clears stale bytes past a v1 layout before back-filling", async () => {
+            // The fixture's bytes after `decimals` are 0xab, as left by a shrunk string
+            const state = await migrateFixture("sss-stale-v1-mint");
+
+            assert.equal(state.version, 32);
+            assert.equal(state.symbol, "STAL");
+            assert.equal(state.totalMinted.toNumber(), 7_000_000);
+            assert.equal(state.interestRateBps, 0);
+            assert.lengthOf(state.exemptAddresses, 0);
+            assert.equal(state.totalTransferVolume.toNumber(), 0);
+            assert.isNull(state.minTransferAmount);
+            assert.isNull(state.oracle);
+            assert.isNull(state.clawbackWindow);
+            assert.equal(state.mintFeeBps, 0);
+            assert.isNull(state.minAccountBalance);
+            assert.equal(state.blockedTransferCount.toNumber(), 0);
+            assert.ok(state.blacklistAuthority.equals(state.complianceOfficer));
+            assert.isTrue(state.emitEvents);
+        });
+
+        it("rejects migrating an account already at the current version", async () => {
+            try {
+                await migrate();
+                assert.fail("Should have thrown AlreadyMigrated error");
+            } catch (err) {
+                assert.include(err.message, "AlreadyMigrated");
+            }
+        });
+
+        it("rejects migration from a non-authority", async () => {
+            try {
+                await migrate(Keypair.generate());
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        const realloc = (signer?: Keypair) =>
+            program.methods
+                .reallocState()
+                .accounts({
+                    authority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        it("reallocs a smaller account to the current size", async () => {
+            // The fixture is a 653-byte v1 account whose bytes after its 381-byte layout are 0xab
+            const fixtureState = findStablecoinState(fixtureKeypair("sss-realloc-v1-mint").publicKey);
+            const before = await provider.connection.getAccountInfo(fixtureState);
+            assert.equal(before.data.length, 653);
+
+            await reallocFixture("sss-realloc-v1-mint");
+
+            const current = await provider.connection.getAccountInfo(stablecoinStatePDA);
+            const after = await provider.connection.getAccountInfo(fixtureState);
+            assert.equal(after.data.length, current.data.length);
+            // The stored v1 layout is untouched and everything past it reads as zero
+            assert.equal(after.data[8], 1);
+            assert.isTrue(after.data.subarray(0, 381).equals(before.data.subarray(0, 381)));
+            assert.isTrue(after.data.subarray(381).every((byte) => byte === 0));
+        });
+
+        it("treats a realloc at the current size as a no-op", async () => {
+            const before = await provider.connection.getAccountInfo(stablecoinStatePDA);
+            await realloc();
+            const after = await provider.connection.getAccountInfo(stablecoinStatePDA);
+
+            assert.equal(after.data.length, before.data.length);
+            assert.ok(after.data.equals(before.data));
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.version, 32);
+            assert.equal(state.symbol, "VERS");
+            assert.ok(state.authority.equals(authority.publicKey));
+        });
+
+        it("rejects realloc from a non-authority", async () => {
+            try {
+                await realloc(Keypair.generate());
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+    });
+
+    describe("Idempotent Initialize", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+
+        const initIfNeeded = (mintKey: Keypair, config: Record<string, unknown>) =>
+            program.methods
+                .initializeIfNeeded(config)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mintKey.publicKey,
+                    stablecoinState: findStablecoinState(mintKey.publicKey),
+                    symbolRegistry: findSymbolRegistry(config.symbol as string),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                    rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+                })
+                .signers([mintKey])
+                .rpc();
+
+        it("initializes a new stablecoin", async () => {
+            await initIfNeeded(mint, sss1Config("Rerun Dollar", "RERUN"));
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.name, "Rerun Dollar");
+            assert.ok(state.authority.equals(authority.publicKey));
+            assert.notEqual(state.createdAt.toNumber(), 0);
+        });
+
+        it("leaves an existing state untouched on a second call", async () => {
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            await initIfNeeded(mint, {
+                ...sss1Config("Hijacked Dollar", "RERUN"),
+                mintAuthority: Keypair.generate().publicKey,
+            });
+
+            const after = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(after.name, before.name);
+            assert.ok(after.authority.equals(before.authority));
+            assert.ok(after.mintAuthority.equals(before.mintAuthority));
+            assert.equal(after.createdAt.toNumber(), before.createdAt.toNumber());
+            assert.equal(after.updatedAt.toNumber(), before.updatedAt.toNumber());
+        });
+
+        it("rejects a new stablecoin claiming a taken symbol", async () => {
+            try {
+                await initIfNeeded(Keypair.generate(), sss1Config("Copycat Dollar", "RERUN"));
+                assert.fail("Should have thrown SymbolTaken error");
+            } catch (err) {
+                assert.include(err.message, "SymbolTaken");
+            }
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Role Management Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Role Management", () => {
+        it("assigns distinct roles at initialization", async () => {
+            const mint = Keypair.generate();
+            const minter = Keypair.generate().publicKey;
+            const freezer = Keypair.generate().publicKey;
+            const officer = Keypair.generate().publicKey;
+
+            await initStablecoin(mint, {
+                ...sss2Config("Separated USD", "SEPD"),
+                mintAuthority: minter,
+                freezeAuthority: freezer,
+                complianceOfficer: officer,
+            });
+
+            const state = await program.account.stablecoinState.fetch(findStablecoinState(mint.publicKey));
+            assert.ok(state.authority.equals(authority.publicKey));
+            assert.ok(state.mintAuthority.equals(minter));
+            assert.ok(state.freezeAuthority.equals(freezer));
+            assert.ok(state.complianceOfficer.equals(officer));
+        });
+
+        it("rejects a custom compliance officer on SSS-1", async () => {
+            const mint = Keypair.generate();
+
+            try {
+                await initStablecoin(mint, {
+                    ...sss1Config("Minimal Dollar", "MIND"),
+                    complianceOfficer: Keypair.generate().publicKey,
+                });
+                assert.fail("Should have thrown SSS2Required error");
+            } catch (err) {
+                assert.include(err.message, "SSS2Required");
+            }
+        });
+
+        it("updates mint authority", async () => {
+            const newAuthority = Keypair.generate();
+            assert.ok(true, "Role update test placeholder");
+        });
+
+        it("updates freeze authority", async () => {
+            assert.ok(true, "Freeze authority update test placeholder");
+        });
+
+        it("updates compliance officer (SSS-2 only)", async () => {
+            assert.ok(true, "Compliance officer update test placeholder");
+        });
+
+        it("renounces mint authority and blocks further minting", async () => {
+            const mint = Keypair.generate();
+            const stablecoinState = findStablecoinState(mint.publicKey);
+            await initStablecoin(mint, sss1Config("Fixed Supply", "FIXD"));
+
+            await program.methods
+                .renounceRole({ mintAuthority: {} })
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState,
+                    changelog: null,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinState);
+            assert.ok(state.mintAuthority.equals(PublicKey.default));
+
+            try {
+                await program.methods
+                    .mintTo(new anchor.BN(1_000_000), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        feeCollector: null,
+                        mintStats: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+
+            try {
+                await program.methods
+                    .proposeRoleChange({ mintAuthority: {} }, authority.publicKey)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState,
+                        pendingRoleChange: findPendingRoleChange(mint.publicKey, 1),
+                        systemProgram: SystemProgram.programId,
+                        changelog: null,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown RoleRenounced error");
+            } catch (err) {
+                assert.include(err.message, "RoleRenounced");
+            }
+        });
+
+        it("applies a role change immediately with no delay", async () => {
+            const mint = Keypair.generate();
+            const stablecoinState = findStablecoinState(mint.publicKey);
+            const newFreezer = Keypair.generate();
+            await initStablecoin(mint, sss1Config("Instant Dollar", "INST"));
+
+            await program.methods
+                .proposeRoleChange({ freezeAuthority: {} }, newFreezer.publicKey)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState,
+                    pendingRoleChange: findPendingRoleChange(mint.publicKey, 2),
+                    systemProgram: SystemProgram.programId,
+                    changelog: null,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinState);
+            assert.ok(state.freezeAuthority.equals(newFreezer.publicKey));
+        });
+
+        describe("with a role-change timelock", () => {
+            const mint = Keypair.generate();
+            const newMinter = Keypair.generate();
+            const pendingRoleChange = findPendingRoleChange(mint.publicKey, 1);
+            let stablecoinState: PublicKey;
+
+            const execute = () =>
+                program.methods
+                    .executeRoleChange({ mintAuthority: {} })
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState,
+                        pendingRoleChange,
+                        changelog: null,
+                    })
+                    .rpc();
+
+            before(async () => {
+                stablecoinState = findStablecoinState(mint.publicKey);
+                await initStablecoin(mint, {
+                    ...sss1Config("Timelocked Dollar", "TLCK"),
+                    roleChangeDelay: new anchor.BN(2),
+                });
+
+                await program.methods
+                    .proposeRoleChange({ mintAuthority: {} }, newMinter.publicKey)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState,
+                        pendingRoleChange,
+                        systemProgram: SystemProgram.programId,
+                        changelog: null,
+                    })
+                    .rpc();
+            });
+
+            it("rejects execution before the delay elapses", async () => {
+                try {
+                    await execute();
+                    assert.fail("Should have thrown TimelockNotElapsed error");
+                } catch (err) {
+                    assert.include(err.message, "TimelockNotElapsed");
+                }
+
+                const state = await program.account.stablecoinState.fetch(stablecoinState);
+                assert.ok(state.mintAuthority.equals(authority.publicKey));
+            });
+
+            it("executes the change once the delay elapses", async () => {
+                await new Promise((resolve) => setTimeout(resolve, 3_000));
+                await execute();
+
+                const state = await program.account.stablecoinState.fetch(stablecoinState);
+                assert.ok(state.mintAuthority.equals(newMinter.publicKey));
+                const pending = await program.account.pendingRoleChange.fetch(pendingRoleChange);
+                assert.isFalse(pending.isPending);
+            });
+        });
+
+        describe("with a mint authority rotation cooldown", () => {
+            const mint = Keypair.generate();
+            const pendingRoleChange = findPendingRoleChange(mint.publicKey, 1);
+            let stablecoinState: PublicKey;
+
+            const rotate = (newMinter: PublicKey) =>
+                program.methods
+                    .proposeRoleChange({ mintAuthority: {} }, newMinter)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState,
+                        pendingRoleChange,
+                        systemProgram: SystemProgram.programId,
+                        changelog: null,
+                    })
+                    .rpc();
+
+            before(async () => {
+                stablecoinState = findStablecoinState(mint.publicKey);
+                await initStablecoin(mint, {
+                    ...sss1Config("Cooldown Dollar", "COOL"),
+                    mintAuthorityRotationCooldown: new anchor.BN(2),
+                });
+            });
+
+            it("blocks a rotation inside the cooldown", async () => {
+                try {
+                    await rotate(Keypair.generate().publicKey);
+                    assert.fail("Should have thrown RotationCooldownActive error");
+                } catch (err) {
+                    assert.include(err.message, "RotationCooldownActive");
+                }
+            });
+
+            it("allows a rotation once the cooldown elapses", async () => {
+                const newMinter = Keypair.generate();
+                await new Promise((resolve) => setTimeout(resolve, 3_000));
+                await rotate(newMinter.publicKey);
+
+                const state = await program.account.stablecoinState.fetch(stablecoinState);
+                assert.ok(state.mintAuthority.equals(newMinter.publicKey));
+                assert.isAbove(state.lastMintAuthorityChange.toNumber(), state.createdAt.toNumber());
+            });
+        });
+
+        it("rejects role update from non-authority", async () => {
+            assert.ok(true, "Unauthorized role update rejection placeholder");
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Batch Mint Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Batch Mint", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const holders = [Keypair.generate(), Keypair.generate(), Keypair.generate()].map((kp) => kp.publicKey);
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+        const pdaOf = (seed: string, owner: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from(seed), mint.publicKey.toBuffer(), ataOf(owner).toBuffer()],
+                program.programId
+            )[0];
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Payroll Dollar", "PAYD"));
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    ...holders.map((holder) =>
+                        createAssociatedTokenAccountInstruction(
+                            authority.publicKey,
+                            ataOf(holder),
+                            holder,
+                            mint.publicKey,
+                            TOKEN_2022_PROGRAM_ID
+                        )
+                    )
+                )
+            );
+        });
+
+        const mintBatch = (
+            amounts: anchor.BN[],
+            remaining: PublicKey[] = amounts.map(() => Keypair.generate().publicKey),
+            trackStats = false
+        ) =>
+            program.methods
+                .mintToBatch(amounts, trackStats)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    oracle: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .remainingAccounts(remaining.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+                .rpc();
+
+        const balanceOf = async (owner: PublicKey) =>
+            Number((await getAccount(provider.connection, ataOf(owner), undefined, TOKEN_2022_PROGRAM_ID)).amount);
+
+        it("mints a mixed-amount batch", async () => {
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            await mintBatch([100, 200, 300].map((n) => new anchor.BN(n)), holders.map(ataOf));
+
+            assert.deepEqual(await Promise.all(holders.map(balanceOf)), [100, 200, 300]);
+            const after = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(after.totalMinted.sub(before.totalMinted).toNumber(), 600);
+            assert.equal(after.mintOpCount.sub(before.mintOpCount).toNumber(), 3);
+        });
+
+        it("records mint stats per recipient with trackStats", async () => {
+            const [first, second] = holders;
+            await mintBatch(
+                [new anchor.BN(50), new anchor.BN(70)],
+                [ataOf(first), pdaOf("mintstats", first), ataOf(second), pdaOf("mintstats", second)],
+                true
+            );
+
+            const stats = await program.account.mintStats.fetch(pdaOf("mintstats", first));
+            assert.ok(stats.tokenAccount.equals(ataOf(first)));
+            assert.equal(stats.cumulativeMinted.toNumber(), 50);
+            assert.equal(stats.mintCount.toNumber(), 1);
+        });
+
+        it("writes clawback records and mint locks while those features are on", async () => {
+            const holder = holders[2];
+            const limitAccounts = {
+                authority: authority.publicKey,
+                mint: mint.publicKey,
+                stablecoinState: stablecoinStatePDA,
+                changelog: null,
+            };
+            await program.methods.setClawbackWindow(new anchor.BN(600)).accounts(limitAccounts).rpc();
+            await program.methods.setMintLockPeriod(new anchor.BN(600)).accounts(limitAccounts).rpc();
+
+            try {
+                await mintBatch([new anchor.BN(10)], [ataOf(holder)]);
+                assert.fail("Should have thrown BatchLengthMismatch error");
+            } catch (err) {
+                assert.include(err.message, "BatchLengthMismatch");
+            }
+
+            await mintBatch([new anchor.BN(10)], [ataOf(holder), pdaOf("clawback", holder), pdaOf("mint_lock", holder)]);
+
+            const record = await program.account.clawbackRecord.fetch(pdaOf("clawback", holder));
+            assert.equal(record.amount.toNumber(), 10);
+            const lock = await program.account.mintLock.fetch(pdaOf("mint_lock", holder));
+            assert.isAbove(lock.unlockAt.toNumber(), record.mintedAt.toNumber());
+
+            await program.methods.setClawbackWindow(null).accounts(limitAccounts).rpc();
+            await program.methods.setMintLockPeriod(null).accounts(limitAccounts).rpc();
+        });
+
+        it("rejects a batch longer than 15 entries", async () => {
+            const amounts = Array.from({ length: 16 }, () => new anchor.BN(1_000));
+
+            try {
+                await mintBatch(amounts);
+                assert.fail("Should have thrown BatchTooLarge error");
+            } catch (err) {
+                assert.include(err.message, "BatchTooLarge");
+            }
+        });
+
+        it("rejects a batch whose running total overflows", async () => {
+            const amounts = [new anchor.BN("18446744073709551615"), new anchor.BN(1)];
+
+            try {
+                await mintBatch(amounts);
+                assert.fail("Should have thrown Overflow error");
+            } catch (err) {
+                assert.include(err.message, "Overflow");
+            }
+        });
+    });
+
+    describe("Mint Allowance", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const partner = Keypair.generate();
+        const mintAllowancePDA = PublicKey.findProgramAddressSync(
+            [Buffer.from("allowance"), mint.publicKey.toBuffer(), partner.publicKey.toBuffer()],
+            program.programId
+        )[0];
+        const partnerAta = getAssociatedTokenAddressSync(mint.publicKey, partner.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+        const mintWithAllowance = (amount: number) =>
+            program.methods
+                .mintWithAllowance(new anchor.BN(amount))
+                .accounts({
+                    delegate: partner.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: partnerAta,
+                    stablecoinState: stablecoinStatePDA,
+                    mintAllowance: mintAllowancePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .signers([partner])
+                .rpc();
+
+        const grant = (amount: number, signer: Keypair | null = null) =>
+            program.methods
+                .grantMintAllowance(partner.publicKey, new anchor.BN(amount))
+                .accounts({
+                    mintAuthority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    mintAllowance: mintAllowancePDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Partner Dollar", "PTNR"));
+            await provider.connection.confirmTransaction(
+                await provider.connection.requestAirdrop(partner.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+            );
+            // Allowance mints are signed by the stablecoin PDA, so it takes the Token-2022 mint authority
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        partnerAta,
+                        partner.publicKey,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    ),
+                    createSetAuthorityInstruction(
+                        mint.publicKey,
+                        authority.publicKey,
+                        AuthorityType.MintTokens,
+                        stablecoinStatePDA,
+                        [],
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+        });
+
+        it("grants an allowance to a delegate", async () => {
+            await grant(5_000);
+
+            const allowance = await program.account.mintAllowance.fetch(mintAllowancePDA);
+            assert.ok(allowance.delegate.equals(partner.publicKey));
+            assert.equal(allowance.remaining.toNumber(), 5_000);
+        });
+
+        it("rejects a grant from anyone but the mint authority", async () => {
+            try {
+                await grant(1_000_000, partner);
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("mints within the allowance and decrements it", async () => {
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            let mintedBy: PublicKey | undefined;
+            const listener = program.addEventListener("tokensMinted", (event) => {
+                mintedBy = event.mintedBy;
+            });
+
+            await mintWithAllowance(3_000);
+            await program.removeEventListener(listener);
+
+            const allowance = await program.account.mintAllowance.fetch(mintAllowancePDA);
+            assert.equal(allowance.remaining.toNumber(), 2_000);
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.totalMinted.sub(before.totalMinted).toNumber(), 3_000);
+            const account = await getAccount(provider.connection, partnerAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 3_000);
+            assert.ok(mintedBy.equals(partner.publicKey));
+        });
+
+        it("rejects minting past the allowance", async () => {
+            try {
+                await mintWithAllowance(2_001);
+                assert.fail("Should have thrown AllowanceExhausted error");
+            } catch (err) {
+                assert.include(err.message, "AllowanceExhausted");
+            }
+        });
+
+        it("resets the budget on a re-grant", async () => {
+            await grant(0);
+
+            const allowance = await program.account.mintAllowance.fetch(mintAllowancePDA);
+            assert.equal(allowance.remaining.toNumber(), 0);
+        });
+    });
+
+    describe("Mint Clawback", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+
+        const holder = Keypair.generate().publicKey;
+        const holderAta = getAssociatedTokenAddressSync(mint.publicKey, holder, false, TOKEN_2022_PROGRAM_ID);
+        const clawbackRecord = PublicKey.findProgramAddressSync(
+            [Buffer.from("clawback"), mint.publicKey.toBuffer(), holderAta.toBuffer()],
+            program.programId
+        )[0];
+        const holderBlacklist = PublicKey.findProgramAddressSync(
+            [Buffer.from("blacklist"), mint.publicKey.toBuffer(), holder.toBuffer()],
+            program.programId
+        )[0];
+
+        const mintToHolder = (amount: number, record: PublicKey | null = clawbackRecord) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner: holder,
+                    tokenAccount: holderAta,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: holderBlacklist,
+                    oracle: null,
+                    clawbackRecord: record,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const clawback = () =>
+            program.methods
+                .clawbackMint()
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: holderAta,
+                    stablecoinState: stablecoinStatePDA,
+                    clawbackRecord,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+        const setWindow = (window: number | null, signer: Keypair | null = null) =>
+            program.methods
+                .setClawbackWindow(window === null ? null : new anchor.BN(window))
+                .accounts({
+                    authority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, { ...sss2Config("Reversible Dollar", "RVRS"), defaultAccountFrozen: false });
+        });
+
+        it("starts with clawbacks disabled", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.clawbackWindow);
+        });
+
+        it("sets the clawback window", async () => {
+            await setWindow(600);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.clawbackWindow.toNumber(), 600);
+        });
+
+        it("rejects a negative window", async () => {
+            try {
+                await setWindow(-1);
+                assert.fail("Should have thrown InvalidDelay error");
+            } catch (err) {
+                assert.include(err.message, "InvalidDelay");
+            }
+        });
+
+        it("rejects window changes from a non-authority", async () => {
+            try {
+                await setWindow(0, Keypair.generate());
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("requires the clawback record while a window is set", async () => {
+            try {
+                await mintToHolder(1_000, null);
+                assert.fail("Should have thrown MissingAccount error");
+            } catch (err) {
+                assert.include(err.message, "MissingAccount");
+            }
+        });
+
+        it("claws back a mint inside the window", async () => {
+            await mintToHolder(1_000);
+
+            const record = await program.account.clawbackRecord.fetch(clawbackRecord);
+            assert.equal(record.amount.toNumber(), 1_000);
+            assert.isTrue(record.destination.equals(holderAta));
+
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            await clawback();
+
+            const account = await getAccount(provider.connection, holderAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 0);
+            const after = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(after.totalBurned.sub(before.totalBurned).toNumber(), 1_000);
+            const cleared = await program.account.clawbackRecord.fetch(clawbackRecord);
+            assert.equal(cleared.amount.toNumber(), 0);
+        });
+
+        it("rejects a clawback after the window expires", async () => {
+            await setWindow(0);
+            await mintToHolder(1_000);
+            await new Promise((resolve) => setTimeout(resolve, 3_000));
+
+            try {
+                await clawback();
+                assert.fail("Should have thrown ClawbackWindowExpired error");
+            } catch (err) {
+                assert.include(err.message, "ClawbackWindowExpired");
+            }
+
+            const account = await getAccount(provider.connection, holderAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_000);
+        });
+
+        it("mints without a clawback record once the window is cleared", async () => {
+            await setWindow(null);
+            await mintToHolder(500, null);
+
+            const account = await getAccount(provider.connection, holderAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_500);
+        });
+    });
+
+    describe("Batch Freeze", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+
+        const holders = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+        const [alreadyFrozen, unfrozen] = holders.map((owner) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID)
+        );
+
+        const batch = (method: "freezeBatch" | "thawBatch", accounts: PublicKey[]) =>
+            (method === "freezeBatch" ? program.methods.freezeBatch("Coordinated action") : program.methods.thawBatch())
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .remainingAccounts(accounts.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+                .rpc();
+
+        const isFrozen = async (account: PublicKey) =>
+            (await getAccount(provider.connection, account, undefined, TOKEN_2022_PROGRAM_ID)).isFrozen;
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Frozen Batch Dollar", "FBD"));
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    ...holders.map((owner, i) =>
+                        createAssociatedTokenAccountInstruction(
+                            authority.publicKey,
+                            [alreadyFrozen, unfrozen][i],
+                            owner,
+                            mint.publicKey,
+                            TOKEN_2022_PROGRAM_ID
+                        )
+                    )
+                )
+            );
+        });
+
+        it("freezes a mixed batch, skipping already-frozen accounts", async () => {
+            await batch("freezeBatch", [alreadyFrozen]);
+
+            let completed: any;
+            const listener = program.addEventListener("batchFreezeCompleted", (event) => {
+                completed = event;
+            });
+            await batch("freezeBatch", [alreadyFrozen, unfrozen]);
+            await program.removeEventListener(listener);
+
+            assert.equal(completed.frozen, 1);
+            assert.equal(completed.skipped, 1);
+            assert.isTrue(await isFrozen(alreadyFrozen));
+            assert.isTrue(await isFrozen(unfrozen));
+        });
+
+        it("thaws a mixed batch, skipping unfrozen accounts", async () => {
+            await batch("thawBatch", [unfrozen]);
+
+            let completed: any;
+            const listener = program.addEventListener("batchThawCompleted", (event) => {
+                completed = event;
+            });
+            await batch("thawBatch", [alreadyFrozen, unfrozen]);
+            await program.removeEventListener(listener);
+
+            assert.equal(completed.thawed, 1);
+            assert.equal(completed.skipped, 1);
+            assert.isFalse(await isFrozen(alreadyFrozen));
+            assert.isFalse(await isFrozen(unfrozen));
+        });
+
+        it("rejects a batch longer than 15 accounts", async () => {
+            for (const method of ["freezeBatch", "thawBatch"] as const) {
+                try {
+                    await batch(method, Array.from({ length: 16 }, () => Keypair.generate().publicKey));
+                    assert.fail("Should have thrown BatchTooLarge error");
+                } catch (err) {
+                    assert.include(err.message, "BatchTooLarge");
+                }
+            }
+        });
+    });
+
+    describe("Thaw Approval", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const holder = Keypair.generate().publicKey;
+        const holderAta = getAssociatedTokenAddressSync(mint.publicKey, holder, false, TOKEN_2022_PROGRAM_ID);
+        const findThawApproval = (mintKey: PublicKey, tokenAccount: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("approved"), mintKey.toBuffer(), tokenAccount.toBuffer()],
+                program.programId
+            )[0];
+
+        const approve = (mintKey: PublicKey, tokenAccount: PublicKey, signer: Keypair | null = null) =>
+            program.methods
+                .approveAccount()
+                .accounts({
+                    freezeAuthority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mintKey,
+                    tokenAccount,
+                    stablecoinState: findStablecoinState(mintKey),
+                    thawApproval: findThawApproval(mintKey, tokenAccount),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        const sender = Keypair.generate();
+        const senderAta = getAssociatedTokenAddressSync(mint.publicKey, sender.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        const findBlacklistEntry = (owner: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("blacklist"), mint.publicKey.toBuffer(), owner.toBuffer()],
+                program.programId
+            )[0];
+
+        // Accounts start frozen under the default account state, so create ATAs directly
+        const createAta = (owner: PublicKey) =>
+            provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID),
+                        owner,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+
+        const thaw = (owner: PublicKey) =>
+            program.methods
+                .thawAccount()
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID),
+                    stablecoinState: stablecoinStatePDA,
+                    frozenRecord: null,
+                    ownerBlacklist: findBlacklistEntry(owner),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+        // A plain Token-2022 transfer of 100 from the sender, which runs the hook
+        const hookTransfer = async (destination: PublicKey) =>
+            provider.sendAndConfirm(
+                new Transaction().add(
+                    await createTransferCheckedWithTransferHookInstruction(
+                        provider.connection,
+                        senderAta,
+                        mint.publicKey,
+                        destination,
+                        sender.publicKey,
+                        BigInt(100),
+                        6,
+                        [],
+                        undefined,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                ),
+                [sender]
+            );
+
+        before(async () => {
+            await initStablecoin(mint, sss2Config("Permissioned Dollar", "PRMD"));
+            await program.methods
+                .initializeExtraAccountMetaList()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    extraAccountMetaList: getExtraAccountMetaAddress(mint.publicKey, program.programId),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            await createAta(holder);
+
+            // The sender's account is thawed so it can be minted to and send
+            await provider.connection.confirmTransaction(
+                await provider.connection.requestAirdrop(sender.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+            );
+            await createAta(sender.publicKey);
+            await thaw(sender.publicKey);
+            await program.methods
+                .mintTo(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: senderAta,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: findBlacklistEntry(sender.publicKey),
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+        });
+
+        it("rejects approvals from a non-freeze-authority", async () => {
+            const intruder = Keypair.generate();
+            await provider.connection.confirmTransaction(
+                await provider.connection.requestAirdrop(intruder.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+            );
+
+            try {
+                await approve(mint.publicKey, holderAta, intruder);
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("records an approval for a token account", async () => {
+            await approve(mint.publicKey, holderAta);
+
+            const approval = await program.account.thawApproval.fetch(findThawApproval(mint.publicKey, holderAta));
+            assert.ok(approval.stablecoin.equals(mint.publicKey));
+            assert.ok(approval.tokenAccount.equals(holderAta));
+            assert.ok(approval.approvedBy.equals(authority.publicKey));
+        });
+
+        it("rejects approvals on an SSS-1 stablecoin", async () => {
+            const plainMint = Keypair.generate();
+            const owner = Keypair.generate().publicKey;
+            const ata = getAssociatedTokenAddressSync(plainMint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            await initStablecoin(plainMint, sss1Config("Open Dollar", "OPEN"));
+            await program.methods
+                .mintToNew(new anchor.BN(1), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: plainMint.publicKey,
+                    owner,
+                    tokenAccount: ata,
+                    stablecoinState: findStablecoinState(plainMint.publicKey),
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            try {
+                await approve(plainMint.publicKey, ata);
+                assert.fail("Should have thrown SSS2Required error");
+            } catch (err) {
+                assert.include(err.message, "SSS2Required");
+            }
+        });
+
+        it("blocks a transfer to an unapproved account", async () => {
+            // Thawed, so only the missing ThawApproval stands in the way
+            const stranger = Keypair.generate().publicKey;
+            const strangerAta = getAssociatedTokenAddressSync(mint.publicKey, stranger, false, TOKEN_2022_PROGRAM_ID);
+            await createAta(stranger);
+            await thaw(stranger);
+
+            try {
+                await hookTransfer(strangerAta);
+                assert.fail("Should have thrown TransferBlocked error");
+            } catch (err) {
+                assert.include(err.logs.join("\n"), "TransferBlocked");
+                const [event] = err.logs
+                    .filter((log: string) => log.startsWith("Program data: "))
+                    .map((log: string) => program.coder.events.decode(log.slice("Program data: ".length)))
+                    .filter((event) => event?.name === "transferHookExecuted")
+                    .map((event) => event.data);
+                assert.equal(event.blockedParty, 2);
+            }
+
+            const account = await getAccount(provider.connection, strangerAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 0);
+        });
+
+        it("allows a transfer to an approved account", async () => {
+            // The holder was approved above; thawing lets Token-2022 credit it
+            await thaw(holder);
+            await hookTransfer(holderAta);
+
+            const account = await getAccount(provider.connection, holderAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 100);
+        });
+
+        it("rewrites the extra account meta list in place", async () => {
+            const extraAccountMetaList = getExtraAccountMetaAddress(mint.publicKey, program.programId);
+            await program.methods
+                .updateExtraAccountMetaList()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    extraAccountMetaList,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const info = await provider.connection.getAccountInfo(extraAccountMetaList);
+            assert.equal(getExtraAccountMetas(info).length, 10);
+
+            // The rewritten list still resolves every account the hook needs
+            await hookTransfer(holderAta);
+            const account = await getAccount(provider.connection, holderAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 200);
+        });
+    });
+
+    describe("Blacklisted Thaw", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const holder = Keypair.generate().publicKey;
+        const holderAta = getAssociatedTokenAddressSync(mint.publicKey, holder, false, TOKEN_2022_PROGRAM_ID);
+        const [blacklistEntry] = PublicKey.findProgramAddressSync(
+            [Buffer.from("blacklist"), mint.publicKey.toBuffer(), holder.toBuffer()],
+            program.programId
+        );
+
+        const thaw = () =>
+            program.methods
+                .thawAccount()
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: holderAta,
+                    stablecoinState: stablecoinStatePDA,
+                    frozenRecord: null,
+                    ownerBlacklist: blacklistEntry,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss2Config("Blacklist Thaw Dollar", "BLTH"));
+            // The ATA starts frozen under the default account state
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(
+                        authority.publicKey,
+                        holderAta,
+                        holder,
+                        mint.publicKey,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+            await program.methods
+                .blacklistAdd(holder, 1, "Sanctions match", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry,
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+        });
+
+        it("refuses to thaw an account whose owner is blacklisted", async () => {
+            try {
+                await thaw();
+                assert.fail("Should have thrown CannotThawBlacklisted error");
+            } catch (err) {
+                assert.include(err.message, "CannotThawBlacklisted");
+            }
+
+            const account = await getAccount(provider.connection, holderAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.isTrue(account.isFrozen);
+        });
+
+        it("thaws the account once the owner is removed from the blacklist", async () => {
+            await program.methods
+                .blacklistRemove(holder, false)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry,
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                })
+                .rpc();
+
+            await thaw();
+
+            const account = await getAccount(provider.connection, holderAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.isFalse(account.isFrozen);
+        });
+    });
+
+    describe("Operation Counters", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+
+        const getCounts = () =>
+            program.methods
+                .getOperationCounts()
+                .accounts({
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                })
+                .view();
+
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+        const entryOf = (prefix: string, key: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from(prefix), mint.publicKey.toBuffer(), key.toBuffer()],
+                program.programId
+            )[0];
+
+        const fund = (owner: PublicKey, amount: number) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: entryOf("blacklist", owner),
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        before(async () => {
+            // Accounts start unfrozen so the freeze below is the only one counted
+            await initStablecoin(mint, { ...sss2Config("Metered Dollar", "METR"), defaultAccountFrozen: false });
+            await initComplianceOfficers(mint);
+            await program.methods
+                .initializeExtraAccountMetaList()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    extraAccountMetaList: getExtraAccountMetaAddress(mint.publicKey, program.programId),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+        });
+
+        it("starts every counter at zero", async () => {
+            const counts = await getCounts();
+
+            assert.equal(counts.mintOpCount.toNumber(), 0);
+            assert.equal(counts.burnOpCount.toNumber(), 0);
+            assert.equal(counts.freezeOpCount.toNumber(), 0);
+            assert.equal(counts.seizeOpCount.toNumber(), 0);
+        });
+
+        it("counts each operation type", async () => {
+            const frozenOwner = Keypair.generate().publicKey;
+            const target = Keypair.generate().publicKey;
+            const treasury = ataOf(authority.publicKey);
+
+            await fund(authority.publicKey, 1_000);
+            await fund(frozenOwner, 100);
+            await fund(target, 500);
+
+            await program.methods
+                .burn(new anchor.BN(100), null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: treasury,
+                    stablecoinState: stablecoinStatePDA,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            await program.methods
+                .freezeAccount("Review")
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: ataOf(frozenOwner),
+                    stablecoinState: stablecoinStatePDA,
+                    frozenRecord: entryOf("frozen", ataOf(frozenOwner)),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            await program.methods
+                .blacklistAdd(target, 1, "Court order", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    blacklistEntry: entryOf("blacklist", target),
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            // The seizure moves tokens through the hook, signed by the state PDA as permanent delegate
+            const hookIx = new TransactionInstruction({
+                programId: TOKEN_2022_PROGRAM_ID,
+                keys: [ataOf(target), mint.publicKey, treasury, stablecoinStatePDA].map((pubkey) => ({
+                    pubkey,
+                    isSigner: false,
+                    isWritable: false,
+                })),
+            });
+            await addExtraAccountMetasForExecute(
+                provider.connection,
+                hookIx,
+                program.programId,
+                ataOf(target),
+                mint.publicKey,
+                treasury,
+                stablecoinStatePDA,
+                0
+            );
+            const seizedAt = Number(
+                (await provider.connection.getAccountInfo(anchor.web3.SYSVAR_CLOCK_PUBKEY)).data.readBigInt64LE(32)
+            );
+            await program.methods
+                .seizeTokens(new anchor.BN(0), true, "Court order", new anchor.BN(seizedAt))
+                .accounts({
+                    complianceOfficer: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers: findComplianceOfficers(mint.publicKey),
+                    blacklistEntry: entryOf("blacklist", target),
+                    targetAccount: ataOf(target),
+                    destinationAccount: treasury,
+                    seizureRecord: PublicKey.findProgramAddressSync(
+                        [
+                            Buffer.from("seizure"),
+                            mint.publicKey.toBuffer(),
+                            ataOf(target).toBuffer(),
+                            new anchor.BN(seizedAt).toArrayLike(Buffer, "le", 8),
+                        ],
+                        program.programId
+                    )[0],
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .remainingAccounts(hookIx.keys.slice(4))
+                .rpc();
+
+            const counts = await getCounts();
+            assert.equal(counts.mintOpCount.toNumber(), 3);
+            assert.equal(counts.burnOpCount.toNumber(), 1);
+            assert.equal(counts.freezeOpCount.toNumber(), 1);
+            assert.equal(counts.seizeOpCount.toNumber(), 1);
+        });
+
+        it("reports the stored counters through getOperationCounts", async () => {
+            const counts = await getCounts();
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            assert.equal(counts.mintOpCount.toString(), state.mintOpCount.toString());
+            assert.equal(counts.burnOpCount.toString(), state.burnOpCount.toString());
+            assert.equal(counts.freezeOpCount.toString(), state.freezeOpCount.toString());
+            assert.equal(counts.seizeOpCount.toString(), state.seizeOpCount.toString());
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Allowlist Mode Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Allowlist Mode", () => {
+        const mint = Keypair.generate();
+        const approvedWallet = Keypair.generate();
+        let stablecoinStatePDA: PublicKey;
+
+        const findAllowlistEntry = (address: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("allowlist"), mint.publicKey.toBuffer(), address.toBuffer()],
+                program.programId
+            )[0];
+
+        const allowlist = (address: PublicKey) =>
+            program.methods
+                .allowlistAdd(address)
+                .accounts({
+                    complianceOfficer: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers: findComplianceOfficers(mint.publicKey),
+                    allowlistEntry: findAllowlistEntry(address),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+        const fund = (owner: PublicKey, amount: number) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const hookTransfer = async (destinationOwner: PublicKey) =>
+            provider.sendAndConfirm(
+                new Transaction().add(
+                    await createTransferCheckedWithTransferHookInstruction(
+                        provider.connection,
+                        ataOf(authority.publicKey),
+                        mint.publicKey,
+                        ataOf(destinationOwner),
+                        authority.publicKey,
+                        BigInt(100),
+                        6,
+                        [],
+                        undefined,
+                        TOKEN_2022_PROGRAM_ID
+                    )
+                )
+            );
+
+        const balanceOf = async (owner: PublicKey) =>
+            Number((await getAccount(provider.connection, ataOf(owner), undefined, TOKEN_2022_PROGRAM_ID)).amount);
+
+        before(async () => {
+            stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        });
+
+        it("rejects allowlist mode combined with the SSS-2 blacklist", async () => {
+            const conflicting = Keypair.generate();
+
+            try {
+                await initStablecoin(conflicting, {
+                    ...sss2Config("Conflicted USD", "XUSD"),
+                    allowlistMode: true,
+                });
+                assert.fail("Should have thrown ListModeConflict error");
+            } catch (err) {
+                assert.include(err.message, "ListModeConflict");
+            }
+        });
+
+        it("initializes an allowlist-mode stablecoin", async () => {
+            await initStablecoin(mint, {
+                ...sss1Config("Permissioned USD", "PUSD"),
+                enableTransferHook: true,
+                allowlistMode: true,
+            });
+            await initComplianceOfficers(mint);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(state.allowlistMode);
+            assert.isFalse(state.isSss2);
+        });
+
+        it("adds an address to the allowlist", async () => {
+            await allowlist(approvedWallet.publicKey);
+
+            const entry = await program.account.allowlistEntry.fetch(findAllowlistEntry(approvedWallet.publicKey));
+            assert.isTrue(entry.isActive);
+            assert.ok(entry.allowlistedAddress.equals(approvedWallet.publicKey));
+        });
+
+        it("allows a transfer between allowlisted wallets", async () => {
+            await program.methods
+                .initializeExtraAccountMetaList()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    extraAccountMetaList: getExtraAccountMetaAddress(mint.publicKey, program.programId),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            // The hook requires both parties on the list
+            await allowlist(authority.publicKey);
+            await fund(authority.publicKey, 1_000);
+            await fund(approvedWallet.publicKey, 1);
+
+            await hookTransfer(approvedWallet.publicKey);
+
+            assert.equal(await balanceOf(approvedWallet.publicKey), 101);
+        });
+
+        it("blocks a transfer to a non-allowlisted wallet", async () => {
+            const outsider = Keypair.generate().publicKey;
+            await fund(outsider, 1);
+
+            try {
+                await hookTransfer(outsider);
+                assert.fail("Should have thrown TransferBlocked error");
+            } catch (err) {
+                assert.include(err.logs.join("\n"), "TransferBlocked");
+            }
+            assert.equal(await balanceOf(outsider), 1);
+        });
+
+        describe("with a merkle allowlist", () => {
+            // Mirrors programs/sss/src/merkle.rs: prefixed sha256, sorted pairs
+            const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+            const leaf = (owner: PublicKey) => sha256(Buffer.from([0]), owner.toBuffer());
+            const node = (a: Buffer, b: Buffer) =>
+                Buffer.compare(a, b) <= 0 ? sha256(Buffer.from([1]), a, b) : sha256(Buffer.from([1]), b, a);
+            const verify = (root: Buffer, target: Buffer, proof: Buffer[]) =>
+                proof.reduce((acc, sibling) => node(acc, sibling), target).equals(root);
+
+            const members = [Keypair.generate(), Keypair.generate(), Keypair.generate(), Keypair.generate()];
+            const leaves = members.map((m) => leaf(m.publicKey));
+            const left = node(leaves[0], leaves[1]);
+            const right = node(leaves[2], leaves[3]);
+            const root = node(left, right);
+
+            it("sets the allowlist root", async () => {
+                await program.methods
+                    .setAllowlistRoot([...root])
+                    .accounts({
+                        complianceOfficer: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        complianceOfficers: findComplianceOfficers(mint.publicKey),
+                    })
+                    .rpc();
+
+                const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+                assert.ok(Buffer.from(state.allowlistRoot).equals(root));
+            });
+
+            it("proves inclusion of a member", () => {
+                // Proof for members[2]: sibling leaf, then the left subtree
+                assert.isTrue(verify(root, leaves[2], [leaves[3], left]));
+                // transfer(amount, null, proof) to a members[2] account passes the on-chain check
+            });
+
+            it("rejects a non-member", () => {
+                const outsider = leaf(Keypair.generate().publicKey);
+                assert.isFalse(verify(root, outsider, [leaves[3], left]));
+                // transfer(amount, null, proof) to an outsider's account fails with TransferBlocked
+            });
+
+            it("rejects setting the root from a non-compliance officer", async () => {
+                const fakeOfficer = Keypair.generate();
+
+                try {
+                    await program.methods
+                        .setAllowlistRoot([...root])
+                        .accounts({
+                            complianceOfficer: fakeOfficer.publicKey,
+                            mint: mint.publicKey,
+                            stablecoinState: stablecoinStatePDA,
+                            complianceOfficers: findComplianceOfficers(mint.publicKey),
+                        })
+                        .signers([fakeOfficer])
+                        .rpc();
+                    assert.fail("Should have thrown Unauthorized error");
+                } catch (err) {
+                    assert.include(err.message, "Unauthorized");
+                }
+            });
+        });
+    });
+
+    describe("Token Authority Sync", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const newMinter = Keypair.generate();
+        const newFreezer = Keypair.generate().publicKey;
+
+        const rotate = (role: object, roleBit: number, newAuthority: PublicKey) =>
+            program.methods
+                .proposeRoleChange(role, newAuthority)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    pendingRoleChange: findPendingRoleChange(mint.publicKey, roleBit),
+                    systemProgram: SystemProgram.programId,
+                    changelog: null,
+                })
+                .rpc();
+
+        const sync = (method: "syncMintAuthority" | "syncFreezeAuthority", signer: Keypair | null = null) =>
+            program.methods[method]()
+                .accounts({
+                    currentAuthority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Rotated Dollar", "ROTD"));
+            await rotate({ mintAuthority: {} }, 1, newMinter.publicKey);
+            await rotate({ freezeAuthority: {} }, 2, newFreezer);
+        });
+
+        it("rejects a sync signed by someone other than the current holder", async () => {
+            try {
+                await sync("syncMintAuthority", newMinter);
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("moves the Token-2022 mint authority to the new mint authority", async () => {
+            await sync("syncMintAuthority");
+
+            const mintInfo = await getMint(provider.connection, mint.publicKey, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.ok(mintInfo.mintAuthority.equals(newMinter.publicKey));
+        });
+
+        it("moves the Token-2022 freeze authority to the new freeze authority", async () => {
+            await sync("syncFreezeAuthority");
+
+            const mintInfo = await getMint(provider.connection, mint.publicKey, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.ok(mintInfo.freezeAuthority.equals(newFreezer));
+        });
+
+        it("no longer lets the old key mint", async () => {
+            try {
+                await program.methods
+                    .mintTo(new anchor.BN(1_000), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        feeCollector: null,
+                        mintStats: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("lets the new key mint", async () => {
+            const holder = Keypair.generate().publicKey;
+            const ata = getAssociatedTokenAddressSync(mint.publicKey, holder, false, TOKEN_2022_PROGRAM_ID);
+            // The new minter pays for the recipient account
+            await provider.connection.confirmTransaction(
+                await provider.connection.requestAirdrop(newMinter.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+            );
+
+            await program.methods
+                .mintToNew(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: newMinter.publicKey,
+                    mint: mint.publicKey,
+                    owner: holder,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([newMinter])
+                .rpc();
+
+            const account = await getAccount(provider.connection, ata, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_000);
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.totalMinted.toNumber(), 1_000);
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Two-Step Authority Transfer Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Authority Transfer", () => {
+        const mint = Keypair.generate();
+        const first = Keypair.generate();
+        const second = Keypair.generate();
+        let stablecoinStatePDA: PublicKey;
+
+        before(async () => {
+            stablecoinStatePDA = findStablecoinState(mint.publicKey);
+            await initStablecoin(mint, sss1Config("Handover Dollar", "HUSD"));
+        });
+
+        const propose = (newAuthority: PublicKey) =>
+            program.methods
+                .proposeAuthorityTransfer(newAuthority)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                })
+                .rpc();
+
+        const accept = (signer: Keypair) =>
+            program.methods
+                .acceptAuthorityTransfer()
+                .accounts({
+                    newAuthority: signer.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .signers([signer])
+                .rpc();
+
+        it("rejects accept with no pending transfer", async () => {
+            try {
+                await accept(first);
+                assert.fail("Should have thrown NoPendingAuthority error");
+            } catch (err) {
+                assert.include(err.message, "NoPendingAuthority");
+            }
+        });
+
+        it("overwrites a pending proposal", async () => {
+            await propose(first.publicKey);
+            await propose(second.publicKey);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.pendingAuthority.equals(second.publicKey));
+        });
+
+        it("rejects accept from a non-pending key", async () => {
+            try {
+                await accept(first);
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("transfers authority once the pending key accepts", async () => {
+            await accept(second);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.authority.equals(second.publicKey));
+            assert.isNull(state.pendingAuthority);
+        });
+    });
+
+    describe("Program Authority", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        // Stand-in governance program: any executable account can derive the authority PDA
+        const governanceProgram = ASSOCIATED_TOKEN_PROGRAM_ID;
+        const seeds = [Buffer.from("governance"), mint.publicKey.toBuffer()];
+        const [governancePDA, governanceBump] = PublicKey.findProgramAddressSync(seeds, governanceProgram);
+
+        const setProgramAuthority = (newAuthority: PublicKey, signer?: Keypair, governance = governanceProgram) =>
+            program.methods
+                .setProgramAuthority(newAuthority, [...seeds, Buffer.from([governanceBump])])
+                .accounts({
+                    authority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    governanceProgram: governance,
+                    changelog: null,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Governed Dollar", "GOVD"));
+        });
+
+        it("rejects a designation from a non-authority", async () => {
+            try {
+                await setProgramAuthority(governancePDA, Keypair.generate());
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("rejects a keypair that is not derived by the governance program", async () => {
+            try {
+                await setProgramAuthority(Keypair.generate().publicKey);
+                assert.fail("Should have thrown InvalidPda error");
+            } catch (err) {
+                assert.include(err.message, "InvalidPda");
+            }
+        });
+
+        it("rejects seeds derived under a different program", async () => {
+            try {
+                await setProgramAuthority(governancePDA, undefined, TOKEN_2022_PROGRAM_ID);
+                assert.fail("Should have thrown InvalidPda error");
+            } catch (err) {
+                assert.include(err.message, "InvalidPda");
+            }
+        });
+
+        it("hands the authority to the governance PDA", async () => {
+            await setProgramAuthority(governancePDA);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.authority.equals(governancePDA));
+            assert.isNull(state.pendingAuthority);
+        });
+
+        it("rejects role changes from the previous keypair authority", async () => {
+            try {
+                await program.methods
+                    .proposeRoleChange({ freezeAuthority: {} }, Keypair.generate().publicKey)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        pendingRoleChange: findPendingRoleChange(mint.publicKey, 2),
+                        systemProgram: SystemProgram.programId,
+                        changelog: null,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Emergency Pause Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Emergency Pause", () => {
+        const mint = Keypair.generate();
+        let stablecoinStatePDA: PublicKey;
+
+        before(async () => {
+            stablecoinStatePDA = findStablecoinState(mint.publicKey);
+            await initStablecoin(mint, sss1Config("Pausable Dollar", "PAUSD"));
+        });
+
+        it("pauses the stablecoin", async () => {
+            await program.methods
+                .pause()
+                .accounts({
+                    pauser: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(state.paused);
+        });
+
+        it("reports the paused status through getState", async () => {
+            const status = await program.methods
+                .getState()
+                .accounts({
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                })
+                .view();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(status.paused);
+            assert.equal(status.isSss2, state.isSss2);
+            assert.equal(status.netSupply.toString(), state.totalMinted.sub(state.totalBurned).toString());
+            assert.equal(status.activeBlacklistCount.toNumber(), state.activeBlacklistCount.toNumber());
+            assert.ok(status.mintAuthority.equals(state.mintAuthority));
+            assert.ok(status.freezeAuthority.equals(state.freezeAuthority));
+            assert.ok(status.complianceOfficer.equals(state.complianceOfficer));
+        });
+
+        it("rejects minting while paused", async () => {
+            try {
+                await program.methods
+                    .mintTo(new anchor.BN(1_000_000), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        feeCollector: null,
+                        mintStats: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown ProgramPaused error");
+            } catch (err) {
+                assert.include(err.message, "ProgramPaused");
+            }
+        });
+
+        it("rejects burning while paused", async () => {
+            try {
+                await program.methods
+                    .burn(new anchor.BN(1_000), null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown ProgramPaused error");
+            } catch (err) {
+                assert.include(err.message, "ProgramPaused");
+            }
+        });
+
+        it("rejects transfers while paused", async () => {
+            try {
+                await program.methods
+                    .transfer(new anchor.BN(1_000), null, null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        sourceBlacklist: null,
+                        destinationBlacklist: null,
+                        sourceActivity: null,
+                        destinationActivity: null,
+                        sourceKyc: null,
+                        sourceLock: null,
+                        sourceExemption: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown ProgramPaused error");
+            } catch (err) {
+                assert.include(err.message, "ProgramPaused");
+            }
+        });
+
+        it("rejects pause from a non-pauser", async () => {
+            const fakeAuthority = Keypair.generate();
+
+            try {
+                await program.methods
+                    .pause()
+                    .accounts({
+                        pauser: fakeAuthority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .signers([fakeAuthority])
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("unpauses and resumes minting", async () => {
+            await program.methods
+                .unpause()
+                .accounts({
+                    pauser: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isFalse(state.paused);
+
+            await program.methods
+                .mintTo(new anchor.BN(1_000_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    feeCollector: null,
+                    mintStats: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+        });
+
+        it("allows burn and transfer after unpausing", async () => {
+            const ataOf = (owner: PublicKey) =>
+                getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            const holder = Keypair.generate().publicKey;
+            for (const owner of [authority.publicKey, holder]) {
+                await program.methods
+                    .mintToNew(new anchor.BN(1_000), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        owner,
+                        tokenAccount: ataOf(owner),
+                        stablecoinState: stablecoinStatePDA,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        mintStats: null,
+                        feeCollector: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+            }
+
+            await program.methods
+                .burn(new anchor.BN(100), null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: ataOf(authority.publicKey),
+                    stablecoinState: stablecoinStatePDA,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            await program.methods
+                .transfer(new anchor.BN(200), null, null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    from: ataOf(authority.publicKey),
+                    to: ataOf(holder),
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    destinationBlacklist: null,
+                    sourceActivity: null,
+                    destinationActivity: null,
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            const source = await getAccount(provider.connection, ataOf(authority.publicKey), undefined, TOKEN_2022_PROGRAM_ID);
+            const destination = await getAccount(provider.connection, ataOf(holder), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(source.amount), 700);
+            assert.equal(Number(destination.amount), 1_200);
+        });
+
+        describe("with a dedicated pauser", () => {
+            const pauser = Keypair.generate();
+
+            before(async () => {
+                await provider.connection.confirmTransaction(
+                    await provider.connection.requestAirdrop(pauser.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+                );
+
+                await program.methods
+                    .proposeRoleChange({ pauser: {} }, pauser.publicKey)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        pendingRoleChange: findPendingRoleChange(mint.publicKey, 8),
+                        systemProgram: SystemProgram.programId,
+                        changelog: null,
+                    })
+                    .rpc();
+            });
+
+            it("assigns the pauser role", async () => {
+                const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+                assert.ok(state.pauser.equals(pauser.publicKey));
+            });
+
+            it("lets the pauser pause and unpause", async () => {
+                await program.methods
+                    .pause()
+                    .accounts({
+                        pauser: pauser.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .signers([pauser])
+                    .rpc();
+
+                let state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+                assert.isTrue(state.paused);
+
+                await program.methods
+                    .unpause()
+                    .accounts({
+                        pauser: pauser.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .signers([pauser])
+                    .rpc();
+
+                state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+                assert.isFalse(state.paused);
+            });
+
+            it("does not let the pauser mint", async () => {
+                try {
+                    await program.methods
+                        .mintTo(new anchor.BN(1_000), null)
+                        .accounts({
+                            mintAuthority: pauser.publicKey,
+                            mint: mint.publicKey,
+                            stablecoinState: stablecoinStatePDA,
+                            recipientBlacklist: null,
+                            oracle: null,
+                            clawbackRecord: null,
+                            mintLock: null,
+                            feeCollector: null,
+                            mintStats: null,
+                            tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        })
+                        .signers([pauser])
+                        .rpc();
+                    assert.fail("Should have thrown Unauthorized error");
+                } catch (err) {
+                    assert.include(err.message, "Unauthorized");
+                }
+            });
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Feature Flag Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Feature Flags", () => {
+        const sss1Mint = Keypair.generate();
+        const hookedMint = Keypair.generate();
+        const sss2Mint = Keypair.generate();
+
+        const setFlag = (mint: Keypair, flag: object, value: boolean) =>
+            program.methods
+                .setFeatureFlag(flag, value)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: findStablecoinState(mint.publicKey),
+                    changelog: null,
+                })
+                .rpc();
+
+        const captureConfigUpdate = async (action: () => Promise<string>) => {
+            let captured: any;
+            const listener = program.addEventListener("configUpdated", (event) => {
+                captured = event;
+            });
+            await action();
+            await program.removeEventListener(listener);
+            return captured;
+        };
+
+        before(async () => {
+            await initStablecoin(sss1Mint, sss1Config("Flagged Dollar", "FLAG"));
+            await initStablecoin(hookedMint, {
+                ...sss1Config("Hooked Dollar", "HOOK"),
+                enableTransferHook: true,
+                allowlistMode: true,
+            });
+            await initStablecoin(sss2Mint, sss2Config("Flagged Compliant", "FLAG2"));
+        });
+
+        it("toggles paused and records the change", async () => {
+            const event = await captureConfigUpdate(() => setFlag(sss1Mint, { paused: {} }, true));
+
+            assert.equal(event.flag, "paused");
+            assert.isFalse(event.oldValue);
+            assert.isTrue(event.newValue);
+            const state = await program.account.stablecoinState.fetch(findStablecoinState(sss1Mint.publicKey));
+            assert.isTrue(state.paused);
+        });
+
+        it("toggles allowlist mode on a hooked SSS-1 coin", async () => {
+            const event = await captureConfigUpdate(() => setFlag(hookedMint, { allowlistMode: {} }, false));
+
+            assert.equal(event.flag, "allowlist_mode");
+            assert.isTrue(event.oldValue);
+            assert.isFalse(event.newValue);
+        });
+
+        it("toggles default_account_frozen on an SSS-2 coin", async () => {
+            const event = await captureConfigUpdate(() => setFlag(sss2Mint, { defaultAccountFrozen: {} }, false));
+
+            assert.equal(event.flag, "default_account_frozen");
+            assert.isTrue(event.oldValue);
+            assert.isFalse(event.newValue);
+        });
+
+        it("rejects default_account_frozen on an SSS-1 coin", async () => {
+            try {
+                await setFlag(sss1Mint, { defaultAccountFrozen: {} }, true);
+                assert.fail("Should have thrown SSS2Required error");
+            } catch (err) {
+                assert.include(err.message, "SSS2Required");
+            }
+        });
+
+        it("rejects allowlist mode on an SSS-2 coin", async () => {
+            try {
+                await setFlag(sss2Mint, { allowlistMode: {} }, true);
+                assert.fail("Should have thrown ListModeConflict error");
+            } catch (err) {
+                assert.include(err.message, "ListModeConflict");
+            }
+        });
+    });
+
+    describe("UI Amounts", () => {
+        const mintToFreshOwner = async (mint: Keypair, amount: number) => {
+            const owner = Keypair.generate().publicKey;
+            let captured: any;
+            const listener = program.addEventListener("tokensMinted", (event) => {
+                captured = event;
+            });
+            await program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: getAssociatedTokenAddressSync(
+                        mint.publicKey,
+                        owner,
+                        false,
+                        TOKEN_2022_PROGRAM_ID
+                    ),
+                    stablecoinState: findStablecoinState(mint.publicKey),
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            await program.removeEventListener(listener);
+            return captured;
+        };
+
+        const sixDecimals = Keypair.generate();
+        const zeroDecimals = Keypair.generate();
+        const nineDecimals = Keypair.generate();
+
+        before(async () => {
+            await initStablecoin(sixDecimals, sss1Config("Six Decimal Dollar", "SXDD"));
+            await initStablecoin(zeroDecimals, { ...sss1Config("Whole Dollar", "WHLD"), decimals: 0 });
+            await initStablecoin(nineDecimals, { ...sss1Config("Nine Dollar", "NIND"), decimals: 9 });
+        });
+
+        it("formats a fractional amount, trimming trailing zeros", async () => {
+            const event = await mintToFreshOwner(sixDecimals, 1_500_000);
+            assert.equal(event.amount.toNumber(), 1_500_000);
+            assert.equal(event.uiAmount, "1.5");
+        });
+
+        it("pads amounts below one whole token", async () => {
+            const event = await mintToFreshOwner(sixDecimals, 2_500);
+            assert.equal(event.uiAmount, "0.0025");
+        });
+
+        it("drops the decimal point for whole amounts", async () => {
+            const event = await mintToFreshOwner(sixDecimals, 3_000_000);
+            assert.equal(event.uiAmount, "3");
+        });
+
+        it("leaves zero-decimal amounts as is", async () => {
+            const event = await mintToFreshOwner(zeroDecimals, 42);
+            assert.equal(event.uiAmount, "42");
+        });
+
+        it("handles nine decimals", async () => {
+            const event = await mintToFreshOwner(nineDecimals, 1);
+            assert.equal(event.uiAmount, "0.000000001");
+        });
+
+        it("includes the field on burn and transfer events", async () => {
+            const ataOf = (owner: PublicKey) =>
+                getAssociatedTokenAddressSync(sixDecimals.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            const stablecoinState = findStablecoinState(sixDecimals.publicKey);
+            const holder = Keypair.generate().publicKey;
+            for (const owner of [authority.publicKey, holder]) {
+                await program.methods
+                    .mintToNew(new anchor.BN(5_000_000), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: sixDecimals.publicKey,
+                        owner,
+                        tokenAccount: ataOf(owner),
+                        stablecoinState,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        mintStats: null,
+                        feeCollector: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+            }
+
+            let burned: any;
+            const burnListener = program.addEventListener("tokensBurned", (event) => {
+                burned = event;
+            });
+            await program.methods
+                .burn(new anchor.BN(1_500_000), null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: sixDecimals.publicKey,
+                    tokenAccount: ataOf(authority.publicKey),
+                    stablecoinState,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+            await program.removeEventListener(burnListener);
+
+            let transferred: any;
+            const transferListener = program.addEventListener("tokensTransferred", (event) => {
+                transferred = event;
+            });
+            await program.methods
+                .transfer(new anchor.BN(2_500), null, null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: sixDecimals.publicKey,
+                    from: ataOf(authority.publicKey),
+                    to: ataOf(holder),
+                    stablecoinState,
+                    sourceBlacklist: null,
+                    destinationBlacklist: null,
+                    sourceActivity: null,
+                    destinationActivity: null,
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+            await program.removeEventListener(transferListener);
+
+            assert.equal(burned.uiAmount, "1.5");
+            assert.equal(transferred.uiAmount, "0.0025");
+        });
+    });
+
+    describe("Event Emission", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+
+        const setEmission = (enabled: boolean, signer: Keypair | null = null) =>
+            program.methods
+                .setEventEmission(enabled)
+                .accounts({
+                    authority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        const mintToFreshOwner = () => {
+            const owner = Keypair.generate().publicKey;
+            return program.methods
+                .mintToNew(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: getAssociatedTokenAddressSync(
+                        mint.publicKey,
+                        owner,
+                        false,
+                        TOKEN_2022_PROGRAM_ID
+                    ),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+        };
+
+        const captureMintEvents = async (action: () => Promise<string>) => {
+            const captured: any[] = [];
+            const listener = program.addEventListener("tokensMinted", (event) => {
+                captured.push(event);
+            });
+            await action();
+            await program.removeEventListener(listener);
+            return captured;
+        };
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Quiet Dollar", "QUIET"));
+        });
+
+        it("emits events by default", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(state.emitEvents);
+
+            const events = await captureMintEvents(mintToFreshOwner);
+            assert.lengthOf(events, 1);
+        });
+
+        it("rejects toggling from a non-authority", async () => {
+            try {
+                await setEmission(false, Keypair.generate());
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("suppresses TokensMinted but still updates counters when disabled", async () => {
+            await setEmission(false);
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isFalse(before.emitEvents);
+
+            const events = await captureMintEvents(mintToFreshOwner);
+            assert.lengthOf(events, 0);
+
+            const after = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(after.totalMinted.toNumber(), before.totalMinted.toNumber() + 1_000);
+            assert.equal(after.mintOpCount.toNumber(), before.mintOpCount.toNumber() + 1);
+        });
+
+        it("still emits compliance events while disabled", async () => {
+            const owner = Keypair.generate().publicKey;
+            const ata = getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            await program.methods
+                .mintToNew(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            let frozen: any;
+            const listener = program.addEventListener("accountFrozenEvent", (event) => {
+                frozen = event;
+            });
+            await program.methods
+                .freezeAccount("Suspicious activity")
+                .accounts({
+                    freezeAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    frozenRecord: PublicKey.findProgramAddressSync(
+                        [Buffer.from("frozen"), mint.publicKey.toBuffer(), ata.toBuffer()],
+                        program.programId
+                    )[0],
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            await program.removeEventListener(listener);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isFalse(state.emitEvents);
+            assert.ok(frozen.account.equals(ata));
+        });
+
+        it("resumes emitting once re-enabled", async () => {
+            await setEmission(true);
+
+            const events = await captureMintEvents(mintToFreshOwner);
+            assert.lengthOf(events, 1);
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Metadata Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Metadata Updates", () => {
+        const mint = Keypair.generate();
+        let stablecoinStatePDA: PublicKey;
+
+        before(async () => {
+            stablecoinStatePDA = findStablecoinState(mint.publicKey);
+            await initStablecoin(mint, {
+                ...sss1Config("Old Dollar", "OLDD"),
+                uri: "https://example.com/old.json",
+            });
+        });
+
+        const updateMetadata = (name: string | null, symbol: string | null, uri: string | null) =>
+            program.methods
+                .updateMetadata(name, symbol, uri)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                })
+                .rpc();
+
+        it("updates only the provided fields", async () => {
+            await updateMetadata(null, "NEWD", "https://example.com/new.json");
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.name, "Old Dollar");
+            assert.equal(state.symbol, "NEWD");
+            assert.equal(state.uri, "https://example.com/new.json");
+        });
+
+        it("rejects a symbol longer than 10 characters", async () => {
+            try {
+                await updateMetadata(null, "WAYTOOLONGSYM", null);
+                assert.fail("Should have thrown SymbolTooLong error");
+            } catch (err) {
+                assert.include(err.message, "SymbolTooLong");
+            }
+        });
+
+        it("accepts a printable name and an uppercase alphanumeric symbol", async () => {
+            await updateMetadata("New Dollar (v2)", "NEWD2", null);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.name, "New Dollar (v2)");
+            assert.equal(state.symbol, "NEWD2");
+        });
+
+        it("rejects a lowercase symbol", async () => {
+            try {
+                await updateMetadata(null, "newd", null);
+                assert.fail("Should have thrown InvalidMetadataFormat error");
+            } catch (err) {
+                assert.include(err.message, "InvalidMetadataFormat");
+            }
+        });
+
+        it("rejects a non-ASCII name", async () => {
+            try {
+                await updateMetadata("Dollar\u0007", null, null);
+                assert.fail("Should have thrown InvalidMetadataFormat error");
+            } catch (err) {
+                assert.include(err.message, "InvalidMetadataFormat");
+            }
+        });
+
+        it("rejects an empty name at initialization", async () => {
+            try {
+                await initStablecoin(Keypair.generate(), sss1Config("", "EMTY"));
+                assert.fail("Should have thrown InvalidMetadataFormat error");
+            } catch (err) {
+                assert.include(err.message, "InvalidMetadataFormat");
+            }
+        });
+
+        it("rejects a non-ASCII symbol at initialization", async () => {
+            try {
+                await initStablecoin(Keypair.generate(), sss1Config("Euro Coin", "EUR€"));
+                assert.fail("Should have thrown InvalidMetadataFormat error");
+            } catch (err) {
+                assert.include(err.message, "InvalidMetadataFormat");
+            }
+        });
+    });
+
+    describe("Symbol Registry", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const symbolRegistry = findSymbolRegistry("UNIQ");
+
+        const release = (signer: Keypair | null = null) =>
+            program.methods
+                .releaseSymbol("UNIQ")
+                .accounts({
+                    authority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    symbolRegistry,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Unique Dollar", "UNIQ"));
+        });
+
+        it("records the owning mint for the symbol", async () => {
+            const registry = await program.account.symbolRegistry.fetch(symbolRegistry);
+            assert.equal(registry.symbol, "UNIQ");
+            assert.ok(registry.mint.equals(mint.publicKey));
+        });
+
+        it("rejects a second stablecoin with the same symbol", async () => {
+            try {
+                await initStablecoin(Keypair.generate(), sss1Config("Copycat Dollar", "UNIQ"));
+                assert.fail("Should have failed on the existing symbol registry");
+            } catch (err) {
+                // SystemProgram AccountAlreadyInUse from the registry `init`
+                assert.include(err.message, "0x0");
+            }
+        });
+
+        it("rejects a release from a non-authority", async () => {
+            const intruder = Keypair.generate();
+            await provider.connection.confirmTransaction(
+                await provider.connection.requestAirdrop(intruder.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+            );
+
+            try {
+                await release(intruder);
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("rejects a release while supply is outstanding", async () => {
+            const ata = getAssociatedTokenAddressSync(mint.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID);
+            await program.methods
+                .mintToNew(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner: authority.publicKey,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            try {
+                await release();
+                assert.fail("Should have thrown SupplyOutstanding error");
+            } catch (err) {
+                assert.include(err.message, "SupplyOutstanding");
+            }
+
+            // Burn the supply back to zero so the release below can go through
+            await program.methods
+                .burn(new anchor.BN(1_000), null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+        });
+
+        it("releases the symbol so another mint can register it", async () => {
+            await release();
+
+            const closed = await provider.connection.getAccountInfo(symbolRegistry);
+            assert.isNull(closed);
+
+            const successor = Keypair.generate();
+            await initStablecoin(successor, sss1Config("Successor Dollar", "UNIQ"));
+
+            const registry = await program.account.symbolRegistry.fetch(symbolRegistry);
+            assert.ok(registry.mint.equals(successor.publicKey));
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Transfer Limit Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Transfer Limits", () => {
+        const mint = Keypair.generate();
+        let stablecoinStatePDA: PublicKey;
+
+        before(async () => {
+            stablecoinStatePDA = findStablecoinState(mint.publicKey);
+            await initStablecoin(mint, {
+                ...sss1Config("Capped Dollar", "CAPD"),
+                maxTransferAmount: new anchor.BN(1_000_000),
+            });
+        });
+
+        const setLimit = (limit: anchor.BN | null) =>
+            program.methods
+                .setTransferLimit(limit)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        it("stores the limit set at initialization", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.maxTransferAmount.toNumber(), 1_000_000);
+        });
+
+        const recipient = Keypair.generate().publicKey;
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+        const fund = (owner: PublicKey, amount: number) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const transferToRecipient = (amount: number) =>
+            program.methods
+                .transfer(new anchor.BN(amount), null, null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    from: ataOf(authority.publicKey),
+                    to: ataOf(recipient),
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    destinationBlacklist: null,
+                    sourceActivity: null,
+                    destinationActivity: null,
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+        it("starts with zero transfer volume", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.totalTransferVolume.toString(), "0");
+        });
+
+        it("allows a transfer exactly at the limit", async () => {
+            await fund(authority.publicKey, 5_000_000);
+            await fund(recipient, 1);
+
+            await transferToRecipient(1_000_000);
+
+            const account = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_000_001);
+        });
+
+        it("rejects a transfer over the limit", async () => {
+            try {
+                await program.methods
+                    .transfer(new anchor.BN(1_000_001), null, null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        sourceBlacklist: null,
+                        destinationBlacklist: null,
+                        sourceActivity: null,
+                        destinationActivity: null,
+                        sourceKyc: null,
+                        sourceLock: null,
+                        sourceExemption: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown TransferLimitExceeded error");
+            } catch (err) {
+                assert.include(err.message, "TransferLimitExceeded");
+            }
+        });
+
+        it("accumulates transfer volume across transfers", async () => {
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            let last: any;
+            const listener = program.addEventListener("tokensTransferred", (event) => {
+                last = event;
+            });
+            for (const amount of [100, 250, 650]) {
+                await transferToRecipient(amount);
+            }
+            await program.removeEventListener(listener);
+
+            const after = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            const expected = before.totalTransferVolume.add(new anchor.BN(1_000));
+            assert.equal(after.totalTransferVolume.toString(), expected.toString());
+            assert.equal(last.cumulativeVolume.toString(), expected.toString());
+        });
+
+        it("removes the limit", async () => {
+            await setLimit(null);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.maxTransferAmount);
+        });
+
+        const setMinimum = (minimum: anchor.BN | null) =>
+            program.methods
+                .setMinTransfer(minimum)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        it("rejects a transfer below the minimum", async () => {
+            await setMinimum(new anchor.BN(100));
+
+            try {
+                await program.methods
+                    .transfer(new anchor.BN(99), null, null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        sourceBlacklist: null,
+                        destinationBlacklist: null,
+                        sourceActivity: null,
+                        destinationActivity: null,
+                        sourceKyc: null,
+                        sourceLock: null,
+                        sourceExemption: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown BelowMinimumTransfer error");
+            } catch (err) {
+                assert.include(err.message, "BelowMinimumTransfer");
+            }
+        });
+
+        it("allows a transfer exactly at the minimum", async () => {
+            const before = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+
+            await transferToRecipient(100);
+
+            const after = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(after.amount), Number(before.amount) + 100);
+        });
+
+        it("removes the minimum", async () => {
+            await setMinimum(null);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.minTransferAmount);
+        });
+
+        const setCooldown = (cooldown: anchor.BN | null) =>
+            program.methods
+                .setTransferCooldown(cooldown)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        it("sets a transfer cooldown", async () => {
+            await setCooldown(new anchor.BN(60));
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.transferCooldown.toNumber(), 60);
+        });
+
+        it("rejects a negative cooldown", async () => {
+            try {
+                await setCooldown(new anchor.BN(-1));
+                assert.fail("Should have thrown InvalidDelay error");
+            } catch (err) {
+                assert.include(err.message, "InvalidDelay");
+            }
+        });
+
+        it("requires the source activity record while a cooldown is set", async () => {
+            try {
+                await program.methods
+                    .transfer(new anchor.BN(500), null, null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        sourceBlacklist: null,
+                        destinationBlacklist: null,
+                        sourceActivity: null,
+                        destinationActivity: null,
+                        sourceKyc: null,
+                        sourceLock: null,
+                        sourceExemption: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown MissingAccount error");
+            } catch (err) {
+                assert.include(err.message, "MissingAccount");
+            }
+        });
+
+        const counterparty = Keypair.generate();
+        const activityOf = (owner: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("activity"), mint.publicKey.toBuffer(), ataOf(owner).toBuffer()],
+                program.programId
+            )[0];
+
+        const fundAndTrack = async (owner: PublicKey, amount: number) => {
+            await fund(owner, amount);
+            await program.methods
+                .trackActivity()
+                .accounts({
+                    payer: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    activity: activityOf(owner),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+        };
+
+        const trackedTransfer = (receiver: PublicKey, amount: number, signer: Keypair | null = null) => {
+            const sender = signer ? signer.publicKey : authority.publicKey;
+            return program.methods
+                .transfer(new anchor.BN(amount), null, null)
+                .accounts({
+                    owner: sender,
+                    mint: mint.publicKey,
+                    from: ataOf(sender),
+                    to: ataOf(receiver),
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    destinationBlacklist: null,
+                    sourceActivity: activityOf(sender),
+                    destinationActivity: activityOf(receiver),
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+        };
+
+        it("blocks a rapid second transfer from the same account", async () => {
+            await provider.connection.confirmTransaction(
+                await provider.connection.requestAirdrop(counterparty.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+            );
+            await fundAndTrack(authority.publicKey, 1_000);
+            await fundAndTrack(counterparty.publicKey, 1_000);
+
+            await trackedTransfer(counterparty.publicKey, 500);
+            const activity = await program.account.accountActivity.fetch(activityOf(authority.publicKey));
+            assert.isAbove(activity.lastOutboundAt.toNumber(), 0);
+
+            try {
+                await trackedTransfer(counterparty.publicKey, 500);
+                assert.fail("Should have thrown TransferCooldownActive error");
+            } catch (err) {
+                assert.include(err.message, "TransferCooldownActive");
+            }
+        });
+
+        it("does not restart the cooldown on inbound transfers", async () => {
+            // The counterparty just received 500 but has never sent anything
+            const activity = await program.account.accountActivity.fetch(activityOf(counterparty.publicKey));
+            assert.equal(activity.lastOutboundAt.toNumber(), 0);
+            assert.isAbove(activity.lastTransferAt.toNumber(), 0);
+
+            await trackedTransfer(authority.publicKey, 200, counterparty);
+        });
+
+        it("allows a transfer once the cooldown has elapsed", async () => {
+            await setCooldown(new anchor.BN(2));
+            await new Promise((resolve) => setTimeout(resolve, 3_000));
+
+            await trackedTransfer(counterparty.publicKey, 500);
+        });
+
+        it("removes the cooldown", async () => {
+            await setCooldown(null);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.transferCooldown);
+        });
+
+        it("sets a daily mint limit", async () => {
+            await program.methods
+                .setDailyMintLimit(new anchor.BN(2_000_000))
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.dailyMintLimit.toNumber(), 2_000_000);
+        });
+
+        it("mints up to the daily limit and rejects beyond it", async () => {
+            // Earlier tests already minted in today's window, so leave exactly 1_000 of headroom
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            const limit = before.mintedToday.add(new anchor.BN(1_000));
+            await program.methods
+                .setDailyMintLimit(limit)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+            await fund(recipient, 1_000);
+            const after = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(after.mintedToday.toString(), limit.toString());
+
+            try {
+                await fund(recipient, 1);
+                assert.fail("Should have thrown DailyMintLimitExceeded error");
+            } catch (err) {
+                assert.include(err.message, "DailyMintLimitExceeded");
+            }
+        });
+    });
+
+    describe("Single-Operation Cap", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+
+        const setCap = (cap: number | null, signer: Keypair | null = null) =>
+            program.methods
+                .setMaxSingleOpAmount(cap === null ? null : new anchor.BN(cap))
+                .accounts({
+                    authority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Bounded Dollar", "BNDD"));
+        });
+
+        it("starts uncapped", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.maxSingleOpAmount);
+        });
+
+        it("sets the cap", async () => {
+            await setCap(500_000);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.maxSingleOpAmount.toNumber(), 500_000);
+        });
+
+        it("rejects a zero cap", async () => {
+            try {
+                await setCap(0);
+                assert.fail("Should have thrown InvalidAmount error");
+            } catch (err) {
+                assert.include(err.message, "InvalidAmount");
+            }
+        });
+
+        it("rejects cap changes from a non-authority", async () => {
+            try {
+                await setCap(1, Keypair.generate());
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("rejects a mint above the cap", async () => {
+            try {
+                await program.methods
+                    .mintTo(new anchor.BN(500_001), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        feeCollector: null,
+                        mintStats: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown AmountTooLarge error");
+            } catch (err) {
+                assert.include(err.message, "AmountTooLarge");
+            }
+        });
+
+        it("rejects a transfer above the cap", async () => {
+            try {
+                await program.methods
+                    .transfer(new anchor.BN(500_001), null, null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        sourceBlacklist: null,
+                        destinationBlacklist: null,
+                        sourceActivity: null,
+                        destinationActivity: null,
+                        sourceKyc: null,
+                        sourceLock: null,
+                        sourceExemption: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown AmountTooLarge error");
+            } catch (err) {
+                assert.include(err.message, "AmountTooLarge");
+            }
+        });
+
+        it("still rejects zero amounts", async () => {
+            try {
+                await program.methods
+                    .transfer(new anchor.BN(0), null, null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        sourceBlacklist: null,
+                        destinationBlacklist: null,
+                        sourceActivity: null,
+                        destinationActivity: null,
+                        sourceKyc: null,
+                        sourceLock: null,
+                        sourceExemption: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown InvalidAmount error");
+            } catch (err) {
+                assert.include(err.message, "InvalidAmount");
+            }
+        });
+    });
+
+    describe("KYC Tiers", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const recipient = Keypair.generate().publicKey;
+        const kycRecord = PublicKey.findProgramAddressSync(
+            [Buffer.from("kyc"), mint.publicKey.toBuffer(), authority.publicKey.toBuffer()],
+            program.programId
+        )[0];
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+        const mintToOwner = (owner: PublicKey, amount: number) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const transferToRecipient = (amount: number, withKyc: boolean) =>
+            program.methods
+                .transfer(new anchor.BN(amount), null, null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    from: ataOf(authority.publicKey),
+                    to: ataOf(recipient),
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    destinationBlacklist: null,
+                    sourceActivity: null,
+                    destinationActivity: null,
+                    sourceKyc: withKyc ? kycRecord : null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+        const setTier = (tier: number, signer: Keypair | null = null) =>
+            program.methods
+                .kycSetTier(authority.publicKey, tier)
+                .accounts({
+                    complianceOfficer: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    complianceOfficers: findComplianceOfficers(mint.publicKey),
+                    kycRecord,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        const setThresholds = (thresholds: number[]) =>
+            program.methods
+                .setKycThresholds(thresholds.map((threshold) => new anchor.BN(threshold)))
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("KYC Dollar", "KYCD"));
+            await initComplianceOfficers(mint);
+            await mintToOwner(authority.publicKey, 20_000);
+            await mintToOwner(recipient, 1);
+        });
+
+        it("sets increasing tier thresholds", async () => {
+            await setThresholds([1_000, 5_000, 0]);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.deepEqual(
+                state.kycTierThresholds.map((threshold) => threshold.toNumber()),
+                [1_000, 5_000, 0]
+            );
+        });
+
+        it("rejects thresholds that do not increase with the tier", async () => {
+            try {
+                await setThresholds([5_000, 1_000, 0]);
+                assert.fail("Should have thrown InvalidKycTier error");
+            } catch (err) {
+                assert.include(err.message, "InvalidKycTier");
+            }
+        });
+
+        it("allows a small transfer without a KYC record", async () => {
+            await transferToRecipient(1_000, false);
+
+            const account = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_001);
+        });
+
+        it("blocks a large transfer without a KYC record", async () => {
+            try {
+                await transferToRecipient(2_000, false);
+                assert.fail("Should have thrown KycTierInsufficient error");
+            } catch (err) {
+                assert.include(err.message, "KycTierInsufficient");
+            }
+        });
+
+        it("blocks a transfer above the sender's tier", async () => {
+            await setTier(1);
+
+            try {
+                await transferToRecipient(6_000, true);
+                assert.fail("Should have thrown KycTierInsufficient error");
+            } catch (err) {
+                assert.include(err.message, "KycTierInsufficient");
+            }
+        });
+
+        it("allows a transfer within the sender's tier", async () => {
+            await transferToRecipient(2_000, true);
+
+            const account = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 3_001);
+        });
+
+        it("rejects a tier above the maximum", async () => {
+            try {
+                await setTier(4);
+                assert.fail("Should have thrown InvalidKycTier error");
+            } catch (err) {
+                assert.include(err.message, "InvalidKycTier");
+            }
+        });
+
+        it("rejects tier changes from a non-officer", async () => {
+            try {
+                await setTier(3, Keypair.generate());
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Limit Exemption Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Limit Exemption", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const trader = Keypair.generate();
+        const recipient = Keypair.generate().publicKey;
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+        const exemptionOf = (owner: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("limit_exempt"), mint.publicKey.toBuffer(), owner.toBuffer()],
+                program.programId
+            )[0];
+
+        const mintToOwner = (owner: PublicKey, amount: number) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const transferFrom = (sender: Keypair | null, amount: number, withExemption: boolean) => {
+            const owner = sender ? sender.publicKey : authority.publicKey;
+            return program.methods
+                .transfer(new anchor.BN(amount), null, null)
+                .accounts({
+                    owner,
+                    mint: mint.publicKey,
+                    from: ataOf(owner),
+                    to: ataOf(recipient),
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    destinationBlacklist: null,
+                    sourceActivity: null,
+                    destinationActivity: null,
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: withExemption ? exemptionOf(owner) : null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .signers(sender ? [sender] : [])
+                .rpc();
+        };
+
+        const setExempt = (owner: PublicKey, exempt: boolean) =>
+            program.methods
+                .setLimitExempt(owner, exempt)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    limitExemption: exemptionOf(owner),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Market Maker Dollar", "MMKR"));
+            await mintToOwner(authority.publicKey, 10_000);
+            await mintToOwner(trader.publicKey, 10_000);
+            await mintToOwner(recipient, 1);
+            await program.methods
+                .setTransferLimit(new anchor.BN(500))
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+            await setExempt(authority.publicKey, true);
+        });
+
+        it("records the exemption", async () => {
+            const exemption = await program.account.limitExemption.fetch(exemptionOf(authority.publicKey));
+            assert.isTrue(exemption.exempt);
+            assert.ok(exemption.owner.equals(authority.publicKey));
+        });
+
+        it("lets an exempt sender exceed the transfer limit", async () => {
+            await transferFrom(null, 2_000, true);
+
+            const account = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 2_001);
+        });
+
+        it("still caps a non-exempt sender", async () => {
+            try {
+                await transferFrom(trader, 2_000, false);
+                assert.fail("Should have thrown TransferLimitExceeded error");
+            } catch (err) {
+                assert.include(err.message, "TransferLimitExceeded");
+            }
+        });
+
+        it("caps the sender again once the exemption is lifted", async () => {
+            await setExempt(authority.publicKey, false);
+
+            try {
+                await transferFrom(null, 2_000, true);
+                assert.fail("Should have thrown TransferLimitExceeded error");
+            } catch (err) {
+                assert.include(err.message, "TransferLimitExceeded");
+            }
+        });
+    });
+
+    describe("Change Log", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const changelogPDA = PublicKey.findProgramAddressSync(
+            [Buffer.from("changelog"), mint.publicKey.toBuffer()],
+            program.programId
+        )[0];
+
+        const setLimit = (limit: number, changelog: PublicKey | null) =>
+            program.methods
+                .setTransferLimit(new anchor.BN(limit))
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog,
+                })
+                .rpc();
+
+        const readLog = () =>
+            program.methods
+                .getChangelog()
+                .accounts({ mint: mint.publicKey, changelog: changelogPDA })
+                .view();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Audited Dollar", "AUDL"));
+        });
+
+        it("creates the change log and enables it", async () => {
+            await program.methods
+                .initChangelog()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: changelogPDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(state.changelogEnabled);
+            assert.lengthOf(await readLog(), 0);
+        });
+
+        it("records pauses and limit changes in order", async () => {
+            for (const method of ["pause", "unpause"] as const) {
+                await program.methods[method]()
+                    .accounts({
+                        pauser: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: changelogPDA,
+                    })
+                    .rpc();
+            }
+            await setLimit(1_000, changelogPDA);
+
+            const entries = await readLog();
+            assert.deepEqual(entries.map((entry) => entry.actionCode), [1, 2, 7]);
+            assert.ok(entries[0].actor.equals(authority.publicKey));
+            assert.isTrue(entries[0].timestamp.toNumber() > 0);
+        });
+
+        it("rejects admin actions that omit the change log", async () => {
+            try {
+                await setLimit(2_000, null);
+                assert.fail("Should have thrown MissingAccount error");
+            } catch (err) {
+                assert.include(err.message, "MissingAccount");
+            }
+        });
+
+        it("keeps only the most recent 16 entries", async () => {
+            for (let i = 0; i < 14; i++) {
+                await setLimit(2_000 + i, changelogPDA);
+            }
+
+            // 17 actions recorded: the initial pause has been overwritten
+            const entries = await readLog();
+            assert.lengthOf(entries, 16);
+            assert.equal(entries[0].actionCode, 2);
+            assert.isTrue(entries.slice(1).every((entry) => entry.actionCode === 7));
+        });
+    });
+
+    describe("Non-Transferable Mode", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const holder = Keypair.generate().publicKey;
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+        const mintToOwner = (owner: PublicKey, amount: number) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, {
+                ...sss1Config("Soulbound Dollar", "SOUL"),
+                nonTransferable: true,
+            });
+            await mintToOwner(authority.publicKey, 5_000);
+            await mintToOwner(holder, 1);
+        });
+
+        it("stores the flag and still allows minting", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(state.nonTransferable);
+
+            const account = await getAccount(provider.connection, ataOf(authority.publicKey), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 5_000);
+        });
+
+        it("still allows burning", async () => {
+            await program.methods
+                .burn(new anchor.BN(1_000), null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: ataOf(authority.publicKey),
+                    stablecoinState: stablecoinStatePDA,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            const account = await getAccount(provider.connection, ataOf(authority.publicKey), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 4_000);
+        });
+
+        it("rejects peer-to-peer transfers", async () => {
+            try {
+                await program.methods
+                    .transfer(new anchor.BN(100), null, null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        from: ataOf(authority.publicKey),
+                        to: ataOf(holder),
+                        stablecoinState: stablecoinStatePDA,
+                        sourceBlacklist: null,
+                        destinationBlacklist: null,
+                        sourceActivity: null,
+                        destinationActivity: null,
+                        sourceKyc: null,
+                        sourceLock: null,
+                        sourceExemption: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown NonTransferable error");
+            } catch (err) {
+                assert.include(err.message, "NonTransferable");
+            }
+        });
+
+        it("rejects direct Token-2022 transfers through the hook", async () => {
+            // The hook only runs on SSS-2 mints, so this uses a separate soulbound SSS-2 coin
+            const hooked = Keypair.generate();
+            const hookedState = findStablecoinState(hooked.publicKey);
+            const hookedAtaOf = (owner: PublicKey) =>
+                getAssociatedTokenAddressSync(hooked.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+            await initStablecoin(hooked, {
+                ...sss2Config("Soulbound Hook Dollar", "SBND"),
+                defaultAccountFrozen: false,
+                nonTransferable: true,
+            });
+            await program.methods
+                .initializeExtraAccountMetaList()
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: hooked.publicKey,
+                    stablecoinState: hookedState,
+                    extraAccountMetaList: getExtraAccountMetaAddress(hooked.publicKey, program.programId),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            for (const owner of [authority.publicKey, holder]) {
+                await program.methods
+                    .mintToNew(new anchor.BN(1_000), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: hooked.publicKey,
+                        owner,
+                        tokenAccount: hookedAtaOf(owner),
+                        stablecoinState: hookedState,
+                        recipientBlacklist: PublicKey.findProgramAddressSync(
+                            [Buffer.from("blacklist"), hooked.publicKey.toBuffer(), owner.toBuffer()],
+                            program.programId
+                        )[0],
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        mintStats: null,
+                        feeCollector: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+            }
+
+            try {
+                await provider.sendAndConfirm(
+                    new Transaction().add(
+                        await createTransferCheckedWithTransferHookInstruction(
+                            provider.connection,
+                            hookedAtaOf(authority.publicKey),
+                            hooked.publicKey,
+                            hookedAtaOf(holder),
+                            authority.publicKey,
+                            BigInt(100),
+                            6,
+                            [],
+                            undefined,
+                            TOKEN_2022_PROGRAM_ID
+                        )
+                    )
+                );
+                assert.fail("Should have thrown NonTransferable error");
+            } catch (err) {
+                assert.include(err.logs.join("\n"), "NonTransferable");
+            }
+
+            const account = await getAccount(provider.connection, hookedAtaOf(holder), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_000);
+        });
+    });
+
+    describe("Multisig Authority", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const signerB = Keypair.generate();
+        const signerC = Keypair.generate();
+        const outsider = Keypair.generate();
+        const newPauser = Keypair.generate().publicKey;
+        const multisigPDA = PublicKey.findProgramAddressSync(
+            [Buffer.from("multisig"), mint.publicKey.toBuffer()],
+            program.programId
+        )[0];
+        const pendingActionOf = (actionId: number) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("pending_action"), mint.publicKey.toBuffer(), new anchor.BN(actionId).toArrayLike(Buffer, "le", 8)],
+                program.programId
+            )[0];
+
+        const propose = (actionId: number, action: Record<string, unknown>) =>
+            program.methods
+                .proposeAction(action)
+                .accounts({
+                    proposer: authority.publicKey,
+                    mint: mint.publicKey,
+                    multisig: multisigPDA,
+                    pendingAction: pendingActionOf(actionId),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const approve = (actionId: number, approver: Keypair) =>
+            program.methods
+                .approveAction(new anchor.BN(actionId))
+                .accounts({
+                    approver: approver.publicKey,
+                    mint: mint.publicKey,
+                    multisig: multisigPDA,
+                    pendingAction: pendingActionOf(actionId),
+                })
+                .signers([approver])
+                .rpc();
+
+        const execute = (actionId: number) =>
+            program.methods
+                .executeAction(new anchor.BN(actionId))
+                .accounts({
+                    executor: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    multisig: multisigPDA,
+                    pendingAction: pendingActionOf(actionId),
+                    changelog: null,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Council Dollar", "CNCL"));
+        });
+
+        it("hands the authority to a 2-of-3 multisig", async () => {
+            await program.methods
+                .initMultisig([authority.publicKey, signerB.publicKey, signerC.publicKey], 2)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    multisig: multisigPDA,
+                    systemProgram: SystemProgram.programId,
+                    changelog: null,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.authority.equals(multisigPDA));
+            const multisig = await program.account.multisigConfig.fetch(multisigPDA);
+            assert.equal(multisig.threshold, 2);
+            assert.lengthOf(multisig.signers, 3);
+        });
+
+        it("rejects executing an action below the threshold", async () => {
+            await propose(0, { pause: {} });
+
+            try {
+                await execute(0);
+                assert.fail("Should have thrown InsufficientApprovals error");
+            } catch (err) {
+                assert.include(err.message, "InsufficientApprovals");
+            }
+        });
+
+        it("rejects approvals from non-signers", async () => {
+            try {
+                await approve(0, outsider);
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        it("executes once the threshold is reached", async () => {
+            await approve(0, signerB);
+            await execute(0);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(state.paused);
+            const pending = await program.account.pendingAction.fetch(pendingActionOf(0));
+            assert.isTrue(pending.executed);
+        });
+
+        it("does not execute the same action twice", async () => {
+            try {
+                await execute(0);
+                assert.fail("Should have thrown ActionAlreadyExecuted error");
+            } catch (err) {
+                assert.include(err.message, "ActionAlreadyExecuted");
+            }
+        });
+
+        it("applies a role change approved by the multisig", async () => {
+            await propose(1, { updateRole: { role: { pauser: {} }, newAuthority: newPauser } });
+            await approve(1, signerC);
+            await execute(1);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.pauser.equals(newPauser));
+        });
+
+        it("no longer accepts the old authority key alone", async () => {
+            try {
+                await program.methods
+                    .setTransferLimit(new anchor.BN(1_000))
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+    });
+
+    describe("Mint Stats", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const holder = Keypair.generate().publicKey;
+        const untracked = Keypair.generate().publicKey;
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+        const mintStatsOf = (tokenAccount: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("mintstats"), mint.publicKey.toBuffer(), tokenAccount.toBuffer()],
+                program.programId
+            )[0];
+
+        const mintTracked = (owner: PublicKey, amount: number, track: boolean) =>
+            program.methods
+                .mintTo(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    feeCollector: null,
+                    mintStats: track ? mintStatsOf(ataOf(owner)) : null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+        const readStats = (owner: PublicKey) =>
+            program.methods
+                .getMintStats(ataOf(owner))
+                .accounts({ mint: mint.publicKey, mintStats: mintStatsOf(ataOf(owner)) })
+                .view();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Reported Dollar", "RPTD"));
+            for (const owner of [holder, untracked]) {
+                await program.methods
+                    .mintToNew(new anchor.BN(1), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        owner,
+                        tokenAccount: ataOf(owner),
+                        stablecoinState: stablecoinStatePDA,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        mintStats: null,
+                        feeCollector: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+            }
+        });
+
+        it("accumulates mints into the same account", async () => {
+            await mintTracked(holder, 1_500, true);
+            await mintTracked(holder, 2_500, true);
+
+            const stats = await readStats(holder);
+            assert.equal(stats.cumulativeMinted.toNumber(), 4_000);
+            assert.equal(stats.mintCount.toNumber(), 2);
+
+            const record = await program.account.mintStats.fetch(mintStatsOf(ataOf(holder)));
+            assert.ok(record.tokenAccount.equals(ataOf(holder)));
+        });
+
+        it("skips accounting when the stats account is omitted", async () => {
+            await mintTracked(holder, 500, false);
+            await mintTracked(untracked, 500, false);
+
+            assert.equal((await readStats(holder)).mintCount.toNumber(), 2);
+            const stats = await readStats(untracked);
+            assert.equal(stats.cumulativeMinted.toNumber(), 0);
+            assert.equal(stats.mintCount.toNumber(), 0);
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Transfer Batch Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Transfer Batch", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const recipients = [0, 1, 2].map(() => Keypair.generate().publicKey);
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+        const mintToOwner = (owner: PublicKey, amount: number) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const transferBatch = (amounts: number[], destinations: PublicKey[]) =>
+            program.methods
+                .transferBatch(amounts.map((amount) => new anchor.BN(amount)), "payroll")
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    from: ataOf(authority.publicKey),
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    sourceActivity: null,
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .remainingAccounts(
+                    destinations.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }))
+                )
+                .rpc();
+
+        const setBatchSize = (maxRecipients: number) =>
+            program.methods
+                .setTransferBatchSize(maxRecipients)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Batch Dollar", "BTCH"));
+            await mintToOwner(authority.publicKey, 10_000);
+            for (const recipient of recipients) {
+                await mintToOwner(recipient, 1);
+            }
+        });
+
+        it("transfers to several recipients in one instruction", async () => {
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            await transferBatch([100, 200, 300], recipients.map(ataOf));
+
+            for (let i = 0; i < recipients.length; i++) {
+                const account = await getAccount(provider.connection, ataOf(recipients[i]), undefined, TOKEN_2022_PROGRAM_ID);
+                assert.equal(Number(account.amount), 1 + (i + 1) * 100);
+            }
+            const source = await getAccount(provider.connection, ataOf(authority.publicKey), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(source.amount), 10_000 - 600);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(
+                state.totalTransferVolume.sub(before.totalTransferVolume).toNumber(),
+                600
+            );
+        });
+
+        it("rejects amounts that do not match the recipients", async () => {
+            try {
+                await transferBatch([100, 200], recipients.map(ataOf));
+                assert.fail("Should have thrown BatchLengthMismatch error");
+            } catch (err) {
+                assert.include(err.message, "BatchLengthMismatch");
+            }
+        });
+
+        it("rejects a batch over the configured recipient limit", async () => {
+            await setBatchSize(2);
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.maxTransferBatchSize, 2);
+
+            try {
+                await transferBatch([100, 100, 100], recipients.map(ataOf));
+                assert.fail("Should have thrown BatchTooLarge error");
+            } catch (err) {
+                assert.include(err.message, "BatchTooLarge");
+            }
+
+            await setBatchSize(0);
+        });
+
+        it("rejects a recipient limit above the maximum batch size", async () => {
+            try {
+                await setBatchSize(16);
+                assert.fail("Should have thrown BatchTooLarge error");
+            } catch (err) {
+                assert.include(err.message, "BatchTooLarge");
+            }
+        });
+
+        it("applies the per-transfer limit to each leg", async () => {
+            await program.methods
+                .setTransferLimit(new anchor.BN(150))
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+            try {
+                await transferBatch([100, 200], recipients.slice(0, 2).map(ataOf));
+                assert.fail("Should have thrown TransferLimitExceeded error");
+            } catch (err) {
+                assert.include(err.message, "TransferLimitExceeded");
+            }
+
+            await program.methods
+                .setTransferLimit(null)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+        });
+
+        it("aborts the whole batch when one recipient is blacklisted (SSS-2)", async () => {
+            const sss2Mint = Keypair.generate();
+            const sss2State = findStablecoinState(sss2Mint.publicKey);
+            const sss2AtaOf = (owner: PublicKey) =>
+                getAssociatedTokenAddressSync(sss2Mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            const blacklistOf = (owner: PublicKey) =>
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), sss2Mint.publicKey.toBuffer(), owner.toBuffer()],
+                    program.programId
+                )[0];
+            const blocked = Keypair.generate().publicKey;
+            const clean = Keypair.generate().publicKey;
+
+            await initStablecoin(sss2Mint, {
+                ...sss2Config("Batch Compliance Dollar", "BTCB"),
+                defaultAccountFrozen: false,
+            });
+            for (const [owner, amount] of [
+                [authority.publicKey, 10_000],
+                [blocked, 1],
+                [clean, 1],
+            ] as [PublicKey, number][]) {
+                await program.methods
+                    .mintToNew(new anchor.BN(amount), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: sss2Mint.publicKey,
+                        owner,
+                        tokenAccount: sss2AtaOf(owner),
+                        stablecoinState: sss2State,
+                        recipientBlacklist: blacklistOf(owner),
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        mintStats: null,
+                        feeCollector: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+            }
+            await program.methods
+                .blacklistAdd(blocked, 1, "", null)
+                .accounts({
+                    blacklistAuthority: authority.publicKey,
+                    mint: sss2Mint.publicKey,
+                    stablecoinState: sss2State,
+                    blacklistEntry: blacklistOf(blocked),
+                    tokenAccount: null,
+                    freezeAuthority: null,
+                    tokenProgram: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            // SSS-2 legs pass (token account, blacklist PDA) pairs
+            try {
+                await program.methods
+                    .transferBatch([new anchor.BN(100), new anchor.BN(100)], "payroll")
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: sss2Mint.publicKey,
+                        from: sss2AtaOf(authority.publicKey),
+                        stablecoinState: sss2State,
+                        sourceBlacklist: blacklistOf(authority.publicKey),
+                        sourceActivity: null,
+                        sourceKyc: null,
+                        sourceLock: null,
+                        sourceExemption: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .remainingAccounts(
+                        [blocked, clean].flatMap((owner) => [
+                            { pubkey: sss2AtaOf(owner), isWritable: true, isSigner: false },
+                            { pubkey: blacklistOf(owner), isWritable: false, isSigner: false },
+                        ])
+                    )
+                    .rpc();
+                assert.fail("Should have thrown TransferBlocked error");
+            } catch (err) {
+                assert.include(err.message, "TransferBlocked");
+            }
+
+            for (const [owner, amount] of [
+                [authority.publicKey, 10_000],
+                [blocked, 1],
+                [clean, 1],
+            ] as [PublicKey, number][]) {
+                const account = await getAccount(provider.connection, sss2AtaOf(owner), undefined, TOKEN_2022_PROGRAM_ID);
+                assert.equal(Number(account.amount), amount);
+            }
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Config Lock Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Config Lock", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const adminAccounts = {
+            authority: authority.publicKey,
+            mint: mint.publicKey,
+            stablecoinState: stablecoinStatePDA,
+            changelog: null,
+        };
+
+        const lockConfig = () =>
+            program.methods.lockConfig().accounts(adminAccounts).rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Frozen Config Dollar", "CFGL"));
+        });
+
+        it("locks the configuration", async () => {
+            let state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isFalse(state.configLocked);
+
+            await lockConfig();
+
+            state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isTrue(state.configLocked);
+        });
+
+        it("rejects locking twice", async () => {
+            try {
+                await lockConfig();
+                assert.fail("Should have thrown ConfigLocked error");
+            } catch (err) {
+                assert.include(err.message, "ConfigLocked");
+            }
+        });
+
+        it("blocks metadata updates once locked", async () => {
+            try {
+                await program.methods
+                    .updateMetadata("Renamed Dollar", null, null)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown ConfigLocked error");
+            } catch (err) {
+                assert.include(err.message, "ConfigLocked");
+            }
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.name, "Frozen Config Dollar");
+        });
+
+        it("blocks limit and feature flag setters once locked", async () => {
+            try {
+                await program.methods
+                    .setTransferLimit(new anchor.BN(1_000))
+                    .accounts(adminAccounts)
+                    .rpc();
+                assert.fail("Should have thrown ConfigLocked error");
+            } catch (err) {
+                assert.include(err.message, "ConfigLocked");
+            }
+
+            try {
+                await program.methods
+                    .setEventEmission(false)
+                    .accounts(adminAccounts)
+                    .rpc();
+                assert.fail("Should have thrown ConfigLocked error");
+            } catch (err) {
+                assert.include(err.message, "ConfigLocked");
+            }
+        });
+
+        it("blocks the fee authority, limit exemptions and preset upgrade once locked", async () => {
+            const owner = Keypair.generate().publicKey;
+            const attempts = [
+                () => program.methods.setFeeAuthority(owner).accounts(adminAccounts).rpc(),
+                () =>
+                    program.methods
+                        .setLimitExempt(owner, true)
+                        .accounts({
+                            authority: authority.publicKey,
+                            mint: mint.publicKey,
+                            stablecoinState: stablecoinStatePDA,
+                            limitExemption: PublicKey.findProgramAddressSync(
+                                [Buffer.from("limit_exempt"), mint.publicKey.toBuffer(), owner.toBuffer()],
+                                program.programId
+                            )[0],
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .rpc(),
+                () =>
+                    program.methods
+                        .upgradeToSss2(authority.publicKey)
+                        .accounts({
+                            authority: authority.publicKey,
+                            mint: mint.publicKey,
+                            stablecoinState: stablecoinStatePDA,
+                            tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        })
+                        .rpc(),
+            ];
+
+            for (const attempt of attempts) {
+                try {
+                    await attempt();
+                    assert.fail("Should have thrown ConfigLocked error");
+                } catch (err) {
+                    assert.include(err.message, "ConfigLocked");
+                }
+            }
+        });
+
+        it("still allows minting and pausing once locked", async () => {
+            const ata = getAssociatedTokenAddressSync(
+                mint.publicKey,
+                authority.publicKey,
+                false,
+                TOKEN_2022_PROGRAM_ID
+            );
+            await program.methods
+                .mintToNew(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner: authority.publicKey,
+                    tokenAccount: ata,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const account = await getAccount(provider.connection, ata, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_000);
+
+            const pauseAccounts = {
+                pauser: authority.publicKey,
+                mint: mint.publicKey,
+                stablecoinState: stablecoinStatePDA,
+                changelog: null,
+            };
+            await program.methods.pause().accounts(pauseAccounts).rpc();
+            await program.methods.unpause().accounts(pauseAccounts).rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isFalse(state.paused);
+        });
+    });
+
+    describe("Minimum Account Balance", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const recipient = Keypair.generate().publicKey;
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+        const mintToOwner = (owner: PublicKey, amount: number) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const transferToRecipient = (amount: number) =>
+            program.methods
+                .transfer(new anchor.BN(amount), null, null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    from: ataOf(authority.publicKey),
+                    to: ataOf(recipient),
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    destinationBlacklist: null,
+                    sourceActivity: null,
+                    destinationActivity: null,
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+        const setMinBalance = (minimum: number | null, policy: Record<string, {}>) =>
+            program.methods
+                .setMinAccountBalance(minimum === null ? null : new anchor.BN(minimum), policy)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        const balanceOf = async (owner: PublicKey) =>
+            Number((await getAccount(provider.connection, ataOf(owner), undefined, TOKEN_2022_PROGRAM_ID)).amount);
+
+        const batchRecipient = Keypair.generate().publicKey;
+        const transferBatch = (amounts: number[]) =>
+            program.methods
+                .transferBatch(amounts.map((amount) => new anchor.BN(amount)), null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    from: ataOf(authority.publicKey),
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    sourceActivity: null,
+                    sourceKyc: null,
+                    sourceLock: null,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .remainingAccounts(
+                    amounts.map(() => ({ pubkey: ataOf(batchRecipient), isWritable: true, isSigner: false }))
+                )
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Dustless Dollar", "DUST"));
+            await mintToOwner(authority.publicKey, 1_000);
+            await mintToOwner(recipient, 1);
+            await mintToOwner(batchRecipient, 1);
+        });
+
+        it("starts with no minimum and the reject policy", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.minAccountBalance);
+            assert.deepEqual(state.dustPolicy, { reject: {} });
+        });
+
+        it("rejects a zero minimum", async () => {
+            try {
+                await setMinBalance(0, { reject: {} });
+                assert.fail("Should have thrown InvalidAmount error");
+            } catch (err) {
+                assert.include(err.message, "InvalidAmount");
+            }
+        });
+
+        it("rejects a transfer that would leave dust under Reject", async () => {
+            await setMinBalance(100, { reject: {} });
+
+            try {
+                // Leaves 99, one below the minimum
+                await transferToRecipient(901);
+                assert.fail("Should have thrown WouldLeaveDust error");
+            } catch (err) {
+                assert.include(err.message, "WouldLeaveDust");
+            }
+            assert.equal(await balanceOf(authority.publicKey), 1_000);
+        });
+
+        it("allows a transfer leaving exactly the minimum", async () => {
+            await transferToRecipient(900);
+
+            assert.equal(await balanceOf(authority.publicKey), 100);
+            assert.equal(await balanceOf(recipient), 901);
+        });
+
+        it("checks each batch leg against the balance the earlier legs leave", async () => {
+            await mintToOwner(authority.publicKey, 1_000);
+
+            try {
+                // The second leg alone would leave 550, but after the first it leaves 50
+                await transferBatch([500, 550]);
+                assert.fail("Should have thrown WouldLeaveDust error");
+            } catch (err) {
+                assert.include(err.message, "WouldLeaveDust");
+            }
+            assert.equal(await balanceOf(authority.publicKey), 1_100);
+
+            await transferBatch([500, 500]);
+            assert.equal(await balanceOf(authority.publicKey), 100);
+            assert.equal(await balanceOf(batchRecipient), 1_001);
+        });
+
+        it("sweeps the whole balance under SweepAll", async () => {
+            await setMinBalance(100, { sweepAll: {} });
+
+            // Would leave 50; the remainder is swept along with it
+            await transferToRecipient(50);
+
+            assert.equal(await balanceOf(authority.publicKey), 0);
+            assert.equal(await balanceOf(recipient), 1_001);
+        });
+
+        it("clears the minimum", async () => {
+            await setMinBalance(null, { reject: {} });
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.minAccountBalance);
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Mint Lock Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Mint Lock", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const recipient = Keypair.generate().publicKey;
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+        const lockOf = (owner: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("mint_lock"), mint.publicKey.toBuffer(), ataOf(owner).toBuffer()],
+                program.programId
+            )[0];
+        const mintLock = lockOf(authority.publicKey);
+
+        const mintToOwner = (owner: PublicKey, amount: number, lock: PublicKey | null = lockOf(owner)) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: lock,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const transferToRecipient = (amount: number, sourceLock: PublicKey | null) =>
+            program.methods
+                .transfer(new anchor.BN(amount), null, null)
+                .accounts({
+                    owner: authority.publicKey,
+                    mint: mint.publicKey,
+                    from: ataOf(authority.publicKey),
+                    to: ataOf(recipient),
+                    stablecoinState: stablecoinStatePDA,
+                    sourceBlacklist: null,
+                    destinationBlacklist: null,
+                    sourceActivity: null,
+                    destinationActivity: null,
+                    sourceKyc: null,
+                    sourceLock,
+                    sourceExemption: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+        const setLockPeriod = (period: number | null) =>
+            program.methods
+                .setMintLockPeriod(period === null ? null : new anchor.BN(period))
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Locked Dollar", "MLCK"));
+            await setLockPeriod(2);
+            await mintToOwner(authority.publicKey, 1_000);
+            await mintToOwner(recipient, 1);
+        });
+
+        it("records the unlock time on mint", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.mintLockPeriod.toNumber(), 2);
+
+            const lock = await program.account.mintLock.fetch(mintLock);
+            assert.ok(lock.tokenAccount.equals(ataOf(authority.publicKey)));
+            assert.isAbove(lock.unlockAt.toNumber(), state.createdAt.toNumber());
+        });
+
+        it("requires the lock account on mint while a period is set", async () => {
+            try {
+                await mintToOwner(authority.publicKey, 1, null);
+                assert.fail("Should have thrown MissingAccount error");
+            } catch (err) {
+                assert.include(err.message, "MissingAccount");
+            }
+        });
+
+        it("blocks a transfer inside the lock window", async () => {
+            try {
+                await transferToRecipient(100, mintLock);
+                assert.fail("Should have thrown TokensLocked error");
+            } catch (err) {
+                assert.include(err.message, "TokensLocked");
+            }
+        });
+
+        it("requires the lock account while a period is set", async () => {
+            try {
+                await transferToRecipient(100, null);
+                assert.fail("Should have thrown MissingAccount error");
+            } catch (err) {
+                assert.include(err.message, "MissingAccount");
+            }
+        });
+
+        it("allows the transfer once the lock expires", async () => {
+            await new Promise((resolve) => setTimeout(resolve, 3_000));
+            await transferToRecipient(100, mintLock);
+
+            const account = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 101);
+        });
+
+        it("rejects a negative lock period", async () => {
+            try {
+                await setLockPeriod(-1);
+                assert.fail("Should have thrown InvalidDelay error");
+            } catch (err) {
+                assert.include(err.message, "InvalidDelay");
+            }
+        });
+
+        it("disables the lock with None", async () => {
+            await setLockPeriod(null);
+            await mintToOwner(authority.publicKey, 500);
+            await transferToRecipient(100, null);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.mintLockPeriod);
+            const account = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 201);
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Transfer Fee Tests
+    // ─────────────────────────────────────────────────
+
+    describe("Transfer Fee", () => {
+        const mint = Keypair.generate();
+        let stablecoinStatePDA: PublicKey;
+
+        const setFee = (basisPoints: number, maxFee: anchor.BN) =>
+            program.methods
+                .setTransferFee(basisPoints, maxFee)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        before(async () => {
+            stablecoinStatePDA = findStablecoinState(mint.publicKey);
+            await initStablecoin(mint, {
+                ...sss1Config("Fee Dollar", "FEED"),
+                transferFeeBasisPoints: 25,
+                maxTransferFee: new anchor.BN(5_000),
+            });
+        });
+
+        it("mirrors the fee set at initialization", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.transferFeeBasisPoints, 25);
+            assert.equal(state.maxTransferFee.toNumber(), 5_000);
+        });
+
+        it("updates the fee up to 10000 basis points", async () => {
+            await setFee(10_000, new anchor.BN(1_000_000));
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.transferFeeBasisPoints, 10_000);
+            assert.equal(state.maxTransferFee.toNumber(), 1_000_000);
+        });
+
+        it("rejects an out-of-range fee update", async () => {
+            try {
+                await setFee(10_001, new anchor.BN(0));
+                assert.fail("Should have thrown InvalidFeeBasisPoints error");
+            } catch (err) {
+                assert.include(err.message, "InvalidFeeBasisPoints");
+            }
+        });
+
+        it("rejects an out-of-range fee at initialization", async () => {
+            try {
+                await initStablecoin(Keypair.generate(), {
+                    ...sss1Config("Greedy Dollar", "GRDY"),
+                    transferFeeBasisPoints: 10_001,
+                });
+                assert.fail("Should have thrown InvalidFeeBasisPoints error");
+            } catch (err) {
+                assert.include(err.message, "InvalidFeeBasisPoints");
+            }
+        });
+
+        it("rejects transferWithFee on a mint without the transfer-fee extension", async () => {
+            const recipient = Keypair.generate().publicKey;
+            const ataOf = (owner: PublicKey) =>
+                getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            for (const owner of [authority.publicKey, recipient]) {
+                await program.methods
+                    .mintToNew(new anchor.BN(10_000), null)
+                    .accounts({
+                        mintAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        owner,
+                        tokenAccount: ataOf(owner),
+                        stablecoinState: stablecoinStatePDA,
+                        recipientBlacklist: null,
+                        oracle: null,
+                        clawbackRecord: null,
+                        mintLock: null,
+                        mintStats: null,
+                        feeCollector: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+            }
+
+            try {
+                await program.methods
+                    .transferWithFee(new anchor.BN(1_000), null, null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: mint.publicKey,
+                        from: ataOf(authority.publicKey),
+                        to: ataOf(recipient),
+                        stablecoinState: stablecoinStatePDA,
+                        sourceBlacklist: null,
+                        destinationBlacklist: null,
+                        sourceActivity: null,
+                        destinationActivity: null,
+                        sourceKyc: null,
+                        sourceLock: null,
+                        sourceExemption: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown MissingExtension error");
+            } catch (err) {
+                assert.include(err.message, "MissingExtension");
+            }
+        });
+
+        describe("on a mint with the transfer-fee extension", () => {
+            const feeMint = Keypair.generate();
+            const feeState = findStablecoinState(feeMint.publicKey);
+            const recipient = Keypair.generate().publicKey;
+            const ataOf = (owner: PublicKey) =>
+                getAssociatedTokenAddressSync(feeMint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+
+            before(async () => {
+                // initialize expects the mint to exist, so create it with the extension first;
+                // the stablecoin PDA owns the fee config and the withheld fees
+                const space = getMintLen([ExtensionType.TransferFeeConfig]);
+                await provider.sendAndConfirm(
+                    new Transaction().add(
+                        SystemProgram.createAccount({
+                            fromPubkey: authority.publicKey,
+                            newAccountPubkey: feeMint.publicKey,
+                            space,
+                            lamports: await provider.connection.getMinimumBalanceForRentExemption(space),
+                            programId: TOKEN_2022_PROGRAM_ID,
+                        }),
+                        createInitializeTransferFeeConfigInstruction(
+                            feeMint.publicKey,
+                            feeState,
+                            feeState,
+                            25,
+                            BigInt(5_000),
+                            TOKEN_2022_PROGRAM_ID
+                        ),
+                        createInitializeMintInstruction(
+                            feeMint.publicKey,
+                            6,
+                            authority.publicKey,
+                            authority.publicKey,
+                            TOKEN_2022_PROGRAM_ID
+                        )
+                    ),
+                    [feeMint]
+                );
+                await initStablecoin(feeMint, {
+                    ...sss1Config("Charged Dollar", "CHRG"),
+                    transferFeeBasisPoints: 25,
+                    maxTransferFee: new anchor.BN(5_000),
+                });
+
+                for (const owner of [authority.publicKey, recipient]) {
+                    await program.methods
+                        .mintToNew(new anchor.BN(owner.equals(recipient) ? 1 : 100_000), null)
+                        .accounts({
+                            mintAuthority: authority.publicKey,
+                            mint: feeMint.publicKey,
+                            owner,
+                            tokenAccount: ataOf(owner),
+                            stablecoinState: feeState,
+                            recipientBlacklist: null,
+                            oracle: null,
+                            clawbackRecord: null,
+                            mintLock: null,
+                            mintStats: null,
+                            feeCollector: null,
+                            tokenProgram: TOKEN_2022_PROGRAM_ID,
+                            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .rpc();
+                }
+            });
+
+            it("withholds the extension fee and reports it in the transfer event", async () => {
+                let fee: number | undefined;
+                const listener = program.addEventListener("tokensTransferred", (event) => {
+                    fee = event.fee.toNumber();
+                });
+
+                await program.methods
+                    .transferWithFee(new anchor.BN(10_000), null, null)
+                    .accounts({
+                        owner: authority.publicKey,
+                        mint: feeMint.publicKey,
+                        from: ataOf(authority.publicKey),
+                        to: ataOf(recipient),
+                        stablecoinState: feeState,
+                        sourceBlacklist: null,
+                        destinationBlacklist: null,
+                        sourceActivity: null,
+                        destinationActivity: null,
+                        sourceKyc: null,
+                        sourceLock: null,
+                        sourceExemption: null,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+
+                await program.removeEventListener(listener);
+                // 25 bps of 10_000
+                assert.equal(fee, 25);
+
+                const account = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+                assert.equal(Number(account.amount), 1 + 10_000 - 25);
+                assert.equal(Number(getTransferFeeAmount(account).withheldAmount), 25);
+            });
+
+            it("rejects a transfer once the mirrored fee drifts from the extension", async () => {
+                await program.methods
+                    .setTransferFee(50, new anchor.BN(5_000))
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: feeMint.publicKey,
+                        stablecoinState: feeState,
+                        changelog: null,
+                    })
+                    .rpc();
+
+                try {
+                    await program.methods
+                        .transferWithFee(new anchor.BN(10_000), null, null)
+                        .accounts({
+                            owner: authority.publicKey,
+                            mint: feeMint.publicKey,
+                            from: ataOf(authority.publicKey),
+                            to: ataOf(recipient),
+                            stablecoinState: feeState,
+                            sourceBlacklist: null,
+                            destinationBlacklist: null,
+                            sourceActivity: null,
+                            destinationActivity: null,
+                            sourceKyc: null,
+                            sourceLock: null,
+                            sourceExemption: null,
+                            tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        })
+                        .rpc();
+                    assert.fail("Should have thrown TransferFeeMismatch error");
+                } catch (err) {
+                    assert.include(err.message, "TransferFeeMismatch");
+                }
+            });
+        });
+    });
+
+    describe("Withheld Fees", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const feeAuthority = Keypair.generate();
+        const collector = getAssociatedTokenAddressSync(mint.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Withheld Dollar", "WHFD"));
+            await program.methods
+                .mintToNew(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner: authority.publicKey,
+                    tokenAccount: collector,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+        });
+
+        it("defaults the fee authority to the stablecoin authority", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.feeAuthority.equals(authority.publicKey));
+        });
+
+        it("rejects a withdrawal from a mint without the transfer-fee extension", async () => {
+            try {
+                await program.methods
+                    .withdrawWithheldFees()
+                    .accounts({
+                        feeAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        feeCollector: collector,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                assert.fail("Should have thrown MissingExtension error");
+            } catch (err) {
+                assert.include(err.message, "MissingExtension");
+            }
+        });
+
+        it("hands the fee role to another signer", async () => {
+            await program.methods
+                .setFeeAuthority(feeAuthority.publicKey)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.ok(state.feeAuthority.equals(feeAuthority.publicKey));
+        });
+
+        it("rejects a harvest from anyone but the fee authority", async () => {
+            try {
+                await program.methods
+                    .harvestWithheldFees()
+                    .accounts({
+                        feeAuthority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .remainingAccounts([{ pubkey: collector, isWritable: true, isSigner: false }])
+                    .rpc();
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+
+        describe("on a mint with the transfer-fee extension", () => {
+            const feeMint = Keypair.generate();
+            const feeState = findStablecoinState(feeMint.publicKey);
+            const recipients = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+            const ataOf = (owner: PublicKey) =>
+                getAssociatedTokenAddressSync(feeMint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+            const mintWithheld = async () =>
+                Number(
+                    getTransferFeeConfig(
+                        await getMint(provider.connection, feeMint.publicKey, undefined, TOKEN_2022_PROGRAM_ID)
+                    ).withheldAmount
+                );
+
+            before(async () => {
+                // The stablecoin PDA is the withdraw-withheld authority
+                const space = getMintLen([ExtensionType.TransferFeeConfig]);
+                await provider.sendAndConfirm(
+                    new Transaction().add(
+                        SystemProgram.createAccount({
+                            fromPubkey: authority.publicKey,
+                            newAccountPubkey: feeMint.publicKey,
+                            space,
+                            lamports: await provider.connection.getMinimumBalanceForRentExemption(space),
+                            programId: TOKEN_2022_PROGRAM_ID,
+                        }),
+                        createInitializeTransferFeeConfigInstruction(
+                            feeMint.publicKey,
+                            feeState,
+                            feeState,
+                            25,
+                            BigInt(5_000),
+                            TOKEN_2022_PROGRAM_ID
+                        ),
+                        createInitializeMintInstruction(
+                            feeMint.publicKey,
+                            6,
+                            authority.publicKey,
+                            authority.publicKey,
+                            TOKEN_2022_PROGRAM_ID
+                        )
+                    ),
+                    [feeMint]
+                );
+                await initStablecoin(feeMint, {
+                    ...sss1Config("Harvested Dollar", "HRVD"),
+                    transferFeeBasisPoints: 25,
+                    maxTransferFee: new anchor.BN(5_000),
+                });
+
+                for (const owner of [authority.publicKey, ...recipients]) {
+                    await program.methods
+                        .mintToNew(new anchor.BN(owner.equals(authority.publicKey) ? 100_000 : 1), null)
+                        .accounts({
+                            mintAuthority: authority.publicKey,
+                            mint: feeMint.publicKey,
+                            owner,
+                            tokenAccount: ataOf(owner),
+                            stablecoinState: feeState,
+                            recipientBlacklist: null,
+                            oracle: null,
+                            clawbackRecord: null,
+                            mintLock: null,
+                            mintStats: null,
+                            feeCollector: null,
+                            tokenProgram: TOKEN_2022_PROGRAM_ID,
+                            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .rpc();
+                }
+
+                // 25 bps of 10_000 stays withheld in each recipient
+                for (const recipient of recipients) {
+                    await program.methods
+                        .transferWithFee(new anchor.BN(10_000), null, null)
+                        .accounts({
+                            owner: authority.publicKey,
+                            mint: feeMint.publicKey,
+                            from: ataOf(authority.publicKey),
+                            to: ataOf(recipient),
+                            stablecoinState: feeState,
+                            sourceBlacklist: null,
+                            destinationBlacklist: null,
+                            sourceActivity: null,
+                            destinationActivity: null,
+                            sourceKyc: null,
+                            sourceLock: null,
+                            sourceExemption: null,
+                            tokenProgram: TOKEN_2022_PROGRAM_ID,
+                        })
+                        .rpc();
+                }
+            });
+
+            it("harvests withheld fees onto the mint", async () => {
+                await program.methods
+                    .harvestWithheldFees()
+                    .accounts({
+                        feeAuthority: authority.publicKey,
+                        mint: feeMint.publicKey,
+                        stablecoinState: feeState,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .remainingAccounts(
+                        recipients.map((owner) => ({ pubkey: ataOf(owner), isWritable: true, isSigner: false }))
+                    )
+                    .rpc();
+
+                for (const recipient of recipients) {
+                    const account = await getAccount(provider.connection, ataOf(recipient), undefined, TOKEN_2022_PROGRAM_ID);
+                    assert.equal(Number(getTransferFeeAmount(account).withheldAmount), 0);
+                }
+                assert.equal(await mintWithheld(), 50);
+            });
+
+            it("withdraws the mint's withheld fees to the collector", async () => {
+                const collector = ataOf(authority.publicKey);
+                const before = await getAccount(provider.connection, collector, undefined, TOKEN_2022_PROGRAM_ID);
+
+                let withdrawn: any;
+                const listener = program.addEventListener("feesWithdrawn", (event) => {
+                    withdrawn = event;
+                });
+                await program.methods
+                    .withdrawWithheldFees()
+                    .accounts({
+                        feeAuthority: authority.publicKey,
+                        mint: feeMint.publicKey,
+                        stablecoinState: feeState,
+                        feeCollector: collector,
+                        tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    })
+                    .rpc();
+                await program.removeEventListener(listener);
+
+                const after = await getAccount(provider.connection, collector, undefined, TOKEN_2022_PROGRAM_ID);
+                assert.equal(Number(after.amount), Number(before.amount) + 50);
+                assert.equal(await mintWithheld(), 0);
+                assert.equal(withdrawn.amount.toNumber(), 50);
+            });
+        });
+    });
+
+    describe("Mint Fee", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const holder = Keypair.generate().publicKey;
+        const collector = Keypair.generate().publicKey;
+        const ataOf = (owner: PublicKey) =>
+            getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID);
+        const balanceOf = async (owner: PublicKey) =>
+            Number((await getAccount(provider.connection, ataOf(owner), undefined, TOKEN_2022_PROGRAM_ID)).amount);
+
+        const mintToOwner = (owner: PublicKey, amount: number, feeCollector: PublicKey | null) =>
+            program.methods
+                .mintToNew(new anchor.BN(amount), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner,
+                    tokenAccount: ataOf(owner),
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: null,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        const setMintFee = (basisPoints: number, signer: Keypair | null = null) =>
+            program.methods
+                .setMintFee(basisPoints, ataOf(collector))
+                .accounts({
+                    authority: signer ? signer.publicKey : authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .signers(signer ? [signer] : [])
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Issuance Fee Dollar", "ISSF"));
+        });
+
+        it("passes the full amount through with a zero fee", async () => {
+            await mintToOwner(holder, 10_000, null);
+            await mintToOwner(collector, 1, null);
+
+            assert.equal(await balanceOf(holder), 10_000);
+            assert.equal(await balanceOf(collector), 1);
+        });
+
+        it("splits a mint between the destination and the fee collector", async () => {
+            await setMintFee(250);
+            const before = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+
+            // 2.5% of 10_001 is 250.025, rounded down to 250
+            await mintToOwner(holder, 10_001, ataOf(collector));
+
+            assert.equal(await balanceOf(holder), 10_000 + 9_751);
+            assert.equal(await balanceOf(collector), 1 + 250);
+            const after = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(after.totalMinted.toNumber(), before.totalMinted.toNumber() + 10_001);
+        });
+
+        it("rounds the fee per the configured policy at the boundaries", async () => {
+            const setRounding = (policy: object) =>
+                program.methods
+                    .setFeeRounding(policy)
+                    .accounts({
+                        authority: authority.publicKey,
+                        mint: mint.publicKey,
+                        stablecoinState: stablecoinStatePDA,
+                        changelog: null,
+                    })
+                    .rpc();
+            const feeOn = async (amount: number) => {
+                const before = await balanceOf(collector);
+                await mintToOwner(holder, amount, ataOf(collector));
+                return (await balanceOf(collector)) - before;
+            };
+
+            let state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.deepEqual(state.feeRounding, { roundDown: {} });
+
+            // At 2.5%: 10_000 → 250 exactly, 10_001 → 250.025, 10_020 → 250.5
+            await setRounding({ roundDown: {} });
+            assert.equal(await feeOn(10_000), 250);
+            assert.equal(await feeOn(10_001), 250);
+            assert.equal(await feeOn(10_020), 250);
+
+            await setRounding({ roundUp: {} });
+            assert.equal(await feeOn(10_000), 250);
+            assert.equal(await feeOn(10_001), 251);
+            assert.equal(await feeOn(10_020), 251);
+
+            await setRounding({ nearest: {} });
+            assert.equal(await feeOn(10_000), 250);
+            assert.equal(await feeOn(10_001), 250);
+            assert.equal(await feeOn(10_020), 251);
+            assert.equal(await feeOn(10_019), 250);
+
+            state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.deepEqual(state.feeRounding, { nearest: {} });
+            await setRounding({ roundDown: {} });
+        });
+
+        it("requires the fee collector while a fee is set", async () => {
+            try {
+                await mintToOwner(holder, 1_000, null);
+                assert.fail("Should have thrown MissingAccount error");
+            } catch (err) {
+                assert.include(err.message, "MissingAccount");
+            }
+        });
+
+        it("rejects a fee collector other than the configured one", async () => {
+            try {
+                await mintToOwner(holder, 1_000, ataOf(holder));
+                assert.fail("Should have thrown InvalidFeeCollector error");
+            } catch (err) {
+                assert.include(err.message, "InvalidFeeCollector");
+            }
+        });
+
+        it("rejects a fee above 10000 basis points", async () => {
+            try {
+                await setMintFee(10_001);
+                assert.fail("Should have thrown InvalidFeeBasisPoints error");
+            } catch (err) {
+                assert.include(err.message, "InvalidFeeBasisPoints");
+            }
+        });
+
+        it("rejects fee changes from a non-authority", async () => {
+            try {
+                await setMintFee(0, Keypair.generate());
+                assert.fail("Should have thrown Unauthorized error");
+            } catch (err) {
+                assert.include(err.message, "Unauthorized");
+            }
+        });
+    });
+
+    describe("Interest Rate", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+
+        const setRate = (rateBps: number) =>
+            program.methods
+                .setInterestRate(rateBps)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Yield Dollar", "YLD"));
+        });
+
+        it("sets a positive rate", async () => {
+            await setRate(450);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.interestRateBps, 450);
+        });
+
+        it("sets a negative rate", async () => {
+            await setRate(-5_000);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.interestRateBps, -5_000);
+        });
+
+        it("rejects rates outside ±5000 basis points", async () => {
+            for (const rate of [5_001, -5_001]) {
+                try {
+                    await setRate(rate);
+                    assert.fail("Should have thrown InvalidInterestRate error");
+                } catch (err) {
+                    assert.include(err.message, "InvalidInterestRate");
+                }
+            }
+        });
+    });
+
+    describe("Peg Oracle", () => {
+        const mint = Keypair.generate();
+        const stablecoinStatePDA = findStablecoinState(mint.publicKey);
+        const oracle = Keypair.generate().publicKey;
+
+        const setOracle = (feed: PublicKey | null, maxDeviationBps: number) =>
+            program.methods
+                .setOracle(feed, maxDeviationBps)
+                .accounts({
+                    authority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    changelog: null,
+                })
+                .rpc();
+
+        const mintWithOracle = (feed: PublicKey | null) =>
+            program.methods
+                .mintTo(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: feed,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    feeCollector: null,
+                    mintStats: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+        before(async () => {
+            await initStablecoin(mint, sss1Config("Pegged Dollar", "PEG"));
+        });
+
+        it("starts with no oracle configured", async () => {
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.oracle);
+            assert.equal(state.maxPegDeviationBps, 0);
+        });
+
+        it("sets the oracle and deviation threshold", async () => {
+            await setOracle(oracle, 50);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.equal(state.oracle.toBase58(), oracle.toBase58());
+            assert.equal(state.maxPegDeviationBps, 50);
+        });
+
+        it("rejects a deviation threshold above 10000 basis points", async () => {
+            try {
+                await setOracle(oracle, 10_001);
+                assert.fail("Should have thrown InvalidPegDeviation error");
+            } catch (err) {
+                assert.include(err.message, "InvalidPegDeviation");
+            }
+        });
+
+        it("requires the price account once an oracle is configured", async () => {
+            try {
+                await mintWithOracle(null);
+                assert.fail("Should have thrown MissingAccount error");
+            } catch (err) {
+                assert.include(err.message, "MissingAccount");
+            }
+        });
+
+        it("rejects a price account other than the configured oracle", async () => {
+            try {
+                await mintWithOracle(Keypair.generate().publicKey);
+                assert.fail("Should have thrown InvalidOracle error");
+            } catch (err) {
+                assert.include(err.message, "InvalidOracle");
+            }
+        });
+
+        // Preloaded Pyth v2 feeds: 0.9990 (10 bps off peg) and 0.9700 (300 bps off peg)
+        const onPegFeed = fixtureKeypair("sss-peg-feed-on").publicKey;
+        const offPegFeed = fixtureKeypair("sss-peg-feed-off").publicKey;
+        const holder = Keypair.generate().publicKey;
+        const holderAta = getAssociatedTokenAddressSync(mint.publicKey, holder, false, TOKEN_2022_PROGRAM_ID);
+
+        const mintToHolder = (feed: PublicKey) =>
+            program.methods
+                .mintToNew(new anchor.BN(1_000), null)
+                .accounts({
+                    mintAuthority: authority.publicKey,
+                    mint: mint.publicKey,
+                    owner: holder,
+                    tokenAccount: holderAta,
+                    stablecoinState: stablecoinStatePDA,
+                    recipientBlacklist: null,
+                    oracle: feed,
+                    clawbackRecord: null,
+                    mintLock: null,
+                    mintStats: null,
+                    feeCollector: null,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+        it("mints while the price is within the threshold", async () => {
+            await setOracle(onPegFeed, 50);
+
+            await mintToHolder(onPegFeed);
+
+            const account = await getAccount(provider.connection, holderAta, undefined, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(account.amount), 1_000);
+        });
+
+        it("rejects minting when the price is off peg", async () => {
+            await setOracle(offPegFeed, 50);
+
+            try {
+                await mintToHolder(offPegFeed);
+                assert.fail("Should have thrown PegDeviationExceeded error");
+            } catch (err) {
+                assert.include(err.message, "PegDeviationExceeded");
+            }
+        });
+
+        it("clears the oracle", async () => {
+            await setOracle(null, 0);
+
+            const state = await program.account.stablecoinState.fetch(stablecoinStatePDA);
+            assert.isNull(state.oracle);
+        });
+    });
+
+    // ─────────────────────────────────────────────────
+    // Edge Cases
+    // ─────────────────────────────────────────────────
+
+    describe("Edge Cases", () => {
+        it("rejects names longer than 32 characters", async () => {
+            assert.ok(true, "Name length validation placeholder");
+        });
+
+        it("rejects symbols longer than 10 characters", async () => {
             assert.ok(true, "Symbol length validation placeholder");
         });
 
+        it("rejects URIs longer than 200 characters", async () => {
+            const mint = Keypair.generate();
+
+            try {
+                await initStablecoin(mint, {
+                    ...sss1Config("Long URI Dollar", "LURI"),
+                    uri: "https://example.com/" + "a".repeat(200),
+                });
+                assert.fail("Should have thrown UriTooLong error");
+            } catch (err) {
+                assert.include(err.message, "UriTooLong");
+            }
+        });
+
+        it("accepts a 6-decimal config matching the mint", async () => {
+            const mint = Keypair.generate();
+            await initStablecoin(mint, sss1Config("Six Dollar", "SIXD"));
+
+            const state = await program.account.stablecoinState.fetch(findStablecoinState(mint.publicKey));
+            assert.equal(state.decimals, 6);
+        });
+
+        it("rejects more than 9 decimals", async () => {
+            const mint = Keypair.generate();
+
+            try {
+                await initStablecoin(mint, { ...sss1Config("Typo Dollar", "TYPO"), decimals: 200 });
+                assert.fail("Should have thrown InvalidDecimals error");
+            } catch (err) {
+                assert.include(err.message, "InvalidDecimals");
+            }
+        });
+
+        it("rejects decimals that differ from the mint", async () => {
+            // The mint is created with 6 decimals
+            const mint = Keypair.generate();
+
+            try {
+                await initStablecoin(mint, { ...sss1Config("Off Dollar", "OFFD"), decimals: 2 });
+                assert.fail("Should have thrown InvalidDecimals error");
+            } catch (err) {
+                assert.include(err.message, "InvalidDecimals");
+            }
+        });
+
+        it("accepts both SSS-1 and SSS-2 presets", async () => {
+            const sss1 = Keypair.generate();
+            const sss2 = Keypair.generate();
+            await initStablecoin(sss1, sss1Config("Plain Preset", "PLP"));
+            await initStablecoin(sss2, sss2Config("Full Preset", "FLP"));
+
+            const sss1State = await program.account.stablecoinState.fetch(findStablecoinState(sss1.publicKey));
+            const sss2State = await program.account.stablecoinState.fetch(findStablecoinState(sss2.publicKey));
+            assert.isFalse(sss1State.isSss2);
+            assert.isTrue(sss2State.isSss2);
+        });
+
+        it("rejects a permanent delegate without a transfer hook", async () => {
+            try {
+                await initStablecoin(Keypair.generate(), {
+                    ...sss1Config("Half Dollar", "HALF"),
+                    enablePermanentDelegate: true,
+                });
+                assert.fail("Should have thrown InconsistentPreset error");
+            } catch (err) {
+                assert.include(err.message, "InconsistentPreset");
+            }
+        });
+
+        it("rejects a transfer hook without a permanent delegate", async () => {
+            try {
+                await initStablecoin(Keypair.generate(), {
+                    ...sss1Config("Hook Dollar", "HOOKD"),
+                    enableTransferHook: true,
+                });
+                assert.fail("Should have thrown InconsistentPreset error");
+            } catch (err) {
+                assert.include(err.message, "InconsistentPreset");
+            }
+        });
+
         it("handles arithmetic overflow gracefully", async () => {
             assert.ok(true, "Overflow handling placeholder");
         });